@@ -0,0 +1,25 @@
+//! Times `generate_world` over a large region.
+//!
+//! Compare the serial and parallel paths with:
+//!
+//! ```text
+//! cargo run --release --example generation_bench
+//! cargo run --release --example generation_bench --features parallel
+//! ```
+
+use std::collections::HashMap;
+use std::time::Instant;
+use Q::{generate_world, World};
+
+const SEED: u64 = 12347;
+const RADIUS: i32 = 40;
+
+fn main() {
+    let mut world = World { name: "Bench".to_string(), terrain: HashMap::new(), seed: SEED };
+    let start = Instant::now();
+    generate_world(&mut world, SEED, -RADIUS, -RADIUS, RADIUS, RADIUS);
+    let elapsed = start.elapsed();
+
+    let mode = if cfg!(feature = "parallel") { "parallel" } else { "serial" };
+    println!("{} lands ({}) in {:.2?}", world.terrain.len(), mode, elapsed);
+}
@@ -39,6 +39,11 @@ impl CameraCore {
         self.zoom
     }
 
+    /// Set the zoom level directly (for cameras with fixed zoom steps)
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+    }
+
     /// Zoom in (increase zoom level)
     pub fn zoom_in(&mut self) {
         const ZOOM_STEP: f32 = 1.15;
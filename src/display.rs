@@ -1,41 +1,125 @@
-use crate::types::{Land, World};
+use std::io::IsTerminal;
+use colored::Colorize;
+use crate::render::Color;
+use crate::types::{Biome, Land, World};
+
+/// Marker printed for lands that haven't been generated yet
+pub const UNGENERATED_CHAR: &str = "⬛";
 
 pub fn print_world(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) {
+    print!("{}", format_world(world, x1, y1, x2, y2, false));
+}
+
+/// Like `print_world`, but tints each biome glyph with its color when stdout is a terminal.
+/// Piped output is identical to `print_world`.
+pub fn print_world_colored(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) {
+    let colored = std::io::stdout().is_terminal();
+    print!("{}", format_world(world, x1, y1, x2, y2, colored));
+}
+
+/// Like `print_world`, with land x coordinates above each column and y coordinates
+/// before each row. Columns are two characters wide, so they show the last digit of x.
+pub fn print_world_with_coords(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) {
+    print!("{}", format_world_with_coords(world, x1, y1, x2, y2));
+}
+
+/// Print the legend for `print_world` glyphs
+pub fn print_world_legend() {
+    print!("{}", format_world_legend());
+}
+
+pub fn print_land(land: &Land) {
+    print!("{}", format_land(land, false));
+}
+
+/// Like `print_land`, but tints each substrate/object glyph with its color when stdout is a terminal.
+/// Piped output is identical to `print_land`.
+pub fn print_land_colored(land: &Land) {
+    let colored = std::io::stdout().is_terminal();
+    print!("{}", format_land(land, colored));
+}
+
+/// Wrap `glyph` in an ANSI truecolor escape for `color`, or return it unchanged
+fn tint(glyph: &str, color: Color, colored: bool) -> String {
+    if colored {
+        let [r, g, b] = color.to_rgb8();
+        glyph.truecolor(r, g, b).to_string()
+    } else {
+        glyph.to_string()
+    }
+}
+
+/// Render the biome overview for a region, one glyph per land
+pub fn format_world(world: &World, x1: i32, y1: i32, x2: i32, y2: i32, colored: bool) -> String {
+    let mut out = String::new();
     // Iterate through rows (y coordinates)
     for y in y1..=y2 {
         // Iterate through columns (x coordinates) for this row
         for x in x1..=x2 {
             if let Some(land) = world.terrain.get(&(x, y)) {
-                print!("{}", land.center.to_char());
+                out += &tint(land.center.to_char(), land.center.to_color(), colored);
             } else {
                 // If land doesn't exist, print ungenerated marker
-                print!("⬛");
+                out += UNGENERATED_CHAR;
             }
         }
         // Newline after each row
-        println!();
+        out += "\n";
     }
+    out
 }
 
-pub fn print_land(land: &Land) {
-    println!("Center Biome: {:?}", land.center);
-    println!("Tiles (substrate or object):");
-    println!("  0 1 2 3 4 5 6 7");
+/// Render the biome overview with coordinate labels (see `print_world_with_coords`)
+pub fn format_world_with_coords(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) -> String {
+    let label_width = y1.to_string().len().max(y2.to_string().len());
+    let mut out = format!("x: {}..={}, y: {}..={}\n", x1, x2, y1, y2);
+
+    out += &" ".repeat(label_width + 1);
+    for x in x1..=x2 {
+        out += &format!("{:>2}", x.rem_euclid(10));
+    }
+    out += "\n";
+
+    let rows = format_world(world, x1, y1, x2, y2, false);
+    for (y, row) in (y1..=y2).zip(rows.lines()) {
+        out += &format!("{:>width$} {}\n", y, row, width = label_width);
+    }
+    out
+}
+
+/// One line per biome glyph plus the ungenerated marker
+pub fn format_world_legend() -> String {
+    let mut out = String::from("Legend:\n");
+    for biome in Biome::ALL {
+        out += &format!("  {} {:?}\n", biome.to_char(), biome);
+    }
+    out += &format!("  {} Ungenerated\n", UNGENERATED_CHAR);
+    out
+}
+
+/// Render a land's tiles with row/column labels
+pub fn format_land(land: &Land, colored: bool) -> String {
+    let mut out = String::new();
+    out += &format!("Center Biome: {:?}\n", land.center);
+    out += "Tiles (substrate or object):\n";
+    out += "  0 1 2 3 4 5 6 7\n";
     for (y, row) in land.tiles.iter().enumerate() {
-        print!("{} ", y);
+        out += &format!("{} ", y);
         for tile in row.iter() {
             // If there are objects, show only the object (not the substrate)
             if tile.objects.is_empty() {
-                print!("{}", tile.substrate.to_char());
+                out += &tint(&tile.substrate.to_char().to_string(), tile.substrate.to_color(), colored);
             } else {
                 // Show first object, or multiple if there are many
                 if tile.objects.len() == 1 {
-                    print!("{}", tile.objects[0].to_char());
+                    let object = &tile.objects[0];
+                    out += &tint(&object.to_char().to_string(), object.to_color(), colored);
                 } else {
-                    print!("🔴"); // Multiple objects (red circle - fixed width)
+                    out += "🔴"; // Multiple objects (red circle - fixed width)
                 }
             }
         }
-        println!();
+        out += "\n";
     }
+    out
 }
@@ -5,7 +5,9 @@
 //! - Mapping individual tiles within a land to their biome
 
 use ::noise::Perlin;
-use crate::types::Biome;
+use crate::render::Color;
+use crate::types::{Biome, Land, World};
+use super::config::GenerationConfig;
 use super::noise::{seed_offset, sample_noise, BIOME_SCALE, HEIGHT_SCALE};
 
 /// Holds the 9 biomes for a land, arranged in a 3x3 pattern.
@@ -33,6 +35,30 @@ pub struct LandBiomes {
     pub bottom_right: Biome,
 }
 
+impl Biome {
+    /// Color `t` of the way from `a`'s color to `b`'s (`t` is clamped to 0-1).
+    /// Only affects how biomes are drawn, never what is generated.
+    pub fn blend_color(a: &Biome, b: &Biome, t: f32) -> Color {
+        a.to_color().lerp(&b.to_color(), t)
+    }
+}
+
+impl From<&Land> for LandBiomes {
+    fn from(land: &Land) -> Self {
+        LandBiomes {
+            center: land.center.clone(),
+            top: land.top.clone(),
+            bottom: land.bottom.clone(),
+            left: land.left.clone(),
+            right: land.right.clone(),
+            top_left: land.top_left.clone(),
+            top_right: land.top_right.clone(),
+            bottom_left: land.bottom_left.clone(),
+            bottom_right: land.bottom_right.clone(),
+        }
+    }
+}
+
 /// Base discriminator for biome Perlin noise generators.
 /// Uses a prime number to ensure unique seed offsets for each biome.
 const BIOME_PERLIN_DISCRIMINATOR_BASE: u64 = 10007; // Prime number
@@ -48,18 +74,8 @@ const MOUNTAIN_DISCRIMINATOR: u64 = 5 * BIOME_PERLIN_DISCRIMINATOR_BASE;
 /// Discriminator for height Perlin noise generator.
 const HEIGHT_DISCRIMINATOR: u64 = 6 * BIOME_PERLIN_DISCRIMINATOR_BASE;
 
-/// Base bias values for each biome.
-/// These represent the inherent likelihood of each biome appearing.
-/// Mountain and Lake biases are reduced since they also get height-based adjustments.
-const LAKE_BIAS: f64 = 0.05;      // Reduced from base since low height boosts lakes
-const MEADOW_BIAS: f64 = 0.0;     // Neutral bias
-const PLAINS_BIAS: f64 = 0.0;     // Neutral bias
-const FOREST_BIAS: f64 = 0.1;     // Slight preference for forests
-const MOUNTAIN_BIAS: f64 = 0.05;  // Reduced from base since high height boosts mountains
-
-/// Strength of height influence on biome selection.
-/// Higher values mean height has more impact on biome determination.
-const HEIGHT_INFLUENCE: f64 = 0.3;
+/// Discriminator for aridity Perlin noise (decides where grassland turns to desert).
+const ARIDITY_DISCRIMINATOR: u64 = 7 * BIOME_PERLIN_DISCRIMINATOR_BASE;
 
 /// Determines which biome exists at a given biome-coordinate.
 ///
@@ -67,18 +83,24 @@ const HEIGHT_INFLUENCE: f64 = 0.3;
 /// Perlin function. Samples all biome Perlin functions and height at the
 /// location, then combines them with biome-specific biases and height adjustments.
 /// Higher heights boost mountain likelihood, lower heights boost lake likelihood.
-/// Returns the biome with the highest final value.
+/// Returns the biome with the highest final value, except that Meadow and Plains
+/// turn into Desert inside the band where aridity noise is high.
 ///
 /// Note: These are biome coordinates, not land coordinates.
 /// Use `calculate_land_biomes` to get the 9 biomes for a land.
 pub fn determine_biome(x: i32, y: i32, seed: u64) -> Biome {
+    determine_biome_with_config(x, y, seed, &GenerationConfig::default())
+}
+
+/// `determine_biome` using the biases, height influence, and cutoffs from `config`.
+/// Lakes and mountains whose final value falls below their cutoff are never selected.
+pub fn determine_biome_with_config(x: i32, y: i32, seed: u64, config: &GenerationConfig) -> Biome {
     // Create Perlin instances for each biome with unique seed offsets
     let lake_perlin = Perlin::new((seed.wrapping_add(LAKE_DISCRIMINATOR)) as u32);
     let meadow_perlin = Perlin::new((seed.wrapping_add(MEADOW_DISCRIMINATOR)) as u32);
     let plains_perlin = Perlin::new((seed.wrapping_add(PLAINS_DISCRIMINATOR)) as u32);
     let forest_perlin = Perlin::new((seed.wrapping_add(FOREST_DISCRIMINATOR)) as u32);
     let mountain_perlin = Perlin::new((seed.wrapping_add(MOUNTAIN_DISCRIMINATOR)) as u32);
-    let height_perlin = Perlin::new((seed.wrapping_add(HEIGHT_DISCRIMINATOR)) as u32);
     
     // Sample all biome Perlin functions and height at this location
     let offset = seed_offset(seed, 0);
@@ -87,40 +109,54 @@ pub fn determine_biome(x: i32, y: i32, seed: u64) -> Biome {
     let plains_value = sample_noise(&plains_perlin, x as f64, y as f64, BIOME_SCALE, offset);
     let forest_value = sample_noise(&forest_perlin, x as f64, y as f64, BIOME_SCALE, offset);
     let mountain_value = sample_noise(&mountain_perlin, x as f64, y as f64, BIOME_SCALE, offset);
-    let height = sample_noise(&height_perlin, x as f64, y as f64, HEIGHT_SCALE, offset);
+    let height = sample_height(x as f64, y as f64, seed);
     
     // Calculate final values: base noise + bias + height adjustment
     // Higher heights boost mountains, lower heights boost lakes
-    let lake_final = lake_value + LAKE_BIAS + (-height * HEIGHT_INFLUENCE);
-    let meadow_final = meadow_value + MEADOW_BIAS;
-    let plains_final = plains_value + PLAINS_BIAS;
-    let forest_final = forest_value + FOREST_BIAS;
-    let mountain_final = mountain_value + MOUNTAIN_BIAS + (height * HEIGHT_INFLUENCE);
+    let lake_final = lake_value + config.lake_bias + (-height * config.height_influence);
+    let meadow_final = meadow_value + config.meadow_bias;
+    let plains_final = plains_value + config.plains_bias;
+    let forest_final = forest_value + config.forest_bias;
+    let mountain_final = mountain_value + config.mountain_bias + (height * config.height_influence);
     
-    // Find the biome with the highest final value
+    // Find the eligible biome with the highest final value
     // In case of ties, prefer biomes in enum order (Lake < Meadow < Plains < Forest < Mountain)
-    let mut max_value = lake_final;
-    let mut selected_biome = Biome::Lake;
+    let candidates = [
+        (lake_final, lake_final >= config.lake_cutoff, Biome::Lake),
+        (meadow_final, true, Biome::Meadow),
+        (plains_final, true, Biome::Plains),
+        (forest_final, true, Biome::Forest),
+        (mountain_final, mountain_final >= config.mountain_cutoff, Biome::Mountain),
+    ];
     
-    if meadow_final > max_value {
-        max_value = meadow_final;
-        selected_biome = Biome::Meadow;
+    let mut selected: Option<(f64, Biome)> = None;
+    for (value, eligible, biome) in candidates {
+        if eligible && selected.as_ref().is_none_or(|(max_value, _)| value > *max_value) {
+            selected = Some((value, biome));
+        }
     }
-    if plains_final > max_value {
-        max_value = plains_final;
-        selected_biome = Biome::Plains;
-    }
-    if forest_final > max_value {
-        max_value = forest_final;
-        selected_biome = Biome::Forest;
-    }
-    if mountain_final > max_value {
-        selected_biome = Biome::Mountain;
+    
+    let selected_biome = selected.map(|(_, biome)| biome).unwrap_or(Biome::Meadow);
+    
+    // Deserts replace dry grassland rather than competing with lakes, forests, or mountains
+    if matches!(selected_biome, Biome::Meadow | Biome::Plains) {
+        let aridity_perlin = Perlin::new((seed.wrapping_add(ARIDITY_DISCRIMINATOR)) as u32);
+        let aridity = sample_noise(&aridity_perlin, x as f64, y as f64, BIOME_SCALE, offset);
+        if aridity >= config.desert_aridity_min {
+            return Biome::Desert;
+        }
     }
     
     selected_biome
 }
 
+/// Samples the height field at (possibly fractional) biome coordinates.
+/// Positive values favor mountains, negative values favor lakes.
+pub(super) fn sample_height(x: f64, y: f64, seed: u64) -> f64 {
+    let height_perlin = Perlin::new((seed.wrapping_add(HEIGHT_DISCRIMINATOR)) as u32);
+    sample_noise(&height_perlin, x, y, HEIGHT_SCALE, seed_offset(seed, 0))
+}
+
 /// Calculates all 9 biomes for a land using biome sub-coordinates.
 ///
 /// # Formula
@@ -144,6 +180,16 @@ pub fn determine_biome(x: i32, y: i32, seed: u64) -> Biome {
 /// center biome coord = (-8, -10)
 /// ```
 pub fn calculate_land_biomes(land_x: i32, land_y: i32, seed: u64) -> LandBiomes {
+    calculate_land_biomes_with_config(land_x, land_y, seed, &GenerationConfig::default())
+}
+
+/// `calculate_land_biomes` using `config` for every biome lookup.
+pub fn calculate_land_biomes_with_config(
+    land_x: i32,
+    land_y: i32,
+    seed: u64,
+    config: &GenerationConfig,
+) -> LandBiomes {
     let x_left   = 2 * land_x - 1;
     let x_center = 2 * land_x;
     let x_right  = 2 * land_x + 1;
@@ -153,16 +199,55 @@ pub fn calculate_land_biomes(land_x: i32, land_y: i32, seed: u64) -> LandBiomes
     let y_bottom = 2 * land_y + 1;
     
     LandBiomes {
-        top_left:     determine_biome(x_left,   y_top,    seed),
-        top:          determine_biome(x_center, y_top,    seed),
-        top_right:    determine_biome(x_right,  y_top,    seed),
-        left:         determine_biome(x_left,   y_center, seed),
-        center:       determine_biome(x_center, y_center, seed),
-        right:        determine_biome(x_right,  y_center, seed),
-        bottom_left:  determine_biome(x_left,   y_bottom, seed),
-        bottom:       determine_biome(x_center, y_bottom, seed),
-        bottom_right: determine_biome(x_right,  y_bottom, seed),
+        top_left:     determine_biome_with_config(x_left,   y_top,    seed, config),
+        top:          determine_biome_with_config(x_center, y_top,    seed, config),
+        top_right:    determine_biome_with_config(x_right,  y_top,    seed, config),
+        left:         determine_biome_with_config(x_left,   y_center, seed, config),
+        center:       determine_biome_with_config(x_center, y_center, seed, config),
+        right:        determine_biome_with_config(x_right,  y_center, seed, config),
+        bottom_left:  determine_biome_with_config(x_left,   y_bottom, seed, config),
+        bottom:       determine_biome_with_config(x_center, y_bottom, seed, config),
+        bottom_right: determine_biome_with_config(x_right,  y_bottom, seed, config),
+    }
+}
+
+/// Compares the slots two neighbouring lands share, in (land, neighbour) order.
+type SeamCheck = fn(&Land, &Land) -> bool;
+
+/// Finds pairs of neighbouring lands whose shared edge or corner biomes disagree.
+///
+/// Because `calculate_land_biomes` derives every slot from biome coordinates, a land's
+/// right edge is the same coordinate as its east neighbour's left edge, and a corner is
+/// shared by all four lands that meet there. Lands loaded from disk or edited in place
+/// can break that; this reports each offending pair once as (land, neighbour), with
+/// the neighbour east, south, south-east, or south-west of the land, sorted by (y, x).
+/// Missing neighbours are skipped.
+pub fn verify_biome_seams(world: &World) -> Vec<((i32, i32), (i32, i32))> {
+    let mut coords: Vec<(i32, i32)> = world.terrain.keys().copied().collect();
+    coords.sort_by_key(|&(x, y)| (y, x));
+
+    let mut violations = Vec::new();
+    for (x, y) in coords {
+        let land = &world.terrain[&(x, y)];
+        let neighbours: [((i32, i32), SeamCheck); 4] = [
+            ((x + 1, y), |a, b| {
+                a.right == b.left && a.top_right == b.top_left && a.bottom_right == b.bottom_left
+            }),
+            ((x, y + 1), |a, b| {
+                a.bottom == b.top && a.bottom_left == b.top_left && a.bottom_right == b.top_right
+            }),
+            ((x + 1, y + 1), |a, b| a.bottom_right == b.top_left),
+            ((x - 1, y + 1), |a, b| a.bottom_left == b.top_right),
+        ];
+        for (neighbour, seam_matches) in neighbours {
+            if let Some(other) = world.terrain.get(&neighbour)
+                && !seam_matches(land, other)
+            {
+                violations.push(((x, y), neighbour));
+            }
+        }
     }
+    violations
 }
 
 /// Gets the biome for a specific tile within a land.
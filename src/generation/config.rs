@@ -0,0 +1,105 @@
+//! Tunable parameters for world generation.
+//!
+//! `GenerationConfig::default()` reproduces the built-in generator exactly, so
+//! worlds made without a config are unchanged. Adjusting the biome values shifts
+//! the biome mix (e.g. a water-heavy or mountain-heavy world); the substrate and
+//! object values control what appears inside each biome.
+
+/// Parameters for biome selection, substrate thresholds, and object densities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationConfig {
+    // --- Biome selection ---
+    /// Added to each biome's noise value before picking the highest
+    pub lake_bias: f64,
+    pub meadow_bias: f64,
+    pub plains_bias: f64,
+    pub forest_bias: f64,
+    pub mountain_bias: f64,
+    /// How strongly height boosts mountains (high) and lakes (low)
+    pub height_influence: f64,
+    /// Minimum final score a lake needs to be selected (NEG_INFINITY = no cutoff)
+    pub lake_cutoff: f64,
+    /// Minimum final score a mountain needs to be selected (NEG_INFINITY = no cutoff)
+    pub mountain_cutoff: f64,
+    /// Meadow/Plains become Desert where aridity noise is at least this value (> 1.0 disables deserts)
+    pub desert_aridity_min: f64,
+
+    // --- Substrate thresholds (compared against substrate noise in -1..1) ---
+    /// Meadow tiles are dirt below this value, grass otherwise
+    pub meadow_dirt_below: f64,
+    /// Forest tiles are dirt below this value
+    pub forest_dirt_below: f64,
+    /// Non-dirt forest tiles become brush above this brush-noise value
+    pub forest_brush_above: f64,
+    /// Rough fraction of plains tiles that are grass (0..1); the rest are dirt.
+    /// Tiles are grass where substrate noise is at least `1 - 2 * plains_grass_ratio`
+    pub plains_grass_ratio: f64,
+    /// Mountain tiles are stone below this value, dirt otherwise
+    pub mountain_stone_below: f64,
+    /// Desert tiles are sand, with stone outcrops above this value
+    pub desert_stone_above: f64,
+
+    // --- Object densities (fraction of tiles, 0..1) ---
+    pub lake_rock_density: f64,
+    pub meadow_tree_density: f64,
+    pub meadow_other_density: f64,
+    pub forest_tree_density: f64,
+    pub forest_other_density: f64,
+    pub plains_tree_density: f64,
+    pub plains_other_density: f64,
+    /// Fraction of grass plains tiles that become brush (scattered bushes)
+    pub plains_brush_density: f64,
+    pub mountain_rock_density: f64,
+    pub mountain_tree_density: f64,
+    pub desert_rock_density: f64,
+    /// Chance for each empty tile next to a tree to get a stick
+    pub stick_near_tree_density: f64,
+    /// Fraction of Mountain-biome or Stone tiles with ore buried underneath
+    pub underground_ore_density: f64,
+    /// Let trees drop sticks across land borders.
+    ///
+    /// Substrate and per-tile object noise already use global coordinates, but the
+    /// sticks-near-trees pass only looks inside a land, so border tiles get about half
+    /// the sticks of interior ones. Enabling this removes that seam at the cost of
+    /// regenerating neighbouring border tiles during generation, and changes the output
+    /// of existing seeds along land borders, so it is off by default.
+    pub seamless_objects: bool,
+}
+
+impl Default for GenerationConfig {
+    fn default() -> Self {
+        Self {
+            lake_bias: 0.05,
+            meadow_bias: 0.0,
+            plains_bias: 0.0,
+            forest_bias: 0.1,
+            mountain_bias: 0.05,
+            height_influence: 0.3,
+            lake_cutoff: f64::NEG_INFINITY,
+            mountain_cutoff: f64::NEG_INFINITY,
+            desert_aridity_min: 0.45,
+
+            meadow_dirt_below: -0.8,
+            forest_dirt_below: -0.4,
+            forest_brush_above: 0.2,
+            plains_grass_ratio: 0.5,
+            mountain_stone_below: 0.6,
+            desert_stone_above: 0.75,
+
+            lake_rock_density: 0.02,
+            meadow_tree_density: 0.015,
+            meadow_other_density: 0.025,
+            forest_tree_density: 0.4,
+            forest_other_density: 0.03,
+            plains_tree_density: 0.02,
+            plains_other_density: 0.03,
+            plains_brush_density: 0.08,
+            mountain_rock_density: 0.03,
+            mountain_tree_density: 0.02,
+            desert_rock_density: 0.01,
+            stick_near_tree_density: 0.02,
+            underground_ore_density: 0.2,
+            seamless_objects: false,
+        }
+    }
+}
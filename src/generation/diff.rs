@@ -0,0 +1,119 @@
+//! Structural comparison of two worlds.
+//!
+//! Useful when changing the generator: instead of a single golden hash that
+//! either matches or doesn't, `diff_worlds` reports which lands and which tiles
+//! moved. Results are sorted by (y, x) so output is stable between runs.
+
+use crate::types::{Land, World};
+
+/// A single tile that differs between two versions of the same land.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileDiff {
+    /// Tile coordinates within the land (0-7)
+    pub tile_x: usize,
+    pub tile_y: usize,
+    pub substrate_changed: bool,
+    pub objects_changed: bool,
+}
+
+/// Differences for a land present in both worlds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LandDiff {
+    pub coords: (i32, i32),
+    /// Any of the 9 biome slots differ
+    pub biomes_changed: bool,
+    pub tiles: Vec<TileDiff>,
+}
+
+/// Result of `diff_worlds`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorldDiff {
+    /// Lands generated in `a` but not in `b`
+    pub only_in_a: Vec<(i32, i32)>,
+    /// Lands generated in `b` but not in `a`
+    pub only_in_b: Vec<(i32, i32)>,
+    /// Shared lands with at least one differing biome or tile
+    pub changed_lands: Vec<LandDiff>,
+}
+
+impl WorldDiff {
+    /// True if both worlds have the same lands with identical contents
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.changed_lands.is_empty()
+    }
+
+    /// Coordinates of every land that appears in the diff, sorted by (y, x)
+    pub fn land_coords(&self) -> Vec<(i32, i32)> {
+        let mut coords: Vec<(i32, i32)> = self
+            .only_in_a
+            .iter()
+            .chain(&self.only_in_b)
+            .copied()
+            .chain(self.changed_lands.iter().map(|land| land.coords))
+            .collect();
+        coords.sort_by_key(|&(x, y)| (y, x));
+        coords
+    }
+}
+
+/// Compares the generated terrain of two worlds. Names and seeds are ignored.
+pub fn diff_worlds(a: &World, b: &World) -> WorldDiff {
+    let mut diff = WorldDiff::default();
+
+    for (&coords, land_a) in &a.terrain {
+        match b.terrain.get(&coords) {
+            None => diff.only_in_a.push(coords),
+            Some(land_b) => {
+                if let Some(land_diff) = diff_lands(coords, land_a, land_b) {
+                    diff.changed_lands.push(land_diff);
+                }
+            }
+        }
+    }
+    diff.only_in_b = b
+        .terrain
+        .keys()
+        .filter(|coords| !a.terrain.contains_key(coords))
+        .copied()
+        .collect();
+
+    diff.only_in_a.sort_by_key(|&(x, y)| (y, x));
+    diff.only_in_b.sort_by_key(|&(x, y)| (y, x));
+    diff.changed_lands.sort_by_key(|land| (land.coords.1, land.coords.0));
+    diff
+}
+
+fn diff_lands(coords: (i32, i32), a: &Land, b: &Land) -> Option<LandDiff> {
+    let biomes_changed = [
+        (&a.center, &b.center),
+        (&a.top, &b.top),
+        (&a.bottom, &b.bottom),
+        (&a.left, &b.left),
+        (&a.right, &b.right),
+        (&a.top_left, &b.top_left),
+        (&a.top_right, &b.top_right),
+        (&a.bottom_left, &b.bottom_left),
+        (&a.bottom_right, &b.bottom_right),
+    ]
+    .iter()
+    .any(|(x, y)| x != y);
+
+    let mut tiles = Vec::new();
+    for tile_y in 0..8 {
+        for tile_x in 0..8 {
+            let tile_a = &a.tiles[tile_y][tile_x];
+            let tile_b = &b.tiles[tile_y][tile_x];
+            let substrate_changed = tile_a.substrate != tile_b.substrate;
+            let objects_changed = tile_a.objects != tile_b.objects;
+            if substrate_changed || objects_changed {
+                tiles.push(TileDiff { tile_x, tile_y, substrate_changed, objects_changed });
+            }
+        }
+    }
+
+    if biomes_changed || !tiles.is_empty() {
+        Some(LandDiff { coords, biomes_changed, tiles })
+    } else {
+        None
+    }
+}
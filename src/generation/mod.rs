@@ -1,17 +1,22 @@
 //! Terrain generation for the world.
 //!
 //! This module handles procedural generation of lands, including:
-//! - Biome determination (Lake, Meadow, Forest, Mountain)
-//! - Substrate generation (Water, Grass, Dirt, Stone, Brush)
+//! - Biome determination (Lake, Meadow, Plains, Forest, Mountain, Desert)
+//! - Substrate generation (Water, Grass, Dirt, Stone, Brush, Sand)
 //! - Object placement (Trees, Rocks, Sticks)
+//! - Underground ore under mountains and stone
 //!
 //! # Architecture
 //!
 //! ```text
 //! mod.rs       - Public API: generate_world, tile generation per biome
 //! ├── noise.rs - Noise utilities, seed offsets, constants
-//! ├── biome.rs - Biome determination and tile-to-biome mapping
-//! └── objects.rs - Object spawning rules per biome
+//! ├── biome.rs - Biome determination, tile-to-biome mapping, seam checks
+//! ├── objects.rs - Object spawning rules per biome
+//! ├── rivers.rs - River carving post-process (carve_rivers)
+//! ├── diff.rs - Structural world comparison (diff_worlds)
+//! ├── stats.rs - Aggregate region counts (region_stats)
+//! └── config.rs - GenerationConfig: biome cutoffs, substrate thresholds, object densities
 //! ```
 //!
 //! # Coordinate Systems
@@ -24,13 +29,26 @@
 mod noise;
 mod biome;
 mod objects;
+mod rivers;
+mod config;
+mod diff;
+mod stats;
 
+use std::collections::HashMap;
 use ::noise::Perlin;
-use crate::types::{Biome, Land, Substrate, Tile, World};
+use crate::types::{Biome, Land, Object, Substrate, Tile, World};
 use noise::{seed_offset, sample_noise, SUBSTRATE_SCALE};
 
 // Re-export public items
-pub use biome::{LandBiomes, calculate_land_biomes, determine_biome, get_tile_biome};
+pub use biome::{
+    LandBiomes, calculate_land_biomes, calculate_land_biomes_with_config, determine_biome,
+    determine_biome_with_config, get_tile_biome, verify_biome_seams,
+};
+pub use config::GenerationConfig;
+pub use noise::seed_from_str;
+pub use rivers::carve_rivers;
+pub use diff::{LandDiff, TileDiff, WorldDiff, diff_worlds};
+pub use stats::{RegionStats, region_stats};
 
 /// Seed offset for the substrate Perlin noise generator.
 /// Uses a prime to ensure substrate patterns differ from biome patterns.
@@ -48,40 +66,51 @@ const BRUSH_SCALE: f64 = 0.15; // Larger features than substrate scale (0.4)
 /// Discriminator for brush-specific noise (separate from substrate noise).
 const BRUSH_DISCRIMINATOR: u64 = 7 * BIOME_DISCRIMINATOR_BASE;
 
+/// Discriminator for the underground ore roll (independent of surface objects).
+const ORE_DISCRIMINATOR: u64 = 8 * BIOME_DISCRIMINATOR_BASE;
+
+/// Splits a global tile coordinate into (land coordinate, (tile_x, tile_y)).
+/// Uses floor division so negative coordinates map into the correct land.
+pub(crate) fn split_global(global_x: i32, global_y: i32) -> ((i32, i32), (usize, usize)) {
+    let land = (global_x.div_euclid(8), global_y.div_euclid(8));
+    let tile = (global_x.rem_euclid(8) as usize, global_y.rem_euclid(8) as usize);
+    (land, tile)
+}
+
 /// Generates a tile for Lake biome.
-fn generate_lake_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
+fn generate_lake_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
     let substrate = Substrate::Water;
-    let objects = objects::generate_lake_objects(seed, global_x, global_y);
-    Tile { substrate, objects }
+    let objects = objects::generate_lake_objects(seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
 }
 
 /// Generates a tile for Meadow biome.
-fn generate_meadow_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
+fn generate_meadow_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
     let perlin = Perlin::new(seed.wrapping_add(SUBSTRATE_SEED_OFFSET) as u32);
     let offset = seed_offset(seed, BIOME_DISCRIMINATOR_BASE); // Meadow discriminator = 1 * BASE
     let noise = sample_noise(&perlin, global_x as f64, global_y as f64, SUBSTRATE_SCALE, offset);
     
-    let substrate = if noise < -0.8 { 
+    let substrate = if noise < config.meadow_dirt_below { 
         Substrate::Dirt 
     } else { 
         Substrate::Grass 
     };
     
-    let objects = objects::generate_meadow_objects(&substrate, seed, global_x, global_y);
-    Tile { substrate, objects }
+    let objects = objects::generate_meadow_objects(&substrate, seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
 }
 
 /// Generates a tile for Forest biome.
 /// Uses a two-stage approach: first determines dirt vs grass/brush using normal scale,
 /// then uses a larger-scale noise to determine brush patches within grass/brush areas.
-fn generate_forest_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
+fn generate_forest_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
     // Stage 1: Determine dirt vs grass/brush using normal substrate scale
     let substrate_perlin = Perlin::new(seed.wrapping_add(SUBSTRATE_SEED_OFFSET) as u32);
     let substrate_offset = seed_offset(seed, 2 * BIOME_DISCRIMINATOR_BASE); // Forest discriminator = 2 * BASE
     let substrate_noise = sample_noise(&substrate_perlin, global_x as f64, global_y as f64, SUBSTRATE_SCALE, substrate_offset);
     
     // First determine if it's dirt or grass/brush area
-    let substrate = if substrate_noise < -0.4 {
+    let substrate = if substrate_noise < config.forest_dirt_below {
         Substrate::Dirt
     } else {
         // Stage 2: For grass/brush areas, use larger-scale noise to determine brush patches
@@ -91,62 +120,97 @@ fn generate_forest_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
         let brush_noise = sample_noise(&brush_perlin, global_x as f64, global_y as f64, BRUSH_SCALE, brush_offset);
         
         // Use the brush noise to determine if this grass/brush area becomes brush
-        // Threshold of 0.2 maintains similar brush frequency but with larger patches
-        if brush_noise > 0.2 {
+        // Default threshold of 0.2 maintains similar brush frequency but with larger patches
+        if brush_noise > config.forest_brush_above {
             Substrate::Brush
         } else {
             Substrate::Grass
         }
     };
     
-    let objects = objects::generate_forest_objects(&substrate, seed, global_x, global_y);
-    Tile { substrate, objects }
+    let objects = objects::generate_forest_objects(&substrate, seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
 }
 
 /// Generates a tile for Plains biome.
-/// Plains are mostly dirt with some grass patches.
-fn generate_plains_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
+/// Plains mix dirt and grass clumps, with occasional single brush tiles (bushes) on the grass.
+fn generate_plains_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
     let perlin = Perlin::new(seed.wrapping_add(SUBSTRATE_SEED_OFFSET) as u32);
     let offset = seed_offset(seed, 4 * BIOME_DISCRIMINATOR_BASE); // Plains discriminator = 4 * BASE
     let noise = sample_noise(&perlin, global_x as f64, global_y as f64, SUBSTRATE_SCALE, offset);
     
-    // Mostly dirt (below 0.45), with some grass patches (above 0.45)
-    let substrate = if noise < 0.45 {
+    // Treat noise as roughly uniform in -1..1 so the ratio maps onto a threshold
+    let substrate = if noise < 1.0 - 2.0 * config.plains_grass_ratio {
         Substrate::Dirt
+    } else if objects::tile_random_value(seed.wrapping_add(BRUSH_DISCRIMINATOR), global_x, global_y)
+        < config.plains_brush_density
+    {
+        // Bushes are sparse and independent per tile, unlike forest brush patches
+        Substrate::Brush
     } else {
         Substrate::Grass
     };
     
-    let objects = objects::generate_plains_objects(&substrate, seed, global_x, global_y);
-    Tile { substrate, objects }
+    let objects = objects::generate_plains_objects(&substrate, seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
 }
 
 /// Generates a tile for Mountain biome.
-fn generate_mountain_tile(global_x: i32, global_y: i32, seed: u64) -> Tile {
+fn generate_mountain_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
     let perlin = Perlin::new(seed.wrapping_add(SUBSTRATE_SEED_OFFSET) as u32);
     let offset = seed_offset(seed, 3 * BIOME_DISCRIMINATOR_BASE); // Mountain discriminator = 3 * BASE
     let noise = sample_noise(&perlin, global_x as f64, global_y as f64, SUBSTRATE_SCALE, offset);
     
-    let substrate = if noise < 0.6 {
+    let substrate = if noise < config.mountain_stone_below {
         Substrate::Stone
     } else {
         Substrate::Dirt
     };
     
-    let objects = objects::generate_mountain_objects(&substrate, seed, global_x, global_y);
-    Tile { substrate, objects }
+    let objects = objects::generate_mountain_objects(&substrate, seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
+}
+
+/// Generates a tile for Desert biome.
+/// Deserts are mostly sand with occasional stone outcrops.
+fn generate_desert_tile(global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
+    let perlin = Perlin::new(seed.wrapping_add(SUBSTRATE_SEED_OFFSET) as u32);
+    let offset = seed_offset(seed, 5 * BIOME_DISCRIMINATOR_BASE); // Desert discriminator = 5 * BASE
+    let noise = sample_noise(&perlin, global_x as f64, global_y as f64, SUBSTRATE_SCALE, offset);
+    
+    let substrate = if noise > config.desert_stone_above {
+        Substrate::Stone
+    } else {
+        Substrate::Sand
+    };
+    
+    let objects = objects::generate_desert_objects(seed, global_x, global_y, config);
+    Tile { substrate, objects, underground: None }
 }
 
 /// Generates terrain tiles for a land based on its 9 biomes.
 ///
 /// Uses the biome at each tile position to determine substrate and objects.
-/// Substrate noise is globally continuous; object noise is land-local.
-/// After initial object placement, adds sticks deterministically near trees.
+/// Substrate and per-tile object noise are globally continuous. After initial object
+/// placement, adds sticks deterministically near trees; that pass is land-local unless
+/// `GenerationConfig::seamless_objects` is set. A final pass buries ore under
+/// Mountain-biome and Stone tiles.
 pub fn generate_land_terrain(
     land_x: i32,
     land_y: i32,
     biomes: &LandBiomes,
     seed: u64,
+) -> [[Tile; 8]; 8] {
+    generate_land_terrain_with_config(land_x, land_y, biomes, seed, &GenerationConfig::default())
+}
+
+/// `generate_land_terrain` using the substrate thresholds and object densities from `config`.
+pub fn generate_land_terrain_with_config(
+    land_x: i32,
+    land_y: i32,
+    biomes: &LandBiomes,
+    seed: u64,
+    config: &GenerationConfig,
 ) -> [[Tile; 8]; 8] {
     // First pass: Generate all tiles with substrate and initial objects
     let mut tiles = std::array::from_fn(|tile_y| {
@@ -154,35 +218,90 @@ pub fn generate_land_terrain(
             let biome = get_tile_biome(biomes, tile_x, tile_y);
             let global_x = land_x * 8 + tile_x as i32;
             let global_y = land_y * 8 + tile_y as i32;
-            
-            match biome {
-                Biome::Lake => generate_lake_tile(global_x, global_y, seed),
-                Biome::Meadow => generate_meadow_tile(global_x, global_y, seed),
-                Biome::Plains => generate_plains_tile(global_x, global_y, seed),
-                Biome::Forest => generate_forest_tile(global_x, global_y, seed),
-                Biome::Mountain => generate_mountain_tile(global_x, global_y, seed),
-            }
+            generate_tile(biome, global_x, global_y, seed, config)
         })
     });
     
     // Second pass: Add sticks deterministically near trees
-    objects::add_sticks_near_trees(&mut tiles, seed, land_x, land_y);
+    if config.seamless_objects {
+        // Trees just across the border come from regenerating the neighbour's tile
+        let mut neighbor_biomes: HashMap<(i32, i32), LandBiomes> = HashMap::new();
+        let tree_outside_land = |global_x: i32, global_y: i32| {
+            let (land, (tile_x, tile_y)) = split_global(global_x, global_y);
+            let biomes = neighbor_biomes
+                .entry(land)
+                .or_insert_with(|| calculate_land_biomes_with_config(land.0, land.1, seed, config));
+            let biome = get_tile_biome(biomes, tile_x, tile_y);
+            generate_tile(biome, global_x, global_y, seed, config)
+                .objects
+                .contains(&Object::Tree)
+        };
+        objects::add_sticks_near_trees_seamless(&mut tiles, seed, land_x, land_y, config, tree_outside_land);
+    } else {
+        objects::add_sticks_near_trees(&mut tiles, seed, land_x, land_y, config);
+    }
+    
+    // Third pass: Bury ore, rolled per global tile so it is continuous across lands
+    for (tile_y, row) in tiles.iter_mut().enumerate() {
+        for (tile_x, tile) in row.iter_mut().enumerate() {
+            let biome = get_tile_biome(biomes, tile_x, tile_y);
+            let global_x = land_x * 8 + tile_x as i32;
+            let global_y = land_y * 8 + tile_y as i32;
+            tile.underground = objects::generate_underground_object(
+                biome,
+                &tile.substrate,
+                seed.wrapping_add(ORE_DISCRIMINATOR),
+                global_x,
+                global_y,
+                config,
+            );
+        }
+    }
     
     tiles
 }
 
+/// Generates the substrate and initial objects for one tile of the given biome.
+fn generate_tile(biome: &Biome, global_x: i32, global_y: i32, seed: u64, config: &GenerationConfig) -> Tile {
+    match biome {
+        Biome::Lake => generate_lake_tile(global_x, global_y, seed, config),
+        Biome::Meadow => generate_meadow_tile(global_x, global_y, seed, config),
+        Biome::Plains => generate_plains_tile(global_x, global_y, seed, config),
+        Biome::Forest => generate_forest_tile(global_x, global_y, seed, config),
+        Biome::Mountain => generate_mountain_tile(global_x, global_y, seed, config),
+        Biome::Desert => generate_desert_tile(global_x, global_y, seed, config),
+    }
+}
+
 /// Generates a single land at the specified coordinates.
 /// Skips generation if the land already exists to preserve any dynamic changes.
 pub fn generate_land(world: &mut World, seed: u64, land_x: i32, land_y: i32) {
+    generate_land_with_config(world, seed, land_x, land_y, &GenerationConfig::default());
+}
+
+/// `generate_land` using `config` for biomes, substrates, and objects.
+pub fn generate_land_with_config(
+    world: &mut World,
+    seed: u64,
+    land_x: i32,
+    land_y: i32,
+    config: &GenerationConfig,
+) {
     // Skip if land already exists to preserve dynamic changes
     if world.terrain.contains_key(&(land_x, land_y)) {
         return;
     }
     
-    let biomes = calculate_land_biomes(land_x, land_y, seed);
-    let tiles = generate_land_terrain(land_x, land_y, &biomes, seed);
+    world.terrain.insert((land_x, land_y), build_land(seed, land_x, land_y, config));
+}
+
+/// Builds a land from scratch. Depends only on its arguments, so lands can be
+/// built in any order (or concurrently) with identical results.
+fn build_land(seed: u64, land_x: i32, land_y: i32, config: &GenerationConfig) -> Land {
+    let biomes = calculate_land_biomes_with_config(land_x, land_y, seed, config);
+    let tiles = generate_land_terrain_with_config(land_x, land_y, &biomes, seed, config);
     
-    let land = Land {
+    Land {
         tiles,
         center: biomes.center,
         top: biomes.top,
@@ -193,9 +312,7 @@ pub fn generate_land(world: &mut World, seed: u64, land_x: i32, land_y: i32) {
         top_right: biomes.top_right,
         bottom_left: biomes.bottom_left,
         bottom_right: biomes.bottom_right,
-    };
-    
-    world.terrain.insert((land_x, land_y), land);
+    }
 }
 
 /// Generates world terrain for a rectangular region of lands.
@@ -203,13 +320,58 @@ pub fn generate_land(world: &mut World, seed: u64, land_x: i32, land_y: i32) {
 /// Coordinates are inclusive: generates from (x1, y1) to (x2, y2).
 /// Skips lands that already exist to preserve any dynamic changes.
 pub fn generate_world(world: &mut World, seed: u64, x1: i32, y1: i32, x2: i32, y2: i32) {
+    generate_world_with_config(world, seed, x1, y1, x2, y2, &GenerationConfig::default());
+}
+
+/// `generate_world` using `config` for every generated land.
+///
+/// With the `parallel` feature this runs `generate_world_parallel_with_config`;
+/// the resulting terrain is identical either way.
+pub fn generate_world_with_config(
+    world: &mut World,
+    seed: u64,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    config: &GenerationConfig,
+) {
+    #[cfg(feature = "parallel")]
+    generate_world_parallel_with_config(world, seed, x1, y1, x2, y2, config);
+    #[cfg(not(feature = "parallel"))]
     for x in x1..=x2 {
         for y in y1..=y2 {
-            generate_land(world, seed, x, y);
+            generate_land_with_config(world, seed, x, y, config);
         }
     }
 }
 
+/// `generate_world_with_config`, building the missing lands concurrently on the
+/// rayon thread pool and inserting them once they're all done.
+/// Existing lands are skipped, exactly as in the serial version.
+#[cfg(feature = "parallel")]
+pub fn generate_world_parallel_with_config(
+    world: &mut World,
+    seed: u64,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    config: &GenerationConfig,
+) {
+    use rayon::prelude::*;
+
+    let missing: Vec<(i32, i32)> = (x1..=x2)
+        .flat_map(|x| (y1..=y2).map(move |y| (x, y)))
+        .filter(|coords| !world.terrain.contains_key(coords))
+        .collect();
+    let lands: Vec<((i32, i32), Land)> = missing
+        .into_par_iter()
+        .map(|(x, y)| ((x, y), build_land(seed, x, y, config)))
+        .collect();
+    world.terrain.extend(lands);
+}
+
 /// Initializes a world with the default generation area (-10 to 10).
 pub fn initialize_world(world: &mut World, seed: u64) {
     generate_world(world, seed, -10, -10, 10, 10);
@@ -219,19 +381,123 @@ pub fn initialize_world(world: &mut World, seed: u64) {
 /// Only generates lands that don't already exist, preserving any dynamic changes.
 /// Uses circular radius (Euclidean distance) instead of square radius.
 pub fn ensure_terrain_generated(world: &mut World, center_x: i32, center_y: i32, radius: f32) {
-    let radius_squared = radius * radius;
-    let radius_ceil = radius.ceil() as i32;
-    
-    // Generate each ungenerated land within circular radius
-    for dx in -radius_ceil..=radius_ceil {
-        for dy in -radius_ceil..=radius_ceil {
-            // Check if this coordinate is within the circular radius
-            let distance_squared = (dx * dx + dy * dy) as f32;
-            if distance_squared <= radius_squared {
-                let x = center_x + dx;
-                let y = center_y + dy;
-                generate_land(world, world.seed, x, y);
+    world.stream_chunks((center_x, center_y), radius);
+}
+
+/// True if `(x, y)` lies within the circular `radius` (in lands) of `center`.
+fn within_radius(center: (i32, i32), radius: f32, x: i32, y: i32) -> bool {
+    let dx = (x - center.0) as f32;
+    let dy = (y - center.1) as f32;
+    dx * dx + dy * dy <= radius * radius
+}
+
+impl World {
+    /// Looks up a tile by global tile coordinates (`land * 8 + tile`).
+    /// Negative coordinates use floor division, so (-1, -1) is tile (7, 7) of land (-1, -1).
+    /// Returns `None` if the containing land hasn't been generated.
+    pub fn tile_at_global(&self, global_x: i32, global_y: i32) -> Option<&Tile> {
+        let (land, (tile_x, tile_y)) = split_global(global_x, global_y);
+        self.terrain.get(&land).map(|land| &land.tiles[tile_y][tile_x])
+    }
+
+    /// Takes the underground object at global tile coordinates, leaving nothing behind.
+    /// Returns `None` if there is nothing buried there or the land hasn't been generated.
+    pub fn mine(&mut self, global_x: i32, global_y: i32) -> Option<Object> {
+        let (land, (tile_x, tile_y)) = split_global(global_x, global_y);
+        self.terrain.get_mut(&land)?.tiles[tile_y][tile_x].underground.take()
+    }
+
+    /// Removes the first object equal to `obj` from the tile at global tile coordinates,
+    /// generating the containing land from the stored `seed` if it doesn't exist yet.
+    /// Returns `false` if the tile had no such object.
+    ///
+    /// Generation never overwrites an existing land, so the removal survives later
+    /// calls like `extend_generated` (but not `unload_beyond`).
+    pub fn remove_object(&mut self, global_x: i32, global_y: i32, obj: &Object) -> bool {
+        let objects = &mut self.tile_mut_generating(global_x, global_y).objects;
+        match objects.iter().position(|o| o == obj) {
+            Some(index) => {
+                objects.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds `obj` to the tile at global tile coordinates, generating the containing
+    /// land from the stored `seed` if it doesn't exist yet.
+    pub fn add_object(&mut self, global_x: i32, global_y: i32, obj: Object) {
+        self.tile_mut_generating(global_x, global_y).objects.push(obj);
+    }
+
+    /// Mutable tile at global tile coordinates, generating its land first if needed.
+    fn tile_mut_generating(&mut self, global_x: i32, global_y: i32) -> &mut Tile {
+        let (land, (tile_x, tile_y)) = split_global(global_x, global_y);
+        generate_land(self, self.seed, land.0, land.1);
+        let land = self.terrain.get_mut(&land).expect("land was just generated");
+        &mut land.tiles[tile_y][tile_x]
+    }
+
+    /// The biome governing the tile at global tile coordinates (one of its land's 9 biomes).
+    /// Returns `None` if the containing land hasn't been generated.
+    pub fn biome_at_global(&self, global_x: i32, global_y: i32) -> Option<Biome> {
+        let (land, (tile_x, tile_y)) = split_global(global_x, global_y);
+        let biomes = LandBiomes::from(self.terrain.get(&land)?);
+        Some(get_tile_biome(&biomes, tile_x, tile_y).clone())
+    }
+
+    /// Generates the lands within `radius` of `center` that don't exist yet and
+    /// returns their coordinates (row by row), so a renderer knows what changed.
+    ///
+    /// As the center moves by a land, only the thin frontier entering the disc is
+    /// generated; existing lands (and any changes made to them) are left alone.
+    pub fn stream_chunks(&mut self, center: (i32, i32), radius: f32) -> Vec<(i32, i32)> {
+        let radius_ceil = radius.ceil() as i32;
+        let mut created = Vec::new();
+        
+        for y in center.1 - radius_ceil..=center.1 + radius_ceil {
+            for x in center.0 - radius_ceil..=center.0 + radius_ceil {
+                if within_radius(center, radius, x, y) && !self.terrain.contains_key(&(x, y)) {
+                    generate_land(self, self.seed, x, y);
+                    created.push((x, y));
+                }
             }
         }
+        
+        created
+    }
+
+    /// Generates the missing lands in (x1, y1)..=(x2, y2) from the world's stored
+    /// `seed` and returns their coordinates (row by row). Because the seed is saved
+    /// with the world, a loaded world extends exactly as the original would have.
+    pub fn extend_generated(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> Vec<(i32, i32)> {
+        let mut created = Vec::new();
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                if !self.terrain.contains_key(&(x, y)) {
+                    generate_land(self, self.seed, x, y);
+                    created.push((x, y));
+                }
+            }
+        }
+        created
+    }
+
+    /// Drops every land farther than `radius` from `center` to cap memory, returning
+    /// the removed coordinates. Unloaded lands regenerate from the seed later, so any
+    /// dynamic changes made to them are lost.
+    pub fn unload_beyond(&mut self, center: (i32, i32), radius: f32) -> Vec<(i32, i32)> {
+        let mut removed: Vec<(i32, i32)> = self
+            .terrain
+            .keys()
+            .copied()
+            .filter(|&(x, y)| !within_radius(center, radius, x, y))
+            .collect();
+        removed.sort_by_key(|&(x, y)| (y, x));
+        
+        for coord in &removed {
+            self.terrain.remove(coord);
+        }
+        removed
     }
 }
@@ -34,6 +34,20 @@ pub fn sample_noise(perlin: &Perlin, x: f64, y: f64, scale: f64, offset: (f64, f
     perlin.get([x * scale + offset.0, y * scale + offset.1])
 }
 
+/// Converts a human-friendly seed phrase (e.g. "myworld") into a numeric seed.
+///
+/// The hash is 64-bit FNV-1a over the string's UTF-8 bytes (offset basis
+/// `0xcbf29ce484222325`, prime `0x100000001b3`). It is part of the public
+/// contract: the same phrase must produce the same world in every version.
+pub fn seed_from_str(s: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    s.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
 /// Creates a land-specific seed for features that don't need cross-boundary continuity.
 /// Uses large primes to distribute seeds across the coordinate space.
 pub fn land_local_seed(base_seed: u64, land_x: i32, land_y: i32) -> u64 {
@@ -1,21 +1,22 @@
 //! Object generation based on biome type and substrate.
 //!
 //! Objects (trees, rocks, sticks) are spawned pseudo-randomly with biome-specific
-//! placement rates. Trees cannot grow on stone substrate.
+//! placement rates. Trees cannot grow on stone substrate. Ore is buried under
+//! Mountain-biome and Stone tiles.
 
-use crate::types::{Object, Substrate};
+use crate::types::{Biome, Object, Substrate};
+use super::config::GenerationConfig;
 
 /// Generates objects for Lake biome.
 pub fn generate_lake_objects(
     seed: u64,
     global_x: i32,
     global_y: i32,
+    config: &GenerationConfig,
 ) -> Vec<Object> {
-    const PLACEMENT_THRESHOLD: f64 = 0.02; // 2% of tiles (sparse)
-    
     let random_value = tile_random_value(seed, global_x, global_y);
     
-    if random_value < PLACEMENT_THRESHOLD {
+    if random_value < config.lake_rock_density {
         vec![Object::Rock]
     } else {
         Vec::new()
@@ -28,9 +29,10 @@ pub fn generate_meadow_objects(
     seed: u64,
     global_x: i32,
     global_y: i32,
+    config: &GenerationConfig,
 ) -> Vec<Object> {
-    const TREE_PLACEMENT_THRESHOLD: f64 = 0.015; // 1.5% of tiles (sparse)
-    const OTHER_PLACEMENT_THRESHOLD: f64 = 0.025; // 2.5% of tiles (sparse)
+    let tree_placement_threshold = config.meadow_tree_density;
+    let other_placement_threshold = config.meadow_other_density;
     
     let random_value = tile_random_value(seed, global_x, global_y);
     let object_type_value = tile_random_value(seed.wrapping_add(1), global_x, global_y);
@@ -39,12 +41,12 @@ pub fn generate_meadow_objects(
     let can_have_tree = matches!(substrate, Substrate::Grass | Substrate::Dirt);
     
     // Try to place a tree first if substrate is eligible
-    if can_have_tree && random_value < TREE_PLACEMENT_THRESHOLD {
+    if can_have_tree && random_value < tree_placement_threshold {
         return vec![Object::Tree];
     }
     
     // Otherwise, try to place rock or stick
-    if random_value < OTHER_PLACEMENT_THRESHOLD {
+    if random_value < other_placement_threshold {
         if object_type_value < 0.8 {
             vec![Object::Rock]
         } else {
@@ -61,9 +63,10 @@ pub fn generate_forest_objects(
     seed: u64,
     global_x: i32,
     global_y: i32,
+    config: &GenerationConfig,
 ) -> Vec<Object> {
-    const TREE_PLACEMENT_THRESHOLD: f64 = 0.4; // 40% of eligible tiles (dense forests)
-    const OTHER_PLACEMENT_THRESHOLD: f64 = 0.03; // 3% of tiles (sparse)
+    let tree_placement_threshold = config.forest_tree_density;
+    let other_placement_threshold = config.forest_other_density;
     
     let random_value = tile_random_value(seed, global_x, global_y);
     let object_type_value = tile_random_value(seed.wrapping_add(1), global_x, global_y);
@@ -76,12 +79,12 @@ pub fn generate_forest_objects(
     let rock_stick_value = tile_random_value(seed.wrapping_add(2), global_x, global_y);
     
     // Try to place a tree if substrate is eligible
-    if can_have_tree && tree_value < TREE_PLACEMENT_THRESHOLD {
+    if can_have_tree && tree_value < tree_placement_threshold {
         return vec![Object::Tree];
     }
     
     // Try to place rock or stick (independent of tree placement)
-    if rock_stick_value < OTHER_PLACEMENT_THRESHOLD {
+    if rock_stick_value < other_placement_threshold {
         if object_type_value < 0.75 {
             vec![Object::Rock]
         } else {
@@ -98,23 +101,25 @@ pub fn generate_plains_objects(
     seed: u64,
     global_x: i32,
     global_y: i32,
+    config: &GenerationConfig,
 ) -> Vec<Object> {
-    const TREE_PLACEMENT_THRESHOLD: f64 = 0.02; // 2% of eligible tiles (sparse)
-    const OTHER_PLACEMENT_THRESHOLD: f64 = 0.03; // 3% of tiles (sparse)
+    let tree_placement_threshold = config.plains_tree_density;
+    let other_placement_threshold = config.plains_other_density;
     
     let random_value = tile_random_value(seed, global_x, global_y);
     let object_type_value = tile_random_value(seed.wrapping_add(1), global_x, global_y);
     
-    // Check if tile is eligible for trees (grass or dirt only)
+    // Check if tile is eligible for trees (grass or dirt only).
+    // Plains brush tiles are single bushes, so they never hold a tree.
     let can_have_tree = matches!(substrate, Substrate::Grass | Substrate::Dirt);
     
     // Try to place a tree first if substrate is eligible
-    if can_have_tree && random_value < TREE_PLACEMENT_THRESHOLD {
+    if can_have_tree && random_value < tree_placement_threshold {
         return vec![Object::Tree];
     }
     
     // Otherwise, try to place rock or stick
-    if random_value < OTHER_PLACEMENT_THRESHOLD {
+    if random_value < other_placement_threshold {
         if object_type_value < 0.75 {
             vec![Object::Rock]
         } else {
@@ -131,9 +136,10 @@ pub fn generate_mountain_objects(
     seed: u64,
     global_x: i32,
     global_y: i32,
+    config: &GenerationConfig,
 ) -> Vec<Object> {
-    const ROCK_PLACEMENT_THRESHOLD: f64 = 0.03; // 3% of tiles (sparse)
-    const TREE_PLACEMENT_THRESHOLD: f64 = 0.02; // 2% of eligible tiles (sparse)
+    let rock_placement_threshold = config.mountain_rock_density;
+    let tree_placement_threshold = config.mountain_tree_density;
     
     let random_value = tile_random_value(seed, global_x, global_y);
     
@@ -141,18 +147,54 @@ pub fn generate_mountain_objects(
     let can_have_tree = matches!(substrate, Substrate::Dirt);
     
     // Try to place a tree first if substrate is dirt
-    if can_have_tree && random_value < TREE_PLACEMENT_THRESHOLD {
+    if can_have_tree && random_value < tree_placement_threshold {
         return vec![Object::Tree];
     }
     
     // Otherwise, try to place rock (rocks can spawn on stone or dirt)
-    if random_value < ROCK_PLACEMENT_THRESHOLD {
+    if random_value < rock_placement_threshold {
+        vec![Object::Rock]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Generates objects for Desert biome.
+/// Deserts never grow trees; only the occasional rock.
+pub fn generate_desert_objects(
+    seed: u64,
+    global_x: i32,
+    global_y: i32,
+    config: &GenerationConfig,
+) -> Vec<Object> {
+    let random_value = tile_random_value(seed, global_x, global_y);
+    
+    if random_value < config.desert_rock_density {
         vec![Object::Rock]
     } else {
         Vec::new()
     }
 }
 
+/// Generates the underground object for a tile: ore under Mountain-biome or Stone tiles.
+/// `seed` should already carry the ore discriminator so this roll is independent of
+/// surface objects.
+pub fn generate_underground_object(
+    biome: &Biome,
+    substrate: &Substrate,
+    seed: u64,
+    global_x: i32,
+    global_y: i32,
+    config: &GenerationConfig,
+) -> Option<Object> {
+    let can_have_ore = matches!(biome, Biome::Mountain) || matches!(substrate, Substrate::Stone);
+    if can_have_ore && tile_random_value(seed, global_x, global_y) < config.underground_ore_density {
+        Some(Object::Ore)
+    } else {
+        None
+    }
+}
+
 /// Adds sticks deterministically near trees in a land.
 ///
 /// For each tile containing a tree, checks nearby tiles (within 1 tile radius)
@@ -164,10 +206,8 @@ pub fn add_sticks_near_trees(
     seed: u64,
     land_x: i32,
     land_y: i32,
+    config: &GenerationConfig,
 ) {
-    // Seed offset for stick placement near trees (different from object placement)
-    const STICK_NEAR_TREE_SEED_OFFSET: u64 = 2000003;
-    
     // Check each tile for trees
     for tile_y in 0..8 {
         for tile_x in 0..8 {
@@ -203,20 +243,13 @@ pub fn add_sticks_near_trees(
                             let nearby_global_x = tree_global_x + dx;
                             let nearby_global_y = tree_global_y + dy;
                             
-                            // Deterministically decide if this nearby tile should have a stick
-                            // Include BOTH the tree's position AND the nearby tile's position in the seed
-                            // This ensures each tree-tile pair has independent randomness
-                            let tree_offset = (tree_global_x as u64)
-                                .wrapping_mul(0x517CC1B727220A95)
-                                .wrapping_add((tree_global_y as u64).wrapping_mul(0x5D6DCB8D5C20A2AB));
-                            let stick_value = tile_random_value(
-                                seed.wrapping_add(STICK_NEAR_TREE_SEED_OFFSET).wrapping_add(tree_offset),
-                                nearby_global_x,
-                                nearby_global_y,
+                            let stick_value = stick_near_tree_value(
+                                seed,
+                                (tree_global_x, tree_global_y),
+                                (nearby_global_x, nearby_global_y),
                             );
                             
-                            // 2% chance to place a stick near a tree (sparse)
-                            if stick_value < 0.02 {
+                            if stick_value < config.stick_near_tree_density {
                                 nearby_tile.objects.push(crate::types::Object::Stick);
                             }
                         }
@@ -227,11 +260,86 @@ pub fn add_sticks_near_trees(
     }
 }
 
+/// Seamless variant of `add_sticks_near_trees` that also sees trees in neighbouring lands.
+///
+/// Each empty tile checks all 8 global neighbours for trees; neighbours outside this
+/// land are answered by `tree_outside_land`, which has to regenerate that tile. Uses the
+/// same per tree-tile roll as the land-local version, so interior tiles come out identical
+/// and only border tiles can gain extra sticks.
+pub fn add_sticks_near_trees_seamless(
+    tiles: &mut [[crate::types::Tile; 8]; 8],
+    seed: u64,
+    land_x: i32,
+    land_y: i32,
+    config: &GenerationConfig,
+    mut tree_outside_land: impl FnMut(i32, i32) -> bool,
+) {
+    let has_tree = |tiles: &[[crate::types::Tile; 8]; 8], x: usize, y: usize| {
+        tiles[y][x].objects.contains(&Object::Tree)
+    };
+    
+    for tile_y in 0..8 {
+        for tile_x in 0..8 {
+            if !tiles[tile_y][tile_x].objects.is_empty() {
+                continue;
+            }
+            
+            let global_x = land_x * 8 + tile_x as i32;
+            let global_y = land_y * 8 + tile_y as i32;
+            
+            'trees: for dy in -1i32..=1 {
+                for dx in -1i32..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    
+                    let tree_x = tile_x as i32 + dx;
+                    let tree_y = tile_y as i32 + dy;
+                    let in_land = (0..8).contains(&tree_x) && (0..8).contains(&tree_y);
+                    let is_tree = if in_land {
+                        has_tree(tiles, tree_x as usize, tree_y as usize)
+                    } else {
+                        tree_outside_land(global_x + dx, global_y + dy)
+                    };
+                    
+                    if is_tree {
+                        let stick_value = stick_near_tree_value(
+                            seed,
+                            (global_x + dx, global_y + dy),
+                            (global_x, global_y),
+                        );
+                        if stick_value < config.stick_near_tree_density {
+                            tiles[tile_y][tile_x].objects.push(Object::Stick);
+                            break 'trees;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Deterministic roll deciding whether the tree at `tree` drops a stick on `nearby`.
+/// Includes BOTH positions in the seed so each tree-tile pair has independent randomness.
+fn stick_near_tree_value(seed: u64, tree: (i32, i32), nearby: (i32, i32)) -> f64 {
+    // Seed offset for stick placement near trees (different from object placement)
+    const STICK_NEAR_TREE_SEED_OFFSET: u64 = 2000003;
+    
+    let tree_offset = (tree.0 as u64)
+        .wrapping_mul(0x517CC1B727220A95)
+        .wrapping_add((tree.1 as u64).wrapping_mul(0x5D6DCB8D5C20A2AB));
+    tile_random_value(
+        seed.wrapping_add(STICK_NEAR_TREE_SEED_OFFSET).wrapping_add(tree_offset),
+        nearby.0,
+        nearby.1,
+    )
+}
+
 /// Generates a deterministic pseudo-random value between 0.0 and 1.0 for a specific tile.
 ///
 /// Uses a high-quality hash function (SplitMix64-inspired) with proper bit mixing to ensure
 /// deterministic placement with good statistical properties (no correlation patterns).
-fn tile_random_value(seed: u64, global_x: i32, global_y: i32) -> f64 {
+pub(super) fn tile_random_value(seed: u64, global_x: i32, global_y: i32) -> f64 {
     // Combine seed with global coordinates using XOR and golden ratio-derived constants
     // XOR provides better mixing than addition (avoids correlation when coords change together)
     let mut hash = seed;
@@ -0,0 +1,146 @@
+//! River carving post-process.
+//!
+//! Rivers are traced over global tile coordinates from Mountain lands toward the
+//! nearest Lake land, so a single river flows continuously across land borders.
+//! At each step the river moves to whichever neighbouring tile brings it closer to
+//! the lake, preferring the lower of the candidates on the height field used for
+//! biome selection. Everything is derived from the seed, so rivers are deterministic.
+
+use std::collections::HashMap;
+use crate::types::{Biome, Land, Substrate, World};
+use super::biome::sample_height;
+use super::objects::tile_random_value;
+use super::split_global;
+
+/// Seed offset for choosing which mountain lands spawn a river.
+const RIVER_SOURCE_SEED_OFFSET: u64 = 3000017;
+
+/// Fraction of mountain lands that act as a river source.
+const RIVER_SOURCE_CHANCE: f64 = 0.35;
+
+/// Rivers only flow to lakes within this many lands (Euclidean) of their source.
+const RIVER_MAX_LAND_DISTANCE: i32 = 8;
+
+/// Converts a global tile coordinate to the continuous biome coordinate used for height.
+/// A land's tiles span 2 biome units, with tile 3.5 at the land's center biome coordinate.
+fn tile_height(global_x: i32, global_y: i32, seed: u64) -> f64 {
+    let bx = (global_x as f64 - 3.5) / 4.0;
+    let by = (global_y as f64 - 3.5) / 4.0;
+    sample_height(bx, by, seed)
+}
+
+/// Carves rivers into every generated land of the world.
+///
+/// Each selected Mountain land starts a river at its highest interior tile and
+/// carves a 4-connected path of `Water` (clearing objects) until it reaches water
+/// inside the nearest Lake land. A river is abandoned where it would have to
+/// cross an ungenerated land.
+pub fn carve_rivers(world: &mut World, seed: u64) {
+    let mut coords: Vec<(i32, i32)> = world.terrain.keys().copied().collect();
+    coords.sort_by_key(|&(x, y)| (y, x));
+
+    let lakes: Vec<(i32, i32)> = coords
+        .iter()
+        .copied()
+        .filter(|coord| world.terrain[coord].center == Biome::Lake)
+        .collect();
+
+    for &(land_x, land_y) in &coords {
+        if world.terrain[&(land_x, land_y)].center != Biome::Mountain {
+            continue;
+        }
+        let source_roll = tile_random_value(seed.wrapping_add(RIVER_SOURCE_SEED_OFFSET), land_x, land_y);
+        if source_roll >= RIVER_SOURCE_CHANCE {
+            continue;
+        }
+        let Some(lake) = nearest_lake(&lakes, (land_x, land_y)) else {
+            continue;
+        };
+
+        let source = highest_interior_tile(land_x, land_y, seed);
+        if let Some(path) = trace_river(&world.terrain, source, lake, seed) {
+            for (gx, gy) in path {
+                let ((lx, ly), (tx, ty)) = split_global(gx, gy);
+                if let Some(land) = world.terrain.get_mut(&(lx, ly)) {
+                    let tile = &mut land.tiles[ty][tx];
+                    tile.substrate = Substrate::Water;
+                    tile.objects.clear();
+                }
+            }
+        }
+    }
+}
+
+/// Finds the closest lake land within range, breaking ties by (y, x) order.
+fn nearest_lake(lakes: &[(i32, i32)], from: (i32, i32)) -> Option<(i32, i32)> {
+    let max_distance_squared = RIVER_MAX_LAND_DISTANCE * RIVER_MAX_LAND_DISTANCE;
+    lakes
+        .iter()
+        .copied()
+        .map(|(x, y)| {
+            let dx = x - from.0;
+            let dy = y - from.1;
+            (dx * dx + dy * dy, (x, y))
+        })
+        .filter(|&(distance_squared, _)| distance_squared <= max_distance_squared)
+        .min_by_key(|&(distance_squared, (x, y))| (distance_squared, y, x))
+        .map(|(_, coord)| coord)
+}
+
+/// The highest tile among the land's center 6x6 tiles, in global coordinates.
+fn highest_interior_tile(land_x: i32, land_y: i32, seed: u64) -> (i32, i32) {
+    let mut best = (land_x * 8 + 1, land_y * 8 + 1);
+    let mut best_height = f64::MIN;
+    for tile_y in 1..=6 {
+        for tile_x in 1..=6 {
+            let gx = land_x * 8 + tile_x;
+            let gy = land_y * 8 + tile_y;
+            let height = tile_height(gx, gy, seed);
+            if height > best_height {
+                best_height = height;
+                best = (gx, gy);
+            }
+        }
+    }
+    best
+}
+
+/// Traces the river path from `source` to the first water tile inside the `lake` land.
+/// Returns `None` if the path would leave the generated terrain.
+fn trace_river(
+    terrain: &HashMap<(i32, i32), Land>,
+    source: (i32, i32),
+    lake: (i32, i32),
+    seed: u64,
+) -> Option<Vec<(i32, i32)>> {
+    let target = (lake.0 * 8 + 4, lake.1 * 8 + 4);
+    let mut current = source;
+    let mut path = vec![current];
+
+    loop {
+        let ((lx, ly), (tx, ty)) = split_global(current.0, current.1);
+        let land = terrain.get(&(lx, ly))?;
+        if (lx, ly) == lake && land.tiles[ty][tx].substrate == Substrate::Water {
+            return Some(path);
+        }
+
+        // Only consider steps that close the distance, so the river always terminates
+        let mut candidates = Vec::with_capacity(2);
+        if current.0 != target.0 {
+            candidates.push((current.0 + (target.0 - current.0).signum(), current.1));
+        }
+        if current.1 != target.1 {
+            candidates.push((current.0, current.1 + (target.1 - current.1).signum()));
+        }
+
+        let next = candidates
+            .into_iter()
+            .filter(|&(gx, gy)| terrain.contains_key(&split_global(gx, gy).0))
+            .min_by(|&a, &b| {
+                tile_height(a.0, a.1, seed).total_cmp(&tile_height(b.0, b.1, seed))
+            })?;
+
+        path.push(next);
+        current = next;
+    }
+}
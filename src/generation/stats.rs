@@ -0,0 +1,54 @@
+//! Aggregate counts over a generated region, for tuning `GenerationConfig`.
+
+use std::collections::HashMap;
+use crate::types::{Biome, Object, Substrate, World};
+
+/// Result of `region_stats`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegionStats {
+    /// Generated lands per center biome
+    pub biomes: HashMap<Biome, usize>,
+    /// Tiles per substrate across all generated lands
+    pub substrates: HashMap<Substrate, usize>,
+    /// Objects per kind (a tile with two rocks counts twice)
+    pub objects: HashMap<Object, usize>,
+    /// Lands in the region that have not been generated and were skipped
+    pub missing_lands: usize,
+}
+
+impl RegionStats {
+    /// Number of generated lands that were counted
+    pub fn land_count(&self) -> usize {
+        self.biomes.values().sum()
+    }
+
+    /// Fraction of counted lands whose center is `biome` (0.0 if nothing was counted)
+    pub fn biome_fraction(&self, biome: &Biome) -> f64 {
+        match self.land_count() {
+            0 => 0.0,
+            total => self.biomes.get(biome).copied().unwrap_or(0) as f64 / total as f64,
+        }
+    }
+}
+
+/// Counts biomes, substrates, and objects over lands (x1, y1)..=(x2, y2).
+/// Ungenerated lands are skipped and tallied in `missing_lands`.
+pub fn region_stats(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) -> RegionStats {
+    let mut stats = RegionStats::default();
+    for y in y1..=y2 {
+        for x in x1..=x2 {
+            let Some(land) = world.terrain.get(&(x, y)) else {
+                stats.missing_lands += 1;
+                continue;
+            };
+            *stats.biomes.entry(land.center.clone()).or_default() += 1;
+            for tile in land.tiles.iter().flatten() {
+                *stats.substrates.entry(tile.substrate.clone()).or_default() += 1;
+                for object in &tile.objects {
+                    *stats.objects.entry(object.clone()).or_default() += 1;
+                }
+            }
+        }
+    }
+    stats
+}
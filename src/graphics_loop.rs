@@ -3,8 +3,11 @@ use crate::render::macroquad::MacroquadRenderer;
 use crate::render::{Color, Key, Renderer};
 use crate::terrain_view::{self, TerrainCamera};
 use crate::types::World;
-use crate::generation;
 use macroquad::prelude::*;
+use std::collections::HashSet;
+
+/// Side length of the minimap drawn in the top-right corner
+const MINIMAP_SIZE: f32 = 170.0;
 
 /// View mode enum for tracking which view is active
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,6 +27,7 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
     // Separate cameras for each view
     let mut terrain_camera = TerrainCamera::new();
     terrain_camera.update_target(); // Initialize target position
+    terrain_camera.blend_borders = true; // Smooth out biome jumps between lands
     
     let mut land_camera = LandCamera::new();
     land_camera.update_target(); // Initialize target position
@@ -31,6 +35,9 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
     // Track current view mode
     let mut view_mode = ViewMode::Terrain;
 
+    // Lands the player has selected in either view, shown undimmed on the minimap
+    let mut explored: HashSet<(i32, i32)> = HashSet::new();
+
     loop {
         let delta_time = get_frame_time();
         
@@ -62,13 +69,11 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
             ViewMode::Terrain => {
                 let should_switch = terrain_view::handle_input(&mut terrain_camera, &keys_pressed_this_frame);
                 
-                // Ensure terrain is generated within 5.5 spaces of current position
-                generation::ensure_terrain_generated(
-                    world,
-                    terrain_camera.selected_land_x,
-                    terrain_camera.selected_land_y,
-                    5.5,
-                );
+                // Ensure every land on screen is generated
+                let (screen_width, screen_height) = renderer.window_size();
+                terrain_camera.set_viewport(screen_width, screen_height);
+                let (x1, y1, x2, y2) = terrain_camera.visible_bounds();
+                world.extend_generated(x1, y1, x2, y2);
                 
                 if should_switch {
                     // Sync land camera with terrain camera's selection
@@ -86,6 +91,7 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
             }
             ViewMode::Land => {
                 let should_switch = land_view::handle_input(&mut land_camera, &keys_pressed_this_frame);
+                land_camera.ensure_land_generated(world);
                 if should_switch {
                     // Sync terrain camera with land camera's selection
                     // Terrain view centers on (land_x, land_y), so sync to that position
@@ -112,6 +118,12 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
         terrain_camera.update(delta_time);
         land_camera.update(delta_time);
 
+        let current_land = match view_mode {
+            ViewMode::Terrain => (terrain_camera.selected_land_x, terrain_camera.selected_land_y),
+            ViewMode::Land => (land_camera.selected_land_x, land_camera.selected_land_y),
+        };
+        explored.insert(current_land);
+
         // Check for exit
         if renderer.should_close() {
             break;
@@ -130,6 +142,9 @@ pub async fn run_graphics_loop(world: &mut World) -> Result<(), Box<dyn std::err
             }
         }
 
+        let (screen_width, _) = renderer.window_size();
+        renderer.draw_minimap(screen_width - MINIMAP_SIZE - 10.0, 10.0, MINIMAP_SIZE, world, &explored, current_land);
+
         // Draw UI text
         let view_mode_text = match view_mode {
             ViewMode::Terrain => "Terrain View",
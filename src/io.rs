@@ -1,9 +1,14 @@
 use std::collections::HashMap;
 use std::fs;
+use std::path::{Path, PathBuf};
 use serde::{Deserializer, Serializer};
 use serde::de::Visitor;
 use std::fmt;
 use crate::types::Land;
+use image::{Rgb, RgbImage};
+
+/// Color used for lands that have not been generated yet
+pub const VOID_COLOR: [u8; 3] = [20, 20, 26];
 
 pub fn serialize_terrain<S>(terrain: &HashMap<(i32, i32), Land>, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -51,29 +56,161 @@ where
     deserializer.deserialize_map(TerrainVisitor)
 }
 
-pub fn save_world(world: &crate::types::World) -> Result<(), Box<dyn std::error::Error>> {
-    let dir = "worlds";
-    // Create the directory if it doesn't exist
-    fs::create_dir_all(dir)?;
-    
-    let filename = format!("{}/{}.json", dir, world.name);
-    let json = serde_json::to_string_pretty(world)?;
-    fs::write(filename, json)?;
-    Ok(())
+/// Errors from saving, loading, and exporting worlds
+#[derive(Debug)]
+pub enum IoError {
+    /// The world could not be converted to JSON
+    Serialize(serde_json::Error),
+    /// Creating, writing, or renaming the save file failed
+    Write(std::io::Error),
+    /// The save file could not be read
+    Read(std::io::Error),
+    /// The file was read but is not a valid world
+    Deserialize(serde_json::Error),
+    /// A world name that can't be used as a file name (empty, or containing a path separator or "..")
+    InvalidName(String),
+    /// PNG export arguments that can't produce an image (zero tile size or an empty region)
+    InvalidExport(String),
+    /// The PNG could not be encoded or written
+    Image(image::ImageError),
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IoError::Serialize(e) => write!(f, "failed to serialize world: {}", e),
+            IoError::Write(e) => write!(f, "failed to write world: {}", e),
+            IoError::Read(e) => write!(f, "failed to read world: {}", e),
+            IoError::Deserialize(e) => write!(f, "failed to parse world: {}", e),
+            IoError::InvalidName(name) => write!(f, "invalid world name: {:?}", name),
+            IoError::InvalidExport(reason) => write!(f, "invalid export: {}", reason),
+            IoError::Image(e) => write!(f, "failed to write image: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for IoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IoError::Serialize(e) | IoError::Deserialize(e) => Some(e),
+            IoError::Write(e) | IoError::Read(e) => Some(e),
+            IoError::Image(e) => Some(e),
+            IoError::InvalidName(_) | IoError::InvalidExport(_) => None,
+        }
+    }
 }
 
-pub fn load_world(path: &str) -> Result<crate::types::World, Box<dyn std::error::Error>> {
+/// Default save location for a world name: `worlds/{name}.json`
+pub fn world_path(name: &str) -> Result<PathBuf, IoError> {
+    if name.is_empty() || name.contains(['/', '\\']) || name == ".." {
+        return Err(IoError::InvalidName(name.to_string()));
+    }
+    Ok(Path::new("worlds").join(format!("{}.json", name)))
+}
+
+/// Save `world` as JSON at `path`, creating parent directories as needed.
+///
+/// The JSON is written to a sibling `.tmp` file and then renamed over `path`, so a
+/// crash mid-write leaves the previous save intact.
+pub fn save_world(world: &crate::types::World, path: impl AsRef<Path>) -> Result<(), IoError> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(IoError::Write)?;
+    }
+
+    let json = serde_json::to_string_pretty(world).map_err(IoError::Serialize)?;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, json).map_err(IoError::Write)?;
+    fs::rename(&tmp, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        IoError::Write(e)
+    })
+}
+
+pub fn load_world(path: &str) -> Result<crate::types::World, IoError> {
     // If path contains a directory separator, use it as-is
     // Otherwise, look in the worlds directory
     let file_path = if path.contains('/') || path.contains('\\') {
-        path.to_string()
+        PathBuf::from(path)
     } else {
         // Remove .json extension if present, then add it back with worlds/ prefix
-        let name = path.strip_suffix(".json").unwrap_or(path);
-        format!("worlds/{}.json", name)
+        world_path(path.strip_suffix(".json").unwrap_or(path))?
     };
     
-    let contents = fs::read_to_string(&file_path)?;
-    let world: crate::types::World = serde_json::from_str(&contents)?;
-    Ok(world)
+    let contents = fs::read_to_string(&file_path).map_err(IoError::Read)?;
+    serde_json::from_str(&contents).map_err(IoError::Deserialize)
+}
+
+/// Paint one land's 8x8 tiles into `img` with its top-left corner at pixel (`ox`, `oy`).
+/// Each object on a tile is drawn as a small square marker along the tile's diagonal.
+fn paint_land(img: &mut RgbImage, land: &Land, ox: u32, oy: u32, tile_px: u32) {
+    let marker_px = (tile_px / 4).max(1);
+    for (ty, row) in land.tiles.iter().enumerate() {
+        for (tx, tile) in row.iter().enumerate() {
+            let x0 = ox + tx as u32 * tile_px;
+            let y0 = oy + ty as u32 * tile_px;
+            fill_rect(img, x0, y0, tile_px, tile_px, tile.substrate.to_color().to_rgb8());
+
+            for (i, object) in tile.objects.iter().enumerate() {
+                let offset = (marker_px * (2 * i as u32 + 1)).min(tile_px - marker_px);
+                fill_rect(img, x0 + offset, y0 + offset, marker_px, marker_px, object.to_color().to_rgb8());
+            }
+        }
+    }
+}
+
+fn fill_rect(img: &mut RgbImage, x: u32, y: u32, w: u32, h: u32, color: [u8; 3]) {
+    for py in y..y + h {
+        for px in x..x + w {
+            img.put_pixel(px, py, Rgb(color));
+        }
+    }
+}
+
+/// Render a single land to a PNG, `tile_px` pixels per tile (image is `8 * tile_px` square)
+pub fn export_land_png(land: &Land, path: &str, tile_px: u32) -> Result<(), IoError> {
+    if tile_px == 0 {
+        return Err(IoError::InvalidExport("tile_px must be at least 1".to_string()));
+    }
+    let size = 8 * tile_px;
+    let mut img = RgbImage::new(size, size);
+    paint_land(&mut img, land, 0, 0, tile_px);
+    img.save(path).map_err(IoError::Image)
+}
+
+/// Render the lands from (x1, y1) to (x2, y2) inclusive into one PNG.
+/// Ungenerated lands are filled with `VOID_COLOR`.
+pub fn export_world_png(
+    world: &crate::types::World,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    path: &str,
+    tile_px: u32,
+) -> Result<(), IoError> {
+    if tile_px == 0 {
+        return Err(IoError::InvalidExport("tile_px must be at least 1".to_string()));
+    }
+    if x2 < x1 || y2 < y1 {
+        return Err(IoError::InvalidExport(format!("empty region ({},{}) to ({},{})", x1, y1, x2, y2)));
+    }
+    let land_px = 8 * tile_px;
+    let width = (x2 - x1 + 1) as u32 * land_px;
+    let height = (y2 - y1 + 1) as u32 * land_px;
+    let mut img = RgbImage::new(width, height);
+
+    for y in y1..=y2 {
+        for x in x1..=x2 {
+            let ox = (x - x1) as u32 * land_px;
+            let oy = (y - y1) as u32 * land_px;
+            match world.terrain.get(&(x, y)) {
+                Some(land) => paint_land(&mut img, land, ox, oy, tile_px),
+                None => fill_rect(&mut img, ox, oy, land_px, land_px, VOID_COLOR),
+            }
+        }
+    }
+
+    img.save(path).map_err(IoError::Image)
 }
@@ -1,5 +1,5 @@
 use crate::render::Renderer;
-use crate::types::World;
+use crate::types::{Object, Substrate, World};
 use crate::camera::CameraCore;
 
 /// Spacing between adjacent lands in pixels
@@ -8,6 +8,18 @@ const LAND_SPACING: f32 = 30.0;
 /// Scale factor for tiles when showing adjacent lands (makes tiles smaller to fit more)
 const ADJACENT_SCALE: f32 = 0.65;
 
+/// What happens when the tile selection moves past the edge of the 8x8 land
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeBehavior {
+    /// Stay on the edge tile
+    #[default]
+    Clamp,
+    /// Jump to the opposite edge of the same land
+    Wrap,
+    /// Move into the adjacent land, onto the tile touching the edge that was crossed
+    StepIntoNeighbor,
+}
+
 /// Camera for land view - manages tile-level selection within a land
 pub struct LandCamera {
     /// Core camera functionality (position, zoom, smooth following)
@@ -23,6 +35,9 @@ pub struct LandCamera {
 
     /// Whether to show the 8 adjacent lands
     pub show_adjacent: bool,
+
+    /// How `move_selection` handles leaving the land
+    pub edge_behavior: EdgeBehavior,
 }
 
 impl LandCamera {
@@ -36,6 +51,7 @@ impl LandCamera {
             selected_tile_x: 4, // Start at center
             selected_tile_y: 4,
             show_adjacent: false,
+            edge_behavior: EdgeBehavior::Clamp,
         }
     }
 
@@ -98,20 +114,42 @@ impl LandCamera {
         self.core.update(delta_time);
     }
 
-    /// Move selection within the land
+    /// Move the tile selection, applying `edge_behavior` on each axis that leaves the land.
+    /// With `StepIntoNeighbor` the selected land may change; call `ensure_land_generated`
+    /// afterwards so it has terrain.
     pub fn move_selection(&mut self, dx: i32, dy: i32) {
-        let new_x = self.selected_tile_x as i32 + dx;
-        let new_y = self.selected_tile_y as i32 + dy;
+        let (tile_x, land_dx) = self.step_axis(self.selected_tile_x, dx);
+        let (tile_y, land_dy) = self.step_axis(self.selected_tile_y, dy);
+        self.selected_tile_x = tile_x;
+        self.selected_tile_y = tile_y;
+        self.selected_land_x += land_dx;
+        self.selected_land_y += land_dy;
+
+        self.update_target();
+    }
 
-        // Clamp to 0-7 range
-        if new_x >= 0 && new_x < 8 {
-            self.selected_tile_x = new_x as usize;
+    /// New tile index on one axis, plus how many lands the selection crossed
+    fn step_axis(&self, tile: usize, delta: i32) -> (usize, i32) {
+        let new = tile as i32 + delta;
+        if (0..8).contains(&new) {
+            return (new as usize, 0);
         }
-        if new_y >= 0 && new_y < 8 {
-            self.selected_tile_y = new_y as usize;
+        match self.edge_behavior {
+            EdgeBehavior::Clamp => (tile, 0),
+            EdgeBehavior::Wrap => (new.rem_euclid(8) as usize, 0),
+            EdgeBehavior::StepIntoNeighbor => (new.rem_euclid(8) as usize, new.div_euclid(8)),
         }
+    }
 
-        self.update_target();
+    /// Generates the selected land from the world's seed if it doesn't exist yet.
+    /// Returns true if a land was generated.
+    pub fn ensure_land_generated(&self, world: &mut World) -> bool {
+        let land = (self.selected_land_x, self.selected_land_y);
+        if world.terrain.contains_key(&land) {
+            return false;
+        }
+        crate::generation::generate_land(world, world.seed, land.0, land.1);
+        true
     }
 
     /// Set which land is being viewed (used when switching from terrain view)
@@ -141,6 +179,28 @@ impl Default for LandCamera {
     }
 }
 
+/// Draw a tile's substrate, then every object in z-order, plus a count badge when stacked
+fn draw_tile_with_objects<R: Renderer>(
+    renderer: &mut R,
+    x: f32,
+    y: f32,
+    size: f32,
+    substrate: &Substrate,
+    objects: &[Object],
+) {
+    renderer.draw_tile(x, y, size, substrate, &[]);
+
+    let mut ordered: Vec<&Object> = objects.iter().collect();
+    ordered.sort_by_key(|object| object.z_order());
+    for object in ordered {
+        renderer.draw_object(x, y, size, object);
+    }
+
+    if objects.len() > 1 {
+        renderer.draw_object_badge(x, y, size, objects.len());
+    }
+}
+
 /// Render land view - shows detailed 8x8 tile grid
 pub fn render<R: Renderer>(
     renderer: &mut R,
@@ -180,7 +240,7 @@ pub fn render<R: Renderer>(
                 let screen_x = grid_start_x + tile_x as f32 * tile_size;
                 let screen_y = grid_start_y + tile_y as f32 * tile_size;
                 
-                renderer.draw_tile(screen_x, screen_y, tile_size, &tile.substrate, &tile.objects);
+                draw_tile_with_objects(renderer, screen_x, screen_y, tile_size, &tile.substrate, &tile.objects);
             }
         }
         
@@ -225,7 +285,7 @@ pub fn render<R: Renderer>(
                             let screen_x = adj_grid_start_x + tile_x as f32 * tile_size;
                             let screen_y = adj_grid_start_y + tile_y as f32 * tile_size;
                             
-                            renderer.draw_tile(screen_x, screen_y, tile_size, &tile.substrate, &tile.objects);
+                            draw_tile_with_objects(renderer, screen_x, screen_y, tile_size, &tile.substrate, &tile.objects);
                         }
                     }
 
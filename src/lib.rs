@@ -6,6 +6,7 @@ pub mod render;
 pub mod camera;
 pub mod terrain_view;
 pub mod land_view;
+pub mod pathfinding;
 
 #[cfg(test)]
 mod tests;
@@ -14,9 +15,17 @@ mod tests;
 pub use types::{Biome, Land, Object, Substrate, Tile, World};
 pub use generation::{
     determine_biome, generate_land_terrain, generate_world, initialize_world,
-    LandBiomes, get_tile_biome,
+    LandBiomes, get_tile_biome, seed_from_str, carve_rivers, GenerationConfig,
+    generate_world_with_config, diff_worlds, WorldDiff, region_stats, RegionStats,
 };
-pub use io::{load_world, save_world};
-pub use display::{print_land, print_world};
+#[cfg(feature = "parallel")]
+pub use generation::generate_world_parallel_with_config;
+pub use pathfinding::{default_passable, find_path};
+pub use io::{export_land_png, export_world_png, load_world, save_world, world_path, IoError};
+pub use display::{
+    format_land, format_world, format_world_legend, format_world_with_coords, print_land,
+    print_land_colored, print_world, print_world_colored, print_world_legend, print_world_with_coords,
+};
+pub use render::headless::{DrawCommand, HeadlessRenderer};
 pub use terrain_view::{TerrainCamera, render as render_terrain_view, handle_input as handle_terrain_input};
-pub use land_view::{LandCamera, render as render_land_view, handle_input as handle_land_input};
+pub use land_view::{LandCamera, EdgeBehavior, render as render_land_view, handle_input as handle_land_input};
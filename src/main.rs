@@ -10,9 +10,9 @@ mod graphics_loop;
 
 use std::collections::HashMap;
 use types::World;
-use generation::initialize_world;
-use io::save_world;
-use display::{print_land, print_world};
+use generation::{initialize_world, seed_from_str};
+use io::{save_world, world_path};
+use display::{print_land, print_world_legend, print_world_with_coords};
 use macroquad::prelude::*;
 
 /// Window configuration - sets window size 50% larger than default (1200x900)
@@ -42,10 +42,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 use_text_mode = true;
             }
             arg => {
-                // Try to parse as seed if it's a number
-                if let Ok(parsed_seed) = arg.parse::<u64>() {
-                    seed = parsed_seed;
-                }
+                // Numbers are used as-is; any other word is hashed into a seed
+                seed = arg.parse::<u64>().unwrap_or_else(|_| seed_from_str(arg));
             }
         }
     }
@@ -63,8 +61,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // In text mode, show a sample of the world
         let range = 5;
         println!("\nWorld overview (showing {} to {}):", -range, range);
-        println!("(Areas with ⬛ are ungenerated, other areas show biomes)");
-        print_world(&world, -range, -range, range, range);
+        print_world_with_coords(&world, -range, -range, range, range);
+        print_world_legend();
 
         // Show a few sample lands from the center area
         println!("\nSample land details:");
@@ -88,8 +86,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     
     println!("\nSaving world...");
-    save_world(&world)?;
-    println!("World saved to worlds/{}.json", world.name);
+    let path = world_path(&world.name)?;
+    save_world(&world, &path)?;
+    println!("World saved to {}", path.display());
     
     Ok(())
 }
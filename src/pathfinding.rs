@@ -0,0 +1,102 @@
+//! A* pathfinding over global tile coordinates.
+//!
+//! Paths move in the 4 cardinal directions at a cost of 1 per tile. Lands are
+//! generated lazily (from `world.seed`) as the search reaches them, so a path can
+//! run into terrain nobody has looked at yet. The search is confined to the
+//! bounding box of start and goal plus `SEARCH_MARGIN` tiles, which keeps
+//! unreachable goals from exploring an infinite world.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use crate::generation::generate_land;
+use crate::types::{Substrate, World};
+
+/// How far (in tiles) the search may stray outside the start/goal bounding box.
+pub const SEARCH_MARGIN: i32 = 16;
+
+/// Default passability: water and stone block movement, everything else is walkable.
+pub fn default_passable(substrate: &Substrate) -> bool {
+    !matches!(substrate, Substrate::Water | Substrate::Stone)
+}
+
+/// Finds a shortest 4-connected path from `start` to `goal` (global tile coordinates).
+///
+/// Returns the tiles visited in order, including both endpoints, or `None` if the
+/// goal is impassable or can't be reached within the search bounds. The start tile
+/// itself is never checked, so a character standing in water can still walk out.
+pub fn find_path(
+    world: &mut World,
+    start: (i32, i32),
+    goal: (i32, i32),
+    passable: impl Fn(&Substrate) -> bool,
+) -> Option<Vec<(i32, i32)>> {
+    let min_x = start.0.min(goal.0) - SEARCH_MARGIN;
+    let max_x = start.0.max(goal.0) + SEARCH_MARGIN;
+    let min_y = start.1.min(goal.1) - SEARCH_MARGIN;
+    let max_y = start.1.max(goal.1) + SEARCH_MARGIN;
+    let in_bounds = |(x, y): (i32, i32)| (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+
+    if !is_passable(world, goal, &passable) {
+        return None;
+    }
+
+    let heuristic = |(x, y): (i32, i32)| (x - goal.0).abs() + (y - goal.1).abs();
+
+    // Ordered by (estimated total, cost so far, position) so ties resolve deterministically
+    let mut open = BinaryHeap::new();
+    let mut best_cost: HashMap<(i32, i32), i32> = HashMap::new();
+    let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+
+    best_cost.insert(start, 0);
+    open.push(Reverse((heuristic(start), 0, start)));
+
+    while let Some(Reverse((_, cost, current))) = open.pop() {
+        if current == goal {
+            return Some(reconstruct_path(&came_from, start, goal));
+        }
+        if best_cost.get(&current).is_some_and(|&best| cost > best) {
+            continue; // stale heap entry
+        }
+
+        let (x, y) = current;
+        for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if !in_bounds(next) || !is_passable(world, next, &passable) {
+                continue;
+            }
+            let next_cost = cost + 1;
+            if best_cost.get(&next).is_none_or(|&best| next_cost < best) {
+                best_cost.insert(next, next_cost);
+                came_from.insert(next, current);
+                open.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks a tile, generating its land first if needed.
+fn is_passable(world: &mut World, (x, y): (i32, i32), passable: &impl Fn(&Substrate) -> bool) -> bool {
+    let land = (x.div_euclid(8), y.div_euclid(8));
+    if !world.terrain.contains_key(&land) {
+        generate_land(world, world.seed, land.0, land.1);
+    }
+    world
+        .tile_at_global(x, y)
+        .is_some_and(|tile| passable(&tile.substrate))
+}
+
+fn reconstruct_path(
+    came_from: &HashMap<(i32, i32), (i32, i32)>,
+    start: (i32, i32),
+    goal: (i32, i32),
+) -> Vec<(i32, i32)> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = came_from[&current];
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
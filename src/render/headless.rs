@@ -0,0 +1,301 @@
+use crate::render::{Color, Key, RenderError, Renderer};
+use crate::types::{Biome, Object, Substrate};
+
+/// A single recorded renderer call
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrawCommand {
+    Clear(Color),
+    Tile {
+        x: f32,
+        y: f32,
+        size: f32,
+        substrate: Substrate,
+        objects: Vec<Object>,
+    },
+    /// Records the already-composited color
+    BlendedTile {
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+    },
+    Object {
+        x: f32,
+        y: f32,
+        size: f32,
+        object: Object,
+    },
+    ObjectBadge {
+        x: f32,
+        y: f32,
+        size: f32,
+        count: usize,
+    },
+    BiomeOverview {
+        x: f32,
+        y: f32,
+        size: f32,
+        biome: Biome,
+    },
+    /// Borders are stored in the order top, bottom, left, right, top_left, top_right, bottom_left, bottom_right
+    BiomeOverviewWithBorders {
+        x: f32,
+        y: f32,
+        size: f32,
+        center: Biome,
+        borders: [Biome; 8],
+        border_width: f32,
+    },
+    /// Borders are stored in the same order as `BiomeOverviewWithBorders`
+    BiomeOverviewWithBorderColors {
+        x: f32,
+        y: f32,
+        size: f32,
+        center: Color,
+        borders: [Color; 8],
+        border_width: f32,
+    },
+    SelectionIndicator {
+        x: f32,
+        y: f32,
+        size: f32,
+    },
+    Grid {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        rows: usize,
+        cols: usize,
+    },
+    MinimapCell {
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+    },
+    MinimapMarker {
+        x: f32,
+        y: f32,
+        size: f32,
+    },
+    Button {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        text: String,
+    },
+}
+
+/// Renderer that records draw calls instead of drawing, for testing views without a window
+pub struct HeadlessRenderer {
+    width: f32,
+    height: f32,
+    commands: Vec<DrawCommand>,
+    frames_presented: usize,
+    /// Keys reported by `get_keys_pressed` (set by tests to simulate input)
+    pub keys_pressed: Vec<Key>,
+    /// Mouse position reported by `get_mouse_pos`
+    pub mouse_pos: Option<(f32, f32)>,
+}
+
+impl HeadlessRenderer {
+    /// Create a headless renderer with the given virtual window size
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            commands: Vec::new(),
+            frames_presented: 0,
+            keys_pressed: Vec::new(),
+            mouse_pos: None,
+        }
+    }
+
+    /// All draw calls recorded since creation (or the last `take_commands`)
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    /// Remove and return the recorded draw calls
+    pub fn take_commands(&mut self) -> Vec<DrawCommand> {
+        std::mem::take(&mut self.commands)
+    }
+
+    /// Number of recorded `draw_tile` calls
+    pub fn tile_draw_count(&self) -> usize {
+        self.commands.iter().filter(|c| matches!(c, DrawCommand::Tile { .. })).count()
+    }
+
+    /// Number of recorded `draw_object` calls
+    pub fn object_draw_count(&self) -> usize {
+        self.commands.iter().filter(|c| matches!(c, DrawCommand::Object { .. })).count()
+    }
+
+    /// Number of recorded `draw_object_badge` calls
+    pub fn badge_count(&self) -> usize {
+        self.commands.iter().filter(|c| matches!(c, DrawCommand::ObjectBadge { .. })).count()
+    }
+
+    /// Number of recorded biome overview calls (with or without borders, biome or color based)
+    pub fn biome_overview_count(&self) -> usize {
+        self.commands
+            .iter()
+            .filter(|c| {
+                matches!(
+                    c,
+                    DrawCommand::BiomeOverview { .. }
+                        | DrawCommand::BiomeOverviewWithBorders { .. }
+                        | DrawCommand::BiomeOverviewWithBorderColors { .. }
+                )
+            })
+            .count()
+    }
+
+    /// Number of recorded `draw_minimap_cell` calls
+    pub fn minimap_cell_count(&self) -> usize {
+        self.commands.iter().filter(|c| matches!(c, DrawCommand::MinimapCell { .. })).count()
+    }
+
+    /// Number of recorded `draw_minimap_marker` calls
+    pub fn minimap_marker_count(&self) -> usize {
+        self.commands.iter().filter(|c| matches!(c, DrawCommand::MinimapMarker { .. })).count()
+    }
+
+    /// Number of frames presented so far
+    pub fn frames_presented(&self) -> usize {
+        self.frames_presented
+    }
+}
+
+impl Default for HeadlessRenderer {
+    fn default() -> Self {
+        Self::new(800.0, 600.0)
+    }
+}
+
+impl Renderer for HeadlessRenderer {
+    fn init(&mut self) -> Result<(), RenderError> {
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Color) {
+        self.commands.push(DrawCommand::Clear(color));
+    }
+
+    fn draw_tile(&mut self, x: f32, y: f32, size: f32, substrate: &Substrate, objects: &[Object]) {
+        self.commands.push(DrawCommand::Tile {
+            x,
+            y,
+            size,
+            substrate: substrate.clone(),
+            objects: objects.to_vec(),
+        });
+    }
+
+    fn draw_tile_blended(&mut self, x: f32, y: f32, size: f32, color: Color, over: Color) {
+        self.commands.push(DrawCommand::BlendedTile { x, y, size, color: color.over(&over) });
+    }
+
+    fn draw_object(&mut self, x: f32, y: f32, size: f32, object: &Object) {
+        self.commands.push(DrawCommand::Object { x, y, size, object: object.clone() });
+    }
+
+    fn draw_object_badge(&mut self, x: f32, y: f32, size: f32, count: usize) {
+        self.commands.push(DrawCommand::ObjectBadge { x, y, size, count });
+    }
+
+    fn draw_biome_overview(&mut self, x: f32, y: f32, size: f32, biome: &Biome) {
+        self.commands.push(DrawCommand::BiomeOverview { x, y, size, biome: biome.clone() });
+    }
+
+    fn draw_biome_overview_with_borders(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        center: &Biome,
+        top: &Biome,
+        bottom: &Biome,
+        left: &Biome,
+        right: &Biome,
+        top_left: &Biome,
+        top_right: &Biome,
+        bottom_left: &Biome,
+        bottom_right: &Biome,
+        border_width: f32,
+    ) {
+        self.commands.push(DrawCommand::BiomeOverviewWithBorders {
+            x,
+            y,
+            size,
+            center: center.clone(),
+            borders: [
+                top.clone(),
+                bottom.clone(),
+                left.clone(),
+                right.clone(),
+                top_left.clone(),
+                top_right.clone(),
+                bottom_left.clone(),
+                bottom_right.clone(),
+            ],
+            border_width,
+        });
+    }
+
+    fn draw_biome_overview_with_border_colors(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        center: Color,
+        borders: [Color; 8],
+        border_width: f32,
+    ) {
+        self.commands.push(DrawCommand::BiomeOverviewWithBorderColors { x, y, size, center, borders, border_width });
+    }
+
+    fn draw_selection_indicator(&mut self, x: f32, y: f32, size: f32) {
+        self.commands.push(DrawCommand::SelectionIndicator { x, y, size });
+    }
+
+    fn draw_grid(&mut self, x: f32, y: f32, width: f32, height: f32, rows: usize, cols: usize) {
+        self.commands.push(DrawCommand::Grid { x, y, width, height, rows, cols });
+    }
+
+    fn draw_minimap_cell(&mut self, x: f32, y: f32, size: f32, color: Color) {
+        self.commands.push(DrawCommand::MinimapCell { x, y, size, color });
+    }
+
+    fn draw_minimap_marker(&mut self, x: f32, y: f32, size: f32) {
+        self.commands.push(DrawCommand::MinimapMarker { x, y, size });
+    }
+
+    fn present(&mut self) -> Result<(), RenderError> {
+        self.frames_presented += 1;
+        Ok(())
+    }
+
+    fn should_close(&self) -> bool {
+        false
+    }
+
+    fn get_mouse_pos(&self) -> Option<(f32, f32)> {
+        self.mouse_pos
+    }
+
+    fn get_keys_pressed(&self) -> Vec<Key> {
+        self.keys_pressed.clone()
+    }
+
+    fn window_size(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    fn draw_button(&mut self, x: f32, y: f32, width: f32, height: f32, text: &str, _is_pressed: bool) -> bool {
+        self.commands.push(DrawCommand::Button { x, y, width, height, text: text.to_string() });
+        false
+    }
+}
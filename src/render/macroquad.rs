@@ -41,6 +41,7 @@ impl MacroquadRenderer {
             Object::Rock => "rock",
             Object::Tree => "tree",
             Object::Stick => "stick",
+            Object::Ore => "ore",
         };
         self.textures.get(name)
     }
@@ -52,25 +53,12 @@ impl MacroquadRenderer {
 
     /// Convert substrate to color
     fn substrate_color(substrate: &Substrate) -> Color {
-        match substrate {
-            Substrate::Grass => Color::rgb(0.7, 0.9, 0.4),      // Light green/yellow (same as meadow)
-            Substrate::Dirt => Color::rgb(0.6, 0.4, 0.2),       // Brown
-            Substrate::Stone => Color::rgb(0.7, 0.7, 0.7),      // Gray
-            Substrate::Mud => Color::rgb(0.4, 0.3, 0.2),        // Dark brown
-            Substrate::Water => Color::rgb(0.2, 0.4, 0.9),      // Blue
-            Substrate::Brush => Color::rgb(0.2, 0.6, 0.15),    // Dark green, similar to forest
-        }
+        substrate.to_color()
     }
 
     /// Convert biome to color
     fn biome_color(biome: &Biome) -> Color {
-        match biome {
-            Biome::Forest => Color::rgb(0.1, 0.5, 0.1),         // Dark green
-            Biome::Meadow => Color::rgb(0.7, 0.9, 0.4),         // Light green/yellow
-            Biome::Lake => Color::rgb(0.2, 0.5, 0.9),           // Blue
-            Biome::Mountain => Color::rgb(0.8, 0.8, 0.85),      // Gray/white
-            Biome::Plains => Color::rgb(0.6, 0.5, 0.35),        // Brown/tan (dirt-colored)
-        }
+        biome.to_color()
     }
 
     /// Create a natural shadow color using shifting approach
@@ -88,15 +76,6 @@ impl MacroquadRenderer {
         )
     }
 
-    /// Convert object to color
-    fn object_color(object: &Object) -> Color {
-        match object {
-            Object::Rock => Color::rgb(0.3, 0.3, 0.3),          // Dark gray
-            Object::Tree => Color::rgb(0.1, 0.6, 0.1),          // Green
-            Object::Stick => Color::rgb(0.5, 0.3, 0.1),         // Brown
-        }
-    }
-
     /// Convert macroquad key to our Key enum
     fn mq_key_to_key(mq_key: macroquad::prelude::KeyCode) -> Option<Key> {
         match mq_key {
@@ -143,227 +122,259 @@ impl Renderer for MacroquadRenderer {
         let substrate_color = Self::substrate_color(substrate);
         draw_rectangle(x, y, size, size, Self::to_mq_color(substrate_color));
 
-        // Draw objects on top
-        if !objects.is_empty() {
-            // Show first object (for single or multiple objects)
-            let object = &objects[0];
-            let center_x = x + size / 2.0;
-            let center_y = y + size / 2.0;
-            let obj_size = size * 0.75; // 50% larger than before (was 0.5, now 0.75)
-            
-            // Try to use texture first, fallback to geometric rendering
-            if let Some(texture) = self.get_texture(object) {
-                let texture_x = center_x - obj_size / 2.0;
-                let texture_y = center_y - obj_size / 2.0;
-                draw_texture_ex(
-                    texture,
-                    texture_x,
-                    texture_y,
-                    WHITE,
-                    DrawTextureParams {
-                        dest_size: Some(vec2(obj_size, obj_size)),
-                        ..Default::default()
-                    },
+        // Draw the first object on top (land view draws every object via draw_object)
+        if let Some(object) = objects.first() {
+            self.draw_object(x, y, size, object);
+        }
+
+        // Draw border
+        draw_rectangle_lines(x, y, size, size, 1.0, Self::to_mq_color(Color::rgb(0.1, 0.1, 0.1)));
+    }
+
+    fn draw_object(&mut self, x: f32, y: f32, size: f32, object: &Object) {
+        let center_x = x + size / 2.0;
+        let center_y = y + size / 2.0;
+        let obj_size = size * 0.75; // 50% larger than before (was 0.5, now 0.75)
+        
+        // Try to use texture first, fallback to geometric rendering
+        if let Some(texture) = self.get_texture(object) {
+            let texture_x = center_x - obj_size / 2.0;
+            let texture_y = center_y - obj_size / 2.0;
+            draw_texture_ex(
+                texture,
+                texture_x,
+                texture_y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(obj_size, obj_size)),
+                    ..Default::default()
+                },
+            );
+        } else {
+            // Fallback to geometric rendering
+            match object {
+            Object::Rock => {
+                // Draw rock in Super Auto Pets style (from rock.svg)
+                let scale = obj_size / 100.0;
+                let offset_x = center_x - obj_size / 2.0;
+                let offset_y = center_y - obj_size / 2.0;
+                
+                // Helper to convert SVG coords to screen coords
+                let sx = |x: f32| offset_x + x * scale;
+                let sy = |y: f32| offset_y + y * scale;
+                
+                // Rock colors
+                let rock_base = Color::rgb(0.38, 0.38, 0.38);      // #606060
+                let rock_highlight = Color::rgb(0.50, 0.50, 0.50); // #808080
+                let rock_shadow = Color::rgb(0.25, 0.25, 0.25);    // #404040
+                let black = Color::rgb(0.0, 0.0, 0.0);
+                
+                // Draw main rock body (irregular shape with overlapping ellipses)
+                draw_ellipse(sx(50.0), sy(55.0), 35.0 * scale, 30.0 * scale, 0.0, Self::to_mq_color(rock_base));
+                draw_ellipse_lines(sx(50.0), sy(55.0), 35.0 * scale, 30.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
+                
+                draw_ellipse(sx(35.0), sy(50.0), 18.0 * scale, 20.0 * scale, 0.0, Self::to_mq_color(rock_base));
+                draw_ellipse_lines(sx(35.0), sy(50.0), 18.0 * scale, 20.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
+                
+                draw_ellipse(sx(65.0), sy(52.0), 15.0 * scale, 18.0 * scale, 0.0, Self::to_mq_color(rock_base));
+                draw_ellipse_lines(sx(65.0), sy(52.0), 15.0 * scale, 18.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
+                
+                draw_ellipse(sx(50.0), sy(35.0), 20.0 * scale, 18.0 * scale, 0.0, Self::to_mq_color(rock_base));
+                draw_ellipse_lines(sx(50.0), sy(35.0), 20.0 * scale, 18.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
+                
+                // Highlights (top left areas)
+                draw_ellipse(sx(40.0), sy(40.0), 15.0 * scale, 12.0 * scale, 0.0, Self::to_mq_color(rock_highlight));
+                draw_ellipse(sx(32.0), sy(48.0), 8.0 * scale, 10.0 * scale, 0.0, Self::to_mq_color(rock_highlight));
+                
+                // Shadows (bottom right areas)
+                draw_ellipse(sx(58.0), sy(60.0), 18.0 * scale, 15.0 * scale, 0.0, Self::to_mq_color(rock_shadow));
+                draw_ellipse(sx(65.0), sy(55.0), 10.0 * scale, 12.0 * scale, 0.0, Self::to_mq_color(rock_shadow));
+            }
+            Object::Tree => {
+                // Draw tree in Super Auto Pets style (from tree.svg)
+                // Scale SVG coordinates (0-100) to fit in tile
+                let scale = obj_size / 100.0;
+                let offset_x = center_x - obj_size / 2.0;
+                let offset_y = center_y - obj_size / 2.0;
+                
+                // Helper to convert SVG coords to screen coords
+                let sx = |x: f32| offset_x + x * scale;
+                let sy = |y: f32| offset_y + y * scale;
+                
+                // Trunk colors
+                let trunk_base = Color::rgb(0.36, 0.25, 0.20);   // #5C4033
+                let trunk_highlight = Color::rgb(0.42, 0.30, 0.23); // #6B4D3B
+                let trunk_shadow = Color::rgb(0.29, 0.20, 0.16);    // #4A3329
+                
+                // Foliage colors
+                let foliage_base = Color::rgb(0.18, 0.31, 0.09);    // #2D5016
+                let foliage_highlight = Color::rgb(0.23, 0.42, 0.12); // #3A6B1E
+                let foliage_shadow = Color::rgb(0.12, 0.23, 0.06);   // #1F3A0F
+                
+                // Draw trunk base
+                draw_rectangle(
+                    sx(42.0), sy(55.0), 16.0 * scale, 30.0 * scale,
+                    Self::to_mq_color(trunk_base)
                 );
-            } else {
-                // Fallback to geometric rendering
-                match object {
-                Object::Rock => {
-                    // Draw rock in Super Auto Pets style (from rock.svg)
-                    let scale = obj_size / 100.0;
-                    let offset_x = center_x - obj_size / 2.0;
-                    let offset_y = center_y - obj_size / 2.0;
-                    
-                    // Helper to convert SVG coords to screen coords
-                    let sx = |x: f32| offset_x + x * scale;
-                    let sy = |y: f32| offset_y + y * scale;
-                    
-                    // Rock colors
-                    let rock_base = Color::rgb(0.38, 0.38, 0.38);      // #606060
-                    let rock_highlight = Color::rgb(0.50, 0.50, 0.50); // #808080
-                    let rock_shadow = Color::rgb(0.25, 0.25, 0.25);    // #404040
-                    let black = Color::rgb(0.0, 0.0, 0.0);
-                    
-                    // Draw main rock body (irregular shape with overlapping ellipses)
-                    draw_ellipse(sx(50.0), sy(55.0), 35.0 * scale, 30.0 * scale, 0.0, Self::to_mq_color(rock_base));
-                    draw_ellipse_lines(sx(50.0), sy(55.0), 35.0 * scale, 30.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                    
-                    draw_ellipse(sx(35.0), sy(50.0), 18.0 * scale, 20.0 * scale, 0.0, Self::to_mq_color(rock_base));
-                    draw_ellipse_lines(sx(35.0), sy(50.0), 18.0 * scale, 20.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                    
-                    draw_ellipse(sx(65.0), sy(52.0), 15.0 * scale, 18.0 * scale, 0.0, Self::to_mq_color(rock_base));
-                    draw_ellipse_lines(sx(65.0), sy(52.0), 15.0 * scale, 18.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                    
-                    draw_ellipse(sx(50.0), sy(35.0), 20.0 * scale, 18.0 * scale, 0.0, Self::to_mq_color(rock_base));
-                    draw_ellipse_lines(sx(50.0), sy(35.0), 20.0 * scale, 18.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                    
-                    // Highlights (top left areas)
-                    draw_ellipse(sx(40.0), sy(40.0), 15.0 * scale, 12.0 * scale, 0.0, Self::to_mq_color(rock_highlight));
-                    draw_ellipse(sx(32.0), sy(48.0), 8.0 * scale, 10.0 * scale, 0.0, Self::to_mq_color(rock_highlight));
-                    
-                    // Shadows (bottom right areas)
-                    draw_ellipse(sx(58.0), sy(60.0), 18.0 * scale, 15.0 * scale, 0.0, Self::to_mq_color(rock_shadow));
-                    draw_ellipse(sx(65.0), sy(55.0), 10.0 * scale, 12.0 * scale, 0.0, Self::to_mq_color(rock_shadow));
-                }
-                Object::Tree => {
-                    // Draw tree in Super Auto Pets style (from tree.svg)
-                    // Scale SVG coordinates (0-100) to fit in tile
-                    let scale = obj_size / 100.0;
-                    let offset_x = center_x - obj_size / 2.0;
-                    let offset_y = center_y - obj_size / 2.0;
-                    
-                    // Helper to convert SVG coords to screen coords
-                    let sx = |x: f32| offset_x + x * scale;
-                    let sy = |y: f32| offset_y + y * scale;
-                    
-                    // Trunk colors
-                    let trunk_base = Color::rgb(0.36, 0.25, 0.20);   // #5C4033
-                    let trunk_highlight = Color::rgb(0.42, 0.30, 0.23); // #6B4D3B
-                    let trunk_shadow = Color::rgb(0.29, 0.20, 0.16);    // #4A3329
-                    
-                    // Foliage colors
-                    let foliage_base = Color::rgb(0.18, 0.31, 0.09);    // #2D5016
-                    let foliage_highlight = Color::rgb(0.23, 0.42, 0.12); // #3A6B1E
-                    let foliage_shadow = Color::rgb(0.12, 0.23, 0.06);   // #1F3A0F
-                    
-                    // Draw trunk base
-                    draw_rectangle(
-                        sx(42.0), sy(55.0), 16.0 * scale, 30.0 * scale,
-                        Self::to_mq_color(trunk_base)
-                    );
-                    draw_rectangle_lines(
-                        sx(42.0), sy(55.0), 16.0 * scale, 30.0 * scale,
-                        5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0))
-                    );
-                    
-                    // Trunk highlight (left side)
-                    draw_rectangle(
-                        sx(42.0), sy(55.0), 6.0 * scale, 30.0 * scale,
-                        Self::to_mq_color(trunk_highlight)
-                    );
-                    
-                    // Trunk shadow (right side)
-                    draw_rectangle(
-                        sx(52.0), sy(55.0), 6.0 * scale, 30.0 * scale,
-                        Self::to_mq_color(trunk_shadow)
-                    );
-                    
-                    // Main foliage circle
-                    draw_circle(sx(50.0), sy(45.0), 28.0 * scale, Self::to_mq_color(foliage_base));
-                    draw_circle_lines(sx(50.0), sy(45.0), 28.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
-                    
-                    // Foliage highlight (top left)
-                    draw_circle(sx(42.0), sy(38.0), 15.0 * scale, Self::to_mq_color(foliage_highlight));
-                    
-                    // Foliage shadow (bottom right)
-                    draw_circle(sx(58.0), sy(52.0), 12.0 * scale, Self::to_mq_color(foliage_shadow));
-                    
-                    // Small foliage details
-                    draw_circle(sx(35.0), sy(50.0), 12.0 * scale, Self::to_mq_color(foliage_base));
-                    draw_circle_lines(sx(35.0), sy(50.0), 12.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
-                    
-                    draw_circle(sx(65.0), sy(48.0), 10.0 * scale, Self::to_mq_color(foliage_base));
-                    draw_circle_lines(sx(65.0), sy(48.0), 10.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
-                    
-                    draw_circle(sx(50.0), sy(25.0), 14.0 * scale, Self::to_mq_color(foliage_base));
-                    draw_circle_lines(sx(50.0), sy(25.0), 14.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
-                    
-                    // Highlight on top foliage
-                    draw_circle(sx(48.0), sy(23.0), 6.0 * scale, Self::to_mq_color(foliage_highlight));
-                }
-                Object::Stick => {
-                    // Draw stick in Super Auto Pets style (from stick.svg)
-                    let scale = obj_size / 100.0;
-                    let offset_x = center_x - obj_size / 2.0;
-                    let offset_y = center_y - obj_size / 2.0;
-                    
-                    // Helper to convert SVG coords to screen coords
-                    let sx = |x: f32| offset_x + x * scale;
-                    let sy = |y: f32| offset_y + y * scale;
-                    
-                    // Stick colors
-                    let stick_base = Color::rgb(0.55, 0.44, 0.28);      // #8B6F47
-                    let stick_highlight = Color::rgb(0.65, 0.54, 0.37); // #A68A5E
-                    let stick_shadow = Color::rgb(0.42, 0.33, 0.22);    // #6B5537
-                    let black = Color::rgb(0.0, 0.0, 0.0);
-                    
-                    // Draw stick as angled line with thickness (25 degree rotation)
-                    let angle = 25.0_f32.to_radians();
-                    let stick_length = 70.0 * scale;
-                    let stick_width = 10.0 * scale;
-                    
-                    // Calculate stick endpoints (center at 35, 50 in SVG coords)
-                    let stick_center_x = sx(45.0);
-                    let stick_center_y = sy(50.0);
-                    
-                    let half_len = stick_length / 2.0;
-                    let start_x = stick_center_x - angle.cos() * half_len;
-                    let start_y = stick_center_y - angle.sin() * half_len;
-                    let end_x = stick_center_x + angle.cos() * half_len;
-                    let end_y = stick_center_y + angle.sin() * half_len;
-                    
-                    // Draw as thick line segments for base, highlight, and shadow
-                    // Base stick
-                    draw_line(start_x, start_y, end_x, end_y, stick_width, Self::to_mq_color(stick_base));
-                    
-                    // Highlight (left side) - draw thinner line offset to the left
-                    let offset_perp = stick_width * 0.25;
-                    let perp_x = -angle.sin();
-                    let perp_y = angle.cos();
-                    draw_line(
-                        start_x + perp_x * offset_perp, 
-                        start_y + perp_y * offset_perp,
-                        end_x + perp_x * offset_perp, 
-                        end_y + perp_y * offset_perp,
-                        stick_width * 0.35,
-                        Self::to_mq_color(stick_highlight)
-                    );
-                    
-                    // Shadow (right side)
-                    draw_line(
-                        start_x - perp_x * offset_perp, 
-                        start_y - perp_y * offset_perp,
-                        end_x - perp_x * offset_perp, 
-                        end_y - perp_y * offset_perp,
-                        stick_width * 0.35,
-                        Self::to_mq_color(stick_shadow)
-                    );
-                    
-                    // Draw outline by drawing thin black lines along edges
-                    draw_line(start_x, start_y, end_x, end_y, stick_width + 5.0 * scale, Self::to_mq_color(black));
-                    draw_line(start_x, start_y, end_x, end_y, stick_width, Self::to_mq_color(stick_base));
-                    
-                    // Re-draw highlights and shadows on top
-                    draw_line(
-                        start_x + perp_x * offset_perp, 
-                        start_y + perp_y * offset_perp,
-                        end_x + perp_x * offset_perp, 
-                        end_y + perp_y * offset_perp,
-                        stick_width * 0.35,
-                        Self::to_mq_color(stick_highlight)
-                    );
-                    draw_line(
-                        start_x - perp_x * offset_perp, 
-                        start_y - perp_y * offset_perp,
-                        end_x - perp_x * offset_perp, 
-                        end_y - perp_y * offset_perp,
-                        stick_width * 0.35,
-                        Self::to_mq_color(stick_shadow)
-                    );
-                    
-                    // Small knot details
-                    draw_ellipse(sx(45.0), sy(35.0), 4.0 * scale, 3.0 * scale, 0.0, Self::to_mq_color(stick_shadow));
-                    draw_ellipse_lines(sx(45.0), sy(35.0), 4.0 * scale, 3.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                    
-                    draw_ellipse(sx(52.0), sy(60.0), 3.5 * scale, 3.0 * scale, 0.0, Self::to_mq_color(stick_shadow));
-                    draw_ellipse_lines(sx(52.0), sy(60.0), 3.5 * scale, 3.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
-                }
+                draw_rectangle_lines(
+                    sx(42.0), sy(55.0), 16.0 * scale, 30.0 * scale,
+                    5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0))
+                );
+                
+                // Trunk highlight (left side)
+                draw_rectangle(
+                    sx(42.0), sy(55.0), 6.0 * scale, 30.0 * scale,
+                    Self::to_mq_color(trunk_highlight)
+                );
+                
+                // Trunk shadow (right side)
+                draw_rectangle(
+                    sx(52.0), sy(55.0), 6.0 * scale, 30.0 * scale,
+                    Self::to_mq_color(trunk_shadow)
+                );
+                
+                // Main foliage circle
+                draw_circle(sx(50.0), sy(45.0), 28.0 * scale, Self::to_mq_color(foliage_base));
+                draw_circle_lines(sx(50.0), sy(45.0), 28.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
+                
+                // Foliage highlight (top left)
+                draw_circle(sx(42.0), sy(38.0), 15.0 * scale, Self::to_mq_color(foliage_highlight));
+                
+                // Foliage shadow (bottom right)
+                draw_circle(sx(58.0), sy(52.0), 12.0 * scale, Self::to_mq_color(foliage_shadow));
+                
+                // Small foliage details
+                draw_circle(sx(35.0), sy(50.0), 12.0 * scale, Self::to_mq_color(foliage_base));
+                draw_circle_lines(sx(35.0), sy(50.0), 12.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
+                
+                draw_circle(sx(65.0), sy(48.0), 10.0 * scale, Self::to_mq_color(foliage_base));
+                draw_circle_lines(sx(65.0), sy(48.0), 10.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
+                
+                draw_circle(sx(50.0), sy(25.0), 14.0 * scale, Self::to_mq_color(foliage_base));
+                draw_circle_lines(sx(50.0), sy(25.0), 14.0 * scale, 5.0 * scale, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
+                
+                // Highlight on top foliage
+                draw_circle(sx(48.0), sy(23.0), 6.0 * scale, Self::to_mq_color(foliage_highlight));
+            }
+            Object::Stick => {
+                // Draw stick in Super Auto Pets style (from stick.svg)
+                let scale = obj_size / 100.0;
+                let offset_x = center_x - obj_size / 2.0;
+                let offset_y = center_y - obj_size / 2.0;
+                
+                // Helper to convert SVG coords to screen coords
+                let sx = |x: f32| offset_x + x * scale;
+                let sy = |y: f32| offset_y + y * scale;
+                
+                // Stick colors
+                let stick_base = Color::rgb(0.55, 0.44, 0.28);      // #8B6F47
+                let stick_highlight = Color::rgb(0.65, 0.54, 0.37); // #A68A5E
+                let stick_shadow = Color::rgb(0.42, 0.33, 0.22);    // #6B5537
+                let black = Color::rgb(0.0, 0.0, 0.0);
+                
+                // Draw stick as angled line with thickness (25 degree rotation)
+                let angle = 25.0_f32.to_radians();
+                let stick_length = 70.0 * scale;
+                let stick_width = 10.0 * scale;
+                
+                // Calculate stick endpoints (center at 35, 50 in SVG coords)
+                let stick_center_x = sx(45.0);
+                let stick_center_y = sy(50.0);
+                
+                let half_len = stick_length / 2.0;
+                let start_x = stick_center_x - angle.cos() * half_len;
+                let start_y = stick_center_y - angle.sin() * half_len;
+                let end_x = stick_center_x + angle.cos() * half_len;
+                let end_y = stick_center_y + angle.sin() * half_len;
+                
+                // Draw as thick line segments for base, highlight, and shadow
+                // Base stick
+                draw_line(start_x, start_y, end_x, end_y, stick_width, Self::to_mq_color(stick_base));
+                
+                // Highlight (left side) - draw thinner line offset to the left
+                let offset_perp = stick_width * 0.25;
+                let perp_x = -angle.sin();
+                let perp_y = angle.cos();
+                draw_line(
+                    start_x + perp_x * offset_perp, 
+                    start_y + perp_y * offset_perp,
+                    end_x + perp_x * offset_perp, 
+                    end_y + perp_y * offset_perp,
+                    stick_width * 0.35,
+                    Self::to_mq_color(stick_highlight)
+                );
+                
+                // Shadow (right side)
+                draw_line(
+                    start_x - perp_x * offset_perp, 
+                    start_y - perp_y * offset_perp,
+                    end_x - perp_x * offset_perp, 
+                    end_y - perp_y * offset_perp,
+                    stick_width * 0.35,
+                    Self::to_mq_color(stick_shadow)
+                );
+                
+                // Draw outline by drawing thin black lines along edges
+                draw_line(start_x, start_y, end_x, end_y, stick_width + 5.0 * scale, Self::to_mq_color(black));
+                draw_line(start_x, start_y, end_x, end_y, stick_width, Self::to_mq_color(stick_base));
+                
+                // Re-draw highlights and shadows on top
+                draw_line(
+                    start_x + perp_x * offset_perp, 
+                    start_y + perp_y * offset_perp,
+                    end_x + perp_x * offset_perp, 
+                    end_y + perp_y * offset_perp,
+                    stick_width * 0.35,
+                    Self::to_mq_color(stick_highlight)
+                );
+                draw_line(
+                    start_x - perp_x * offset_perp, 
+                    start_y - perp_y * offset_perp,
+                    end_x - perp_x * offset_perp, 
+                    end_y - perp_y * offset_perp,
+                    stick_width * 0.35,
+                    Self::to_mq_color(stick_shadow)
+                );
+                
+                // Small knot details
+                draw_ellipse(sx(45.0), sy(35.0), 4.0 * scale, 3.0 * scale, 0.0, Self::to_mq_color(stick_shadow));
+                draw_ellipse_lines(sx(45.0), sy(35.0), 4.0 * scale, 3.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
+                
+                draw_ellipse(sx(52.0), sy(60.0), 3.5 * scale, 3.0 * scale, 0.0, Self::to_mq_color(stick_shadow));
+                draw_ellipse_lines(sx(52.0), sy(60.0), 3.5 * scale, 3.0 * scale, 0.0, 5.0 * scale, Self::to_mq_color(black));
             }
+            Object::Ore => {
+                // Draw ore as a faceted nugget (no texture yet)
+                let radius = obj_size * 0.3;
+                draw_poly(center_x, center_y, 6, radius, 0.0, Self::to_mq_color(object.to_color()));
+                draw_poly_lines(center_x, center_y, 6, radius, 0.0, 3.0, Self::to_mq_color(Color::rgb(0.0, 0.0, 0.0)));
+                draw_circle(center_x - radius * 0.3, center_y - radius * 0.3, radius * 0.25, Self::to_mq_color(Color::rgb(1.0, 0.9, 0.6)));
             }
         }
+        }
+    }
 
-        // Draw border
-        draw_rectangle_lines(x, y, size, size, 1.0, Self::to_mq_color(Color::rgb(0.1, 0.1, 0.1)));
+    fn draw_tile_blended(&mut self, x: f32, y: f32, size: f32, color: Color, over: Color) {
+        draw_rectangle(x, y, size, size, Self::to_mq_color(color.over(&over)));
+    }
+
+    fn draw_object_badge(&mut self, x: f32, y: f32, size: f32, count: usize) {
+        // Dark circle in the top-right corner with the count in white
+        let radius = (size * 0.16).max(6.0);
+        let center_x = x + size - radius - 1.0;
+        let center_y = y + radius + 1.0;
+        draw_circle(center_x, center_y, radius, Self::to_mq_color(Color::new(0.0, 0.0, 0.0, 0.75)));
+
+        let text = count.to_string();
+        let font_size = radius * 1.6;
+        let text_dims = measure_text(&text, None, font_size as u16, 1.0);
+        draw_text(
+            &text,
+            center_x - text_dims.width / 2.0,
+            center_y + text_dims.offset_y / 2.0,
+            font_size,
+            Self::to_mq_color(Color::rgb(1.0, 1.0, 1.0)),
+        );
     }
 
     fn draw_biome_overview(&mut self, x: f32, y: f32, size: f32, biome: &Biome) {
@@ -390,42 +401,38 @@ impl Renderer for MacroquadRenderer {
         bottom_right: &Biome,
         border_width: f32,
     ) {
-        // Draw center biome as the main rectangle
-        let center_color = Self::biome_color(center);
-        draw_rectangle(x, y, size, size, Self::to_mq_color(center_color));
+        let borders = [top, bottom, left, right, top_left, top_right, bottom_left, bottom_right].map(Self::biome_color);
+        self.draw_biome_overview_with_border_colors(x, y, size, Self::biome_color(center), borders, border_width);
+    }
+
+    fn draw_biome_overview_with_border_colors(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        center: Color,
+        borders: [Color; 8],
+        border_width: f32,
+    ) {
+        // Draw center color as the main rectangle
+        draw_rectangle(x, y, size, size, Self::to_mq_color(center));
         
         // Apply natural shadow effect to border colors (darker, blue-shifted)
         // This makes grid lines distinguishable while showing biome transitions
+        let [top, bottom, left, right, top_left, top_right, bottom_left, bottom_right] =
+            borders.map(|color| Self::to_mq_color(Self::shadow_color(color)));
         
-        // Draw borders using edge biome colors with shadow effect
-        // Top edge
-        let top_color = Self::shadow_color(Self::biome_color(top));
-        draw_rectangle(x, y, size, border_width, Self::to_mq_color(top_color));
-        
-        // Bottom edge
-        let bottom_color = Self::shadow_color(Self::biome_color(bottom));
-        draw_rectangle(x, y + size - border_width, size, border_width, Self::to_mq_color(bottom_color));
-        
-        // Left edge
-        let left_color = Self::shadow_color(Self::biome_color(left));
-        draw_rectangle(x, y, border_width, size, Self::to_mq_color(left_color));
-        
-        // Right edge
-        let right_color = Self::shadow_color(Self::biome_color(right));
-        draw_rectangle(x + size - border_width, y, border_width, size, Self::to_mq_color(right_color));
-        
-        // Draw corners using corner biome colors with shadow effect (overlay on top of edges)
-        let top_left_color = Self::shadow_color(Self::biome_color(top_left));
-        draw_rectangle(x, y, border_width, border_width, Self::to_mq_color(top_left_color));
+        // Edges
+        draw_rectangle(x, y, size, border_width, top);
+        draw_rectangle(x, y + size - border_width, size, border_width, bottom);
+        draw_rectangle(x, y, border_width, size, left);
+        draw_rectangle(x + size - border_width, y, border_width, size, right);
         
-        let top_right_color = Self::shadow_color(Self::biome_color(top_right));
-        draw_rectangle(x + size - border_width, y, border_width, border_width, Self::to_mq_color(top_right_color));
-        
-        let bottom_left_color = Self::shadow_color(Self::biome_color(bottom_left));
-        draw_rectangle(x, y + size - border_width, border_width, border_width, Self::to_mq_color(bottom_left_color));
-        
-        let bottom_right_color = Self::shadow_color(Self::biome_color(bottom_right));
-        draw_rectangle(x + size - border_width, y + size - border_width, border_width, border_width, Self::to_mq_color(bottom_right_color));
+        // Corners (overlay on top of edges)
+        draw_rectangle(x, y, border_width, border_width, top_left);
+        draw_rectangle(x + size - border_width, y, border_width, border_width, top_right);
+        draw_rectangle(x, y + size - border_width, border_width, border_width, bottom_left);
+        draw_rectangle(x + size - border_width, y + size - border_width, border_width, border_width, bottom_right);
     }
 
     fn draw_selection_indicator(&mut self, x: f32, y: f32, size: f32) {
@@ -487,6 +494,17 @@ impl Renderer for MacroquadRenderer {
         }
     }
 
+    fn draw_minimap_cell(&mut self, x: f32, y: f32, size: f32, color: Color) {
+        draw_rectangle(x, y, size, size, Self::to_mq_color(color));
+    }
+
+    fn draw_minimap_marker(&mut self, x: f32, y: f32, size: f32) {
+        // White outline with a dot so the marker stays visible on any biome color
+        let marker_color = Self::to_mq_color(Color::rgb(1.0, 1.0, 1.0));
+        draw_rectangle_lines(x, y, size, size, (size * 0.2).max(1.0), marker_color);
+        draw_circle(x + size / 2.0, y + size / 2.0, size * 0.2, marker_color);
+    }
+
     fn present(&mut self) -> Result<(), RenderError> {
         // Macroquad handles presentation automatically after each frame
         // next_frame() is called in the main loop, not here
@@ -1,4 +1,11 @@
-use crate::types::{Biome, Object, Substrate};
+use std::collections::HashSet;
+use crate::types::{Biome, Object, Substrate, World};
+
+/// Lands shown on each side of the center land in `Renderer::draw_minimap`
+pub const MINIMAP_RADIUS: i32 = 8;
+
+/// Overlay composited over unexplored minimap cells
+pub const MINIMAP_FOG: Color = Color { r: 0.0, g: 0.0, b: 0.0, a: 0.6 };
 
 /// Error type for rendering operations
 #[derive(Debug)]
@@ -37,6 +44,85 @@ impl Color {
     pub fn rgb(r: f32, g: f32, b: f32) -> Self {
         Self::new(r, g, b, 1.0)
     }
+
+    /// Composite this color over `background` using source-over alpha blending.
+    ///
+    /// For an opaque background this is `out = src*a + dst*(1-a)` per channel. A
+    /// translucent background is handled with the general (straight alpha) form,
+    /// so stacking overlays works regardless of the order they're combined in.
+    pub fn over(&self, background: &Color) -> Color {
+        let out_a = self.a + background.a * (1.0 - self.a);
+        if out_a <= 0.0 {
+            return Color::new(0.0, 0.0, 0.0, 0.0);
+        }
+        let blend = |src: f32, dst: f32| (src * self.a + dst * background.a * (1.0 - self.a)) / out_a;
+        Color::new(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+            out_a,
+        )
+    }
+
+    /// Linear interpolation toward `other`: `t = 0` is `self`, `t = 1` is `other` (clamped)
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Color::new(mix(self.r, other.r), mix(self.g, other.g), mix(self.b, other.b), mix(self.a, other.a))
+    }
+
+    /// Convert to 8-bit RGB channels (alpha is dropped)
+    pub fn to_rgb8(&self) -> [u8; 3] {
+        let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [channel(self.r), channel(self.g), channel(self.b)]
+    }
+}
+
+impl Substrate {
+    pub fn to_color(&self) -> Color {
+        match self {
+            Substrate::Grass => Color::rgb(0.7, 0.9, 0.4),      // Light green/yellow (same as meadow)
+            Substrate::Dirt => Color::rgb(0.6, 0.4, 0.2),       // Brown
+            Substrate::Stone => Color::rgb(0.7, 0.7, 0.7),      // Gray
+            Substrate::Mud => Color::rgb(0.4, 0.3, 0.2),        // Dark brown
+            Substrate::Water => Color::rgb(0.2, 0.4, 0.9),      // Blue
+            Substrate::Brush => Color::rgb(0.2, 0.6, 0.15),    // Dark green, similar to forest
+            Substrate::Sand => Color::rgb(0.93, 0.84, 0.55),    // Pale sandy yellow
+        }
+    }
+}
+
+impl Biome {
+    pub fn to_color(&self) -> Color {
+        match self {
+            Biome::Forest => Color::rgb(0.1, 0.5, 0.1),         // Dark green
+            Biome::Meadow => Color::rgb(0.7, 0.9, 0.4),         // Light green/yellow
+            Biome::Lake => Color::rgb(0.2, 0.5, 0.9),           // Blue
+            Biome::Mountain => Color::rgb(0.8, 0.8, 0.85),      // Gray/white
+            Biome::Plains => Color::rgb(0.6, 0.5, 0.35),        // Brown/tan (dirt-colored)
+            Biome::Desert => Color::rgb(0.9, 0.75, 0.45),       // Sandy orange
+        }
+    }
+}
+
+impl Object {
+    pub fn to_color(&self) -> Color {
+        match self {
+            Object::Rock => Color::rgb(0.3, 0.3, 0.3),          // Dark gray
+            Object::Tree => Color::rgb(0.1, 0.6, 0.1),          // Green
+            Object::Stick => Color::rgb(0.5, 0.3, 0.1),         // Brown
+            Object::Ore => Color::rgb(0.85, 0.6, 0.2),          // Copper/gold
+        }
+    }
+
+    /// Draw order within a tile: lower values are drawn first (rocks and ore under sticks under trees)
+    pub fn z_order(&self) -> u8 {
+        match self {
+            Object::Rock | Object::Ore => 0,
+            Object::Stick => 1,
+            Object::Tree => 2,
+        }
+    }
 }
 
 /// Input key representation
@@ -74,6 +160,16 @@ pub trait Renderer {
     /// The tile should show the substrate as the base and objects on top
     fn draw_tile(&mut self, x: f32, y: f32, size: f32, substrate: &Substrate, objects: &[Object]);
 
+    /// Fill a tile with `color` composited over `over` (see `Color::over`).
+    /// Used for translucent overlays such as highlights and fog.
+    fn draw_tile_blended(&mut self, x: f32, y: f32, size: f32, color: Color, over: Color);
+
+    /// Draw a single object centered in the tile at the given position
+    fn draw_object(&mut self, x: f32, y: f32, size: f32, object: &Object);
+
+    /// Draw a small badge in the tile's corner showing how many objects it holds
+    fn draw_object_badge(&mut self, x: f32, y: f32, size: f32, count: usize);
+
     /// Draw a biome overview tile at the given position with the specified size
     fn draw_biome_overview(&mut self, x: f32, y: f32, size: f32, biome: &Biome);
 
@@ -96,6 +192,19 @@ pub trait Renderer {
         border_width: f32,
     );
 
+    /// Like `draw_biome_overview_with_borders`, but with precomputed colors
+    /// (e.g. blended toward neighboring lands, see `terrain_view::blended_border_colors`).
+    /// Borders are in the order top, bottom, left, right, top_left, top_right, bottom_left, bottom_right.
+    fn draw_biome_overview_with_border_colors(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        center: Color,
+        borders: [Color; 8],
+        border_width: f32,
+    );
+
     /// Draw a selection indicator (highlight) at the given position and size
     fn draw_selection_indicator(&mut self, x: f32, y: f32, size: f32);
 
@@ -105,6 +214,52 @@ pub trait Renderer {
     /// rows, cols: number of grid cells
     fn draw_grid(&mut self, x: f32, y: f32, width: f32, height: f32, rows: usize, cols: usize);
 
+    /// Fill one minimap cell with a flat color
+    fn draw_minimap_cell(&mut self, x: f32, y: f32, size: f32, color: Color);
+
+    /// Mark the player's cell on the minimap
+    fn draw_minimap_marker(&mut self, x: f32, y: f32, size: f32);
+
+    /// Draw a square minimap of side `size` with its top-left corner at (x, y).
+    /// Shows the lands within `MINIMAP_RADIUS` of `center`, one cell per generated land
+    /// colored by center biome. Lands not in `explored` are dimmed with `MINIMAP_FOG`,
+    /// and the center land gets a marker.
+    fn draw_minimap(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        world: &World,
+        explored: &HashSet<(i32, i32)>,
+        center: (i32, i32),
+    ) {
+        let cell_size = size / (2 * MINIMAP_RADIUS + 1) as f32;
+        let cell_pos = |land_x: i32, land_y: i32| {
+            (
+                x + (land_x - center.0 + MINIMAP_RADIUS) as f32 * cell_size,
+                y + (land_y - center.1 + MINIMAP_RADIUS) as f32 * cell_size,
+            )
+        };
+
+        for land_y in center.1 - MINIMAP_RADIUS..=center.1 + MINIMAP_RADIUS {
+            for land_x in center.0 - MINIMAP_RADIUS..=center.0 + MINIMAP_RADIUS {
+                let Some(land) = world.terrain.get(&(land_x, land_y)) else {
+                    continue;
+                };
+                let color = if explored.contains(&(land_x, land_y)) {
+                    land.center.to_color()
+                } else {
+                    MINIMAP_FOG.over(&land.center.to_color())
+                };
+                let (cell_x, cell_y) = cell_pos(land_x, land_y);
+                self.draw_minimap_cell(cell_x, cell_y, cell_size, color);
+            }
+        }
+
+        let (marker_x, marker_y) = cell_pos(center.0, center.1);
+        self.draw_minimap_marker(marker_x, marker_y, cell_size);
+    }
+
     /// Present the rendered frame to the screen
     fn present(&mut self) -> Result<(), RenderError>;
 
@@ -127,6 +282,7 @@ pub trait Renderer {
 
 // Re-export implementations
 pub mod macroquad;
+pub mod headless;
 mod textures;
 
 // Future Bevy implementation
@@ -1,5 +1,5 @@
-use crate::render::Renderer;
-use crate::types::World;
+use crate::render::{Color, Renderer};
+use crate::types::{Biome, World};
 use crate::camera::CameraCore;
 
 /// Camera for terrain view - manages land-level selection and camera following
@@ -10,16 +10,36 @@ pub struct TerrainCamera {
     /// Currently selected land coordinates
     pub selected_land_x: i32,
     pub selected_land_y: i32,
+
+    /// Index into `ZOOM_LEVELS`
+    zoom_level: usize,
+
+    /// Screen size in pixels, used by `visible_bounds`
+    viewport: (f32, f32),
+
+    /// Generated lands (x1, y1, x2, y2) that `center_on` keeps the selection inside
+    generated_bounds: Option<(i32, i32, i32, i32)>,
+
+    /// Blend border colors toward neighboring lands (see `blended_border_colors`)
+    pub blend_borders: bool,
 }
 
 impl TerrainCamera {
     const BASE_TILE_SIZE: f32 = 48.0;
 
+    /// Discrete zoom factors, from most zoomed out (most lands on screen) to most zoomed in
+    pub const ZOOM_LEVELS: [f32; 5] = [0.5, 0.75, 1.0, 1.5, 2.0];
+    const DEFAULT_ZOOM_LEVEL: usize = 2;
+
     pub fn new() -> Self {
         Self {
             core: CameraCore::new(Self::BASE_TILE_SIZE),
             selected_land_x: 0,
             selected_land_y: 0,
+            zoom_level: Self::DEFAULT_ZOOM_LEVEL,
+            viewport: (1200.0, 900.0),
+            generated_bounds: None,
+            blend_borders: false,
         }
     }
 
@@ -28,14 +48,65 @@ impl TerrainCamera {
         self.core.get_tile_size()
     }
 
-    /// Zoom in (increase zoom level)
+    /// Current index into `ZOOM_LEVELS`
+    pub fn zoom_level(&self) -> usize {
+        self.zoom_level
+    }
+
+    /// Jump to a zoom level, clamped to the last entry of `ZOOM_LEVELS`
+    pub fn set_zoom_level(&mut self, level: usize) {
+        self.zoom_level = level.min(Self::ZOOM_LEVELS.len() - 1);
+        self.core.set_zoom(Self::ZOOM_LEVELS[self.zoom_level]);
+    }
+
+    /// Zoom in one level (fewer, larger lands on screen)
     pub fn zoom_in(&mut self) {
-        self.core.zoom_in();
+        self.set_zoom_level(self.zoom_level + 1);
     }
 
-    /// Zoom out (decrease zoom level)
+    /// Zoom out one level (more, smaller lands on screen)
     pub fn zoom_out(&mut self) {
-        self.core.zoom_out();
+        self.set_zoom_level(self.zoom_level.saturating_sub(1));
+    }
+
+    /// Set the screen size used by `visible_bounds` (call when the window resizes)
+    pub fn set_viewport(&mut self, width: f32, height: f32) {
+        self.viewport = (width, height);
+    }
+
+    /// Lands drawn on a `width` x `height` screen around the selection, as
+    /// inclusive (x1, y1, x2, y2). Includes a partial land on every edge.
+    pub fn visible_bounds_in(&self, width: f32, height: f32) -> (i32, i32, i32, i32) {
+        let tile_size = self.get_tile_size();
+        let half_cols = ((width / tile_size) as i32 + 2) / 2;
+        let half_rows = ((height / tile_size) as i32 + 2) / 2;
+        (
+            self.selected_land_x - half_cols,
+            self.selected_land_y - half_rows,
+            self.selected_land_x + half_cols,
+            self.selected_land_y + half_rows,
+        )
+    }
+
+    /// `visible_bounds_in` for the current viewport, e.g. to generate exactly what's on screen
+    pub fn visible_bounds(&self) -> (i32, i32, i32, i32) {
+        self.visible_bounds_in(self.viewport.0, self.viewport.1)
+    }
+
+    /// Restrict `center_on` to the given generated region (inclusive land coordinates),
+    /// or lift the restriction with `None`
+    pub fn set_generated_bounds(&mut self, bounds: Option<(i32, i32, i32, i32)>) {
+        self.generated_bounds = bounds;
+    }
+
+    /// Select `land` and pan smoothly towards it. If generated bounds are set, the
+    /// land is clamped into them so the view always contains generated terrain.
+    pub fn center_on(&mut self, land: (i32, i32)) {
+        let (x, y) = match self.generated_bounds {
+            Some((x1, y1, x2, y2)) => (land.0.clamp(x1.min(x2), x1.max(x2)), land.1.clamp(y1.min(y2), y1.max(y2))),
+            None => land,
+        };
+        self.set_selected_land(x, y);
     }
 
     /// Convert world coordinates to screen coordinates
@@ -91,6 +162,31 @@ impl Default for TerrainCamera {
     }
 }
 
+/// How far `blended_border_colors` moves each border toward the neighboring land's center biome
+pub const BORDER_BLEND: f32 = 0.5;
+
+/// Border colors for a land's overview, each moved `t` of the way toward the center biome
+/// of the land it faces (edges toward edge neighbors, corners toward diagonal neighbors).
+/// Borders facing ungenerated lands keep their own color. Order matches
+/// `Renderer::draw_biome_overview_with_border_colors`. Returns None if the land isn't generated.
+pub fn blended_border_colors(world: &World, land_x: i32, land_y: i32, t: f32) -> Option<[Color; 8]> {
+    let land = world.terrain.get(&(land_x, land_y))?;
+    let borders = [
+        (&land.top, (0, -1)),
+        (&land.bottom, (0, 1)),
+        (&land.left, (-1, 0)),
+        (&land.right, (1, 0)),
+        (&land.top_left, (-1, -1)),
+        (&land.top_right, (1, -1)),
+        (&land.bottom_left, (-1, 1)),
+        (&land.bottom_right, (1, 1)),
+    ];
+    Some(borders.map(|(biome, (dx, dy))| match world.terrain.get(&(land_x + dx, land_y + dy)) {
+        Some(neighbor) => Biome::blend_color(biome, &neighbor.center, t),
+        None => biome.to_color(),
+    }))
+}
+
 /// Render terrain view - shows biome overview
 pub fn render<R: Renderer>(
     renderer: &mut R,
@@ -99,15 +195,7 @@ pub fn render<R: Renderer>(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let (screen_width, screen_height) = renderer.window_size();
     let tile_size = camera.get_tile_size();
-    
-    // Calculate how many tiles we can fit on screen
-    let tiles_per_row = (screen_width / tile_size) as i32 + 2;
-    let tiles_per_col = (screen_height / tile_size) as i32 + 2;
-    
-    let start_x = camera.selected_land_x - tiles_per_row / 2;
-    let start_y = camera.selected_land_y - tiles_per_col / 2;
-    let end_x = camera.selected_land_x + tiles_per_row / 2;
-    let end_y = camera.selected_land_y + tiles_per_col / 2;
+    let (start_x, start_y, end_x, end_y) = camera.visible_bounds_in(screen_width, screen_height);
 
     for y in start_y..=end_y {
         for x in start_x..=end_x {
@@ -118,21 +206,36 @@ pub fn render<R: Renderer>(
                 
                 // Draw single square per land with center biome color and colored borders from edge/corner biomes
                 let border_width = 2.0; // Borders to show biome transitions
-                renderer.draw_biome_overview_with_borders(
-                    screen_x,
-                    screen_y,
-                    tile_size,
-                    &land.center,
-                    &land.top,
-                    &land.bottom,
-                    &land.left,
-                    &land.right,
-                    &land.top_left,
-                    &land.top_right,
-                    &land.bottom_left,
-                    &land.bottom_right,
-                    border_width,
-                );
+                let blended = if camera.blend_borders {
+                    blended_border_colors(world, x, y, BORDER_BLEND)
+                } else {
+                    None
+                };
+                match blended {
+                    Some(borders) => renderer.draw_biome_overview_with_border_colors(
+                        screen_x,
+                        screen_y,
+                        tile_size,
+                        land.center.to_color(),
+                        borders,
+                        border_width,
+                    ),
+                    None => renderer.draw_biome_overview_with_borders(
+                        screen_x,
+                        screen_y,
+                        tile_size,
+                        &land.center,
+                        &land.top,
+                        &land.bottom,
+                        &land.left,
+                        &land.right,
+                        &land.top_left,
+                        &land.top_right,
+                        &land.bottom_left,
+                        &land.bottom_right,
+                        border_width,
+                    ),
+                }
             }
         }
     }
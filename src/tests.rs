@@ -8,6 +8,7 @@ mod tests {
         let mut world = World {
             name: "TestWorld".to_string(),
             terrain: HashMap::new(),
+            seed: 12347,
         };
         initialize_world(&mut world, 12347);
         world
@@ -18,6 +19,7 @@ mod tests {
         let mut world = World {
             name: "TestWorld".to_string(),
             terrain: HashMap::new(),
+            seed: 12347,
         };
         initialize_world(&mut world, 12347);
         
@@ -47,6 +49,7 @@ mod tests {
         let mut has_lake = false;
         let mut has_mountain = false;
         let mut has_plains = false;
+        let mut has_desert = false;
         
         for land in world.terrain.values() {
             match land.center {
@@ -55,11 +58,12 @@ mod tests {
                 Biome::Lake => has_lake = true,
                 Biome::Mountain => has_mountain = true,
                 Biome::Plains => has_plains = true,
+                Biome::Desert => has_desert = true,
             }
         }
         
         // With a seed, we should have at least some variety
-        assert!(has_forest || has_meadow || has_lake || has_mountain || has_plains);
+        assert!(has_forest || has_meadow || has_lake || has_mountain || has_plains || has_desert);
     }
 
     #[test]
@@ -81,6 +85,7 @@ mod tests {
         let mut world = World {
             name: "LakeTest".to_string(),
             terrain: HashMap::new(),
+            seed: 42,
         };
         initialize_world(&mut world, 42);
 
@@ -133,12 +138,14 @@ mod tests {
         let mut world1 = World {
             name: "Test1".to_string(),
             terrain: HashMap::new(),
+            seed: 42,
         };
         initialize_world(&mut world1, 42);
         
         let mut world2 = World {
             name: "Test2".to_string(),
             terrain: HashMap::new(),
+            seed: 42,
         };
         initialize_world(&mut world2, 42);
         
@@ -162,7 +169,8 @@ mod tests {
             }
             Biome::Plains => {
                 matches!(substrate, crate::types::Substrate::Dirt | 
-                                 crate::types::Substrate::Grass)
+                                 crate::types::Substrate::Grass | 
+                                 crate::types::Substrate::Brush)
             }
             Biome::Forest => {
                 matches!(substrate, crate::types::Substrate::Dirt | 
@@ -173,6 +181,10 @@ mod tests {
                 matches!(substrate, crate::types::Substrate::Stone | 
                                  crate::types::Substrate::Dirt)
             }
+            Biome::Desert => {
+                matches!(substrate, crate::types::Substrate::Sand | 
+                                 crate::types::Substrate::Stone)
+            }
         }
     }
 
@@ -313,4 +325,1104 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_seed_from_str_is_stable_fnv1a() {
+        use crate::generation::seed_from_str;
+
+        // Reference FNV-1a 64-bit values; these must never change
+        assert_eq!(seed_from_str(""), 0xcbf29ce484222325);
+        assert_eq!(seed_from_str("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(seed_from_str("myworld"), seed_from_str("myworld"));
+        assert_ne!(seed_from_str("myworld"), seed_from_str("myworld2"));
+    }
+
+    /// Hashes every tile in a region (row-major over land coords) so generator output can be pinned
+    fn region_tile_hash(world: &World, x1: i32, y1: i32, x2: i32, y2: i32) -> u64 {
+        let mut encoded = String::new();
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                let land = world.terrain.get(&(x, y)).expect("region should be generated");
+                encoded.push_str(&serde_json::to_string(&land.tiles).unwrap());
+            }
+        }
+        crate::generation::seed_from_str(&encoded)
+    }
+
+    /// Golden output for seed 12347 over lands (-2,-2)..=(2,2).
+    /// If this fails, `generate_land_terrain` output changed: only update the value
+    /// when the change to existing worlds is intentional.
+    const GOLDEN_REGION_HASH: u64 = 10788205752771183311;
+
+    #[test]
+    fn test_generation_matches_golden_hash() {
+        let mut world = World {
+            name: "Golden".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, -2, -2, 2, 2);
+
+        assert_eq!(region_tile_hash(&world, -2, -2, 2, 2), GOLDEN_REGION_HASH);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_generation_matches_serial() {
+        use crate::generation::{
+            diff_worlds, generate_land_with_config, generate_world_parallel_with_config, GenerationConfig,
+        };
+        use crate::types::Object;
+
+        let config = GenerationConfig::default();
+        let mut serial = World { name: "Serial".to_string(), terrain: HashMap::new(), seed: 12347 };
+        for x in -6..=6 {
+            for y in -6..=6 {
+                generate_land_with_config(&mut serial, 12347, x, y, &config);
+            }
+        }
+
+        let mut parallel = World { name: "Parallel".to_string(), terrain: HashMap::new(), seed: 12347 };
+        // An already generated (and edited) land must be left alone
+        generate_land_with_config(&mut parallel, 12347, 0, 0, &config);
+        parallel.add_object(0, 0, Object::Stick);
+        generate_world_parallel_with_config(&mut parallel, 12347, -6, -6, 6, 6, &config);
+
+        assert_eq!(parallel.terrain.len(), serial.terrain.len());
+        let diff = diff_worlds(&serial, &parallel);
+        // Only the edited land differs; every freshly built land matches the serial run
+        assert_eq!(diff.land_coords(), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn test_rivers_connect_mountain_to_lake() {
+        use crate::generation::carve_rivers;
+        use crate::types::Substrate;
+        use std::collections::{HashSet, VecDeque};
+
+        let mut world = World {
+            name: "Rivers".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, -6, -6, 6, 6);
+        let before = world.clone();
+        carve_rivers(&mut world, 12347);
+        assert_ne!(world, before, "Expected at least one river to be carved");
+
+        let mut rerun = before.clone();
+        carve_rivers(&mut rerun, 12347);
+        assert_eq!(world, rerun, "Rivers must be deterministic for a seed");
+
+        let is_water = |gx: i32, gy: i32| {
+            let land = world.terrain.get(&(gx.div_euclid(8), gy.div_euclid(8)));
+            land.is_some_and(|land| {
+                land.tiles[gy.rem_euclid(8) as usize][gx.rem_euclid(8) as usize].substrate == Substrate::Water
+            })
+        };
+        let center_biome = |gx: i32, gy: i32| world.terrain[&(gx.div_euclid(8), gy.div_euclid(8))].center.clone();
+
+        // Mountain interiors are never water on their own, so water there comes from a river
+        let mut queue: VecDeque<(i32, i32)> = VecDeque::new();
+        let mut seen: HashSet<(i32, i32)> = HashSet::new();
+        for (&(lx, ly), land) in &world.terrain {
+            if land.center != Biome::Mountain {
+                continue;
+            }
+            for ty in 1..=6 {
+                for tx in 1..=6 {
+                    let (gx, gy) = (lx * 8 + tx, ly * 8 + ty);
+                    if is_water(gx, gy) && seen.insert((gx, gy)) {
+                        queue.push_back((gx, gy));
+                    }
+                }
+            }
+        }
+        assert!(!queue.is_empty(), "Expected river water inside a mountain land");
+
+        let mut reached_lake = false;
+        while let Some((gx, gy)) = queue.pop_front() {
+            if center_biome(gx, gy) == Biome::Lake {
+                reached_lake = true;
+                break;
+            }
+            for (nx, ny) in [(gx + 1, gy), (gx - 1, gy), (gx, gy + 1), (gx, gy - 1)] {
+                if is_water(nx, ny) && seen.insert((nx, ny)) {
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        assert!(reached_lake, "Expected a connected water path from a mountain land to a lake land");
+    }
+
+    #[test]
+    fn test_high_lake_cutoff_produces_no_lakes() {
+        use crate::generation::{generate_world_with_config, GenerationConfig};
+
+        let config = GenerationConfig {
+            lake_cutoff: 10.0,
+            ..GenerationConfig::default()
+        };
+        let mut world = World {
+            name: "Dry".to_string(),
+            terrain: HashMap::new(),
+            seed: 42,
+        };
+        generate_world_with_config(&mut world, 42, -5, -5, 5, 5, &config);
+
+        for land in world.terrain.values() {
+            let biomes = [
+                &land.center, &land.top, &land.bottom, &land.left, &land.right,
+                &land.top_left, &land.top_right, &land.bottom_left, &land.bottom_right,
+            ];
+            assert!(biomes.iter().all(|biome| **biome != Biome::Lake));
+        }
+    }
+
+    #[test]
+    fn test_default_config_matches_golden_hash() {
+        use crate::generation::{generate_world_with_config, GenerationConfig};
+
+        let mut world = World {
+            name: "GoldenConfig".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world_with_config(&mut world, 12347, -2, -2, 2, 2, &GenerationConfig::default());
+
+        assert_eq!(region_tile_hash(&world, -2, -2, 2, 2), GOLDEN_REGION_HASH);
+    }
+
+    #[test]
+    fn test_desert_tiles_never_spawn_trees() {
+        use crate::generation::{generate_world_with_config, GenerationConfig};
+        use crate::types::{Object, Substrate};
+
+        // Every meadow/plains biome becomes desert, so the region is guaranteed to contain some
+        let config = GenerationConfig {
+            desert_aridity_min: -1.0,
+            ..GenerationConfig::default()
+        };
+        let mut world = World {
+            name: "Dunes".to_string(),
+            terrain: HashMap::new(),
+            seed: 42,
+        };
+        generate_world_with_config(&mut world, 42, -4, -4, 4, 4, &config);
+
+        let mut desert_tiles = 0;
+        for land in world.terrain.values().filter(|land| land.center == Biome::Desert) {
+            for row in &land.tiles[1..7] {
+                for tile in &row[1..7] {
+                    desert_tiles += 1;
+                    assert!(matches!(tile.substrate, Substrate::Sand | Substrate::Stone));
+                    assert!(!tile.objects.contains(&Object::Tree));
+                    assert!(!tile.objects.contains(&Object::Stick));
+                }
+            }
+        }
+        assert!(desert_tiles > 0, "Expected at least one desert land");
+    }
+
+    #[test]
+    fn test_plains_bushes_never_spawn_trees() {
+        use crate::generation::{generate_world_with_config, GenerationConfig};
+        use crate::types::{Object, Substrate};
+
+        // Every eligible plains tile gets a tree, so only the substrate rule keeps bushes bare
+        let config = GenerationConfig {
+            plains_grass_ratio: 1.0,
+            plains_brush_density: 0.3,
+            plains_tree_density: 1.0,
+            ..GenerationConfig::default()
+        };
+        let mut world = World {
+            name: "Shrubland".to_string(),
+            terrain: HashMap::new(),
+            seed: 42,
+        };
+        generate_world_with_config(&mut world, 42, -8, -8, 8, 8, &config);
+
+        let (mut grass, mut brush) = (0, 0);
+        for land in world.terrain.values().filter(|land| land.center == Biome::Plains) {
+            for row in &land.tiles[1..7] {
+                for tile in &row[1..7] {
+                    match tile.substrate {
+                        Substrate::Grass => {
+                            grass += 1;
+                            assert!(tile.objects.contains(&Object::Tree));
+                        }
+                        Substrate::Brush => {
+                            brush += 1;
+                            assert!(!tile.objects.contains(&Object::Tree));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        assert!(grass > 0 && brush > 0, "Expected both grass and bush plains tiles");
+    }
+
+    #[test]
+    fn test_plains_grass_fraction_with_default_config() {
+        use crate::types::Substrate;
+
+        let mut world = World {
+            name: "Grassland".to_string(),
+            terrain: HashMap::new(),
+            seed: 42,
+        };
+        generate_world(&mut world, 42, -8, -8, 8, 8);
+
+        // Only the 6x6 center of each land is guaranteed to use the center biome
+        let (mut tiles, mut grass, mut brush) = (0, 0, 0);
+        for land in world.terrain.values().filter(|land| land.center == Biome::Plains) {
+            for row in &land.tiles[1..7] {
+                for tile in &row[1..7] {
+                    tiles += 1;
+                    match tile.substrate {
+                        Substrate::Grass => grass += 1,
+                        Substrate::Brush => brush += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        assert!(tiles >= 200, "Expected a sizeable plains region, got {} tiles", tiles);
+
+        let grass_fraction = grass as f64 / tiles as f64;
+        let brush_fraction = brush as f64 / tiles as f64;
+        assert!((0.3..=0.6).contains(&grass_fraction), "grass fraction {:.3}", grass_fraction);
+        assert!(brush > 0 && brush_fraction < 0.1, "brush fraction {:.3}", brush_fraction);
+    }
+
+    #[test]
+    fn test_sand_substrate_round_trips() {
+        use crate::types::{Object, Substrate, Tile};
+
+        let tile = Tile {
+            substrate: Substrate::Sand,
+            objects: vec![Object::Rock],
+            underground: None,
+        };
+        let json = serde_json::to_string(&tile).unwrap();
+        assert!(json.contains("\"Sand\""));
+        assert_eq!(serde_json::from_str::<Tile>(&json).unwrap(), tile);
+
+        let biome: Biome = serde_json::from_str(&serde_json::to_string(&Biome::Desert).unwrap()).unwrap();
+        assert_eq!(biome, Biome::Desert);
+    }
+
+    #[test]
+    fn test_stream_chunks_only_generates_frontier() {
+        let radius = 3.0;
+        let mut world = World {
+            name: "Stream".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+
+        let initial = world.stream_chunks((0, 0), radius);
+        assert_eq!(initial.len(), world.terrain.len());
+        assert_eq!(initial.len(), 29); // lattice points within a radius-3 disc
+
+        // Re-streaming the same center creates nothing
+        assert!(world.stream_chunks((0, 0), radius).is_empty());
+
+        let frontier = world.stream_chunks((1, 0), radius);
+        assert!(!frontier.is_empty());
+        assert!(frontier.len() < initial.len() / 3, "Expected a thin frontier, got {}", frontier.len());
+        for &(x, y) in &frontier {
+            let old = (x * x + y * y) as f32;
+            let new = ((x - 1) * (x - 1) + y * y) as f32;
+            assert!(old > radius * radius && new <= radius * radius);
+        }
+        assert_eq!(world.terrain.len(), initial.len() + frontier.len());
+    }
+
+    #[test]
+    fn test_unload_beyond_drops_distant_lands() {
+        let mut world = World {
+            name: "Unload".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, -3, -3, 3, 3);
+
+        let removed = world.unload_beyond((0, 0), 2.0);
+
+        assert_eq!(world.terrain.len(), 13);
+        assert_eq!(removed.len(), 49 - 13);
+        assert!(world.terrain.contains_key(&(0, 2)));
+        assert!(!world.terrain.contains_key(&(2, 2)));
+    }
+
+    #[test]
+    fn test_substrate_continuous_across_land_boundaries() {
+        let mut world = World {
+            name: "Seams".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, -3, -3, 3, 3);
+
+        for x in -3..3 {
+            for y in -3..=3 {
+                // Horizontal neighbours share the biome coordinate along their common edge
+                let left = &world.terrain[&(x, y)];
+                let right = &world.terrain[&(x + 1, y)];
+                assert_eq!(left.right, right.left);
+                assert_eq!(left.top_right, right.top_left);
+                assert_eq!(left.bottom_right, right.bottom_left);
+                for ty in 1..7 {
+                    assert!(is_valid_substrate_for_biome(&left.tiles[ty][7].substrate, &left.right));
+                    assert!(is_valid_substrate_for_biome(&right.tiles[ty][0].substrate, &left.right));
+                }
+
+                // Same for vertical neighbours (x and y swapped)
+                let top = &world.terrain[&(y, x)];
+                let bottom = &world.terrain[&(y, x + 1)];
+                assert_eq!(top.bottom, bottom.top);
+                for tx in 1..7 {
+                    assert!(is_valid_substrate_for_biome(&top.tiles[7][tx].substrate, &top.bottom));
+                    assert!(is_valid_substrate_for_biome(&bottom.tiles[0][tx].substrate, &top.bottom));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seamless_objects_only_add_border_sticks() {
+        use crate::generation::{generate_world_with_config, GenerationConfig};
+        use crate::types::Object;
+
+        let legacy_config = GenerationConfig {
+            stick_near_tree_density: 0.5,
+            ..GenerationConfig::default()
+        };
+        let seamless_config = GenerationConfig {
+            seamless_objects: true,
+            ..legacy_config.clone()
+        };
+        let new_world = |name: &str| World {
+            name: name.to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        let mut legacy = new_world("Legacy");
+        let mut seamless = new_world("Seamless");
+        generate_world_with_config(&mut legacy, 12347, -3, -3, 3, 3, &legacy_config);
+        generate_world_with_config(&mut seamless, 12347, -3, -3, 3, 3, &seamless_config);
+
+        let mut cross_border_sticks = 0;
+        for (coord, legacy_land) in &legacy.terrain {
+            let seamless_land = &seamless.terrain[coord];
+            for ty in 0..8 {
+                for tx in 0..8 {
+                    let before = &legacy_land.tiles[ty][tx];
+                    let after = &seamless_land.tiles[ty][tx];
+                    let on_border = tx == 0 || tx == 7 || ty == 0 || ty == 7;
+                    if !on_border || before == after {
+                        assert_eq!(before, after);
+                        continue;
+                    }
+                    assert_eq!(before.substrate, after.substrate);
+                    assert!(before.objects.is_empty());
+                    assert_eq!(after.objects, vec![Object::Stick]);
+                    cross_border_sticks += 1;
+                }
+            }
+        }
+        assert!(cross_border_sticks > 0, "Expected trees to drop sticks across land borders");
+    }
+
+    #[test]
+    fn test_tile_at_global_origin_and_negative_coords() {
+        let mut world = World {
+            name: "Lookup".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, -1, -1, 0, 0);
+
+        assert_eq!(world.tile_at_global(0, 0), Some(&world.terrain[&(0, 0)].tiles[0][0]));
+        assert_eq!(world.tile_at_global(7, 3), Some(&world.terrain[&(0, 0)].tiles[3][7]));
+        assert_eq!(world.tile_at_global(-8, -8), Some(&world.terrain[&(-1, -1)].tiles[0][0]));
+        assert_eq!(world.tile_at_global(-3, 2), Some(&world.terrain[&(-1, 0)].tiles[2][5]));
+
+        // (-1, -1) is the bottom-right tile of land (-1, -1), not tile (1, 1) of land (0, 0)
+        let land = &world.terrain[&(-1, -1)];
+        assert_eq!(world.tile_at_global(-1, -1), Some(&land.tiles[7][7]));
+        assert_eq!(world.biome_at_global(-1, -1), Some(land.bottom_right.clone()));
+        assert_eq!(world.biome_at_global(-8, -8), Some(land.top_left.clone()));
+        assert_eq!(world.biome_at_global(-4, -4), Some(land.center.clone()));
+        assert_eq!(world.biome_at_global(0, 3), Some(world.terrain[&(0, 0)].left.clone()));
+    }
+
+    #[test]
+    fn test_tile_at_global_ungenerated_is_none() {
+        let mut world = World {
+            name: "Sparse".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        generate_world(&mut world, 12347, 0, 0, 0, 0);
+
+        assert!(world.tile_at_global(8, 0).is_none());
+        assert!(world.tile_at_global(-1, 0).is_none());
+        assert!(world.biome_at_global(0, -1).is_none());
+        assert!(world.biome_at_global(7, 7).is_some());
+    }
+
+    /// A world of lands (-3,-3)..=(3,3) where every tile is empty grass
+    fn open_grass_world() -> World {
+        use crate::types::{Land, Substrate, Tile};
+
+        let mut world = World {
+            name: "Open".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+        for x in -3..=3 {
+            for y in -3..=3 {
+                let land = Land {
+                    tiles: std::array::from_fn(|_| {
+                        std::array::from_fn(|_| Tile { substrate: Substrate::Grass, objects: Vec::new(), underground: None })
+                    }),
+                    center: Biome::Meadow,
+                    top: Biome::Meadow,
+                    bottom: Biome::Meadow,
+                    left: Biome::Meadow,
+                    right: Biome::Meadow,
+                    top_left: Biome::Meadow,
+                    top_right: Biome::Meadow,
+                    bottom_left: Biome::Meadow,
+                    bottom_right: Biome::Meadow,
+                };
+                world.terrain.insert((x, y), land);
+            }
+        }
+        world
+    }
+
+    fn set_water(world: &mut World, gx: i32, gy: i32) {
+        let land = world.terrain.get_mut(&(gx.div_euclid(8), gy.div_euclid(8))).unwrap();
+        land.tiles[gy.rem_euclid(8) as usize][gx.rem_euclid(8) as usize].substrate = crate::types::Substrate::Water;
+    }
+
+    #[test]
+    fn test_find_path_straight_open() {
+        use crate::pathfinding::{default_passable, find_path};
+
+        let mut world = open_grass_world();
+        let path = find_path(&mut world, (0, 0), (10, 0), default_passable).expect("open path");
+
+        assert_eq!(path.len(), 11);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(10, 0)));
+        assert!(path.iter().all(|&(_, y)| y == 0));
+    }
+
+    #[test]
+    fn test_find_path_around_water() {
+        use crate::pathfinding::{default_passable, find_path};
+
+        let mut world = open_grass_world();
+        for gy in -6..=6 {
+            set_water(&mut world, 5, gy);
+        }
+
+        let path = find_path(&mut world, (0, 0), (10, 0), default_passable).expect("path around the wall");
+
+        assert_eq!(path.len(), 11 + 2 * 7);
+        assert!(path.iter().all(|&(x, y)| x != 5 || !(-6..=6).contains(&y)));
+        for step in path.windows(2) {
+            let (a, b) = (step[0], step[1]);
+            assert_eq!((a.0 - b.0).abs() + (a.1 - b.1).abs(), 1);
+        }
+    }
+
+    #[test]
+    fn test_find_path_unreachable_goal() {
+        use crate::pathfinding::{default_passable, find_path};
+
+        let mut world = open_grass_world();
+        for d in -2..=2 {
+            set_water(&mut world, 10 + d, 8);
+            set_water(&mut world, 10 + d, 12);
+            set_water(&mut world, 8, 10 + d);
+            set_water(&mut world, 12, 10 + d);
+        }
+
+        assert_eq!(find_path(&mut world, (0, 0), (10, 10), default_passable), None);
+        // An impassable goal fails immediately
+        assert_eq!(find_path(&mut world, (0, 0), (8, 10), default_passable), None);
+    }
+
+    #[test]
+    fn test_find_path_generates_lands_lazily() {
+        use crate::pathfinding::find_path;
+
+        let mut world = World {
+            name: "Lazy".to_string(),
+            terrain: HashMap::new(),
+            seed: 12347,
+        };
+
+        let path = find_path(&mut world, (0, 0), (9, 0), |_| true).expect("everything passable");
+
+        assert_eq!(path.len(), 10);
+        assert!(world.terrain.contains_key(&(1, 0)));
+    }
+
+    #[test]
+    fn test_headless_land_view_draws_every_tile() {
+        use crate::land_view::{render, LandCamera};
+        use crate::render::headless::{DrawCommand, HeadlessRenderer};
+
+        let mut world = World { name: "Headless".to_string(), terrain: HashMap::new(), seed: 12347 };
+        crate::generation::generate_land(&mut world, 12347, 0, 0);
+        crate::generation::generate_land(&mut world, 12347, 1, 0);
+
+        let mut camera = LandCamera::new();
+        camera.set_land(0, 0);
+        let mut renderer = HeadlessRenderer::default();
+        render(&mut renderer, &world, &camera).unwrap();
+
+        assert_eq!(renderer.tile_draw_count(), 64);
+        let tile = renderer.commands().iter().find_map(|c| match c {
+            DrawCommand::Tile { substrate, objects, .. } => Some((substrate.clone(), objects.clone())),
+            _ => None,
+        });
+        let first = &world.terrain[&(0, 0)].tiles[0][0];
+        assert_eq!(tile, Some((first.substrate.clone(), first.objects.clone())));
+
+        // Showing adjacent lands adds one full grid per generated neighbour
+        camera.show_adjacent = true;
+        renderer.take_commands();
+        render(&mut renderer, &world, &camera).unwrap();
+        assert_eq!(renderer.tile_draw_count(), 128);
+    }
+
+    #[test]
+    fn test_headless_terrain_view_draws_visible_lands() {
+        use crate::terrain_view::{render, TerrainCamera};
+        use crate::render::headless::HeadlessRenderer;
+
+        let mut world = World { name: "Headless".to_string(), terrain: HashMap::new(), seed: 12347 };
+        for y in 0..2 {
+            for x in 0..3 {
+                crate::generation::generate_land(&mut world, 12347, x, y);
+            }
+        }
+
+        let camera = TerrainCamera::new();
+        let mut renderer = HeadlessRenderer::default();
+        render(&mut renderer, &world, &camera).unwrap();
+
+        assert_eq!(renderer.biome_overview_count(), 6);
+        assert_eq!(renderer.tile_draw_count(), 0);
+    }
+
+    #[test]
+    fn test_terrain_camera_zoom_changes_visible_bounds() {
+        use crate::terrain_view::TerrainCamera;
+
+        let mut camera = TerrainCamera::new();
+        camera.set_viewport(480.0, 240.0);
+        // 48px lands: 10 + 2 columns and 5 + 2 rows around (0, 0)
+        assert_eq!(camera.visible_bounds(), (-6, -3, 6, 3));
+
+        camera.zoom_out();
+        assert_eq!(camera.zoom_level(), 1);
+        // 36px lands: 13 + 2 columns and 6 + 2 rows
+        assert_eq!(camera.visible_bounds(), (-7, -4, 7, 4));
+
+        camera.zoom_in();
+        camera.zoom_in();
+        // 72px lands: 6 + 2 columns and 3 + 2 rows
+        assert_eq!(camera.visible_bounds(), (-4, -2, 4, 2));
+
+        // Zoom levels saturate at both ends
+        for _ in 0..10 {
+            camera.zoom_in();
+        }
+        assert_eq!(camera.zoom_level(), TerrainCamera::ZOOM_LEVELS.len() - 1);
+        for _ in 0..10 {
+            camera.zoom_out();
+        }
+        assert_eq!(camera.zoom_level(), 0);
+        assert_eq!(camera.get_tile_size(), 24.0);
+    }
+
+    #[test]
+    fn test_terrain_camera_center_on_clamps_to_generated_bounds() {
+        use crate::terrain_view::TerrainCamera;
+
+        let mut camera = TerrainCamera::new();
+        camera.set_viewport(480.0, 240.0);
+
+        camera.center_on((20, -7));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (20, -7));
+        assert_eq!(camera.visible_bounds(), (14, -10, 26, -4));
+        // Panning is smooth: the camera heads for the land rather than jumping
+        camera.update(0.05);
+        let (x, y) = camera.get_position();
+        assert!(x > 0.0 && x < 20.0 && y < 0.0 && y > -7.0);
+
+        camera.set_generated_bounds(Some((-10, -10, 10, 10)));
+        camera.center_on((20, -7));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (10, -7));
+        camera.center_on((-50, 50));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (-10, 10));
+    }
+
+    /// A land camera with `behavior` on tile (7, 3) of land (0, 0)
+    fn camera_on_right_edge(behavior: crate::land_view::EdgeBehavior) -> crate::land_view::LandCamera {
+        let mut camera = crate::land_view::LandCamera::new();
+        camera.edge_behavior = behavior;
+        camera.selected_tile_x = 7;
+        camera.selected_tile_y = 3;
+        camera
+    }
+
+    #[test]
+    fn test_land_camera_clamps_at_edge_by_default() {
+        use crate::land_view::EdgeBehavior;
+
+        assert_eq!(crate::land_view::LandCamera::new().edge_behavior, EdgeBehavior::Clamp);
+        let mut camera = camera_on_right_edge(EdgeBehavior::Clamp);
+        camera.move_selection(1, 0);
+        assert_eq!((camera.selected_tile_x, camera.selected_tile_y), (7, 3));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (0, 0));
+    }
+
+    #[test]
+    fn test_land_camera_wraps_within_land() {
+        use crate::land_view::EdgeBehavior;
+
+        let mut camera = camera_on_right_edge(EdgeBehavior::Wrap);
+        camera.move_selection(1, 0);
+        assert_eq!((camera.selected_tile_x, camera.selected_tile_y), (0, 3));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (0, 0));
+        camera.move_selection(-1, 0);
+        assert_eq!(camera.selected_tile_x, 7);
+    }
+
+    #[test]
+    fn test_land_camera_steps_into_neighbor_land() {
+        use crate::land_view::EdgeBehavior;
+
+        let mut world = World { name: "Step".to_string(), terrain: HashMap::new(), seed: 12347 };
+        crate::generation::generate_land(&mut world, 12347, 0, 0);
+
+        let mut camera = camera_on_right_edge(EdgeBehavior::StepIntoNeighbor);
+        camera.move_selection(1, 0);
+        assert_eq!((camera.selected_tile_x, camera.selected_tile_y), (0, 3));
+        assert_eq!((camera.selected_land_x, camera.selected_land_y), (1, 0));
+
+        assert!(camera.ensure_land_generated(&mut world));
+        assert!(world.terrain.contains_key(&(1, 0)));
+        assert!(!camera.ensure_land_generated(&mut world));
+
+        // Stepping back lands on the touching edge of the original land
+        camera.move_selection(-1, 0);
+        assert_eq!((camera.selected_tile_x, camera.selected_land_x), (7, 0));
+    }
+
+    #[test]
+    fn test_headless_land_view_stacks_objects_in_z_order() {
+        use crate::land_view::{render, LandCamera};
+        use crate::render::headless::{DrawCommand, HeadlessRenderer};
+        use crate::types::{Object, Substrate};
+
+        let mut world = World { name: "Headless".to_string(), terrain: HashMap::new(), seed: 12347 };
+        crate::generation::generate_land(&mut world, 12347, 0, 0);
+        let land = world.terrain.get_mut(&(0, 0)).unwrap();
+        for row in land.tiles.iter_mut() {
+            for tile in row.iter_mut() {
+                tile.objects.clear();
+            }
+        }
+        land.tiles[2][3].substrate = Substrate::Grass;
+        land.tiles[2][3].objects = vec![Object::Tree, Object::Rock, Object::Stick];
+
+        let mut camera = LandCamera::new();
+        camera.set_land(0, 0);
+        let mut renderer = HeadlessRenderer::default();
+        render(&mut renderer, &world, &camera).unwrap();
+
+        assert_eq!(renderer.tile_draw_count(), 64);
+        assert_eq!(renderer.object_draw_count(), 3);
+        assert_eq!(renderer.badge_count(), 1);
+
+        let drawn: Vec<Object> = renderer.commands().iter().filter_map(|c| match c {
+            DrawCommand::Object { object, .. } => Some(object.clone()),
+            _ => None,
+        }).collect();
+        assert_eq!(drawn, vec![Object::Rock, Object::Stick, Object::Tree]);
+        assert!(renderer.commands().iter().any(|c| matches!(c, DrawCommand::ObjectBadge { count: 3, .. })));
+    }
+
+    fn assert_color_close(actual: crate::render::Color, expected: crate::render::Color) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+        assert!(
+            close(actual.r, expected.r) && close(actual.g, expected.g)
+                && close(actual.b, expected.b) && close(actual.a, expected.a),
+            "{:?} != {:?}", actual, expected
+        );
+    }
+
+    #[test]
+    fn test_color_over_alpha_extremes_and_half() {
+        use crate::render::Color;
+
+        let background = Color::rgb(0.2, 0.4, 0.6);
+
+        // Fully transparent source leaves the background untouched
+        assert_color_close(Color::new(1.0, 0.0, 0.0, 0.0).over(&background), background);
+
+        // Fully opaque source replaces the background
+        assert_color_close(Color::new(1.0, 0.0, 0.0, 1.0).over(&background), Color::rgb(1.0, 0.0, 0.0));
+
+        // Half alpha lands halfway between the two
+        assert_color_close(Color::new(1.0, 0.0, 0.0, 0.5).over(&background), Color::rgb(0.6, 0.2, 0.3));
+    }
+
+    #[test]
+    fn test_biome_blend_color_interpolates() {
+        use crate::render::Color;
+
+        assert_color_close(Biome::blend_color(&Biome::Meadow, &Biome::Lake, 0.0), Biome::Meadow.to_color());
+        assert_color_close(Biome::blend_color(&Biome::Meadow, &Biome::Lake, 1.0), Biome::Lake.to_color());
+        // Meadow (0.7, 0.9, 0.4) halfway to Lake (0.2, 0.5, 0.9)
+        assert_color_close(Biome::blend_color(&Biome::Meadow, &Biome::Lake, 0.5), Color::rgb(0.45, 0.7, 0.65));
+        // Out of range t is clamped
+        assert_color_close(Biome::blend_color(&Biome::Meadow, &Biome::Lake, 2.0), Biome::Lake.to_color());
+    }
+
+    #[test]
+    fn test_land_flanked_by_lakes_gets_bluer_side_borders() {
+        use crate::render::headless::{DrawCommand, HeadlessRenderer};
+        use crate::terrain_view::{blended_border_colors, render, TerrainCamera, BORDER_BLEND};
+
+        let mut world = open_grass_world();
+        world.terrain.get_mut(&(-1, 0)).unwrap().center = Biome::Lake;
+        world.terrain.get_mut(&(1, 0)).unwrap().center = Biome::Lake;
+
+        let meadow = Biome::Meadow.to_color();
+        let [top, bottom, left, right, ..] = blended_border_colors(&world, 0, 0, BORDER_BLEND).unwrap();
+        assert!(left.b > meadow.b && right.b > meadow.b);
+        assert_color_close(top, meadow);
+        assert_color_close(bottom, meadow);
+        // No blending leaves the land's own biome colors
+        assert_color_close(blended_border_colors(&world, 0, 0, 0.0).unwrap()[2], meadow);
+
+        // The terrain view only switches to blended colors when asked
+        let mut camera = TerrainCamera::new();
+        let mut renderer = HeadlessRenderer::default();
+        render(&mut renderer, &world, &camera).unwrap();
+        assert!(!renderer.commands().iter().any(|c| matches!(c, DrawCommand::BiomeOverviewWithBorderColors { .. })));
+
+        camera.blend_borders = true;
+        renderer.take_commands();
+        render(&mut renderer, &world, &camera).unwrap();
+        let blended = renderer.commands().iter()
+            .filter(|c| matches!(c, DrawCommand::BiomeOverviewWithBorderColors { .. }))
+            .count();
+        assert!(blended > 0);
+        assert_eq!(blended, renderer.biome_overview_count());
+    }
+
+    #[test]
+    fn test_headless_draw_tile_blended_records_composited_color() {
+        use crate::render::headless::{DrawCommand, HeadlessRenderer};
+        use crate::render::{Color, Renderer};
+
+        let mut renderer = HeadlessRenderer::default();
+        renderer.draw_tile_blended(0.0, 0.0, 10.0, Color::new(1.0, 1.0, 1.0, 0.5), Color::rgb(0.0, 0.0, 0.0));
+
+        match renderer.commands() {
+            [DrawCommand::BlendedTile { color, .. }] => assert_color_close(*color, Color::rgb(0.5, 0.5, 0.5)),
+            other => panic!("unexpected commands: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_headless_minimap_draws_lands_in_range_and_marker() {
+        use std::collections::HashSet;
+        use crate::render::headless::{DrawCommand, HeadlessRenderer};
+        use crate::render::{Renderer, MINIMAP_FOG, MINIMAP_RADIUS};
+
+        let mut world = World { name: "Minimap".to_string(), terrain: HashMap::new(), seed: 12347 };
+        // 5x3 lands in range, plus one just past the right edge
+        crate::generation::generate_world(&mut world, 12347, -2, -1, 2, 1);
+        crate::generation::generate_land(&mut world, 12347, MINIMAP_RADIUS + 1, 0);
+        let explored: HashSet<(i32, i32)> = [(0, 0)].into_iter().collect();
+
+        let mut renderer = HeadlessRenderer::default();
+        renderer.draw_minimap(0.0, 0.0, 170.0, &world, &explored, (0, 0));
+
+        assert_eq!(renderer.minimap_cell_count(), 15);
+        assert_eq!(renderer.minimap_marker_count(), 1);
+
+        let cell_size = 170.0 / (2 * MINIMAP_RADIUS + 1) as f32;
+        let center = MINIMAP_RADIUS as f32 * cell_size;
+        let color_at = |x: f32, y: f32| {
+            renderer.commands().iter().find_map(|command| match command {
+                DrawCommand::MinimapCell { x: cx, y: cy, color, .. } if *cx == x && *cy == y => Some(*color),
+                _ => None,
+            })
+        };
+        // Explored land keeps its biome color; its neighbor is dimmed
+        assert_eq!(color_at(center, center), Some(world.terrain[&(0, 0)].center.to_color()));
+        assert_eq!(
+            color_at(center + cell_size, center),
+            Some(MINIMAP_FOG.over(&world.terrain[&(1, 0)].center.to_color()))
+        );
+        assert!(renderer.commands().contains(&DrawCommand::MinimapMarker { x: center, y: center, size: cell_size }));
+    }
+
+    /// Remove ANSI escape sequences (`ESC [ ... m`) from a string
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_colored_land_output_strips_to_plain() {
+        use crate::display::{format_land, format_world};
+
+        colored::control::set_override(true);
+        let world = create_test_world();
+        let land = &world.terrain[&(0, 0)];
+
+        let plain = format_land(land, false);
+        let colored = format_land(land, true);
+        assert!(colored.contains('\x1b'));
+        assert_eq!(strip_ansi(&colored), plain);
+
+        let plain = format_world(&world, -2, -2, 2, 2, false);
+        let colored = format_world(&world, -2, -2, 2, 2, true);
+        assert_ne!(colored, plain);
+        assert_eq!(strip_ansi(&colored), plain);
+    }
+
+    #[test]
+    fn test_world_legend_lists_each_biome_once() {
+        use crate::display::format_world_legend;
+
+        let legend = format_world_legend();
+        for biome in Biome::ALL {
+            let entry = format!("{} {:?}", biome.to_char(), biome);
+            assert_eq!(legend.matches(&entry).count(), 1, "{:?} in legend:\n{}", biome, legend);
+        }
+        // Header, one line per biome, and the ungenerated marker
+        assert_eq!(legend.lines().count(), Biome::ALL.len() + 2);
+    }
+
+    #[test]
+    fn test_world_with_coords_labels_rows() {
+        use crate::display::{format_world, format_world_with_coords};
+
+        let world = create_test_world();
+        let labeled = format_world_with_coords(&world, -2, -1, 2, 1);
+        let plain = format_world(&world, -2, -1, 2, 1, false);
+
+        let lines: Vec<&str> = labeled.lines().collect();
+        assert_eq!(lines[1], "    8 9 0 1 2");
+        for (line, (y, row)) in lines[2..].iter().zip((-1..=1).zip(plain.lines())) {
+            assert_eq!(*line, format!("{:>2} {}", y, row));
+        }
+    }
+
+    fn generate_region(seed: u64) -> World {
+        let mut world = World { name: "Diff".to_string(), terrain: HashMap::new(), seed };
+        generate_world(&mut world, seed, -2, -2, 2, 2);
+        world
+    }
+
+    #[test]
+    fn test_diff_worlds_same_seed_is_empty() {
+        let diff = crate::generation::diff_worlds(&generate_region(12347), &generate_region(12347));
+        assert!(diff.is_empty(), "identical generations should not differ: {:?}", diff);
+    }
+
+    #[test]
+    fn test_diff_worlds_different_seeds_lists_lands() {
+        let a = generate_region(12347);
+        let mut b = generate_region(99991);
+        b.terrain.remove(&(2, 2));
+        let mut extra = World { name: "Extra".to_string(), terrain: HashMap::new(), seed: 99991 };
+        crate::generation::generate_land(&mut extra, 99991, 3, 0);
+        b.terrain.insert((3, 0), extra.terrain[&(3, 0)].clone());
+
+        let diff = crate::generation::diff_worlds(&a, &b);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.only_in_a, vec![(2, 2)]);
+        assert_eq!(diff.only_in_b, vec![(3, 0)]);
+        assert!(!diff.changed_lands.is_empty());
+        for land in &diff.changed_lands {
+            assert!((-2..=2).contains(&land.coords.0) && (-2..=2).contains(&land.coords.1));
+            assert_ne!(land.coords, (2, 2));
+            assert!(land.biomes_changed || !land.tiles.is_empty());
+            for tile in &land.tiles {
+                assert!(tile.substrate_changed || tile.objects_changed);
+                let tile_a = &a.terrain[&land.coords].tiles[tile.tile_y][tile.tile_x];
+                let tile_b = &b.terrain[&land.coords].tiles[tile.tile_y][tile.tile_x];
+                assert_ne!(tile_a, tile_b);
+            }
+        }
+        assert!(diff.land_coords().contains(&(2, 2)));
+        assert!(diff.land_coords().contains(&(3, 0)));
+    }
+
+    #[test]
+    fn test_region_stats_biome_histogram_sums_to_generated_lands() {
+        use crate::generation::region_stats;
+
+        let mut world = generate_region(12347);
+        world.terrain.remove(&(0, 0));
+
+        // The region is one land wider than what was generated on each side
+        let stats = region_stats(&world, -3, -3, 3, 3);
+        assert_eq!(stats.land_count(), world.terrain.len());
+        assert_eq!(stats.biomes.values().sum::<usize>(), 24);
+        assert_eq!(stats.missing_lands, 49 - 24);
+        assert_eq!(stats.substrates.values().sum::<usize>(), 24 * 64);
+
+        let objects: usize = world
+            .terrain
+            .values()
+            .flat_map(|land| land.tiles.iter().flatten())
+            .map(|tile| tile.objects.len())
+            .sum();
+        assert_eq!(stats.objects.values().sum::<usize>(), objects);
+        let fractions: f64 = Biome::ALL.iter().map(|biome| stats.biome_fraction(biome)).sum();
+        assert!((fractions - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generated_region_has_no_biome_seam_violations() {
+        let mut world = World { name: "Seams".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world, 12347, -6, -6, 6, 6);
+        assert_eq!(crate::generation::verify_biome_seams(&world), vec![]);
+    }
+
+    #[test]
+    fn test_verify_biome_seams_reports_edited_land() {
+        let mut world = World { name: "Seams".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world, 12347, 0, 0, 2, 2);
+        let land = world.terrain.get_mut(&(1, 1)).unwrap();
+        land.right = if land.right == Biome::Lake { Biome::Mountain } else { Biome::Lake };
+
+        assert_eq!(crate::generation::verify_biome_seams(&world), vec![((1, 1), (2, 1))]);
+    }
+
+    #[test]
+    fn test_underground_ore_is_deterministic_and_only_under_mountain_or_stone() {
+        use crate::types::{Object, Substrate};
+
+        let mut world1 = World { name: "Ore1".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world1, 12347, -4, -4, 4, 4);
+        let mut world2 = World { name: "Ore2".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world2, 12347, -4, -4, 4, 4);
+
+        let mut ore_count = 0;
+        for gy in -32..40 {
+            for gx in -32..40 {
+                let tile = world1.tile_at_global(gx, gy).unwrap();
+                assert_eq!(tile.underground, world2.tile_at_global(gx, gy).unwrap().underground);
+                if tile.underground.is_some() {
+                    assert_eq!(tile.underground, Some(Object::Ore));
+                    let biome = world1.biome_at_global(gx, gy).unwrap();
+                    assert!(
+                        biome == Biome::Mountain || tile.substrate == Substrate::Stone,
+                        "ore at ({}, {}) under {:?} / {:?}",
+                        gx,
+                        gy,
+                        biome,
+                        tile.substrate
+                    );
+                    ore_count += 1;
+                }
+            }
+        }
+        assert!(ore_count > 0);
+    }
+
+    #[test]
+    fn test_mine_takes_ore_once() {
+        use crate::types::Object;
+
+        let mut world = World { name: "Mine".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world, 12347, -4, -4, 4, 4);
+        let (gx, gy) = (-32..40)
+            .flat_map(|gy| (-32..40).map(move |gx| (gx, gy)))
+            .find(|&(gx, gy)| world.tile_at_global(gx, gy).unwrap().underground.is_some())
+            .expect("region should contain ore");
+
+        assert_eq!(world.mine(gx, gy), Some(Object::Ore));
+        assert_eq!(world.mine(gx, gy), None);
+        assert_eq!(world.tile_at_global(gx, gy).unwrap().underground, None);
+        // Ungenerated lands have nothing to mine
+        assert_eq!(world.mine(1000, 1000), None);
+    }
+
+    #[test]
+    fn test_removed_object_survives_regeneration() {
+        use crate::types::Object;
+
+        let mut world = World { name: "Edit".to_string(), terrain: HashMap::new(), seed: 12347 };
+        generate_world(&mut world, 12347, -2, -2, 2, 2);
+        let (gx, gy) = (-16..24)
+            .flat_map(|gy| (-16..24).map(move |gx| (gx, gy)))
+            .find(|&(gx, gy)| world.tile_at_global(gx, gy).unwrap().objects.contains(&Object::Tree))
+            .expect("region should contain a tree");
+        let trees = |world: &World| {
+            world.tile_at_global(gx, gy).unwrap().objects.iter().filter(|o| **o == Object::Tree).count()
+        };
+        let before = trees(&world);
+
+        assert!(world.remove_object(gx, gy, &Object::Tree));
+        assert_eq!(trees(&world), before - 1);
+
+        // Regenerating the region keeps the edit, and the next land over still generates
+        let created = world.extend_generated(-2, -2, 3, 2);
+        assert_eq!(created.len(), 5);
+        assert!(created.contains(&(3, 0)));
+        assert_eq!(trees(&world), before - 1);
+
+        world.add_object(gx, gy, Object::Tree);
+        assert_eq!(trees(&world), before);
+
+        // Ungenerated lands are created on demand
+        assert!(!world.terrain.contains_key(&(100, 100)));
+        assert!(!world.remove_object(800, 800, &Object::Ore));
+        assert!(world.terrain.contains_key(&(100, 100)));
+    }
+
+    #[test]
+    fn test_tile_without_underground_field_deserializes() {
+        use crate::types::{Substrate, Tile};
+
+        let tile: Tile = serde_json::from_str(r#"{"substrate":"Stone","objects":[]}"#).unwrap();
+        assert_eq!(tile.substrate, Substrate::Stone);
+        assert_eq!(tile.underground, None);
+        // Tiles with nothing buried serialize the same way older versions did
+        assert!(!serde_json::to_string(&tile).unwrap().contains("underground"));
+    }
 }
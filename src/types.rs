@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Substrate {
     Grass,
     Dirt,
@@ -9,28 +9,34 @@ pub enum Substrate {
     Mud,
     Water,
     Brush,
+    Sand,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Object {
     Rock,
     Tree,
     Stick,
+    Ore,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tile {
     pub substrate: Substrate,
     pub objects: Vec<Object>,
+    /// Resource buried under the tile, taken with `World::mine` (absent in older saves)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underground: Option<Object>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Biome {
     Forest,
     Meadow,
     Lake,
     Mountain,
     Plains,
+    Desert,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,6 +68,16 @@ pub struct World {
 }
 
 impl Biome {
+    /// Every biome variant, in declaration order
+    pub const ALL: [Biome; 6] = [
+        Biome::Forest,
+        Biome::Meadow,
+        Biome::Lake,
+        Biome::Mountain,
+        Biome::Plains,
+        Biome::Desert,
+    ];
+
     pub fn to_char(&self) -> &str {
         match self {
             Biome::Forest => "🟩",  // Green square
@@ -69,6 +85,7 @@ impl Biome {
             Biome::Lake => "🟦",   // Blue square
             Biome::Mountain => "⬜", // White square
             Biome::Plains => "🟫",  // Brown square
+            Biome::Desert => "🟧",  // Orange square
         }
     }
 }
@@ -82,6 +99,7 @@ impl Substrate {
             Substrate::Mud => '🟫',   // Brown square
             Substrate::Water => '🔵', // Blue circle
             Substrate::Brush => '🟡', // Yellow circle
+            Substrate::Sand => '🟠',  // Orange circle
         }
     }
 }
@@ -92,6 +110,7 @@ impl Object {
             Object::Rock => '⚫',  // Black circle
             Object::Tree => '🟩',  // Green square (same as Forest biome)
             Object::Stick => '🟤',  // Brown circle (same as Dirt)
+            Object::Ore => '🔶',    // Orange diamond
         }
     }
 }
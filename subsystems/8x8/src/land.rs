@@ -0,0 +1,18 @@
+//! Adapter from generated `Q` lands to grids, so the viewer can show real terrain.
+
+use crate::{Color, Grid8x8, Tile};
+use q::Land;
+
+/// Build a grid from a land: each tile takes its substrate's color, and its
+/// objects' names (e.g. "Tree", "Rock") become the tile's strings in order.
+pub fn grid_from_land(land: &Land) -> Grid8x8 {
+    let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+    for (y, row) in land.tiles.iter().enumerate() {
+        for (x, land_tile) in row.iter().enumerate() {
+            let c = land_tile.substrate.to_color();
+            let strings = land_tile.objects.iter().map(|object| format!("{:?}", object)).collect();
+            grid.set(x, y, Tile::with_strings(Color::new(c.r, c.g, c.b, c.a), strings));
+        }
+    }
+    grid
+}
@@ -1,11 +1,25 @@
 //! 8x8 subsystem - Base for various systems that involve the land view
 //!
 //! Provides an 8x8 grid of tiles, where each tile has a color and a vector of strings.
+//! The grid is generic over its size (`GridNxN<N>`), with `Grid8x8` as the default alias.
 
+use std::iter::{Enumerate, Flatten};
 use std::ops::{Index, IndexMut};
+use std::slice;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod text;
+
+#[cfg(feature = "land")]
+mod land;
+#[cfg(feature = "land")]
+pub use land::grid_from_land;
 
 /// RGBA color representation
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Color {
     pub r: f32,
     pub g: f32,
@@ -23,10 +37,99 @@ impl Color {
     pub fn rgb(r: f32, g: f32, b: f32) -> Self {
         Self::new(r, g, b, 1.0)
     }
+
+    /// Parse a color from `#RRGGBB` or `#RRGGBBAA` (leading `#` optional)
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 && digits.len() != 8 {
+            return Err(format!(
+                "Invalid hex color '{}': expected 6 or 8 hex digits, got {}",
+                hex,
+                digits.len()
+            ));
+        }
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("Invalid hex color '{}': contains non-hex digits", hex));
+        }
+
+        let channel = |i: usize| -> f32 {
+            // Safe to unwrap: length and digits were validated above
+            u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap() as f32 / 255.0
+        };
+        let a = if digits.len() == 8 { channel(3) } else { 1.0 };
+        Ok(Self::new(channel(0), channel(1), channel(2), a))
+    }
+
+    /// Perceived brightness (ITU-R BT.601 weights), ignoring alpha
+    pub fn luminance(&self) -> f32 {
+        0.299 * self.r + 0.587 * self.g + 0.114 * self.b
+    }
+
+    /// Whether the color is dark enough that light text should be drawn on it
+    pub fn is_dark(&self) -> bool {
+        self.luminance() < 0.5
+    }
+
+    /// Scale RGB channels up by `factor` (e.g. 0.2 = 20% brighter), clamped to 1.0
+    pub fn lighten(&self, factor: f32) -> Self {
+        self.scale_rgb(1.0 + factor)
+    }
+
+    /// Scale RGB channels down by `factor` (e.g. 0.2 = 20% darker), clamped to 0.0
+    pub fn darken(&self, factor: f32) -> Self {
+        self.scale_rgb(1.0 - factor)
+    }
+
+    /// Linearly interpolate all four channels towards `other` (t = 0.0 is self, 1.0 is other)
+    pub fn blend(&self, other: &Color, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| (a + (b - a) * t).clamp(0.0, 1.0);
+        Self::new(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            lerp(self.a, other.a),
+        )
+    }
+
+    fn scale_rgb(&self, scale: f32) -> Self {
+        Self::new(
+            (self.r * scale).clamp(0.0, 1.0),
+            (self.g * scale).clamp(0.0, 1.0),
+            (self.b * scale).clamp(0.0, 1.0),
+            self.a,
+        )
+    }
+
+    /// Euclidean distance between two colors in RGBA space
+    pub fn distance(&self, other: &Color) -> f32 {
+        let dr = self.r - other.r;
+        let dg = self.g - other.g;
+        let db = self.b - other.b;
+        let da = self.a - other.a;
+        (dr * dr + dg * dg + db * db + da * da).sqrt()
+    }
+
+    /// Format the color as `#RRGGBB`, or `#RRGGBBAA` if alpha is not 1.0
+    pub fn to_hex(&self) -> String {
+        let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        if self.a == 1.0 {
+            format!("#{:02X}{:02X}{:02X}", byte(self.r), byte(self.g), byte(self.b))
+        } else {
+            format!(
+                "#{:02X}{:02X}{:02X}{:02X}",
+                byte(self.r),
+                byte(self.g),
+                byte(self.b),
+                byte(self.a)
+            )
+        }
+    }
 }
 
 /// A single tile in the 8x8 grid
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Tile {
     /// The color of the tile
     pub color: Color,
@@ -47,16 +150,50 @@ impl Tile {
     pub fn with_strings(color: Color, strings: Vec<String>) -> Self {
         Self { color, strings }
     }
+
+    /// Check whether this tile contains the given string
+    pub fn has_string(&self, s: &str) -> bool {
+        self.strings.iter().any(|string| string == s)
+    }
+
+    /// Compare colors exactly and strings as multisets, ignoring their order.
+    /// Derived `==` is order-sensitive.
+    pub fn equals_unordered(&self, other: &Tile) -> bool {
+        if self.color != other.color || self.strings.len() != other.strings.len() {
+            return false;
+        }
+        let mut ours: Vec<&String> = self.strings.iter().collect();
+        let mut theirs: Vec<&String> = other.strings.iter().collect();
+        ours.sort();
+        theirs.sort();
+        ours == theirs
+    }
 }
 
-/// An 8x8 grid of tiles
+/// What differs between two versions of a tile, as reported by [`GridNxN::diff_detailed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileChange {
+    /// Only the color differs
+    Color,
+    /// Only the strings differ
+    Strings,
+    /// Both the color and the strings differ
+    Both,
+}
+
+/// A square NxN grid of tiles
 #[derive(Debug, Clone, PartialEq)]
-pub struct Grid8x8 {
-    tiles: [[Tile; 8]; 8],
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridNxN<const N: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_tiles"))]
+    tiles: [[Tile; N]; N],
 }
 
-impl Grid8x8 {
-    /// Create a new 8x8 grid with all tiles initialized to the default color
+/// An 8x8 grid of tiles
+pub type Grid8x8 = GridNxN<8>;
+
+impl<const N: usize> GridNxN<N> {
+    /// Create a new NxN grid with all tiles initialized to the default color
     pub fn new(default_color: Color) -> Self {
         Self {
             tiles: std::array::from_fn(|_| std::array::from_fn(|_| Tile::new(default_color))),
@@ -66,7 +203,7 @@ impl Grid8x8 {
     /// Get a reference to the tile at the given coordinates
     /// Returns None if coordinates are out of bounds
     pub fn get(&self, x: usize, y: usize) -> Option<&Tile> {
-        if x < 8 && y < 8 {
+        if x < N && y < N {
             Some(&self.tiles[y][x])
         } else {
             None
@@ -76,7 +213,7 @@ impl Grid8x8 {
     /// Get a mutable reference to the tile at the given coordinates
     /// Returns None if coordinates are out of bounds
     pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Tile> {
-        if x < 8 && y < 8 {
+        if x < N && y < N {
             Some(&mut self.tiles[y][x])
         } else {
             None
@@ -86,7 +223,7 @@ impl Grid8x8 {
     /// Set the tile at the given coordinates
     /// Returns true if successful, false if coordinates are out of bounds
     pub fn set(&mut self, x: usize, y: usize, tile: Tile) -> bool {
-        if x < 8 && y < 8 {
+        if x < N && y < N {
             self.tiles[y][x] = tile;
             true
         } else {
@@ -127,33 +264,391 @@ impl Grid8x8 {
         }
     }
 
-    /// Get the width of the grid (always 8)
+    /// Remove the first occurrence of a string from the tile at the given coordinates
+    /// Returns true if a string was removed, false if not found or out of bounds
+    pub fn remove_string(&mut self, x: usize, y: usize, s: &str) -> bool {
+        if let Some(tile) = self.get_mut(x, y) {
+            if let Some(pos) = tile.strings.iter().position(|string| string == s) {
+                tile.strings.remove(pos);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Remove all occurrences of a string from the tile at the given coordinates
+    /// Returns the number of strings removed (0 if out of bounds)
+    pub fn remove_string_all(&mut self, x: usize, y: usize, s: &str) -> usize {
+        if let Some(tile) = self.get_mut(x, y) {
+            let before = tile.strings.len();
+            tile.strings.retain(|string| string != s);
+            before - tile.strings.len()
+        } else {
+            0
+        }
+    }
+
+    /// Exchange two tiles (color and strings)
+    /// Returns true if successful, false if either coordinate is out of bounds
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) -> bool {
+        let ((ax, ay), (bx, by)) = (a, b);
+        if ax >= N || ay >= N || bx >= N || by >= N {
+            return false;
+        }
+        if a == b {
+            return true;
+        }
+        // Two rows can't be borrowed mutably at once, so rotate through a placeholder
+        let tile = std::mem::replace(&mut self.tiles[ay][ax], Tile::new(Color::rgb(0.0, 0.0, 0.0)));
+        let tile = std::mem::replace(&mut self.tiles[by][bx], tile);
+        self.tiles[ay][ax] = tile;
+        true
+    }
+
+    /// Move a tile's contents to `to`, overwriting it, and reset `from` to a
+    /// string-free tile of the `replacement` color. Moving a tile onto itself
+    /// leaves it unchanged.
+    /// Returns true if successful, false if either coordinate is out of bounds
+    pub fn move_tile(&mut self, from: (usize, usize), to: (usize, usize), replacement: Color) -> bool {
+        let ((fx, fy), (tx, ty)) = (from, to);
+        if fx >= N || fy >= N || tx >= N || ty >= N {
+            return false;
+        }
+        if from != to {
+            self.tiles[ty][tx] = std::mem::replace(&mut self.tiles[fy][fx], Tile::new(replacement));
+        }
+        true
+    }
+
+    /// Get the width of the grid (N)
     pub fn width(&self) -> usize {
-        8
+        N
     }
 
-    /// Get the height of the grid (always 8)
+    /// Get the height of the grid (N)
     pub fn height(&self) -> usize {
-        8
+        N
+    }
+
+    /// Get the in-bounds orthogonal neighbors of (x, y)
+    /// Returns an empty vec if the coordinates are out of bounds
+    pub fn neighbors(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.neighbors_with_offsets(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Get the in-bounds orthogonal and diagonal neighbors of (x, y)
+    /// Returns an empty vec if the coordinates are out of bounds
+    pub fn neighbors8(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        self.neighbors_with_offsets(
+            x,
+            y,
+            &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)],
+        )
+    }
+
+    /// Get the in-bounds orthogonal neighbors of (x, y) along with their tiles
+    pub fn neighbor_tiles(&self, x: usize, y: usize) -> Vec<(usize, usize, &Tile)> {
+        self.neighbors(x, y)
+            .into_iter()
+            .map(|(nx, ny)| (nx, ny, &self.tiles[ny][nx]))
+            .collect()
+    }
+
+    fn neighbors_with_offsets(&self, x: usize, y: usize, offsets: &[(isize, isize)]) -> Vec<(usize, usize)> {
+        if x >= N || y >= N {
+            return Vec::new();
+        }
+        offsets
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let nx = x.checked_add_signed(dx)?;
+                let ny = y.checked_add_signed(dy)?;
+                (nx < N && ny < N).then_some((nx, ny))
+            })
+            .collect()
+    }
+
+    /// Find all tiles 4-connected to (x, y) whose color is within `tolerance`
+    /// (Euclidean distance in RGBA space) of the starting tile's color
+    /// Returns an empty vec if the start coordinates are out of bounds
+    pub fn flood_fill(&self, x: usize, y: usize, tolerance: f32) -> Vec<(usize, usize)> {
+        let start_color = match self.get(x, y) {
+            Some(tile) => tile.color,
+            None => return Vec::new(),
+        };
+
+        let mut visited = [[false; N]; N];
+        let mut region = Vec::new();
+        let mut stack = vec![(x, y)];
+        visited[y][x] = true;
+
+        while let Some((cx, cy)) = stack.pop() {
+            region.push((cx, cy));
+
+            for (nx, ny) in self.neighbors(cx, cy) {
+                if !visited[ny][nx] && self.tiles[ny][nx].color.distance(&start_color) <= tolerance {
+                    visited[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Recolor the region found by `flood_fill` starting at (x, y)
+    /// Returns the number of tiles recolored
+    pub fn flood_fill_color(&mut self, x: usize, y: usize, tolerance: f32, new: Color) -> usize {
+        let region = self.flood_fill(x, y, tolerance);
+        for &(rx, ry) in &region {
+            self.tiles[ry][rx].color = new;
+        }
+        region.len()
+    }
+
+    /// Set every tile's color (strings are kept)
+    pub fn fill(&mut self, color: Color) {
+        for (_, _, tile) in self.iter_mut() {
+            tile.color = color;
+        }
+    }
+
+    /// Color the inclusive rectangle with corners (x0, y0) and (x1, y1), given in any order
+    /// Returns false (and changes nothing) if either corner is out of bounds
+    pub fn fill_rect(&mut self, x0: usize, y0: usize, x1: usize, y1: usize, color: Color) -> bool {
+        self.map_region(x0, y0, x1, y1, |tile| tile.color = color)
+    }
+
+    /// Apply `f` to every tile in the inclusive rectangle with corners (x0, y0) and (x1, y1),
+    /// given in any order, in row-major order. Unlike `fill_rect`, `f` gets the whole tile.
+    /// Returns false (and calls `f` on nothing) if either corner is out of bounds
+    pub fn map_region<F: FnMut(&mut Tile)>(&mut self, x0: usize, y0: usize, x1: usize, y1: usize, mut f: F) -> bool {
+        if x0 >= N || y0 >= N || x1 >= N || y1 >= N {
+            return false;
+        }
+        let (left, right) = (x0.min(x1), x0.max(x1));
+        let (top, bottom) = (y0.min(y1), y0.max(y1));
+        for row in &mut self.tiles[top..=bottom] {
+            for tile in &mut row[left..=right] {
+                f(tile);
+            }
+        }
+        true
+    }
+
+    /// Color only the outer ring of tiles
+    pub fn draw_border(&mut self, color: Color) {
+        for (x, y, tile) in self.iter_mut() {
+            if x == 0 || y == 0 || x == N - 1 || y == N - 1 {
+                tile.color = color;
+            }
+        }
+    }
+
+    /// Get the coordinates of all tiles matching the predicate, in row-major order
+    pub fn find<F: Fn(&Tile) -> bool>(&self, pred: F) -> Vec<(usize, usize)> {
+        self.iter()
+            .filter(|(_, _, tile)| pred(tile))
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    /// Count the tiles matching the predicate
+    pub fn count<F: Fn(&Tile) -> bool>(&self, pred: F) -> usize {
+        self.iter().filter(|(_, _, tile)| pred(tile)).count()
+    }
+
+    /// Get the coordinates of all tiles that have at least one string
+    pub fn tiles_with_strings(&self) -> Vec<(usize, usize)> {
+        self.find(|tile| !tile.strings.is_empty())
+    }
+
+    /// Return a copy of the grid rotated 90 degrees clockwise
+    pub fn rotate_cw(&self) -> Self {
+        self.remap(|x, y| (y, N - 1 - x))
+    }
+
+    /// Return a copy of the grid rotated 90 degrees counter-clockwise
+    pub fn rotate_ccw(&self) -> Self {
+        self.remap(|x, y| (N - 1 - y, x))
+    }
+
+    /// Return a copy of the grid mirrored left-to-right
+    pub fn flip_horizontal(&self) -> Self {
+        self.remap(|x, y| (N - 1 - x, y))
+    }
+
+    /// Return a copy of the grid mirrored top-to-bottom
+    pub fn flip_vertical(&self) -> Self {
+        self.remap(|x, y| (x, N - 1 - y))
+    }
+
+    /// Return a copy of the grid mirrored across the main diagonal
+    pub fn transpose(&self) -> Self {
+        self.remap(|x, y| (y, x))
+    }
+
+    /// Compare tile by tile with `Tile::equals_unordered`, ignoring string order
+    pub fn equals_unordered(&self, other: &Self) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|((_, _, a), (_, _, b))| a.equals_unordered(b))
+    }
+
+    /// Coordinates of the tiles that differ from `other` (color or strings), in
+    /// row-major order. Strings are compared in order, as derived `==` does.
+    pub fn diff(&self, other: &Self) -> Vec<(usize, usize)> {
+        self.diff_detailed(other)
+            .into_iter()
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    /// Like [`GridNxN::diff`], also noting whether each tile's color, strings, or both changed
+    pub fn diff_detailed(&self, other: &Self) -> Vec<(usize, usize, TileChange)> {
+        self.iter()
+            .zip(other.iter())
+            .filter_map(|((x, y, a), (_, _, b))| {
+                let change = match (a.color != b.color, a.strings != b.strings) {
+                    (false, false) => return None,
+                    (true, false) => TileChange::Color,
+                    (false, true) => TileChange::Strings,
+                    (true, true) => TileChange::Both,
+                };
+                Some((x, y, change))
+            })
+            .collect()
+    }
+
+    /// Build a new grid where the tile at (x, y) is cloned from `source(x, y)`
+    fn remap<F: Fn(usize, usize) -> (usize, usize)>(&self, source: F) -> Self {
+        Self {
+            tiles: std::array::from_fn(|y| {
+                std::array::from_fn(|x| {
+                    let (sx, sy) = source(x, y);
+                    self.tiles[sy][sx].clone()
+                })
+            }),
+        }
+    }
+
+    /// Iterate over all tiles in row-major order, yielding `(x, y, &Tile)`
+    pub fn iter(&self) -> Iter<'_, N> {
+        Iter {
+            inner: self.tiles.iter().flatten().enumerate(),
+        }
+    }
+
+    /// Iterate mutably over all tiles in row-major order, yielding `(x, y, &mut Tile)`
+    pub fn iter_mut(&mut self) -> IterMut<'_, N> {
+        IterMut {
+            inner: self.tiles.iter_mut().flatten().enumerate(),
+        }
+    }
+}
+
+/// Iterator over the tiles of a grid, created by [`GridNxN::iter`]
+pub struct Iter<'a, const N: usize> {
+    inner: Enumerate<Flatten<slice::Iter<'a, [Tile; N]>>>,
+}
+
+impl<'a, const N: usize> Iterator for Iter<'a, N> {
+    type Item = (usize, usize, &'a Tile);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, tile)| (i % N, i / N, tile))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Mutable iterator over the tiles of a grid, created by [`GridNxN::iter_mut`]
+pub struct IterMut<'a, const N: usize> {
+    inner: Enumerate<Flatten<slice::IterMut<'a, [Tile; N]>>>,
+}
+
+impl<'a, const N: usize> Iterator for IterMut<'a, N> {
+    type Item = (usize, usize, &'a mut Tile);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, tile)| (i % N, i / N, tile))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a GridNxN<N> {
+    type Item = (usize, usize, &'a Tile);
+    type IntoIter = Iter<'a, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a mut GridNxN<N> {
+    type Item = (usize, usize, &'a mut Tile);
+    type IntoIter = IterMut<'a, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
-impl Index<(usize, usize)> for Grid8x8 {
+impl<const N: usize> Index<(usize, usize)> for GridNxN<N> {
     type Output = Tile;
 
     fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
-        assert!(x < 8 && y < 8, "Index out of bounds: ({}, {})", x, y);
+        assert!(x < N && y < N, "Index out of bounds: ({}, {})", x, y);
         &self.tiles[y][x]
     }
 }
 
-impl IndexMut<(usize, usize)> for Grid8x8 {
+impl<const N: usize> IndexMut<(usize, usize)> for GridNxN<N> {
     fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
-        assert!(x < 8 && y < 8, "Index out of bounds: ({}, {})", x, y);
+        assert!(x < N && y < N, "Index out of bounds: ({}, {})", x, y);
         &mut self.tiles[y][x]
     }
 }
 
+/// Serde can't derive for const-generic arrays, so tiles are (de)serialized
+/// as nested sequences and length-checked on the way back in
+#[cfg(feature = "serde")]
+mod serde_tiles {
+    use super::Tile;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S, const N: usize>(tiles: &[[Tile; N]; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(tiles.iter().map(|row| row.as_slice()))
+    }
+
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[[Tile; N]; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rows = Vec::<Vec<Tile>>::deserialize(deserializer)?;
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                let len = row.len();
+                <[Tile; N]>::try_from(row)
+                    .map_err(|_| D::Error::custom(format!("expected {} tiles per row, got {}", N, len)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let len = rows.len();
+        <[[Tile; N]; N]>::try_from(rows)
+            .map_err(|_| D::Error::custom(format!("expected {} rows, got {}", N, len)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +662,58 @@ mod tests {
         assert_eq!(color.a, 1.0);
     }
 
+    #[test]
+    fn test_color_from_hex() {
+        let color = Color::from_hex("#FF8000").unwrap();
+        assert_eq!(color, Color::rgb(1.0, 128.0 / 255.0, 0.0));
+        assert_eq!(Color::from_hex("ff8000").unwrap(), color);
+        assert_eq!(Color::from_hex("#Ff8000").unwrap(), color);
+
+        let translucent = Color::from_hex("#00000080").unwrap();
+        assert_eq!(translucent.a, 128.0 / 255.0);
+        assert_eq!(Color::from_hex("#FFFFFFFF").unwrap().a, 1.0);
+
+        assert!(Color::from_hex("#FFF").is_err());
+        assert!(Color::from_hex("#GG0000").is_err());
+        assert!(Color::from_hex("").is_err());
+    }
+
+    #[test]
+    fn test_color_to_hex() {
+        assert_eq!(Color::rgb(1.0, 0.0, 0.5).to_hex(), "#FF0080");
+        assert_eq!(Color::new(0.0, 0.0, 0.0, 0.5).to_hex(), "#00000080");
+        assert_eq!(Color::new(0.0, 0.0, 0.0, 0.0).to_hex(), "#00000000");
+
+        let color = Color::from_hex("#12abCD80").unwrap();
+        assert_eq!(color.to_hex(), "#12ABCD80");
+    }
+
+    #[test]
+    fn test_color_luminance() {
+        assert_eq!(Color::rgb(0.0, 0.0, 0.0).luminance(), 0.0);
+        assert!((Color::rgb(1.0, 1.0, 1.0).luminance() - 1.0).abs() < 1e-6);
+        assert!(Color::rgb(0.0, 0.0, 1.0).is_dark());
+        assert!(!Color::rgb(1.0, 1.0, 0.0).is_dark());
+    }
+
+    #[test]
+    fn test_color_lighten_darken() {
+        let color = Color::new(0.5, 0.9, 0.0, 0.5);
+        assert_eq!(color.lighten(0.2), Color::new(0.6, 1.0, 0.0, 0.5));
+        assert_eq!(color.darken(0.5), Color::new(0.25, 0.45, 0.0, 0.5));
+    }
+
+    #[test]
+    fn test_color_blend() {
+        let black = Color::new(0.0, 0.0, 0.0, 0.0);
+        let white = Color::new(1.0, 1.0, 1.0, 1.0);
+
+        assert_eq!(black.blend(&white, 0.0), black);
+        assert_eq!(black.blend(&white, 1.0), white);
+        assert_eq!(black.blend(&white, 0.5), Color::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(black.blend(&white, 2.0), white);
+    }
+
     #[test]
     fn test_tile_creation() {
         let color = Color::rgb(0.5, 0.5, 0.5);
@@ -204,6 +751,47 @@ mod tests {
         assert!(grid.get(0, 8).is_none());
     }
 
+    #[test]
+    fn test_grid_swap() {
+        let black = Color::rgb(0.0, 0.0, 0.0);
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let mut grid = Grid8x8::new(black);
+        grid.set(1, 2, Tile::with_strings(red, vec!["gem".to_string()]));
+
+        assert!(grid.swap((1, 2), (7, 7)));
+        assert_eq!(grid.get(1, 2).unwrap(), &Tile::new(black));
+        assert_eq!(grid.get(7, 7).unwrap(), &Tile::with_strings(red, vec!["gem".to_string()]));
+
+        // Swapping a tile with itself is a no-op that still succeeds
+        let before = grid.clone();
+        assert!(grid.swap((7, 7), (7, 7)));
+        assert_eq!(grid, before);
+
+        // Out of bounds on either side leaves the grid untouched
+        assert!(!grid.swap((7, 7), (8, 0)));
+        assert!(!grid.swap((0, 8), (7, 7)));
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn test_grid_move_tile() {
+        let black = Color::rgb(0.0, 0.0, 0.0);
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let gray = Color::rgb(0.5, 0.5, 0.5);
+        let mut grid = Grid8x8::new(black);
+        grid.set(0, 0, Tile::with_strings(red, vec!["pawn".to_string()]));
+
+        assert!(grid.move_tile((0, 0), (3, 3), gray));
+        assert_eq!(grid.get(3, 3).unwrap(), &Tile::with_strings(red, vec!["pawn".to_string()]));
+        assert_eq!(grid.get(0, 0).unwrap(), &Tile::new(gray));
+
+        let before = grid.clone();
+        assert!(grid.move_tile((3, 3), (3, 3), gray));
+        assert_eq!(grid, before);
+        assert!(!grid.move_tile((3, 3), (3, 8), gray));
+        assert_eq!(grid, before);
+    }
+
     #[test]
     fn test_grid_set() {
         let default_color = Color::rgb(0.0, 0.0, 0.0);
@@ -270,4 +858,449 @@ mod tests {
         
         assert!(!grid.clear_strings(8, 0));
     }
+
+    #[test]
+    fn test_grid_iter_row_major() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        grid.add_string(2, 5, "marker".to_string());
+
+        let coords: Vec<(usize, usize)> = grid.iter().map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(coords.len(), 64);
+        assert_eq!(coords[0], (0, 0));
+        assert_eq!(coords[1], (1, 0));
+        assert_eq!(coords[8], (0, 1));
+        assert_eq!(coords[63], (7, 7));
+
+        for (x, y, tile) in &grid {
+            assert_eq!(tile, grid.get(x, y).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_grid_iter_mut() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let red = Color::rgb(1.0, 0.0, 0.0);
+
+        for (x, y, tile) in grid.iter_mut() {
+            tile.color = red;
+            tile.strings.push(format!("{},{}", x, y));
+        }
+
+        assert_eq!(grid.get(7, 7).unwrap().color, red);
+        assert_eq!(grid.get(3, 6).unwrap().strings, vec!["3,6".to_string()]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_grid_serde_round_trip() {
+        let mut grid = Grid8x8::new(Color::rgb(0.5, 0.5, 0.5));
+        grid.set_color(0, 0, Color::new(1.0, 0.0, 0.25, 0.75));
+        grid.add_string(0, 0, "top-left".to_string());
+        grid.add_string(7, 3, "edge".to_string());
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: Grid8x8 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, grid);
+
+        let color_json = serde_json::to_value(Color::new(1.0, 0.0, 0.25, 0.75)).unwrap();
+        assert_eq!(color_json["b"], serde_json::json!(0.25));
+    }
+
+    #[test]
+    fn test_flood_fill_uniform_grid() {
+        let grid = Grid8x8::new(Color::rgb(0.5, 0.5, 0.5));
+        let region = grid.flood_fill(3, 3, 0.0);
+        assert_eq!(region.len(), 64);
+
+        assert!(grid.flood_fill(8, 0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_flood_fill_two_blocks() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let blue = Color::rgb(0.0, 0.0, 1.0);
+        let mut grid = Grid8x8::new(red);
+        for y in 0..8 {
+            for x in 4..8 {
+                grid.set_color(x, y, blue);
+            }
+        }
+
+        let left = grid.flood_fill(0, 0, 0.1);
+        assert_eq!(left.len(), 32);
+        assert!(left.iter().all(|&(x, _)| x < 4));
+
+        // A large tolerance spans both blocks
+        assert_eq!(grid.flood_fill(0, 0, 2.0).len(), 64);
+
+        let green = Color::rgb(0.0, 1.0, 0.0);
+        assert_eq!(grid.flood_fill_color(7, 7, 0.1, green), 32);
+        assert_eq!(grid.get(4, 0).unwrap().color, green);
+        assert_eq!(grid.get(3, 0).unwrap().color, red);
+        assert_eq!(grid.flood_fill_color(8, 8, 0.1, green), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+
+        assert_eq!(grid.neighbors(0, 0), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors(7, 7).len(), 2);
+        assert_eq!(grid.neighbors(3, 0).len(), 3);
+        assert_eq!(grid.neighbors(0, 5).len(), 3);
+        assert_eq!(grid.neighbors(4, 4), vec![(4, 3), (3, 4), (5, 4), (4, 5)]);
+        assert!(grid.neighbors(8, 0).is_empty());
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+
+        assert_eq!(grid.neighbors8(0, 0), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbors8(7, 0).len(), 3);
+        assert_eq!(grid.neighbors8(3, 7).len(), 5);
+        assert_eq!(grid.neighbors8(4, 4).len(), 8);
+        assert!(grid.neighbors8(0, 8).is_empty());
+    }
+
+    #[test]
+    fn test_neighbor_tiles() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        grid.add_string(1, 0, "right".to_string());
+
+        let tiles = grid.neighbor_tiles(0, 0);
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[0].0, 1);
+        assert_eq!(tiles[0].2.strings, vec!["right".to_string()]);
+        assert!(grid.neighbor_tiles(9, 9).is_empty());
+    }
+
+    #[test]
+    fn test_tile_has_string() {
+        let tile = Tile::with_strings(Color::rgb(0.0, 0.0, 0.0), vec!["a".to_string()]);
+        assert!(tile.has_string("a"));
+        assert!(!tile.has_string("b"));
+    }
+
+    #[test]
+    fn test_grid_diff() {
+        let before = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let mut after = before.clone();
+        assert!(before.diff(&after).is_empty());
+
+        after.set_color(1, 0, Color::rgb(1.0, 0.0, 0.0));
+        after.add_string(4, 3, "tree".to_string());
+        after.set(7, 7, Tile::with_strings(Color::rgb(0.0, 0.0, 1.0), vec!["water".to_string()]));
+
+        assert_eq!(before.diff(&after), vec![(1, 0), (4, 3), (7, 7)]);
+        assert_eq!(
+            before.diff_detailed(&after),
+            vec![(1, 0, TileChange::Color), (4, 3, TileChange::Strings), (7, 7, TileChange::Both)]
+        );
+        // Symmetric apart from direction
+        assert_eq!(after.diff(&before), before.diff(&after));
+    }
+
+    #[test]
+    fn test_equals_unordered() {
+        let color = Color::rgb(0.2, 0.4, 0.6);
+        let ab = Tile::with_strings(color, vec!["a".to_string(), "b".to_string()]);
+        let ba = Tile::with_strings(color, vec!["b".to_string(), "a".to_string()]);
+        assert_ne!(ab, ba);
+        assert!(ab.equals_unordered(&ba));
+
+        // Multisets: duplicates count, and colors must still match exactly
+        let aab = Tile::with_strings(color, vec!["a".to_string(), "a".to_string(), "b".to_string()]);
+        let abb = Tile::with_strings(color, vec!["a".to_string(), "b".to_string(), "b".to_string()]);
+        assert!(!aab.equals_unordered(&abb));
+        assert!(!ab.equals_unordered(&Tile::with_strings(Color::rgb(0.2, 0.4, 0.7), ab.strings.clone())));
+
+        let mut grid_ab = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let mut grid_ba = grid_ab.clone();
+        grid_ab.set(3, 5, ab.clone());
+        grid_ba.set(3, 5, ba);
+        assert_ne!(grid_ab, grid_ba);
+        assert!(grid_ab.equals_unordered(&grid_ba));
+
+        grid_ba.set_color(0, 0, Color::rgb(1.0, 1.0, 1.0));
+        assert!(!grid_ab.equals_unordered(&grid_ba));
+    }
+
+    #[test]
+    fn test_grid_remove_string() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        grid.add_string(2, 2, "tree".to_string());
+        grid.add_string(2, 2, "rock".to_string());
+        grid.add_string(2, 2, "tree".to_string());
+
+        assert!(grid.remove_string(2, 2, "tree"));
+        assert_eq!(grid.get(2, 2).unwrap().strings, vec!["rock".to_string(), "tree".to_string()]);
+
+        assert!(!grid.remove_string(2, 2, "stick"));
+        assert_eq!(grid.get(2, 2).unwrap().strings.len(), 2);
+        assert!(!grid.remove_string(8, 0, "tree"));
+    }
+
+    #[test]
+    fn test_grid_remove_string_all() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        grid.add_string(0, 0, "tree".to_string());
+        grid.add_string(0, 0, "rock".to_string());
+        grid.add_string(0, 0, "tree".to_string());
+
+        assert_eq!(grid.remove_string_all(0, 0, "tree"), 2);
+        assert_eq!(grid.get(0, 0).unwrap().strings, vec!["rock".to_string()]);
+        assert_eq!(grid.remove_string_all(0, 0, "tree"), 0);
+        assert_eq!(grid.remove_string_all(0, 8, "rock"), 0);
+    }
+
+    #[test]
+    fn test_grid_nxn_sizes() {
+        let mut small: GridNxN<4> = GridNxN::new(Color::rgb(0.0, 0.0, 0.0));
+        assert_eq!(small.width(), 4);
+        assert_eq!(small.height(), 4);
+        assert!(small.set_color(3, 3, Color::rgb(1.0, 0.0, 0.0)));
+        assert!(!small.set_color(4, 0, Color::rgb(1.0, 0.0, 0.0)));
+        assert_eq!(small.iter().count(), 16);
+
+        let mut large: GridNxN<16> = GridNxN::new(Color::rgb(0.0, 0.0, 0.0));
+        assert!(large.add_string(15, 15, "corner".to_string()));
+        assert_eq!(large[(15, 15)].strings, vec!["corner".to_string()]);
+        assert_eq!(large.neighbors(15, 15).len(), 2);
+        assert_eq!(large.flood_fill(0, 0, 0.0).len(), 256);
+    }
+
+    /// Grid with a distinct "x,y" label on every tile
+    fn labeled_grid() -> Grid8x8 {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        for (x, y, tile) in grid.iter_mut() {
+            tile.strings.push(format!("{},{}", x, y));
+        }
+        grid
+    }
+
+    fn label(grid: &Grid8x8, x: usize, y: usize) -> &str {
+        &grid[(x, y)].strings[0]
+    }
+
+    #[test]
+    fn test_grid_rotate() {
+        let grid = labeled_grid();
+
+        let cw = grid.rotate_cw();
+        assert_eq!(label(&cw, 0, 0), "0,7");
+        assert_eq!(label(&cw, 7, 0), "0,0");
+        assert_eq!(label(&cw, 7, 7), "7,0");
+
+        let ccw = grid.rotate_ccw();
+        assert_eq!(label(&ccw, 0, 0), "7,0");
+        assert_eq!(label(&ccw, 0, 7), "0,0");
+        assert_eq!(ccw.rotate_cw(), grid);
+
+        let full_turn = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(full_turn, grid);
+    }
+
+    #[test]
+    fn test_grid_flip_and_transpose() {
+        let grid = labeled_grid();
+
+        let horizontal = grid.flip_horizontal();
+        assert_eq!(label(&horizontal, 0, 2), "7,2");
+        assert_eq!(horizontal.flip_horizontal(), grid);
+
+        let vertical = grid.flip_vertical();
+        assert_eq!(label(&vertical, 2, 0), "2,7");
+        assert_eq!(vertical.flip_vertical(), grid);
+
+        let transposed = grid.transpose();
+        assert_eq!(label(&transposed, 1, 6), "6,1");
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn test_grid_find_and_count() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+
+        assert!(grid.find(|tile| tile.color == red).is_empty());
+        assert_eq!(grid.count(|tile| tile.color == red), 0);
+        assert!(grid.tiles_with_strings().is_empty());
+        assert_eq!(grid.count(|_| true), 64);
+
+        grid.set_color(5, 1, red);
+        grid.set_color(2, 0, red);
+        grid.add_string(5, 1, "red".to_string());
+
+        assert_eq!(grid.find(|tile| tile.color == red), vec![(2, 0), (5, 1)]);
+        assert_eq!(grid.count(|tile| tile.color == red && tile.has_string("red")), 1);
+        assert_eq!(grid.tiles_with_strings(), vec![(5, 1)]);
+    }
+
+    #[test]
+    fn test_grid_fill() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        grid.add_string(2, 2, "kept".to_string());
+        let red = Color::rgb(1.0, 0.0, 0.0);
+
+        grid.fill(red);
+        assert_eq!(grid.count(|tile| tile.color == red), 64);
+        assert!(grid[(2, 2)].has_string("kept"));
+    }
+
+    #[test]
+    fn test_grid_fill_rect() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let red = Color::rgb(1.0, 0.0, 0.0);
+
+        // 3 columns x 4 rows
+        assert!(grid.fill_rect(1, 2, 3, 5, red));
+        assert_eq!(grid.count(|tile| tile.color == red), 12);
+        assert_eq!(grid.find(|tile| tile.color == red).first(), Some(&(1, 2)));
+        assert_eq!(grid.find(|tile| tile.color == red).last(), Some(&(3, 5)));
+
+        // Corners may be given in any order; a single tile is a 1x1 rectangle
+        let blue = Color::rgb(0.0, 0.0, 1.0);
+        assert!(grid.fill_rect(7, 7, 6, 6, blue));
+        assert_eq!(grid.count(|tile| tile.color == blue), 4);
+        let green = Color::rgb(0.0, 1.0, 0.0);
+        assert!(grid.fill_rect(0, 0, 0, 0, green));
+        assert_eq!(grid.count(|tile| tile.color == green), 1);
+
+        // Out-of-range rectangles are rejected without changes
+        let before = grid.clone();
+        assert!(!grid.fill_rect(0, 0, 8, 3, red));
+        assert!(!grid.fill_rect(9, 9, 2, 2, red));
+        assert!(!grid.fill_rect(0, 8, 0, 0, red));
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn test_grid_map_region() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let before = grid.clone();
+
+        assert!(grid.map_region(4, 3, 3, 2, |tile| tile.strings.push("marker".to_string())));
+        let changed: Vec<(usize, usize)> = grid
+            .iter()
+            .filter(|(x, y, tile)| before.get(*x, *y) != Some(*tile))
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(changed, vec![(3, 2), (4, 2), (3, 3), (4, 3)]);
+        assert_eq!(grid.find(|tile| tile.strings == ["marker"]), changed);
+
+        // Out-of-range corners never call the closure
+        let mut calls = 0;
+        assert!(!grid.map_region(0, 0, 8, 8, |_| calls += 1));
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_grid_draw_border() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 0.0, 0.0));
+        let white = Color::rgb(1.0, 1.0, 1.0);
+        grid.draw_border(white);
+
+        assert_eq!(grid.count(|tile| tile.color == white), 28);
+        assert_eq!(grid[(0, 0)].color, white);
+        assert_eq!(grid[(7, 3)].color, white);
+        assert_eq!(grid[(3, 3)].color, Color::rgb(0.0, 0.0, 0.0));
+
+        let mut small = GridNxN::<1>::new(Color::rgb(0.0, 0.0, 0.0));
+        small.draw_border(white);
+        assert_eq!(small[(0, 0)].color, white);
+    }
+
+    #[test]
+    fn test_grid_text_round_trip() {
+        let mut grid = Grid8x8::new(Color::rgb(0.0, 1.0, 0.0));
+        grid.set_color(0, 0, Color::rgb(1.0, 0.0, 0.0));
+        grid.set_color(7, 7, Color::from_hex("#804020").unwrap());
+        grid.set_color(3, 5, Color::from_hex("#10203040").unwrap());
+        grid.add_string(0, 0, "tree".to_string());
+        grid.add_string(0, 0, "two words".to_string());
+        grid.add_string(4, 2, "line\\one\nline two".to_string());
+
+        let text = grid.to_text();
+        assert!(text.starts_with("rggggggg\n"));
+        assert_eq!(Grid8x8::from_text(&text).unwrap(), grid);
+
+        let typed = "\
+r.......
+........
+........
+........
+........
+........
+........
+.......A
+palette:
+A #804020
+strings:
+0,0 tree
+";
+        let parsed = Grid8x8::from_text(typed).unwrap();
+        assert_eq!(parsed[(0, 0)].color, Color::rgb(1.0, 0.0, 0.0));
+        assert_eq!(parsed[(7, 7)].color, Color::from_hex("#804020").unwrap());
+        assert_eq!(parsed[(0, 0)].strings, vec!["tree".to_string()]);
+        assert_eq!(parsed.to_text(), typed);
+    }
+
+    #[test]
+    fn test_grid_text_malformed_input() {
+        let rows = "........\n".repeat(8);
+
+        let err = Grid8x8::from_text(&"........\n".repeat(7)).unwrap_err();
+        assert!(err.contains("Expected 8 rows, got 7"), "{}", err);
+
+        let short_row = rows.replacen("........", ".......", 1);
+        let err = Grid8x8::from_text(&short_row).unwrap_err();
+        assert!(err.contains("Row 0: expected 8 columns, got 7"), "{}", err);
+
+        let unknown = rows.replacen("........", "...q....", 1);
+        let err = Grid8x8::from_text(&unknown).unwrap_err();
+        assert!(err.contains("column 3: unknown color code 'q'"), "{}", err);
+
+        let err = Grid8x8::from_text(&format!("{}palette:\nA red\n", rows)).unwrap_err();
+        assert!(err.contains("Invalid palette entry 'A red'"), "{}", err);
+
+        let err = Grid8x8::from_text(&format!("{}strings:\n8,0 tree\n", rows)).unwrap_err();
+        assert!(err.contains("out of bounds"), "{}", err);
+
+        let err = Grid8x8::from_text(&format!("{}strings:\ntree\n", rows)).unwrap_err();
+        assert!(err.contains("expected '<x>,<y> <string>'"), "{}", err);
+    }
+
+    #[cfg(feature = "land")]
+    #[test]
+    fn test_grid_from_land() {
+        use q::{Biome, Land, Object, Substrate};
+
+        let grass = q::Tile { substrate: Substrate::Grass, objects: vec![], underground: None };
+        let mut land = Land {
+            tiles: std::array::from_fn(|_| std::array::from_fn(|_| grass.clone())),
+            center: Biome::Meadow,
+            top: Biome::Meadow,
+            bottom: Biome::Meadow,
+            left: Biome::Meadow,
+            right: Biome::Meadow,
+            top_left: Biome::Meadow,
+            top_right: Biome::Meadow,
+            bottom_left: Biome::Meadow,
+            bottom_right: Biome::Meadow,
+        };
+        // tiles are indexed [y][x]
+        land.tiles[1][6] = q::Tile { substrate: Substrate::Water, objects: vec![], underground: None };
+        land.tiles[4][2] = q::Tile { substrate: Substrate::Dirt, objects: vec![Object::Tree, Object::Stick], underground: None };
+
+        let grid = grid_from_land(&land);
+        let grass_color = Color::rgb(0.7, 0.9, 0.4);
+
+        assert_eq!(grid.count(|tile| tile.color == grass_color), 62);
+        assert_eq!(grid[(6, 1)].color, Color::rgb(0.2, 0.4, 0.9));
+        assert!(grid[(6, 1)].strings.is_empty());
+        assert_eq!(grid[(2, 4)].color, Color::rgb(0.6, 0.4, 0.2));
+        assert_eq!(grid[(2, 4)].strings, vec!["Tree".to_string(), "Stick".to_string()]);
+        assert_eq!(grid.tiles_with_strings(), vec![(2, 4)]);
+    }
 }
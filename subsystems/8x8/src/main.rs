@@ -107,12 +107,7 @@ impl GridGUI {
                     // Highlight hovered tile
                     let is_hovered = self.hovered_tile == Some((x, y));
                     let draw_color = if is_hovered {
-                        Color::new(
-                            (tile_color.r * 1.2).min(1.0),
-                            (tile_color.g * 1.2).min(1.0),
-                            (tile_color.b * 1.2).min(1.0),
-                            tile_color.a,
-                        )
+                        self.color_to_macroquad(tile.color.lighten(0.2))
                     } else {
                         tile_color
                     };
@@ -130,7 +125,7 @@ impl GridGUI {
 
                     // Draw strings on the tile
                     if !tile.strings.is_empty() {
-                        let text_color = if self.is_dark_color(tile.color) {
+                        let text_color = if tile.color.is_dark() {
                             WHITE
                         } else {
                             BLACK
@@ -273,13 +268,6 @@ impl GridGUI {
     fn color_to_macroquad(&self, color: GridColor) -> Color {
         Color::new(color.r, color.g, color.b, color.a)
     }
-
-    /// Determine if a color is dark (for choosing text color)
-    fn is_dark_color(&self, color: GridColor) -> bool {
-        // Calculate luminance
-        let luminance = 0.299 * color.r + 0.587 * color.g + 0.114 * color.b;
-        luminance < 0.5
-    }
 }
 
 fn create_example_grid() -> Grid8x8 {
@@ -0,0 +1,183 @@
+//! Compact, hand-editable text format for grids (see [`GridNxN::to_text`]).
+
+use crate::{Color, GridNxN, Tile};
+
+/// Built-in color codes, usable without a `palette:` section
+const PALETTE: [(char, Color); 9] = [
+    ('.', Color { r: 0.0, g: 0.0, b: 0.0, a: 1.0 }),
+    ('w', Color { r: 1.0, g: 1.0, b: 1.0, a: 1.0 }),
+    ('-', Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 }),
+    ('r', Color { r: 1.0, g: 0.0, b: 0.0, a: 1.0 }),
+    ('g', Color { r: 0.0, g: 1.0, b: 0.0, a: 1.0 }),
+    ('b', Color { r: 0.0, g: 0.0, b: 1.0, a: 1.0 }),
+    ('y', Color { r: 1.0, g: 1.0, b: 0.0, a: 1.0 }),
+    ('c', Color { r: 0.0, g: 1.0, b: 1.0, a: 1.0 }),
+    ('m', Color { r: 1.0, g: 0.0, b: 1.0, a: 1.0 }),
+];
+
+const PALETTE_HEADER: &str = "palette:";
+const STRINGS_HEADER: &str = "strings:";
+
+/// Codes handed out to colors outside the built-in palette, in order
+fn custom_codes() -> impl Iterator<Item = char> {
+    ('A'..='Z')
+        .chain('a'..='z')
+        .chain('0'..='9')
+        .chain("!$%&*+/<=>?@^~".chars())
+        .chain((0xC0..=0x10FFFF).filter_map(char::from_u32))
+        .filter(|c| !c.is_whitespace() && PALETTE.iter().all(|(code, _)| code != c))
+}
+
+impl<const N: usize> GridNxN<N> {
+    /// Write the grid in a compact text format meant to be typed by hand.
+    ///
+    /// The first N lines hold one color code per tile. Codes `.` `w` `-` `r` `g`
+    /// `b` `y` `c` `m` are black, white, gray, red, green, blue, yellow, cyan and
+    /// magenta. Any other color is given a code in a `palette:` section
+    /// (`<code> <#hex>`). Tile strings follow in a `strings:` section, one per line
+    /// as `<x>,<y> <string>`, with `\` and newlines escaped as `\\` and `\n`.
+    ///
+    /// ```text
+    /// rg......
+    /// ........   (8 rows total)
+    /// palette:
+    /// A #804020
+    /// strings:
+    /// 0,0 tree
+    /// ```
+    ///
+    /// Custom colors are stored as hex, so they round-trip at 8 bits per channel.
+    pub fn to_text(&self) -> String {
+        let mut custom: Vec<(char, Color)> = Vec::new();
+        let mut codes = custom_codes();
+        let mut rows = String::new();
+
+        for row in &self.tiles {
+            for tile in row {
+                let builtin = PALETTE.iter().find(|(_, color)| *color == tile.color);
+                let code = match builtin.or_else(|| custom.iter().find(|(_, color)| *color == tile.color)) {
+                    Some(&(code, _)) => code,
+                    None => {
+                        // Every color needs a code; the iterator never runs out for realistic N
+                        let code = codes.next().expect("ran out of color codes");
+                        custom.push((code, tile.color));
+                        code
+                    }
+                };
+                rows.push(code);
+            }
+            rows.push('\n');
+        }
+
+        let mut out = rows;
+        if !custom.is_empty() {
+            out += PALETTE_HEADER;
+            out += "\n";
+            for (code, color) in &custom {
+                out += &format!("{} {}\n", code, color.to_hex());
+            }
+        }
+
+        let strings: Vec<String> = self
+            .iter()
+            .flat_map(|(x, y, tile)| tile.strings.iter().map(move |s| format!("{},{} {}\n", x, y, escape(s))))
+            .collect();
+        if !strings.is_empty() {
+            out += STRINGS_HEADER;
+            out += "\n";
+            out += &strings.concat();
+        }
+        out
+    }
+
+    /// Parse the format written by [`GridNxN::to_text`].
+    ///
+    /// Blank lines are ignored. Errors name the offending row, column, or line:
+    /// wrong row/column counts, unknown color codes, malformed palette entries, and
+    /// string entries that are malformed or out of bounds.
+    pub fn from_text(text: &str) -> Result<Self, String> {
+        let mut rows: Vec<&str> = Vec::new();
+        let mut palette_lines: Vec<&str> = Vec::new();
+        let mut string_lines: Vec<&str> = Vec::new();
+
+        let mut section = &mut rows;
+        for line in text.lines().map(|line| line.trim_end_matches('\r')) {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match line.trim() {
+                PALETTE_HEADER => section = &mut palette_lines,
+                STRINGS_HEADER => section = &mut string_lines,
+                _ => section.push(line),
+            }
+        }
+
+        let mut palette: Vec<(char, Color)> = PALETTE.to_vec();
+        for line in palette_lines {
+            let mut parts = line.split_whitespace();
+            let entry = match (parts.next(), parts.next(), parts.next()) {
+                (Some(code), Some(hex), None) if code.chars().count() == 1 => {
+                    code.chars().next().map(|c| (c, hex))
+                }
+                _ => None,
+            };
+            let (code, hex) = entry.ok_or_else(|| format!("Invalid palette entry '{}': expected '<code> <#hex>'", line))?;
+            let color = Color::from_hex(hex).map_err(|e| format!("Invalid palette entry '{}': {}", line, e))?;
+            // Later entries shadow built-in codes
+            palette.insert(0, (code, color));
+        }
+
+        if rows.len() != N {
+            return Err(format!("Expected {} rows, got {}", N, rows.len()));
+        }
+        let mut grid = Self::new(PALETTE[0].1);
+        for (y, row) in rows.iter().enumerate() {
+            let columns = row.chars().count();
+            if columns != N {
+                return Err(format!("Row {}: expected {} columns, got {}", y, N, columns));
+            }
+            for (x, code) in row.chars().enumerate() {
+                let color = palette
+                    .iter()
+                    .find(|(c, _)| *c == code)
+                    .map(|&(_, color)| color)
+                    .ok_or_else(|| format!("Row {}, column {}: unknown color code '{}'", y, x, code))?;
+                grid.tiles[y][x] = Tile::new(color);
+            }
+        }
+
+        for line in string_lines {
+            let (coords, string) = line.split_once(' ').unwrap_or((line, ""));
+            let (x, y) = coords
+                .split_once(',')
+                .and_then(|(x, y)| Some((x.parse::<usize>().ok()?, y.parse::<usize>().ok()?)))
+                .ok_or_else(|| format!("Invalid string entry '{}': expected '<x>,<y> <string>'", line))?;
+            if !grid.add_string(x, y, unescape(string)) {
+                return Err(format!("Invalid string entry '{}': ({}, {}) is out of bounds", line, x, y));
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some(other) => out.push(other),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
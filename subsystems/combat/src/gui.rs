@@ -1,4 +1,4 @@
-use combat::{Combatant, CombatState, CombatResult};
+use combat::{Combatant, CombatState, CombatResult, RoundRecord};
 use macroquad::prelude::*;
 
 /// Window configuration
@@ -73,7 +73,7 @@ struct CombatGUI {
     
     // Combat state
     combat_state: Option<CombatState>,
-    combat_history: Vec<(u32, i32, i32, i32, i32, CombatResult)>,
+    combat_history: Vec<RoundRecord>,
     current_round: usize,
     auto_play: bool,
     auto_play_timer: f32,
@@ -168,20 +168,8 @@ impl CombatGUI {
 
     fn execute_round(&mut self) {
         if let Some(ref mut state) = self.combat_state {
-            let round_before = state.round;
-            let health1_before = state.combatant1.health;
-            let health2_before = state.combatant2.health;
-            
-            let result = state.execute_round();
-            
-            self.combat_history.push((
-                round_before + 1,
-                health1_before,
-                health2_before,
-                state.combatant1.health,
-                state.combatant2.health,
-                result,
-            ));
+            let record = state.execute_round_logged();
+            self.combat_history.push(record);
             self.current_round = self.combat_history.len();
             self.last_attack_round = state.round;
             self.attack_animation_timer = 0.3; // 0.3 seconds animation
@@ -623,8 +611,8 @@ impl CombatGUI {
         let c1 = self.get_combatant1();
         let c2 = self.get_combatant2();
         let (c1_health, c1_max_health, c1_attack, c1_defeated) = if let Some(ref state) = self.combat_state {
-            let max = if let Some((_, h1_before, _, _, _, _)) = self.combat_history.first() {
-                *h1_before
+            let max = if let Some(record) = self.combat_history.first() {
+                record.c1_before
             } else {
                 c1.health
             };
@@ -634,8 +622,8 @@ impl CombatGUI {
         };
 
         let (c2_health, c2_max_health, c2_attack, c2_defeated) = if let Some(ref state) = self.combat_state {
-            let max = if let Some((_, _, h2_before, _, _, _)) = self.combat_history.first() {
-                *h2_before
+            let max = if let Some(record) = self.combat_history.first() {
+                record.c2_before
             } else {
                 c2.health
             };
@@ -705,12 +693,14 @@ impl CombatGUI {
                 CombatResult::Combatant1Wins => "Pet 1 Wins!".to_string(),
                 CombatResult::Combatant2Wins => "Pet 2 Wins!".to_string(),
                 CombatResult::Draw => "Draw!".to_string(),
+                CombatResult::Stalemate => "Stalemate!".to_string(),
             };
             let result_color = match result {
                 CombatResult::Ongoing => Color::new(0.5, 0.5, 0.5, 1.0),
                 CombatResult::Combatant1Wins => Color::new(0.2, 0.8, 0.3, 1.0),
                 CombatResult::Combatant2Wins => Color::new(0.8, 0.2, 0.2, 1.0),
                 CombatResult::Draw => Color::new(0.8, 0.8, 0.2, 1.0),
+                CombatResult::Stalemate => Color::new(0.5, 0.5, 0.5, 1.0),
             };
             let text_size = 32.0;
             let text_width = measure_text(&result_text, None, text_size as u16, 1.0).width;
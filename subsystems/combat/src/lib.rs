@@ -1,41 +1,125 @@
+use std::cmp::Ordering;
+
+pub use combat_engine::{simulate, simulate_bounded, CombatEngine, CombatOutcome, Side, DEFAULT_MAX_ROUNDS};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Simple one-v-one combat system with simultaneous attack resolution
 /// Similar to Super Auto Pets combat mechanics
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// `Clone` but not `Copy`: the status effect list can grow without bound, so
+/// copies have to be explicit `.clone()` calls
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Combatant {
     pub health: i32,
     pub attack: i32,
+    /// Subtracted from each incoming hit (hits still deal at least 1 damage)
+    pub defense: i32,
+    /// Healing is capped at this value
+    pub max_health: i32,
+    /// Percentage of damage dealt that is healed back each round (0 = none)
+    pub lifesteal: i32,
+    /// Higher speed strikes first in `ResolutionMode::Initiative`
+    pub speed: i32,
+    /// Effects that tick at the start of each round, in order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status_effects: Vec<StatusEffect>,
+    /// Shown in result messages; empty falls back to "Combatant 1"/"Combatant 2"
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: String,
+}
+
+/// A lasting effect on a combatant, applied at the start of each round before attacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StatusEffect {
+    /// Lose `per_round` health for the next `rounds` rounds (can defeat the combatant)
+    Poison { per_round: i32, rounds: u32 },
+    /// Heal `per_round` health (up to max health) for the next `rounds` rounds
+    Regen { per_round: i32, rounds: u32 },
+}
+
+impl StatusEffect {
+    /// Rounds left before the effect expires
+    pub fn rounds(&self) -> u32 {
+        match *self {
+            StatusEffect::Poison { rounds, .. } | StatusEffect::Regen { rounds, .. } => rounds,
+        }
+    }
+
+    fn rounds_mut(&mut self) -> &mut u32 {
+        match self {
+            StatusEffect::Poison { rounds, .. } | StatusEffect::Regen { rounds, .. } => rounds,
+        }
+    }
 }
 
 /// Predefined combatants with different stat combinations
 impl Combatant {
-    /// Tank: High health, low attack - survives long but deals little damage
-    pub const TANK: Combatant = Combatant { health: 20, attack: 2 };
+    /// Tank: High health, low attack, some defense - survives long but deals little damage
+    pub const TANK: Combatant = Combatant { health: 20, attack: 2, defense: 2, max_health: 20, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
     
     /// Glass Cannon: Low health, high attack - deals massive damage but fragile
-    pub const GLASS_CANNON: Combatant = Combatant { health: 5, attack: 8 };
+    pub const GLASS_CANNON: Combatant = Combatant { health: 5, attack: 8, defense: 0, max_health: 5, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
     
     /// Balanced Fighter: Medium health and attack - well-rounded combatant
-    pub const BALANCED: Combatant = Combatant { health: 10, attack: 5 };
+    pub const BALANCED: Combatant = Combatant { health: 10, attack: 5, defense: 1, max_health: 10, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
     
     /// Bruiser: High health, medium attack - durable and hits hard
-    pub const BRUISER: Combatant = Combatant { health: 15, attack: 6 };
+    pub const BRUISER: Combatant = Combatant { health: 15, attack: 6, defense: 1, max_health: 15, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
     
     /// Assassin: Very low health, very high attack - extreme glass cannon
-    pub const ASSASSIN: Combatant = Combatant { health: 3, attack: 10 };
+    pub const ASSASSIN: Combatant = Combatant { health: 3, attack: 10, defense: 0, max_health: 3, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
     
-    /// Defender: Very high health, very low attack - ultimate tank
-    pub const DEFENDER: Combatant = Combatant { health: 25, attack: 1 };
+    /// Defender: Very high health, very low attack, high defense - ultimate tank
+    pub const DEFENDER: Combatant = Combatant { health: 25, attack: 1, defense: 3, max_health: 25, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() };
+}
+
+/// Health snapshot of a single executed round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoundRecord {
+    /// Round number (1-based)
+    pub round: u32,
+    pub c1_before: i32,
+    pub c2_before: i32,
+    pub c1_after: i32,
+    pub c2_after: i32,
+    /// Combat result after this round
+    pub result: CombatResult,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CombatState {
     pub combatant1: Combatant,
     pub combatant2: Combatant,
     pub round: u32,
+    pub mode: ResolutionMode,
+    /// Per-hit damage floor applied after defense (None = no floor)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min_damage: Option<i32>,
+    /// Per-hit damage cap applied after defense (None = no cap)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_damage: Option<i32>,
+}
+
+/// How the two attacks within a round are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResolutionMode {
+    /// Both combatants attack at the same time
+    #[default]
+    Simultaneous,
+    /// The faster combatant strikes first; if that defeats the opponent, it doesn't strike back
+    /// Equal speeds fall back to simultaneous resolution
+    Initiative,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CombatResult {
     /// Combat continues, both combatants still alive
     Ongoing,
@@ -45,12 +129,98 @@ pub enum CombatResult {
     Combatant2Wins,
     /// Both combatants defeated simultaneously
     Draw,
+    /// Round limit reached with both combatants still alive
+    Stalemate,
 }
 
 impl Combatant {
-    /// Create a new combatant with specified health and attack stats
+    /// Create a new combatant with specified health and attack stats (no defense)
     pub fn new(health: i32, attack: i32) -> Self {
-        Self { health, attack }
+        Self::new_with_defense(health, attack, 0)
+    }
+
+    /// Create a new combatant with specified health, attack, and defense stats
+    pub fn new_with_defense(health: i32, attack: i32, defense: i32) -> Self {
+        Self { health, attack, defense, max_health: health, lifesteal: 0, speed: 0, status_effects: Vec::new(), name: String::new() }
+    }
+
+    /// Start building a combatant by named stat; unset stats default to 0 and
+    /// max health defaults to the starting health
+    pub fn builder() -> CombatantBuilder {
+        CombatantBuilder::default()
+    }
+
+    /// Set the speed used to order attacks in `ResolutionMode::Initiative`
+    pub fn with_speed(mut self, speed: i32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Set the lifesteal percentage (e.g. 50 heals half of the damage dealt)
+    pub fn with_lifesteal(mut self, lifesteal: i32) -> Self {
+        self.lifesteal = lifesteal;
+        self
+    }
+
+    /// Add a status effect; it first ticks at the start of the next round
+    pub fn with_status_effect(mut self, effect: StatusEffect) -> Self {
+        self.status_effects.push(effect);
+        self
+    }
+
+    /// Set the name used in result messages
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// The combatant's name, or `fallback` if it has none
+    pub fn display_name<'a>(&'a self, fallback: &'a str) -> &'a str {
+        if self.name.is_empty() {
+            fallback
+        } else {
+            &self.name
+        }
+    }
+
+    /// Apply each status effect once, then drop the ones that have run out
+    /// Poison can defeat the combatant; defeated combatants are not healed by regen
+    fn tick_status_effects(&mut self) {
+        for effect in &mut self.status_effects {
+            if effect.rounds() == 0 {
+                continue;
+            }
+            match *effect {
+                StatusEffect::Poison { per_round, .. } => self.health -= per_round,
+                StatusEffect::Regen { per_round, .. } => {
+                    if self.health > 0 {
+                        self.health = (self.health + per_round).min(self.max_health.max(self.health));
+                    }
+                }
+            }
+            *effect.rounds_mut() -= 1;
+        }
+        self.status_effects.retain(|effect| effect.rounds() > 0);
+    }
+
+    /// Heal based on `damage_dealt` and lifesteal, never above max health
+    /// Defeated combatants are not healed
+    fn apply_lifesteal(&mut self, damage_dealt: i32) {
+        if self.is_defeated() || self.lifesteal <= 0 {
+            return;
+        }
+        let heal = damage_dealt * self.lifesteal / 100;
+        self.health = (self.health + heal).min(self.max_health.max(self.health));
+    }
+
+    /// Damage this combatant deals to `target` in one hit
+    /// Defense reduces the hit, but any positive attack deals at least 1 damage
+    pub fn damage_against(&self, target: &Combatant) -> i32 {
+        if self.attack <= 0 {
+            0
+        } else {
+            (self.attack - target.defense).max(1)
+        }
     }
 
     /// Check if this combatant is defeated (health <= 0)
@@ -59,6 +229,84 @@ impl Combatant {
     }
 }
 
+/// Builder returned by `Combatant::builder`
+#[derive(Debug, Clone, Default)]
+pub struct CombatantBuilder {
+    health: i32,
+    attack: i32,
+    defense: i32,
+    max_health: Option<i32>,
+    lifesteal: i32,
+    speed: i32,
+    status_effects: Vec<StatusEffect>,
+    name: String,
+}
+
+impl CombatantBuilder {
+    pub fn health(mut self, health: i32) -> Self {
+        self.health = health;
+        self
+    }
+
+    pub fn attack(mut self, attack: i32) -> Self {
+        self.attack = attack;
+        self
+    }
+
+    pub fn defense(mut self, defense: i32) -> Self {
+        self.defense = defense;
+        self
+    }
+
+    /// Healing cap; defaults to the starting health
+    pub fn max_health(mut self, max_health: i32) -> Self {
+        self.max_health = Some(max_health);
+        self
+    }
+
+    pub fn lifesteal(mut self, lifesteal: i32) -> Self {
+        self.lifesteal = lifesteal;
+        self
+    }
+
+    pub fn speed(mut self, speed: i32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Add a status effect; may be called more than once
+    pub fn status_effect(mut self, effect: StatusEffect) -> Self {
+        self.status_effects.push(effect);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Finish the combatant, rejecting negative health or max health
+    pub fn build(self) -> Result<Combatant, String> {
+        if self.health < 0 {
+            return Err(format!("Health cannot be negative (got {})", self.health));
+        }
+        let max_health = self.max_health.unwrap_or(self.health);
+        if max_health < 0 {
+            return Err(format!("Max health cannot be negative (got {})", max_health));
+        }
+        Ok(Combatant {
+            health: self.health,
+            attack: self.attack,
+            defense: self.defense,
+            max_health,
+            lifesteal: self.lifesteal,
+            speed: self.speed,
+            status_effects: self.status_effects,
+            name: self.name,
+        })
+    }
+}
+
 impl CombatState {
     /// Create a new combat state with two combatants
     pub fn new(combatant1: Combatant, combatant2: Combatant) -> Self {
@@ -66,22 +314,112 @@ impl CombatState {
             combatant1,
             combatant2,
             round: 0,
+            mode: ResolutionMode::default(),
+            min_damage: None,
+            max_damage: None,
         }
     }
 
-    /// Execute one round of combat where both combatants attack simultaneously
+    /// Set how attacks within a round are ordered
+    pub fn with_mode(mut self, mode: ResolutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Clamp every hit into `[min, max]` after defense (no cap if `max` is None)
+    ///
+    /// Attacks of 0 or less deal 0 damage before clamping and never heal, so a
+    /// "healing attack" deals `min` when `min` is positive and nothing otherwise;
+    /// a negative `min` has no effect. If `max` is below `min`, `max` wins.
+    pub fn with_damage_bounds(mut self, min: i32, max: Option<i32>) -> Self {
+        self.min_damage = Some(min);
+        self.max_damage = max;
+        self
+    }
+
+    /// Apply the damage bounds to one hit
+    fn clamp_damage(&self, damage: i32) -> i32 {
+        let damage = self.min_damage.map_or(damage, |min| damage.max(min));
+        self.max_damage.map_or(damage, |max| damage.min(max))
+    }
+
+    /// Serialize the combat state (including the current round) to JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        // Plain data with no maps or custom serializers, so this can't fail
+        serde_json::to_string_pretty(self).expect("CombatState is always serializable")
+    }
+
+    /// Deserialize a combat state previously produced by `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse combat state: {}", e))
+    }
+
+    /// Execute one round of combat
+    /// Status effects tick first; a combatant they defeat doesn't attack, and the round ends there
+    /// Attacks resolve simultaneously unless the mode is `Initiative` and speeds differ
     /// Returns the combat result after this round
     pub fn execute_round(&mut self) -> CombatResult {
         self.round += 1;
 
-        // Both attacks resolve simultaneously
-        self.combatant1.health -= self.combatant2.attack;
-        self.combatant2.health -= self.combatant1.attack;
+        self.combatant1.tick_status_effects();
+        self.combatant2.tick_status_effects();
+        if self.combatant1.is_defeated() || self.combatant2.is_defeated() {
+            return self.get_result();
+        }
+
+        let mut damage_to_1 = self.clamp_damage(self.combatant2.damage_against(&self.combatant1));
+        let mut damage_to_2 = self.clamp_damage(self.combatant1.damage_against(&self.combatant2));
+
+        let speed_order = match self.mode {
+            ResolutionMode::Simultaneous => Ordering::Equal,
+            ResolutionMode::Initiative => self.combatant1.speed.cmp(&self.combatant2.speed),
+        };
+        match speed_order {
+            Ordering::Equal => {
+                self.combatant1.health -= damage_to_1;
+                self.combatant2.health -= damage_to_2;
+            }
+            Ordering::Greater => {
+                self.combatant2.health -= damage_to_2;
+                if self.combatant2.is_defeated() {
+                    damage_to_1 = 0;
+                }
+                self.combatant1.health -= damage_to_1;
+            }
+            Ordering::Less => {
+                self.combatant1.health -= damage_to_1;
+                if self.combatant1.is_defeated() {
+                    damage_to_2 = 0;
+                }
+                self.combatant2.health -= damage_to_2;
+            }
+        }
+
+        // Lifesteal heals after all damage lands, so it can't revive a defeated combatant
+        self.combatant1.apply_lifesteal(damage_to_2);
+        self.combatant2.apply_lifesteal(damage_to_1);
 
         // Determine result
         self.get_result()
     }
 
+    /// Execute one round of combat and return a record of health before and after
+    pub fn execute_round_logged(&mut self) -> RoundRecord {
+        let c1_before = self.combatant1.health;
+        let c2_before = self.combatant2.health;
+        let result = self.execute_round();
+        RoundRecord {
+            round: self.round,
+            c1_before,
+            c2_before,
+            c1_after: self.combatant1.health,
+            c2_after: self.combatant2.health,
+            result,
+        }
+    }
+
     /// Get the current combat result without executing a round
     pub fn get_result(&self) -> CombatResult {
         let c1_defeated = self.combatant1.is_defeated();
@@ -96,15 +434,279 @@ impl CombatState {
     }
 
     /// Simulate combat to completion, executing rounds until someone wins or draw
+    /// Gives up with `CombatResult::Stalemate` after `DEFAULT_MAX_ROUNDS` rounds
     /// Returns the final state and result
-    pub fn simulate_combat(mut self) -> (Self, CombatResult) {
-        loop {
-            let result = self.execute_round();
-            match result {
-                CombatResult::Ongoing => continue,
-                _ => return (self, result),
+    pub fn simulate_combat(self) -> (Self, CombatResult) {
+        self.simulate_combat_bounded(DEFAULT_MAX_ROUNDS)
+    }
+
+    /// Simulate combat until someone wins, draws, or the state reaches `max_rounds`
+    /// Returns the final state and result (`CombatResult::Stalemate` if the cap was hit)
+    pub fn simulate_combat_bounded(self, max_rounds: u32) -> (Self, CombatResult) {
+        let (state, result, _) = self.simulate_with_log_bounded(max_rounds);
+        (state, result)
+    }
+
+    /// Simulate combat to completion like `simulate_combat`, also returning a record of every round
+    pub fn simulate_with_log(self) -> (Self, CombatResult, Vec<RoundRecord>) {
+        self.simulate_with_log_bounded(DEFAULT_MAX_ROUNDS)
+    }
+
+    /// Simulate combat with a custom round cap, also returning a record of every round
+    pub fn simulate_with_log_bounded(mut self, max_rounds: u32) -> (Self, CombatResult, Vec<RoundRecord>) {
+        let mut log = Vec::new();
+        while self.round < max_rounds {
+            let record = self.execute_round_logged();
+            log.push(record);
+            if record.result != CombatResult::Ongoing {
+                return (self, record.result, log);
             }
         }
+        (self, CombatResult::Stalemate, log)
+    }
+}
+
+impl CombatResult {
+    /// Describe the result using the combatants' names, e.g. "Tank defeats Glass Cannon"
+    ///
+    /// Unnamed combatants are called "Combatant 1"/"Combatant 2". If neither has a
+    /// name this is the plain form ("Combatant 1 Wins", "Draw", ...).
+    pub fn describe(&self, combatant1: &Combatant, combatant2: &Combatant) -> String {
+        let named = !combatant1.name.is_empty() || !combatant2.name.is_empty();
+        let name1 = combatant1.display_name("Combatant 1");
+        let name2 = combatant2.display_name("Combatant 2");
+        match (self, named) {
+            (CombatResult::Combatant1Wins, false) => "Combatant 1 Wins".to_string(),
+            (CombatResult::Combatant2Wins, false) => "Combatant 2 Wins".to_string(),
+            (CombatResult::Draw, false) => "Draw".to_string(),
+            (CombatResult::Stalemate, false) => "Stalemate".to_string(),
+            (CombatResult::Combatant1Wins, true) => format!("{} defeats {}", name1, name2),
+            (CombatResult::Combatant2Wins, true) => format!("{} defeats {}", name2, name1),
+            (CombatResult::Draw, true) => format!("Draw between {} and {}", name1, name2),
+            (CombatResult::Stalemate, true) => format!("Stalemate between {} and {}", name1, name2),
+            (CombatResult::Ongoing, _) => "Ongoing".to_string(),
+        }
+    }
+}
+
+impl CombatOutcome for CombatResult {
+    fn is_ongoing(&self) -> bool {
+        *self == CombatResult::Ongoing
+    }
+
+    fn winner(&self) -> Option<Side> {
+        match self {
+            CombatResult::Combatant1Wins => Some(Side::First),
+            CombatResult::Combatant2Wins => Some(Side::Second),
+            _ => None,
+        }
+    }
+}
+
+/// Generic engine view of a 1v1 fight. A capped `simulate` returns `Ongoing` rather
+/// than `Stalemate`; use `simulate_combat` for the 1v1-specific result.
+impl CombatEngine for CombatState {
+    type Result = CombatResult;
+
+    fn execute_round(&mut self) -> CombatResult {
+        CombatState::execute_round(self)
+    }
+
+    fn get_result(&self) -> CombatResult {
+        CombatState::get_result(self)
+    }
+
+    fn round(&self) -> u32 {
+        self.round
+    }
+}
+
+/// A saved fight: the starting state and round cap, plus the rounds and result it produced
+///
+/// Combat has no randomness, so the initial state (including mode and damage bounds) and the
+/// cap fully determine the fight. `rounds` and `result` are kept only to check a replay against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FightLog {
+    pub initial: CombatState,
+    pub max_rounds: u32,
+    pub rounds: Vec<RoundRecord>,
+    pub result: CombatResult,
+}
+
+impl FightLog {
+    /// Run `initial` to completion with `DEFAULT_MAX_ROUNDS` and record the fight
+    pub fn record(initial: CombatState) -> Self {
+        Self::record_bounded(initial, DEFAULT_MAX_ROUNDS)
+    }
+
+    /// Run `initial` to completion with a custom round cap and record the fight
+    pub fn record_bounded(initial: CombatState, max_rounds: u32) -> Self {
+        let (_, result, rounds) = initial.clone().simulate_with_log_bounded(max_rounds);
+        Self { initial, max_rounds, rounds, result }
+    }
+
+    /// Step through the fight again, recomputing every round from `initial`
+    pub fn replay(&self) -> Replay<'_> {
+        Replay { log: self, state: self.initial.clone(), finished: false }
+    }
+
+    /// Serialize the fight log to JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("FightLog is always serializable")
+    }
+
+    /// Deserialize a fight log previously produced by `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse fight log: {}", e))
+    }
+}
+
+/// One recomputed round of a `Replay`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayStep {
+    /// State after the round
+    pub state: CombatState,
+    /// The round as recomputed from the initial state
+    pub record: RoundRecord,
+    /// The same round as stored in the log (None if the log has fewer rounds)
+    pub stored: Option<RoundRecord>,
+}
+
+impl ReplayStep {
+    /// True if the recomputed round matches the stored one
+    pub fn matches_log(&self) -> bool {
+        self.stored == Some(self.record)
+    }
+}
+
+/// Iterator over the rounds of a `FightLog`, recomputed one at a time (see `FightLog::replay`)
+pub struct Replay<'a> {
+    log: &'a FightLog,
+    state: CombatState,
+    finished: bool,
+}
+
+impl Replay<'_> {
+    /// Result of the replayed fight so far (`Stalemate` once the round cap is reached)
+    pub fn result(&self) -> CombatResult {
+        match self.state.get_result() {
+            CombatResult::Ongoing if self.state.round >= self.log.max_rounds => CombatResult::Stalemate,
+            result => result,
+        }
+    }
+}
+
+impl Iterator for Replay<'_> {
+    type Item = ReplayStep;
+
+    fn next(&mut self) -> Option<ReplayStep> {
+        if self.finished || self.state.round >= self.log.max_rounds {
+            return None;
+        }
+        let played = self.state.round - self.log.initial.round;
+        let stored = self.log.rounds.get(played as usize).copied();
+        let record = self.state.execute_round_logged();
+        self.finished = record.result != CombatResult::Ongoing;
+        Some(ReplayStep { state: self.state.clone(), record, stored })
+    }
+}
+
+/// Simulate every unordered pair of `combatants` once, in the default resolution mode
+///
+/// Pairs are returned as `(i, j, result)` with `i < j`, ordered by `i` then `j`.
+/// `combatants[i]` is always `combatant1`, so `Combatant1Wins` means `i` won.
+pub fn round_robin(combatants: &[Combatant]) -> Vec<(usize, usize, CombatResult)> {
+    let mut results = Vec::new();
+    for i in 0..combatants.len() {
+        for j in (i + 1)..combatants.len() {
+            let (_, result) = CombatState::new(combatants[i].clone(), combatants[j].clone()).simulate_combat();
+            results.push((i, j, result));
+        }
+    }
+    results
+}
+
+/// Win/loss/draw tally for one combatant in a tournament
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WinCounts {
+    pub wins: u32,
+    pub losses: u32,
+    /// Includes stalemates (round cap reached with both alive)
+    pub draws: u32,
+}
+
+/// Tally `round_robin` results into one `WinCounts` per combatant (indexed like the input)
+/// Indices at or beyond `combatant_count` are ignored
+pub fn win_counts(combatant_count: usize, results: &[(usize, usize, CombatResult)]) -> Vec<WinCounts> {
+    let mut counts = vec![WinCounts::default(); combatant_count];
+    for &(i, j, result) in results {
+        if i >= combatant_count || j >= combatant_count {
+            continue;
+        }
+        match result {
+            CombatResult::Combatant1Wins => {
+                counts[i].wins += 1;
+                counts[j].losses += 1;
+            }
+            CombatResult::Combatant2Wins => {
+                counts[j].wins += 1;
+                counts[i].losses += 1;
+            }
+            CombatResult::Draw | CombatResult::Stalemate => {
+                counts[i].draws += 1;
+                counts[j].draws += 1;
+            }
+            CombatResult::Ongoing => {}
+        }
+    }
+    counts
+}
+
+/// Cell symbols used by `outcome_matrix_csv`, from the row combatant's point of view
+pub const MATRIX_WIN: &str = "W";
+pub const MATRIX_LOSS: &str = "L";
+pub const MATRIX_DRAW: &str = "D";
+pub const MATRIX_STALEMATE: &str = "S";
+
+/// Every ordered pairing of `combatants` as CSV, for balancing in a spreadsheet
+///
+/// The header row and first column hold the names. Cell `[i][j]` is the result of
+/// `i` (as `combatant1`) against `j`, simulated like `round_robin`: `W` win, `L` loss,
+/// `D` draw (both defeated), `S` stalemate (round cap reached). Lines end with `\n`,
+/// and names containing commas, quotes, or newlines are quoted.
+pub fn outcome_matrix_csv(combatants: &[(String, Combatant)]) -> String {
+    let mut header = vec![String::new()];
+    header.extend(combatants.iter().map(|(name, _)| csv_field(name)));
+    let mut out = header.join(",") + "\n";
+
+    for (name, row) in combatants {
+        let mut cells = vec![csv_field(name)];
+        for (_, column) in combatants {
+            let (_, result) = CombatState::new(row.clone(), column.clone()).simulate_combat();
+            let symbol = match result {
+                CombatResult::Combatant1Wins => MATRIX_WIN,
+                CombatResult::Combatant2Wins => MATRIX_LOSS,
+                CombatResult::Draw => MATRIX_DRAW,
+                // simulate_combat never stops while the fight is ongoing
+                CombatResult::Stalemate | CombatResult::Ongoing => MATRIX_STALEMATE,
+            };
+            cells.push(symbol.to_string());
+        }
+        out += &(cells.join(",") + "\n");
+    }
+    out
+}
+
+/// Quote a CSV field if it contains a separator, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
     }
 }
 
@@ -117,9 +719,31 @@ mod tests {
         let c = Combatant::new(10, 5);
         assert_eq!(c.health, 10);
         assert_eq!(c.attack, 5);
+        assert_eq!(c.defense, 0);
         assert!(!c.is_defeated());
     }
 
+    #[test]
+    fn test_combatant_builder() {
+        let c = Combatant::builder().attack(4).health(12).defense(2).build().unwrap();
+        assert_eq!(c, Combatant::new_with_defense(12, 4, 2));
+        
+        // Unset stats default to 0, max health follows health
+        let c = Combatant::builder().health(7).build().unwrap();
+        assert_eq!(c.attack, 0);
+        assert_eq!(c.defense, 0);
+        assert_eq!(c.max_health, 7);
+        assert_eq!(c.lifesteal, 0);
+        assert_eq!(c.speed, 0);
+        assert!(c.status_effects.is_empty());
+        
+        let c = Combatant::builder().health(5).max_health(10).speed(3).build().unwrap();
+        assert_eq!((c.health, c.max_health, c.speed), (5, 10, 3));
+        
+        assert!(Combatant::builder().health(-1).build().is_err());
+        assert!(Combatant::builder().health(5).max_health(-1).build().is_err());
+    }
+
     #[test]
     fn test_combatant_defeated() {
         let mut c = Combatant::new(10, 5);
@@ -241,4 +865,417 @@ mod tests {
         assert_eq!(state.combatant1.health, 14);
         assert_eq!(state.combatant2.health, 6);
     }
+
+    #[test]
+    fn test_defense_reduces_damage() {
+        let c1 = Combatant::new_with_defense(10, 5, 2);
+        let c2 = Combatant::new_with_defense(10, 5, 10);
+        let mut state = CombatState::new(c1, c2);
+
+        state.execute_round();
+
+        // c1 takes 5 - 2 = 3, c2's defense exceeds the hit so it takes the minimum of 1
+        assert_eq!(state.combatant1.health, 7);
+        assert_eq!(state.combatant2.health, 9);
+    }
+
+    #[test]
+    fn test_defense_changes_winner() {
+        // Without defense, c2 wins: c1 dies in round 2, c2 would need 3 rounds
+        let (_, result) = CombatState::new(Combatant::new(8, 4), Combatant::new(10, 5)).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+
+        // With 3 defense, c1 only takes 2 per round and outlasts c2
+        let (final_state, result) = CombatState::new(
+            Combatant::new_with_defense(8, 4, 3),
+            Combatant::new(10, 5),
+        )
+        .simulate_combat();
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(final_state.round, 3);
+        assert_eq!(final_state.combatant1.health, 2);
+    }
+
+    #[test]
+    fn test_zero_attack_stalemate() {
+        let state = CombatState::new(Combatant::new(10, 0), Combatant::new(10, 0));
+
+        let (final_state, result) = state.clone().simulate_combat();
+        assert_eq!(result, CombatResult::Stalemate);
+        assert_eq!(final_state.round, DEFAULT_MAX_ROUNDS);
+
+        let (final_state, result) = state.simulate_combat_bounded(25);
+        assert_eq!(result, CombatResult::Stalemate);
+        assert_eq!(final_state.round, 25);
+        assert_eq!(final_state.combatant1.health, 10);
+    }
+
+    #[test]
+    fn test_bounded_combat_finishes_normally() {
+        let state = CombatState::new(Combatant::new(10, 3), Combatant::new(8, 2));
+        let (final_state, result) = state.simulate_combat_bounded(3);
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(final_state.round, 3);
+    }
+
+    #[test]
+    fn test_simulate_with_log() {
+        let state = CombatState::new(Combatant::new(10, 3), Combatant::new(8, 2));
+        let (final_state, result, log) = state.simulate_with_log();
+
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(log.len(), final_state.round as usize);
+        assert_eq!(
+            log,
+            vec![
+                RoundRecord { round: 1, c1_before: 10, c2_before: 8, c1_after: 8, c2_after: 5, result: CombatResult::Ongoing },
+                RoundRecord { round: 2, c1_before: 8, c2_before: 5, c1_after: 6, c2_after: 2, result: CombatResult::Ongoing },
+                RoundRecord { round: 3, c1_before: 6, c2_before: 2, c1_after: 4, c2_after: -1, result: CombatResult::Combatant1Wins },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulate_with_log_stalemate() {
+        let state = CombatState::new(Combatant::new(5, 0), Combatant::new(5, 0));
+        let (_, result, log) = state.simulate_with_log_bounded(4);
+        assert_eq!(result, CombatResult::Stalemate);
+        assert_eq!(log.len(), 4);
+        assert!(log.iter().all(|record| record.result == CombatResult::Ongoing));
+    }
+
+    #[test]
+    fn test_lifesteal_flips_matchup() {
+        // Without lifesteal, c2 wins in round 2
+        let (_, result) = CombatState::new(Combatant::new(10, 4), Combatant::new(12, 5)).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+
+        // With 100% lifesteal, c1 heals 4 per round and wins in round 3
+        let c1 = Combatant::new(10, 4).with_lifesteal(100);
+        let (final_state, result) = CombatState::new(c1, Combatant::new(12, 5)).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(final_state.round, 3);
+        assert_eq!(final_state.combatant1.health, 7);
+    }
+
+    #[test]
+    fn test_lifesteal_capped_at_max_health() {
+        let c1 = Combatant::new(10, 6).with_lifesteal(100);
+        let mut state = CombatState::new(c1, Combatant::new(20, 2));
+
+        state.execute_round();
+        // 10 - 2 = 8, heal 6 but capped at 10
+        assert_eq!(state.combatant1.health, 10);
+    }
+
+    #[test]
+    fn test_lifesteal_does_not_revive() {
+        let c1 = Combatant::new(4, 4).with_lifesteal(100);
+        let mut state = CombatState::new(c1, Combatant::new(10, 5));
+
+        let result = state.execute_round();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+        assert_eq!(state.combatant1.health, -1);
+    }
+
+    #[test]
+    fn test_min_damage_gets_through_defense() {
+        let harmless = Combatant::new(10, 0);
+        let wall = Combatant::new_with_defense(10, 0, 50);
+
+        // Zero attack deals nothing without a floor...
+        let mut state = CombatState::new(harmless.clone(), wall.clone());
+        state.execute_round();
+        assert_eq!(state.combatant2.health, 10);
+
+        // ...and exactly the floor with one
+        let mut state = CombatState::new(harmless, wall).with_damage_bounds(1, None);
+        state.execute_round();
+        assert_eq!(state.combatant1.health, 9);
+        assert_eq!(state.combatant2.health, 9);
+    }
+
+    #[test]
+    fn test_max_damage_caps_glass_cannon() {
+        let mut state = CombatState::new(Combatant::GLASS_CANNON, Combatant::BALANCED)
+            .with_damage_bounds(0, Some(3));
+        state.execute_round();
+        // Glass cannon's 8 - 1 defense = 7 is capped at 3; balanced's 5 is capped too
+        assert_eq!(state.combatant2.health, Combatant::BALANCED.health - 3);
+        assert_eq!(state.combatant1.health, Combatant::GLASS_CANNON.health - 3);
+
+        // A negative floor never turns a hit into healing
+        let mut state = CombatState::new(Combatant::new(10, -5), Combatant::new(10, 1))
+            .with_damage_bounds(-5, None);
+        state.execute_round();
+        assert_eq!(state.combatant2.health, 10);
+    }
+
+    #[test]
+    fn test_initiative_first_strike_wins() {
+        let fast = Combatant::new(5, 5).with_speed(3);
+        let slow = Combatant::new(5, 5).with_speed(1);
+
+        // Simultaneous (default) ignores speed and draws
+        let (_, result) = CombatState::new(fast.clone(), slow.clone()).simulate_combat();
+        assert_eq!(result, CombatResult::Draw);
+
+        // With initiative the faster combatant kills first and takes no retaliation
+        let (final_state, result) = CombatState::new(fast.clone(), slow.clone())
+            .with_mode(ResolutionMode::Initiative)
+            .simulate_combat();
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(final_state.combatant1.health, 5);
+
+        let (final_state, result) = CombatState::new(slow, fast)
+            .with_mode(ResolutionMode::Initiative)
+            .simulate_combat();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+        assert_eq!(final_state.combatant2.health, 5);
+    }
+
+    #[test]
+    fn test_initiative_survivor_retaliates() {
+        let fast = Combatant::new(10, 3).with_speed(2);
+        let slow = Combatant::new(10, 4);
+        let mut state = CombatState::new(fast, slow).with_mode(ResolutionMode::Initiative);
+
+        state.execute_round();
+        assert_eq!(state.combatant1.health, 6);
+        assert_eq!(state.combatant2.health, 7);
+    }
+
+    #[test]
+    fn test_initiative_speed_tie_is_simultaneous() {
+        let c = Combatant::new(5, 5).with_speed(2);
+        let (_, result) = CombatState::new(c.clone(), c)
+            .with_mode(ResolutionMode::Initiative)
+            .simulate_combat();
+        assert_eq!(result, CombatResult::Draw);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip_mid_fight() {
+        let c1 = Combatant::new_with_defense(20, 3, 1).with_lifesteal(50).with_speed(2);
+        let mut state = CombatState::new(c1, Combatant::BRUISER).with_mode(ResolutionMode::Initiative);
+        state.execute_round();
+        state.execute_round();
+
+        let json = state.to_json();
+        let restored = CombatState::from_json(&json).unwrap();
+        assert_eq!(restored, state);
+        assert_eq!(restored.round, 2);
+
+        assert!(CombatState::from_json("{ not json").is_err());
+    }
+
+    #[test]
+    fn test_replay_reproduces_stored_fight() {
+        let initial = CombatState::new(Combatant::BRUISER, Combatant::TANK.with_lifesteal(50))
+            .with_mode(ResolutionMode::Initiative);
+        let log = FightLog::record(initial.clone());
+        let (final_state, result) = initial.simulate_combat();
+
+        let mut replay = log.replay();
+        let steps: Vec<ReplayStep> = replay.by_ref().collect();
+        assert_eq!(steps.len(), log.rounds.len());
+        assert!(steps.iter().all(ReplayStep::matches_log));
+        assert_eq!(steps.last().unwrap().state, final_state);
+        assert_eq!(replay.result(), result);
+        assert_eq!(replay.result(), log.result);
+    }
+
+    #[test]
+    fn test_replay_flags_tampered_rounds() {
+        let mut log = FightLog::record(CombatState::new(Combatant::new(10, 5), Combatant::new(8, 3)));
+        log.rounds[0].c2_after += 1;
+
+        let steps: Vec<ReplayStep> = log.replay().collect();
+        assert!(!steps[0].matches_log());
+        assert!(steps[1..].iter().all(ReplayStep::matches_log));
+        // The recomputed round ignores the stored value
+        assert_eq!(steps[0].record.c2_after, 3);
+    }
+
+    #[test]
+    fn test_replay_stops_at_round_cap() {
+        let log = FightLog::record_bounded(CombatState::new(Combatant::new(10, 0), Combatant::new(10, 0)), 5);
+        assert_eq!(log.result, CombatResult::Stalemate);
+
+        let mut replay = log.replay();
+        assert_eq!(replay.by_ref().count(), 5);
+        assert_eq!(replay.result(), CombatResult::Stalemate);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fight_log_json_round_trip() {
+        let log = FightLog::record(CombatState::new(Combatant::BALANCED, Combatant::ASSASSIN).with_damage_bounds(1, Some(4)));
+        assert_eq!(FightLog::from_json(&log.to_json()).unwrap(), log);
+        assert!(FightLog::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn test_round_robin_predefined() {
+        let roster = [
+            Combatant::TANK,
+            Combatant::GLASS_CANNON,
+            Combatant::BALANCED,
+            Combatant::BRUISER,
+            Combatant::ASSASSIN,
+            Combatant::DEFENDER,
+        ];
+        let results = round_robin(&roster);
+        assert_eq!(results.len(), 15); // C(6, 2)
+        assert!(results.iter().all(|&(i, j, _)| i < j && j < roster.len()));
+        assert_eq!(results[0].0, 0);
+        assert_eq!(results[0].1, 1);
+
+        // Each pair matches a standalone fight with i as combatant1
+        for &(i, j, result) in &results {
+            assert_ne!(result, CombatResult::Ongoing);
+            let (_, expected) = CombatState::new(roster[i].clone(), roster[j].clone()).simulate_combat();
+            assert_eq!(result, expected);
+        }
+
+        let counts = win_counts(roster.len(), &results);
+        for c in &counts {
+            assert_eq!(c.wins + c.losses + c.draws, 5);
+        }
+        let total_wins: u32 = counts.iter().map(|c| c.wins).sum();
+        let total_losses: u32 = counts.iter().map(|c| c.losses).sum();
+        let total_draws: u32 = counts.iter().map(|c| c.draws).sum();
+        assert_eq!(total_wins, total_losses);
+        assert_eq!(total_wins + total_draws / 2, 15);
+    }
+
+    #[test]
+    fn test_outcome_matrix_csv() {
+        let roster: Vec<(String, Combatant)> = [
+            ("Tank", Combatant::TANK),
+            ("Glass Cannon", Combatant::GLASS_CANNON),
+            ("Balanced", Combatant::BALANCED),
+            ("Bruiser", Combatant::BRUISER),
+            ("Assassin", Combatant::ASSASSIN),
+            ("Defender", Combatant::DEFENDER),
+        ]
+        .into_iter()
+        .map(|(name, c)| (name.to_string(), c))
+        .collect();
+        let csv = outcome_matrix_csv(&roster);
+
+        let rows: Vec<Vec<&str>> = csv.lines().map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), roster.len() + 1);
+        assert_eq!(rows[0][0], "");
+        for (i, (name, _)) in roster.iter().enumerate() {
+            assert_eq!(rows[0][i + 1], name);
+            assert_eq!(rows[i + 1][0], name);
+            assert_eq!(rows[i + 1].len(), roster.len() + 1);
+            // Identical stats trade identical blows, so self vs self is always a draw
+            assert_eq!(rows[i + 1][i + 1], MATRIX_DRAW);
+        }
+
+        // Off-diagonal cells agree with round_robin (which fights i < j with i as combatant1)
+        let fighters: Vec<Combatant> = roster.iter().map(|(_, c)| c.clone()).collect();
+        for (i, j, result) in round_robin(&fighters) {
+            let expected = match result {
+                CombatResult::Combatant1Wins => MATRIX_WIN,
+                CombatResult::Combatant2Wins => MATRIX_LOSS,
+                CombatResult::Draw => MATRIX_DRAW,
+                _ => MATRIX_STALEMATE,
+            };
+            assert_eq!(rows[i + 1][j + 1], expected);
+        }
+
+        let quoted = outcome_matrix_csv(&[("Tank, \"Big\"".to_string(), Combatant::TANK)]);
+        assert_eq!(quoted, ",\"Tank, \"\"Big\"\"\"\n\"Tank, \"\"Big\"\"\",D\n");
+    }
+
+    #[test]
+    fn test_named_result_messages() {
+        let tank = Combatant::TANK.with_name("Tank");
+        let cannon = Combatant::builder().health(5).attack(8).name("Glass Cannon").build().unwrap();
+        let (state, result) = CombatState::new(tank.clone(), cannon.clone()).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant1Wins);
+        assert_eq!(result.describe(&state.combatant1, &state.combatant2), "Tank defeats Glass Cannon");
+
+        // The winner is named first whichever side it fought on
+        let (state, result) = CombatState::new(cannon, tank).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+        assert_eq!(result.describe(&state.combatant1, &state.combatant2), "Tank defeats Glass Cannon");
+
+        // Unnamed sides keep the old wording, and fill in when only one side is named
+        let plain = Combatant::new(10, 5);
+        assert_eq!(CombatResult::Combatant1Wins.describe(&plain, &plain), "Combatant 1 Wins");
+        assert_eq!(CombatResult::Draw.describe(&plain, &plain), "Draw");
+        let wolf = Combatant::new(10, 5).with_name("Wolf");
+        assert_eq!(CombatResult::Combatant2Wins.describe(&plain, &wolf), "Wolf defeats Combatant 1");
+        assert_eq!(CombatResult::Stalemate.describe(&wolf, &plain), "Stalemate between Wolf and Combatant 2");
+    }
+
+    #[test]
+    fn test_poison_kills_combatant_that_would_have_won() {
+        let strong = Combatant::new(10, 5);
+        let weak = Combatant::new(10, 3);
+        let (_, result) = CombatState::new(strong.clone(), weak.clone()).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant1Wins);
+
+        // Round 1: poison 10 -> 6, then the hit takes it to 3; round 2: poison defeats it before it attacks
+        let poisoned = strong.with_status_effect(StatusEffect::Poison { per_round: 4, rounds: 3 });
+        let (state, result) = CombatState::new(poisoned, weak).simulate_combat();
+        assert_eq!(result, CombatResult::Combatant2Wins);
+        assert_eq!(state.round, 2);
+        assert_eq!(state.combatant1.health, -1);
+        assert_eq!(state.combatant2.health, 5);
+    }
+
+    #[test]
+    fn test_status_effects_tick_and_expire() {
+        let regen = StatusEffect::Regen { per_round: 3, rounds: 2 };
+        let poison = StatusEffect::Poison { per_round: 1, rounds: 1 };
+        let c1 = Combatant::new(10, 0).with_status_effect(regen).with_status_effect(poison);
+        let c2 = Combatant::new(10, 4);
+        let mut state = CombatState::new(c1, c2);
+
+        // Regen is capped at max health, then poison ticks
+        state.execute_round();
+        assert_eq!(state.combatant1.health, 10 - 1 - 4);
+        assert_eq!(state.combatant1.status_effects, vec![StatusEffect::Regen { per_round: 3, rounds: 1 }]);
+
+        state.execute_round();
+        assert_eq!(state.combatant1.health, 5 + 3 - 4);
+        assert!(state.combatant1.status_effects.is_empty());
+
+        state.execute_round();
+        assert_eq!(state.combatant1.health, 0);
+    }
+
+    #[test]
+    fn test_poison_on_both_sides_can_draw() {
+        let poison = StatusEffect::Poison { per_round: 5, rounds: 1 };
+        let c1 = Combatant::new(5, 1).with_status_effect(poison);
+        let c2 = Combatant::new(5, 1).with_status_effect(poison);
+        let mut state = CombatState::new(c1, c2);
+        assert_eq!(state.execute_round(), CombatResult::Draw);
+    }
+
+    #[test]
+    fn test_generic_simulate_matches_simulate_combat() {
+        let state = CombatState::new(Combatant::new(10, 5), Combatant::new(8, 3));
+        let (generic, result) = simulate(state.clone());
+        let (concrete, expected) = state.simulate_combat();
+        assert_eq!(result, expected);
+        assert_eq!(generic, concrete);
+        assert_eq!(result.winner(), Some(Side::First));
+        assert!(!result.is_ongoing());
+
+        // Zero damage never resolves; the generic cap leaves it ongoing
+        let state = CombatState::new(Combatant::new(10, 0), Combatant::new(10, 0));
+        let (capped, result) = simulate_bounded(state, 25);
+        assert_eq!(result, CombatResult::Ongoing);
+        assert_eq!(capped.round, 25);
+        assert_eq!(CombatResult::Draw.winner(), None);
+    }
+
 }
@@ -1,6 +1,16 @@
-use combat::{Combatant, CombatState, CombatResult};
+use combat::{outcome_matrix_csv, Combatant, CombatState, CombatResult, FightLog, DEFAULT_MAX_ROUNDS};
+use serde_json::{json, Value};
 use std::io::{self, Write};
 
+// One-shot exit codes, so scripts can tell the outcome without parsing output
+const EXIT_DRAW: i32 = 0;
+const EXIT_SIDE1_WINS: i32 = 1;
+const EXIT_SIDE2_WINS: i32 = 2;
+/// Stalemate, or a single round that left both combatants standing
+const EXIT_NO_WINNER: i32 = 3;
+/// Bad arguments or unknown command
+const EXIT_USAGE: i32 = 64;
+
 struct CombatSession {
     side1: Option<Combatant>,
     side2: Option<Combatant>,
@@ -26,27 +36,35 @@ impl CombatSession {
 
     fn show(&self) {
         println!("Current Combat State:");
-        match self.side1 {
+        match &self.side1 {
             Some(c) => println!("  Side 1: HP={}, ATK={}", c.health, c.attack),
             None => println!("  Side 1: Not set"),
         }
-        match self.side2 {
+        match &self.side2 {
             Some(c) => println!("  Side 2: HP={}, ATK={}", c.health, c.attack),
             None => println!("  Side 2: Not set"),
         }
     }
 
     fn fight(&self) -> Result<(), String> {
-        let combatant1 = self.side1.ok_or_else(|| "Side 1 not set. Use 'set-side1 <health> <attack>' first.".to_string())?;
-        let combatant2 = self.side2.ok_or_else(|| "Side 2 not set. Use 'set-side2 <health> <attack>' first.".to_string())?;
+        let combatant1 = self.side1.clone().ok_or_else(|| "Side 1 not set. Use 'set-side1 <health> <attack>' first.".to_string())?;
+        let combatant2 = self.side2.clone().ok_or_else(|| "Side 2 not set. Use 'set-side2 <health> <attack>' first.".to_string())?;
         
-        run_full_combat(combatant1, combatant2);
+        run_full_combat(combatant1, combatant2, false);
         Ok(())
     }
 }
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+    // --json prints the outcome as JSON on stdout and moves the human report to stderr
+    let json = args.iter().any(|a| a == "--json");
+    args.retain(|a| a != "--json");
+    // --save <file> writes the fight as a log that `replay` can step through
+    let save_path = take_option(&mut args, "--save");
+    // --name1/--name2 <name> label the combatants in result messages
+    let name1 = take_option(&mut args, "--name1").unwrap_or_default();
+    let name2 = take_option(&mut args, "--name2").unwrap_or_default();
     
     // If arguments provided, run in one-shot mode (backward compatibility)
     if args.len() > 1 {
@@ -58,53 +76,82 @@ fn main() {
                 if args.len() < 6 {
                     eprintln!("Error: combat requires 4 arguments: <health1> <attack1> <health2> <attack2>");
                     eprintln!("Example: combat 10 5 8 3");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let health1: i32 = args[2].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid health1: {}", args[2]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let attack1: i32 = args[3].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid attack1: {}", args[3]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let health2: i32 = args[4].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid health2: {}", args[4]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let attack2: i32 = args[5].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid attack2: {}", args[5]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 
-                run_full_combat(Combatant::new(health1, attack1), Combatant::new(health2, attack2));
+                let combatant1 = Combatant::new(health1, attack1).with_name(name1);
+                let combatant2 = Combatant::new(health2, attack2).with_name(name2);
+                let log = run_full_combat(combatant1, combatant2, json);
+                if let Some(path) = &save_path {
+                    if let Err(e) = save_fight_log(&log, path) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(EXIT_USAGE);
+                    }
+                }
+                std::process::exit(exit_code(log.result));
             }
             "combat-round" | "cr" => {
                 if args.len() < 6 {
                     eprintln!("Error: combat-round requires 4 arguments: <health1> <attack1> <health2> <attack2>");
                     eprintln!("Example: combat-round 10 5 8 3");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let health1: i32 = args[2].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid health1: {}", args[2]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let attack1: i32 = args[3].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid attack1: {}", args[3]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let health2: i32 = args[4].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid health2: {}", args[4]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 let attack2: i32 = args[5].parse().unwrap_or_else(|_| {
                     eprintln!("Error: Invalid attack2: {}", args[5]);
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 });
                 
-                run_single_round(health1, attack1, health2, attack2);
+                let combatant1 = Combatant::new(health1, attack1).with_name(name1);
+                let combatant2 = Combatant::new(health2, attack2).with_name(name2);
+                let result = run_single_round(combatant1, combatant2, json);
+                std::process::exit(exit_code(result));
+            }
+            "replay" => {
+                if args.len() < 3 {
+                    eprintln!("Error: replay requires a file: replay <file>");
+                    eprintln!("Example: replay fight.json");
+                    std::process::exit(EXIT_USAGE);
+                }
+                match run_replay(&args[2]) {
+                    Ok(result) => std::process::exit(exit_code(result)),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(EXIT_USAGE);
+                    }
+                }
+            }
+            "matrix" => {
+                print!("{}", outcome_matrix_csv(&archetypes()));
             }
             "help" | "-h" | "--help" => {
                 print_usage();
@@ -112,7 +159,7 @@ fn main() {
             _ => {
                 eprintln!("Error: Unknown command: {}", args[1]);
                 print_usage();
-                std::process::exit(1);
+                std::process::exit(EXIT_USAGE);
             }
         }
     } else {
@@ -232,6 +279,24 @@ fn print_usage() {
     println!("    Execute one round of combat");
     println!("    Example: combat-round 10 5 8 3");
     println!("    Alias: cr");
+    println!();
+    println!("  replay <file>");
+    println!("    Step through a fight saved with --save, pressing Enter for each round");
+    println!("    Rounds are recomputed and checked against the stored log");
+    println!("    Example: replay fight.json");
+    println!();
+    println!("  matrix");
+    println!("    Print every archetype vs every archetype as CSV (W/L/D/S from the row's view)");
+    println!("    Example: matrix > outcomes.csv");
+    println!();
+    println!("Options:");
+    println!("  --json         Print the result and final stats as JSON on stdout (human output goes to stderr)");
+    println!("  --save <file>  With 'combat', also write the fight to <file> for 'replay'");
+    println!("  --name1 <name> / --name2 <name>");
+    println!("                 Name the combatants in the report, e.g. \"Tank defeats Wolf\"");
+    println!();
+    println!("Exit Codes (one-shot commands):");
+    println!("  0 draw, 1 combatant 1 wins, 2 combatant 2 wins, 3 no winner (stalemate or ongoing), 64 usage error");
 }
 
 fn print_interactive_help() {
@@ -250,70 +315,177 @@ fn print_interactive_help() {
     println!("  combat> fight");
 }
 
-fn run_full_combat(combatant1: Combatant, combatant2: Combatant) {
-    let state = CombatState::new(combatant1, combatant2);
-    
-    let mut history = Vec::new();
-    let mut current_state = state.clone();
-    
-    loop {
-        let round_before = current_state.round;
-        let health1_before = current_state.combatant1.health;
-        let health2_before = current_state.combatant2.health;
-        
-        let result = current_state.execute_round();
-        
-        history.push((round_before + 1, health1_before, health2_before, 
-                     current_state.combatant1.health, current_state.combatant2.health, result));
-        
-        match result {
-            CombatResult::Ongoing => continue,
-            _ => {
-                println!("Combat Result: {}", format_result(result));
-                println!("Total Rounds: {}", current_state.round);
-                println!("Combatant 1: HP={}, ATK={}", 
-                    current_state.combatant1.health, 
-                    current_state.combatant1.attack);
-                println!("Combatant 2: HP={}, ATK={}", 
-                    current_state.combatant2.health, 
-                    current_state.combatant2.attack);
-                
-                if !history.is_empty() {
-                    println!("\nRound History:");
-                    for (round, c1_before, c2_before, c1_after, c2_after, _) in history {
-                        println!("  Round {}: C1 {} -> {}, C2 {} -> {}",
-                            round, c1_before, c1_after, c2_before, c2_after);
-                    }
-                }
-                break;
-            }
+/// The predefined combatants, named as in the GUI
+fn archetypes() -> Vec<(String, Combatant)> {
+    [
+        ("Tank", Combatant::TANK),
+        ("Glass Cannon", Combatant::GLASS_CANNON),
+        ("Balanced", Combatant::BALANCED),
+        ("Bruiser", Combatant::BRUISER),
+        ("Assassin", Combatant::ASSASSIN),
+        ("Defender", Combatant::DEFENDER),
+    ]
+    .into_iter()
+    .map(|(name, combatant)| (name.to_string(), combatant))
+    .collect()
+}
+
+/// Run and report a fight, returning it as a log so `--save` doesn't have to fight it again
+fn run_full_combat(combatant1: Combatant, combatant2: Combatant, json: bool) -> FightLog {
+    let initial = CombatState::new(combatant1, combatant2);
+    let (final_state, result, history) = initial.clone().simulate_with_log();
+
+    let (c1, c2) = (&final_state.combatant1, &final_state.combatant2);
+    let mut report = String::new();
+    report += &format!("Combat Result: {}\n", result.describe(c1, c2));
+    report += &format!("Total Rounds: {}\n", final_state.round);
+    report += &format!("{}: HP={}, ATK={}\n", c1.display_name("Combatant 1"), c1.health, c1.attack);
+    report += &format!("{}: HP={}, ATK={}\n", c2.display_name("Combatant 2"), c2.health, c2.attack);
+
+    if !history.is_empty() {
+        report += "\nRound History:\n";
+        for record in &history {
+            report += &format!("  Round {}: {} {} -> {}, {} {} -> {}\n",
+                record.round,
+                c1.display_name("C1"), record.c1_before, record.c1_after,
+                c2.display_name("C2"), record.c2_before, record.c2_after);
         }
     }
+
+    emit(&report, json, || result_json(&final_state, result));
+    FightLog { initial, max_rounds: DEFAULT_MAX_ROUNDS, rounds: history, result }
 }
 
-fn run_single_round(health1: i32, attack1: i32, health2: i32, attack2: i32) {
-    let combatant1 = Combatant::new(health1, attack1);
-    let combatant2 = Combatant::new(health2, attack2);
+fn run_single_round(combatant1: Combatant, combatant2: Combatant, json: bool) -> CombatResult {
     let mut state = CombatState::new(combatant1, combatant2);
-    
-    let health1_before = state.combatant1.health;
-    let health2_before = state.combatant2.health;
-    
-    let result = state.execute_round();
-    
-    println!("Round: {}", state.round);
-    println!("Combatant 1: {} -> {} (ATK={})", 
-        health1_before, state.combatant1.health, state.combatant1.attack);
-    println!("Combatant 2: {} -> {} (ATK={})", 
-        health2_before, state.combatant2.health, state.combatant2.attack);
+
+    let record = state.execute_round_logged();
+
+    let (c1, c2) = (&state.combatant1, &state.combatant2);
+    let mut report = String::new();
+    report += &format!("Round: {}\n", record.round);
+    report += &format!("{}: {} -> {} (ATK={})\n",
+        c1.display_name("Combatant 1"), record.c1_before, record.c1_after, c1.attack);
+    report += &format!("{}: {} -> {} (ATK={})\n",
+        c2.display_name("Combatant 2"), record.c2_before, record.c2_after, c2.attack);
+    report += &format!("Result: {}\n", record.result.describe(c1, c2));
+
+    emit(&report, json, || result_json(&state, record.result));
+    record.result
+}
+
+/// Remove `flag` and the value after it from `args`, returning the value
+fn take_option(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let index = args.iter().position(|a| a == flag)?;
+    args.remove(index);
+    if index < args.len() {
+        Some(args.remove(index))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+fn save_fight_log(log: &FightLog, path: &str) -> Result<(), String> {
+    std::fs::write(path, log.to_json()).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+#[cfg(not(feature = "serde"))]
+fn save_fight_log(_log: &FightLog, _path: &str) -> Result<(), String> {
+    Err("--save needs the serde feature (build without --no-default-features)".to_string())
+}
+
+/// Step through a saved fight, recomputing each round and warning where it disagrees with the log
+#[cfg(feature = "serde")]
+fn run_replay(path: &str) -> Result<CombatResult, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let log = FightLog::from_json(&json)?;
+
+    let (c1, c2) = (&log.initial.combatant1, &log.initial.combatant2);
+    let format_result = |result: CombatResult| result.describe(c1, c2);
+    println!("Replaying {}: {} stored rounds, stored result: {}", path, log.rounds.len(), format_result(log.result));
+    println!("{}: HP={}, ATK={}", c1.display_name("Combatant 1"), c1.health, c1.attack);
+    println!("{}: HP={}, ATK={}", c2.display_name("Combatant 2"), c2.health, c2.attack);
+    println!("Press Enter for each round, or 'q' to play the rest without stopping");
+
+    // Stop waiting once the user quits or stdin runs out
+    let mut stepping = true;
+    let mut replayed = 0;
+    let mut replay = log.replay();
+    for step in replay.by_ref() {
+        if stepping {
+            let mut input = String::new();
+            stepping = matches!(io::stdin().read_line(&mut input), Ok(n) if n > 0) && input.trim() != "q";
+        }
+
+        let record = step.record;
+        println!("Round {}: {} {} -> {} ({:+}), {} {} -> {} ({:+})",
+            record.round,
+            c1.display_name("C1"), record.c1_before, record.c1_after, record.c1_after - record.c1_before,
+            c2.display_name("C2"), record.c2_before, record.c2_after, record.c2_after - record.c2_before);
+        if !step.matches_log() {
+            let stored = match step.stored {
+                Some(s) => format!("stored C1 {} -> {}, C2 {} -> {}", s.c1_before, s.c1_after, s.c2_before, s.c2_after),
+                None => "missing from the stored log".to_string(),
+            };
+            eprintln!("Warning: round {} disagrees with the stored log ({})", record.round, stored);
+        }
+        replayed += 1;
+    }
+
+    let result = replay.result();
+    if replayed < log.rounds.len() {
+        eprintln!("Warning: stored log has {} rounds but the fight ended after {}", log.rounds.len(), replayed);
+    }
+    if result != log.result {
+        eprintln!("Warning: replayed result ({}) disagrees with the stored result ({})",
+            format_result(result), format_result(log.result));
+    }
     println!("Result: {}", format_result(result));
+    Ok(result)
+}
+
+#[cfg(not(feature = "serde"))]
+fn run_replay(_path: &str) -> Result<CombatResult, String> {
+    Err("replay needs the serde feature (build without --no-default-features)".to_string())
 }
 
-fn format_result(result: CombatResult) -> &'static str {
+/// Print the human report to stdout, or in json mode print it to stderr and the JSON to stdout
+fn emit(report: &str, json: bool, to_json: impl FnOnce() -> Value) {
+    if json {
+        eprint!("{}", report);
+        println!("{}", to_json());
+    } else {
+        print!("{}", report);
+    }
+}
+
+/// Final result and stats, shaped like the crafting CLI's `{"status", "data"}` responses
+fn result_json(state: &CombatState, result: CombatResult) -> Value {
+    let stats = |c: &Combatant| json!({
+        "name": c.name,
+        "health": c.health,
+        "max_health": c.max_health,
+        "attack": c.attack,
+        "defense": c.defense,
+    });
+    json!({
+        "status": "success",
+        "data": {
+            "result": format!("{:?}", result),
+            "rounds": state.round,
+            "combatant1": stats(&state.combatant1),
+            "combatant2": stats(&state.combatant2),
+        }
+    })
+}
+
+fn exit_code(result: CombatResult) -> i32 {
     match result {
-        CombatResult::Combatant1Wins => "Combatant 1 Wins",
-        CombatResult::Combatant2Wins => "Combatant 2 Wins",
-        CombatResult::Draw => "Draw",
-        CombatResult::Ongoing => "Ongoing",
+        CombatResult::Draw => EXIT_DRAW,
+        CombatResult::Combatant1Wins => EXIT_SIDE1_WINS,
+        CombatResult::Combatant2Wins => EXIT_SIDE2_WINS,
+        CombatResult::Stalemate | CombatResult::Ongoing => EXIT_NO_WINNER,
     }
 }
+
@@ -0,0 +1,106 @@
+use serde_json::Value;
+use std::process::{Command, Output};
+
+/// Run the combat binary with `args` and return its output
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_combat"))
+        .args(args)
+        .output()
+        .expect("Failed to run combat binary")
+}
+
+fn parse_stdout(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value")
+}
+
+#[test]
+fn test_exit_code_reports_winner() {
+    assert_eq!(run(&["combat", "10", "5", "8", "3"]).status.code(), Some(1));
+    assert_eq!(run(&["combat", "8", "3", "10", "5"]).status.code(), Some(2));
+    assert_eq!(run(&["combat", "5", "5", "5", "5"]).status.code(), Some(0));
+    assert_eq!(run(&["combat", "10", "0", "10", "0"]).status.code(), Some(3));
+}
+
+#[test]
+fn test_usage_error_exit_code() {
+    assert_eq!(run(&["combat", "10", "5"]).status.code(), Some(64));
+    assert_eq!(run(&["not-a-command"]).status.code(), Some(64));
+}
+
+#[test]
+fn test_json_output() {
+    let output = run(&["combat", "10", "5", "8", "3", "--json"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let response = parse_stdout(&output);
+    assert_eq!(response["status"], "success");
+    let data = &response["data"];
+    assert_eq!(data["result"], "Combatant1Wins");
+    assert_eq!(data["rounds"], 2);
+    assert_eq!(data["combatant1"]["health"], 4);
+    assert_eq!(data["combatant2"]["health"], -2);
+
+    // Human report moves to stderr
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Combat Result: Combatant 1 Wins"));
+}
+
+#[test]
+fn test_json_single_round() {
+    let output = run(&["--json", "combat-round", "10", "5", "8", "3"]);
+    assert_eq!(output.status.code(), Some(3));
+
+    let data = parse_stdout(&output)["data"].clone();
+    assert_eq!(data["result"], "Ongoing");
+    assert_eq!(data["rounds"], 1);
+    assert_eq!(data["combatant1"]["health"], 7);
+    assert_eq!(data["combatant2"]["health"], 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_replay_saved_fight() {
+    let path = std::env::temp_dir().join(format!("combat_replay_{}.json", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = run(&["combat", "10", "5", "8", "3", "--save", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+
+    // Stdin is closed, so the replay runs straight through
+    let output = run(&["replay", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Round 1: C1 10 -> 7 (-3), C2 8 -> 3 (-5)"));
+    assert!(stdout.contains("Result: Combatant 1 Wins"));
+    assert!(output.stderr.is_empty());
+
+    // Edit a stored round: the replay still recomputes the real fight but warns
+    let tampered = std::fs::read_to_string(&path).unwrap().replacen("\"c1_after\": 7", "\"c1_after\": 6", 1);
+    std::fs::write(&path, tampered).unwrap();
+    let output = run(&["replay", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Warning: round 1 disagrees with the stored log"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_matrix_prints_csv() {
+    let output = run(&["matrix"]);
+    assert!(output.status.success());
+    let csv = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert!(lines[0].starts_with(",Tank,Glass Cannon,"));
+    assert!(lines[1].starts_with("Tank,D,"));
+}
+
+#[test]
+fn test_named_combatants() {
+    let output = run(&["combat", "10", "5", "8", "3", "--name1", "Tank", "--name2", "Wolf"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Combat Result: Tank defeats Wolf"));
+    assert!(stdout.contains("Round 1: Tank 10 -> 7, Wolf 8 -> 3"));
+}
@@ -0,0 +1,124 @@
+//! Traits shared by the 1v1 (`combat`) and multi-combatant (`multi_combat`) engines,
+//! so front-ends (simulation, logging, tournaments) can be written once for either
+
+use std::fmt::Debug;
+
+/// Round cap used by `simulate` (and re-exported by both engines for their own
+/// simulations) so zero-damage matchups can't loop forever
+pub const DEFAULT_MAX_ROUNDS: u32 = 10_000;
+
+/// Which side of a fight won
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Combatant 1 / side 1
+    First,
+    /// Combatant 2 / side 2
+    Second,
+}
+
+/// A round or fight outcome as reported by a `CombatEngine`
+pub trait CombatOutcome: Debug + Clone + Copy + PartialEq {
+    /// True while neither side has won, lost, or drawn
+    fn is_ongoing(&self) -> bool;
+
+    /// The winning side, or None while ongoing and for draws/stalemates
+    fn winner(&self) -> Option<Side>;
+}
+
+/// A fight that advances one round at a time
+pub trait CombatEngine {
+    type Result: CombatOutcome;
+
+    /// Execute one round and return the result after it
+    fn execute_round(&mut self) -> Self::Result;
+
+    /// The current result without executing a round
+    fn get_result(&self) -> Self::Result;
+
+    /// Rounds executed so far
+    fn round(&self) -> u32;
+}
+
+/// Execute rounds until the fight is decided or `DEFAULT_MAX_ROUNDS` is reached
+/// Returns the final state and result (still ongoing if the cap was hit)
+pub fn simulate<E: CombatEngine>(engine: E) -> (E, E::Result) {
+    simulate_bounded(engine, DEFAULT_MAX_ROUNDS)
+}
+
+/// Like `simulate`, but stops once the engine has executed `max_rounds` rounds
+pub fn simulate_bounded<E: CombatEngine>(mut engine: E, max_rounds: u32) -> (E, E::Result) {
+    let mut result = engine.get_result();
+    while result.is_ongoing() && engine.round() < max_rounds {
+        result = engine.execute_round();
+    }
+    (engine, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Countdown {
+        Ticking,
+        Done,
+    }
+
+    impl CombatOutcome for Countdown {
+        fn is_ongoing(&self) -> bool {
+            *self == Countdown::Ticking
+        }
+
+        fn winner(&self) -> Option<Side> {
+            match self {
+                Countdown::Ticking => None,
+                Countdown::Done => Some(Side::First),
+            }
+        }
+    }
+
+    /// Decided once `rounds` reaches `length`
+    struct Timer {
+        rounds: u32,
+        length: u32,
+    }
+
+    impl CombatEngine for Timer {
+        type Result = Countdown;
+
+        fn execute_round(&mut self) -> Countdown {
+            self.rounds += 1;
+            self.get_result()
+        }
+
+        fn get_result(&self) -> Countdown {
+            if self.rounds >= self.length {
+                Countdown::Done
+            } else {
+                Countdown::Ticking
+            }
+        }
+
+        fn round(&self) -> u32 {
+            self.rounds
+        }
+    }
+
+    #[test]
+    fn test_simulate_runs_until_decided() {
+        let (timer, result) = simulate(Timer { rounds: 0, length: 7 });
+        assert_eq!(result, Countdown::Done);
+        assert_eq!(timer.round(), 7);
+    }
+
+    #[test]
+    fn test_simulate_bounded_stops_at_cap() {
+        let (timer, result) = simulate_bounded(Timer { rounds: 0, length: 50 }, 10);
+        assert!(result.is_ongoing());
+        assert_eq!(timer.round(), 10);
+
+        // Already decided: no rounds are executed
+        let (timer, _) = simulate(Timer { rounds: 3, length: 2 });
+        assert_eq!(timer.round(), 3);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ItemId, ItemInstanceId, Quality, RecipeId, Registry, Provenance,
-    ItemInstance, SimpleInstance, ItemKind,
+    ItemInstance, SimpleInstance, ItemKind, RecipeRef,
 };
 use serde_json::{json, Value};
 use std::io::{self, Write as IoWrite};
@@ -20,8 +20,12 @@ pub enum Command {
     ShowRecipe(String),
     /// Show instance details
     ShowInstance(u64),
+    /// Find recipes that produce or consume an item
+    FindRecipe(String),
     /// Create a raw material instance
     New { item_id: String, quality: Option<Quality> },
+    /// List, per slot of a composite recipe, the instance IDs that fit it
+    Fits(String),
     /// Execute a recipe with given material instances
     Craft { recipe_id: String, instance_ids: Vec<u64> },
     /// Show full provenance tree
@@ -85,6 +89,18 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
             };
             Ok(Command::New { item_id, quality })
         }
+        "find-recipe" => {
+            if parts.len() < 2 {
+                return Err("find-recipe requires: find-recipe <item_id>".to_string());
+            }
+            Ok(Command::FindRecipe(parts[1].to_string()))
+        }
+        "fits" => {
+            if parts.len() < 2 {
+                return Err("fits requires: fits <recipe_id>".to_string());
+            }
+            Ok(Command::Fits(parts[1].to_string()))
+        }
         "craft" => {
             if parts.len() < 2 {
                 return Err("craft requires: craft <recipe_id> [instance_id...]".to_string());
@@ -402,6 +418,66 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                 }
             })
         }
+        Command::FindRecipe(id_str) => {
+            let item_id = ItemId(id_str.clone());
+            if registry.get_item(&item_id).is_none() {
+                return json!({
+                    "status": "error",
+                    "message": format!("Item not found: {}", id_str)
+                });
+            }
+
+            let summarize = |recipes: Vec<RecipeRef<'_>>| -> Vec<Value> {
+                recipes.iter().map(|r| json!({
+                    "id": r.id().0,
+                    "name": r.name(),
+                    "type": r.type_name(),
+                })).collect()
+            };
+
+            json!({
+                "status": "success",
+                "data": {
+                    "item": id_str,
+                    "produced_by": summarize(registry.recipes_producing(&item_id)),
+                    "consumed_by": summarize(registry.recipes_consuming(&item_id)),
+                }
+            })
+        }
+        Command::Fits(recipe_str) => {
+            let recipe = match registry.get_composite_recipe(&RecipeId(recipe_str.clone())) {
+                Some(recipe) => recipe,
+                None => return json!({
+                    "status": "error",
+                    "message": format!("Composite recipe not found: {}", recipe_str)
+                }),
+            };
+            let slots = match registry.get_item(&recipe.output).map(|def| &def.kind) {
+                Some(ItemKind::Composite(def)) => &def.slots,
+                _ => return json!({
+                    "status": "error",
+                    "message": format!("Recipe output is not a composite: {}", recipe.output.0)
+                }),
+            };
+
+            let fits: Vec<Value> = slots.iter()
+                .map(|slot| json!({
+                    "slot": slot.name,
+                    "component_kind": slot.component_kind.0,
+                    "instance_ids": registry.compatible_instances(slot).iter()
+                        .map(|id| id.0)
+                        .collect::<Vec<_>>(),
+                }))
+                .collect();
+
+            json!({
+                "status": "success",
+                "data": {
+                    "recipe": recipe_str,
+                    "fits": fits,
+                }
+            })
+        }
         Command::Craft { recipe_id: _, instance_ids: _ } => {
             json!({
                 "status": "error",
@@ -426,6 +502,8 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                         {"command": "show recipe <id>", "description": "Show recipe with requirements"},
                         {"command": "show instance <id>", "description": "Show instance with components and provenance"},
                         {"command": "new <item_id> [quality]", "description": "Create raw material instance (defaults to common)"},
+                        {"command": "find-recipe <item_id>", "description": "Find recipes that produce or consume an item"},
+                        {"command": "fits <recipe_id>", "description": "List which instances fit each slot of a composite recipe"},
                         {"command": "craft <recipe_id> <instance_id>...", "description": "Execute recipe with materials"},
                         {"command": "trace <instance_id>", "description": "Show full provenance tree"},
                         {"command": "help", "description": "Show this help"},
@@ -640,6 +718,20 @@ mod tests {
         assert_eq!(cmd, Command::Trace(5));
     }
 
+    #[test]
+    fn test_parse_find_recipe() {
+        let cmd = parse_command("find-recipe iron_bar").unwrap();
+        assert_eq!(cmd, Command::FindRecipe("iron_bar".to_string()));
+        assert!(parse_command("find-recipe").is_err());
+    }
+
+    #[test]
+    fn test_parse_fits() {
+        let cmd = parse_command("fits assemble_scimitar").unwrap();
+        assert_eq!(cmd, Command::Fits("assemble_scimitar".to_string()));
+        assert!(parse_command("fits").is_err());
+    }
+
     #[test]
     fn test_parse_help() {
         let cmd = parse_command("help").unwrap();
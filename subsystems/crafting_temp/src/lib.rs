@@ -23,7 +23,7 @@ pub use item_def::{
 pub use provenance::{ConsumedInput, Provenance};
 pub use quality::Quality;
 pub use recipe::{
-    ComponentRecipe, CompositeRecipe, SimpleInput, SimpleRecipe, ToolRequirement,
+    ComponentRecipe, CompositeRecipe, RecipeRef, SimpleInput, SimpleRecipe, ToolRequirement,
     WorldObjectRequirement,
 };
 pub use registry::Registry;
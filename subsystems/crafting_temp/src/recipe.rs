@@ -86,3 +86,47 @@ pub struct WorldObjectRequirement {
     /// e.g., ["high_heat"] matches forge, kiln, bonfire
     pub required_tags: Vec<WorldObjectTag>,
 }
+
+//==============================================================================
+// RECIPE REFERENCES
+//==============================================================================
+
+/// Borrowed view over any of the three recipe kinds.
+///
+/// Returned by registry queries that search across simple, component, and
+/// composite recipes at once (e.g. `Registry::recipes_producing`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RecipeRef<'a> {
+    Simple(&'a SimpleRecipe),
+    Component(&'a ComponentRecipe),
+    Composite(&'a CompositeRecipe),
+}
+
+impl<'a> RecipeRef<'a> {
+    /// The recipe's ID
+    pub fn id(&self) -> &'a RecipeId {
+        match self {
+            RecipeRef::Simple(r) => &r.id,
+            RecipeRef::Component(r) => &r.id,
+            RecipeRef::Composite(r) => &r.id,
+        }
+    }
+
+    /// The recipe's display name
+    pub fn name(&self) -> &'a str {
+        match self {
+            RecipeRef::Simple(r) => &r.name,
+            RecipeRef::Component(r) => &r.name,
+            RecipeRef::Composite(r) => &r.name,
+        }
+    }
+
+    /// The recipe type as shown in CLI output ("Simple", "Component", "Composite")
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            RecipeRef::Simple(_) => "Simple",
+            RecipeRef::Component(_) => "Component",
+            RecipeRef::Composite(_) => "Composite",
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 use crate::ids::{ItemId, ItemInstanceId, RecipeId, MaterialId, SubmaterialId, ComponentKindId};
 use crate::instance::ItemInstance;
-use crate::item_def::ItemDefinition;
+use crate::item_def::{CompositeSlot, ItemDefinition, ItemKind};
 use crate::materials::{Material, Submaterial, ComponentKind};
-use crate::recipe::{SimpleRecipe, ComponentRecipe, CompositeRecipe};
+use crate::recipe::{SimpleRecipe, ComponentRecipe, CompositeRecipe, RecipeRef};
 
 /// Central registry for materials, items, recipes, and item instances
 pub struct Registry {
@@ -159,6 +159,110 @@ impl Registry {
         self.instances.values()
     }
 
+    //==========================================================================
+    // QUERIES
+    //==========================================================================
+
+    /// Component instances that can fill `slot`: same component kind, and a
+    /// submaterial whose parent material the kind accepts. Sorted by instance ID.
+    pub fn compatible_instances(&self, slot: &CompositeSlot) -> Vec<ItemInstanceId> {
+        let Some(kind) = self.get_component_kind(&slot.component_kind) else {
+            return Vec::new();
+        };
+
+        let mut ids: Vec<ItemInstanceId> = self.all_instances()
+            .filter_map(|instance| match instance {
+                ItemInstance::Component(c) => Some(c),
+                _ => None,
+            })
+            .filter(|c| c.component_kind == slot.component_kind)
+            .filter(|c| self.get_submaterial(&c.submaterial)
+                .is_some_and(|s| kind.accepted_materials.contains(&s.material)))
+            .map(|c| c.id)
+            .collect();
+        ids.sort_by_key(|id| id.0);
+        ids
+    }
+
+    /// Find every recipe whose output is the given item.
+    ///
+    /// Simple and composite recipes match on their output item directly. Component
+    /// recipes output a component kind, so they match when `item` is a Component
+    /// item definition of that kind. Results are sorted by recipe ID.
+    pub fn recipes_producing(&self, item: &ItemId) -> Vec<RecipeRef<'_>> {
+        let component_kind = match self.get_item(item).map(|def| &def.kind) {
+            Some(ItemKind::Component { component_kind }) => Some(component_kind),
+            _ => None,
+        };
+
+        let mut recipes: Vec<RecipeRef<'_>> = Vec::new();
+        recipes.extend(self.all_simple_recipes()
+            .filter(|r| &r.output == item)
+            .map(RecipeRef::Simple));
+        recipes.extend(self.all_component_recipes()
+            .filter(|r| component_kind == Some(&r.output))
+            .map(RecipeRef::Component));
+        recipes.extend(self.all_composite_recipes()
+            .filter(|r| &r.output == item)
+            .map(RecipeRef::Composite));
+
+        recipes.sort_by(|a, b| a.id().0.cmp(&b.id().0));
+        recipes
+    }
+
+    /// Find every recipe that can consume the given item as an input.
+    ///
+    /// - Simple recipes match when the item is listed in their inputs
+    /// - Component recipes match when the item is a submaterial item whose parent
+    ///   material is accepted by the recipe's component kind
+    /// - Composite recipes match when the item is a Component item whose kind fills
+    ///   one of the output composite's slots
+    ///
+    /// Results are sorted by recipe ID.
+    pub fn recipes_consuming(&self, item: &ItemId) -> Vec<RecipeRef<'_>> {
+        let item_kind = self.get_item(item).map(|def| &def.kind);
+
+        // Parent material of the item, if it is a submaterial item
+        let material = match item_kind {
+            Some(ItemKind::Simple { submaterial: Some(submat_id) }) => {
+                self.get_submaterial(submat_id).map(|s| &s.material)
+            }
+            _ => None,
+        };
+
+        // Component kind of the item, if it is a component item
+        let component_kind = match item_kind {
+            Some(ItemKind::Component { component_kind }) => Some(component_kind),
+            _ => None,
+        };
+
+        let mut recipes: Vec<RecipeRef<'_>> = Vec::new();
+        recipes.extend(self.all_simple_recipes()
+            .filter(|r| r.inputs.iter().any(|input| &input.item_id == item))
+            .map(RecipeRef::Simple));
+
+        if let Some(material) = material {
+            recipes.extend(self.all_component_recipes()
+                .filter(|r| self.get_component_kind(&r.output)
+                    .is_some_and(|kind| kind.accepted_materials.contains(material)))
+                .map(RecipeRef::Component));
+        }
+
+        if let Some(component_kind) = component_kind {
+            recipes.extend(self.all_composite_recipes()
+                .filter(|r| match self.get_item(&r.output).map(|def| &def.kind) {
+                    Some(ItemKind::Composite(def)) => {
+                        def.slots.iter().any(|slot| &slot.component_kind == component_kind)
+                    }
+                    _ => false,
+                })
+                .map(RecipeRef::Composite));
+        }
+
+        recipes.sort_by(|a, b| a.id().0.cmp(&b.id().0));
+        recipes
+    }
+
     //==========================================================================
     // INSTANCE ID GENERATION
     //==========================================================================
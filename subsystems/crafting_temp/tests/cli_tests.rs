@@ -521,6 +521,140 @@ fn test_e2e_progression_to_sword_and_boots() {
     println!("\n");
 }
 
+// ============================================================================
+// RECIPE SEARCH TESTS
+// ============================================================================
+
+fn recipe_ids(recipes: Vec<crafting::RecipeRef<'_>>) -> Vec<String> {
+    recipes.iter().map(|r| r.id().0.clone()).collect()
+}
+
+#[test]
+fn test_recipes_producing_each_recipe_kind() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let iron_bar = crafting::ItemId("iron_bar".to_string());
+    assert_eq!(recipe_ids(registry.recipes_producing(&iron_bar)), vec!["smelt_iron_bar"]);
+
+    let handle = crafting::ItemId("handle".to_string());
+    let producing = registry.recipes_producing(&handle);
+    assert_eq!(recipe_ids(producing.clone()), vec!["craft_handle"]);
+    assert_eq!(producing[0].type_name(), "Component");
+
+    let scimitar = crafting::ItemId("scimitar".to_string());
+    let producing = registry.recipes_producing(&scimitar);
+    assert_eq!(recipe_ids(producing.clone()), vec!["assemble_scimitar"]);
+    assert_eq!(producing[0].type_name(), "Composite");
+
+    // Raw ores have no recipe producing them
+    let copper_ore = crafting::ItemId("copper_ore".to_string());
+    assert!(registry.recipes_producing(&copper_ore).is_empty());
+}
+
+#[test]
+fn test_recipes_consuming_each_recipe_kind() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let copper_ore = crafting::ItemId("copper_ore".to_string());
+    assert_eq!(recipe_ids(registry.recipes_consuming(&copper_ore)), vec!["smelt_bronze_bar"]);
+
+    // iron_bar is a metal submaterial item: every component kind accepting metal can use it
+    let iron_bar = crafting::ItemId("iron_bar".to_string());
+    assert_eq!(recipe_ids(registry.recipes_consuming(&iron_bar)), vec![
+        "craft_hatchet_head",
+        "craft_knife_blade",
+        "craft_pickaxe_head",
+        "craft_pommel",
+        "craft_scimitar_blade",
+        "craft_sword_blade",
+    ]);
+
+    let scimitar_blade = crafting::ItemId("scimitar_blade".to_string());
+    assert_eq!(recipe_ids(registry.recipes_consuming(&scimitar_blade)), vec!["assemble_scimitar"]);
+
+    // Final composites are not consumed by anything
+    let scimitar = crafting::ItemId("scimitar".to_string());
+    assert!(registry.recipes_consuming(&scimitar).is_empty());
+}
+
+#[test]
+fn test_find_recipe_command() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let response = exec_command("find-recipe handle", &mut registry);
+    assert!(is_success(&response));
+
+    let data = get_data(&response);
+    assert_eq!(data["item"], "handle");
+    let produced_by = data["produced_by"].as_array().unwrap();
+    assert_eq!(produced_by.len(), 1);
+    assert_eq!(produced_by[0]["id"], "craft_handle");
+    assert_eq!(produced_by[0]["type"], "Component");
+    let consumed_by = data["consumed_by"].as_array().unwrap();
+    assert!(consumed_by.iter().any(|r| r["id"] == "assemble_scimitar"));
+    assert!(consumed_by.iter().all(|r| r["type"] == "Composite"));
+
+    let response = exec_command("find-recipe unobtainium", &mut registry);
+    assert!(!is_success(&response));
+}
+
+// ============================================================================
+// SLOT COMPATIBILITY TESTS
+// ============================================================================
+
+/// Register a component instance with a raw provenance
+fn new_component(registry: &mut Registry, kind: &str, submaterial: &str) -> crafting::ItemInstanceId {
+    let id = registry.next_instance_id();
+    registry.register_instance(crafting::ItemInstance::Component(crafting::ComponentInstance {
+        id,
+        component_kind: crafting::ComponentKindId(kind.to_string()),
+        submaterial: crafting::SubmaterialId(submaterial.to_string()),
+        provenance: crafting::Provenance {
+            recipe_id: crafting::RecipeId("raw_material".to_string()),
+            consumed_inputs: vec![],
+            tool_used: None,
+            world_object_used: None,
+            crafted_at: 0,
+        },
+    }));
+    id
+}
+
+#[test]
+fn test_fits_lists_compatible_instances_per_slot() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let oak_handle = new_component(&mut registry, "handle", "oak_wood");
+    // Right kind, but leather isn't an accepted handle material
+    new_component(&mut registry, "handle", "deer_leather");
+    let binding = new_component(&mut registry, "binding", "deer_leather");
+
+    let slot = |name: &str| crafting::CompositeSlot {
+        name: name.to_string(),
+        component_kind: crafting::ComponentKindId(name.to_string()),
+    };
+    assert_eq!(registry.compatible_instances(&slot("handle")), vec![oak_handle]);
+    assert_eq!(registry.compatible_instances(&slot("binding")), vec![binding]);
+
+    let response = exec_command("fits assemble_scimitar", &mut registry);
+    assert!(is_success(&response));
+    let fits = get_data(&response)["fits"].as_array().unwrap();
+    let ids = |slot: &str| -> Vec<u64> {
+        let entry = fits.iter().find(|f| f["slot"] == slot).unwrap();
+        entry["instance_ids"].as_array().unwrap().iter().map(|i| i.as_u64().unwrap()).collect()
+    };
+    assert_eq!(ids("handle"), vec![oak_handle.0]);
+    assert_eq!(ids("binding"), vec![binding.0]);
+    assert!(ids("blade").is_empty());
+
+    let response = exec_command("fits no_such_recipe", &mut registry);
+    assert!(!is_success(&response));
+}
+
 // ============================================================================
 // QUALITY SYSTEM TESTS
 // ============================================================================
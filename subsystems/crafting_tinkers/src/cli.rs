@@ -1,8 +1,8 @@
 use crate::{
     ItemId, ItemInstanceId, RecipeId, Registry, Provenance,
-    ItemInstance, SimpleInstance, ItemKind, WorldObjectInstanceId,
+    ItemInstance, SimpleInstance, ItemKind, WorldObjectInstanceId, RecipeRef, LineageNode, Quality,
 };
-use crate::ids::{CraftingStationId, WorldObjectTag};
+use crate::ids::{CraftingStationId, ResourceNodeId, WorldObjectTag};
 use crate::world_object::{WorldObjectKind, WorldObjectInstance};
 use serde_json::{json, Value};
 use std::io::{self, Write as IoWrite};
@@ -25,14 +25,38 @@ pub enum Command {
     ShowRecipe(String),
     /// Show instance details
     ShowInstance(u64),
+    /// Find recipes that produce or consume an item
+    FindRecipe(String),
+    /// List, per slot of a composite recipe, the inventory indices that fit it
+    Fits(String),
     /// Create a raw material instance (Simple items only)
-    New { item_id: String },
-    /// Craft an item using a recipe and inventory indices
-    Craft { recipe_id: String, input_indices: Vec<usize>, station_index: Option<usize> },
+    New { item_id: String, quality: Quality },
+    /// Craft an item using a recipe and inventory indices, optionally at a station and with a tool
+    Craft { recipe_id: String, input_indices: Vec<usize>, station_index: Option<usize>, tool_index: Option<usize> },
+    /// Report what a craft would produce and consume, without crafting
+    Preview { recipe_id: String, input_indices: Vec<usize>, station_index: Option<usize>, tool_index: Option<usize> },
+    /// Craft the same recipe up to `count` times from the given inputs
+    CraftMany { recipe_id: String, count: u32, input_indices: Vec<usize>, station_index: Option<usize>, tool_index: Option<usize> },
     /// Place a crafting station from inventory
     Place { instance_index: usize },
-    /// List all placed world objects
-    ListStations,
+    /// Place a resource node in the world, optionally limited to `uses` harvests
+    PlaceNode { resource_id: String, uses: Option<u32> },
+    /// Disassemble a composite from inventory back into its components
+    Disassemble { instance_index: usize },
+    /// Show the full crafting ancestry of an instance from inventory
+    Lineage { instance_index: usize },
+    /// Sell an instance from inventory for its value
+    Sell { instance_index: usize },
+    /// Show the currency balance
+    Balance,
+    /// Harvest one item from a placed resource node
+    Harvest { node_index: usize },
+    /// List placed world objects, optionally only those with a tag
+    ListStations { tag: Option<String> },
+    /// Save the registry to a JSON file
+    Save { path: String },
+    /// Load the registry from a JSON file, replacing the current state
+    Load { path: String },
     /// Show help
     Help,
     /// Exit REPL
@@ -59,28 +83,31 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
         "li" => Ok(Command::ListItems),
         "lis" => Ok(Command::ListSimpleItems),
         "ls" => Ok(Command::ListInstances),
-        "c" => {
+        "c" | "craft" => {
             if parts.len() < 2 {
-                return Err("craft requires: craft <recipe_id> [index1] [index2] ... [@station_index]".to_string());
+                return Err("craft requires: craft <recipe_id> [index1] [index2] ... [@station_index] [+tool_index]".to_string());
             }
             let recipe_id = parts[1].to_string();
-            let mut input_indices = Vec::new();
-            let mut station_index = None;
-            
-            for part in &parts[2..] {
-                if part.starts_with('@') {
-                    // Station reference
-                    let station_str = &part[1..];
-                    station_index = Some(station_str.parse::<usize>()
-                        .map_err(|_| format!("Invalid station index: {}", station_str))?);
-                } else {
-                    // Input index
-                    let idx = part.parse::<usize>()
-                        .map_err(|_| format!("Invalid index: {}", part))?;
-                    input_indices.push(idx);
-                }
+            let (input_indices, station_index, tool_index) = parse_craft_args(&parts[2..])?;
+            Ok(Command::Craft { recipe_id, input_indices, station_index, tool_index })
+        }
+        "preview" => {
+            if parts.len() < 2 {
+                return Err("preview requires: preview <recipe_id> [index1] [index2] ... [@station_index] [+tool_index]".to_string());
             }
-            Ok(Command::Craft { recipe_id, input_indices, station_index })
+            let recipe_id = parts[1].to_string();
+            let (input_indices, station_index, tool_index) = parse_craft_args(&parts[2..])?;
+            Ok(Command::Preview { recipe_id, input_indices, station_index, tool_index })
+        }
+        "craft-many" | "cm" => {
+            if parts.len() < 3 {
+                return Err("craft-many requires: craft-many <recipe_id> <count> [index1] [index2] ... [@station_index] [+tool_index]".to_string());
+            }
+            let recipe_id = parts[1].to_string();
+            let count = parts[2].parse::<u32>()
+                .map_err(|_| format!("Invalid count: {}", parts[2]))?;
+            let (input_indices, station_index, tool_index) = parse_craft_args(&parts[3..])?;
+            Ok(Command::CraftMany { recipe_id, count, input_indices, station_index, tool_index })
         }
         "i" | "inventory" | "inv" => Ok(Command::ListInstances),
         "si" => {
@@ -105,9 +132,10 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
         }
         "n" => {
             if parts.len() < 2 {
-                return Err("new requires: new <item_id>".to_string());
+                return Err("new requires: new <item_id> [quality]".to_string());
             }
-            Ok(Command::New { item_id: parts[1].to_string() })
+            let quality = parts.get(2).map(|q| parse_quality(q)).transpose()?.unwrap_or(Quality::Common);
+            Ok(Command::New { item_id: parts[1].to_string(), quality })
         }
         "h" | "?" => Ok(Command::Help),
         "q" => Ok(Command::Exit),
@@ -138,35 +166,25 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
                 _ => Err(format!("Unknown show type: {}", parts[1])),
             }
         }
-        "new" => {
+        "find-recipe" | "fr" => {
             if parts.len() < 2 {
-                return Err("new requires: new <item_id>".to_string());
+                return Err("find-recipe requires: find-recipe <item_id>".to_string());
             }
-            let item_id = parts[1].to_string();
-            Ok(Command::New { item_id })
+            Ok(Command::FindRecipe(parts[1].to_string()))
         }
-        "craft" => {
+        "fits" => {
             if parts.len() < 2 {
-                return Err("craft requires: craft <recipe_id> [index1] [index2] ... [@station_index]".to_string());
+                return Err("fits requires: fits <recipe_id>".to_string());
             }
-            let recipe_id = parts[1].to_string();
-            let mut input_indices = Vec::new();
-            let mut station_index = None;
-            
-            for part in &parts[2..] {
-                if part.starts_with('@') {
-                    // Station reference
-                    let station_str = &part[1..];
-                    station_index = Some(station_str.parse::<usize>()
-                        .map_err(|_| format!("Invalid station index: {}", station_str))?);
-                } else {
-                    // Input index
-                    let idx = part.parse::<usize>()
-                        .map_err(|_| format!("Invalid index: {}", part))?;
-                    input_indices.push(idx);
-                }
+            Ok(Command::Fits(parts[1].to_string()))
+        }
+        "new" => {
+            if parts.len() < 2 {
+                return Err("new requires: new <item_id> [quality]".to_string());
             }
-            Ok(Command::Craft { recipe_id, input_indices, station_index })
+            let item_id = parts[1].to_string();
+            let quality = parts.get(2).map(|q| parse_quality(q)).transpose()?.unwrap_or(Quality::Common);
+            Ok(Command::New { item_id, quality })
         }
         "place" => {
             if parts.len() < 2 {
@@ -184,8 +202,71 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
                 .map_err(|_| format!("Invalid instance index: {}", parts[1]))?;
             Ok(Command::Place { instance_index })
         }
+        "place-node" | "pn" => {
+            if parts.len() < 2 {
+                return Err("place-node requires: place-node <resource_id> [uses]".to_string());
+            }
+            let resource_id = parts[1].to_string();
+            let uses = parts.get(2)
+                .map(|uses| uses.parse::<u32>().map_err(|_| format!("Invalid uses: {}", uses)))
+                .transpose()?;
+            Ok(Command::PlaceNode { resource_id, uses })
+        }
         "stations" => {
-            Ok(Command::ListStations)
+            match parts.get(1) {
+                None => Ok(Command::ListStations { tag: None }),
+                Some(&"--tag") => {
+                    let tag = parts.get(2)
+                        .ok_or_else(|| "stations --tag requires: stations --tag <tag>".to_string())?;
+                    Ok(Command::ListStations { tag: Some(tag.to_string()) })
+                }
+                Some(other) => Err(format!("Unknown stations option: {}. Usage: stations [--tag <tag>]", other)),
+            }
+        }
+        "disassemble" => {
+            if parts.len() < 2 {
+                return Err("disassemble requires: disassemble <instance_index>".to_string());
+            }
+            let instance_index = parts[1].parse::<usize>()
+                .map_err(|_| format!("Invalid instance index: {}", parts[1]))?;
+            Ok(Command::Disassemble { instance_index })
+        }
+        "lineage" | "trace" => {
+            if parts.len() < 2 {
+                return Err("lineage requires: lineage <instance_index>".to_string());
+            }
+            let instance_index = parts[1].parse::<usize>()
+                .map_err(|_| format!("Invalid instance index: {}", parts[1]))?;
+            Ok(Command::Lineage { instance_index })
+        }
+        "sell" => {
+            if parts.len() < 2 {
+                return Err("sell requires: sell <instance_index>".to_string());
+            }
+            let instance_index = parts[1].parse::<usize>()
+                .map_err(|_| format!("Invalid instance index: {}", parts[1]))?;
+            Ok(Command::Sell { instance_index })
+        }
+        "balance" => Ok(Command::Balance),
+        "harvest" => {
+            if parts.len() < 2 {
+                return Err("harvest requires: harvest <station_index>".to_string());
+            }
+            let node_index = parts[1].parse::<usize>()
+                .map_err(|_| format!("Invalid station index: {}", parts[1]))?;
+            Ok(Command::Harvest { node_index })
+        }
+        "save" => {
+            if parts.len() < 2 {
+                return Err("save requires: save <path>".to_string());
+            }
+            Ok(Command::Save { path: parts[1].to_string() })
+        }
+        "load" => {
+            if parts.len() < 2 {
+                return Err("load requires: load <path>".to_string());
+            }
+            Ok(Command::Load { path: parts[1].to_string() })
         }
         "help" => Ok(Command::Help),
         "exit" | "quit" => Ok(Command::Exit),
@@ -193,6 +274,20 @@ pub fn parse_command(input: &str) -> Result<Command, String> {
     }
 }
 
+/// Parse a quality name (case-insensitive)
+fn parse_quality(s: &str) -> Result<Quality, String> {
+    match s.to_lowercase().as_str() {
+        "makeshift" => Ok(Quality::Makeshift),
+        "crude" => Ok(Quality::Crude),
+        "common" => Ok(Quality::Common),
+        "uncommon" => Ok(Quality::Uncommon),
+        "rare" => Ok(Quality::Rare),
+        "epic" => Ok(Quality::Epic),
+        "legendary" => Ok(Quality::Legendary),
+        _ => Err(format!("Unknown quality: {}", s)),
+    }
+}
+
 /// Execute a command against the registry
 pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
     match command {
@@ -313,6 +408,8 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                             "id": i.id.0,
                             "kind": "Simple",
                             "item": i.definition.0,
+                            "quantity": i.quantity,
+                            "quality": format!("{:?}", i.quality),
                         }),
                         ItemInstance::Component(i) => json!({
                             "index": index,
@@ -320,6 +417,7 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                             "kind": "Component",
                             "component_kind": i.component_kind.0,
                             "submaterial": i.submaterial.0,
+                            "quality": format!("{:?}", i.quality),
                         }),
                         ItemInstance::Composite(i) => json!({
                             "index": index,
@@ -447,17 +545,93 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
         Command::ShowInstance(id) => {
             let instance_id = ItemInstanceId(id);
             match registry.get_instance(instance_id) {
-                Some(instance) => json!({
-                    "status": "success",
-                    "data": serialize_instance(instance)
-                }),
+                Some(instance) => {
+                    let mut data = serialize_instance(instance);
+                    if let Ok(properties) = registry.composite_properties(instance_id) {
+                        // Sorted so output is stable
+                        let sorted: std::collections::BTreeMap<_, _> = properties.into_iter().collect();
+                        data["properties"] = json!(sorted);
+                    }
+                    json!({
+                        "status": "success",
+                        "data": data
+                    })
+                }
                 None => json!({
                     "status": "error",
                     "message": format!("Instance not found: {}", id)
                 }),
             }
         }
-        Command::New { item_id } => {
+        Command::FindRecipe(id_str) => {
+            let item_id = ItemId(id_str.clone());
+            if registry.get_item(&item_id).is_none() {
+                return json!({
+                    "status": "error",
+                    "message": format!("Item not found: {}", id_str)
+                });
+            }
+
+            let summarize = |recipes: Vec<RecipeRef<'_>>| -> Vec<Value> {
+                recipes.iter().map(|r| json!({
+                    "id": r.id().0,
+                    "name": r.name(),
+                    "type": r.type_name(),
+                })).collect()
+            };
+
+            json!({
+                "status": "success",
+                "data": {
+                    "item": id_str,
+                    "produced_by": summarize(registry.recipes_producing(&item_id)),
+                    "consumed_by": summarize(registry.recipes_consuming(&item_id)),
+                }
+            })
+        }
+        Command::Fits(recipe_str) => {
+            let recipe = match registry.get_composite_recipe(&RecipeId(recipe_str.clone())) {
+                Some(recipe) => recipe,
+                None => return json!({
+                    "status": "error",
+                    "message": format!("Composite recipe not found: {}", recipe_str)
+                }),
+            };
+            let slots = match registry.get_item(&recipe.output).map(|def| &def.kind) {
+                Some(ItemKind::Composite(def)) => &def.slots,
+                _ => return json!({
+                    "status": "error",
+                    "message": format!("Recipe output is not a composite: {}", recipe.output.0)
+                }),
+            };
+
+            // Inventory indices are positions in all_instances() order
+            let instances_vec: Vec<ItemInstanceId> = registry.all_instances()
+                .map(|inst| inst.id())
+                .collect();
+            let fits: Vec<Value> = slots.iter()
+                .map(|slot| {
+                    let mut indices: Vec<usize> = registry.compatible_instances(slot).iter()
+                        .filter_map(|id| instances_vec.iter().position(|i| i == id))
+                        .collect();
+                    indices.sort();
+                    json!({
+                        "slot": slot.name,
+                        "component_kind": slot.component_kind.0,
+                        "indices": indices,
+                    })
+                })
+                .collect();
+
+            json!({
+                "status": "success",
+                "data": {
+                    "fits_recipe": recipe_str,
+                    "fits": fits,
+                }
+            })
+        }
+        Command::New { item_id, quality } => {
             let item_id_obj = ItemId(item_id.clone());
             
             // Verify item exists and is Simple
@@ -481,16 +655,19 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
             let instance = ItemInstance::Simple(SimpleInstance {
                 id: instance_id,
                 definition: item_id_obj,
+                quantity: 1,
+                quality,
                 provenance: Provenance {
                     recipe_id: RecipeId("raw_material".to_string()),
                     consumed_inputs: vec![],
                     tool_used: None,
                     world_object_used: None,
+                    disassembled_from: None,
                     crafted_at: 0,
                 },
             });
             
-            registry.register_instance(instance);
+            let instance_id = registry.register_new_instance(instance);
             
             json!({
                 "status": "success",
@@ -500,9 +677,12 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                 }
             })
         }
-        Command::ListStations => {
+        Command::ListStations { tag } => {
+            let tag = tag.map(WorldObjectTag);
+            // Filter after numbering so indices still match `@station_index` in craft
             let stations: Vec<Value> = registry.all_world_objects()
                 .enumerate()
+                .filter(|(_, wo)| tag.as_ref().is_none_or(|tag| wo.has_tag(tag)))
                 .map(|(index, wo)| {
                     let kind_str = match &wo.kind {
                         WorldObjectKind::CraftingStation(id) => format!("CraftingStation({})", id.0),
@@ -524,6 +704,41 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                 }
             })
         }
+        Command::Save { path } => {
+            match registry.save(&path) {
+                Ok(()) => json!({
+                    "status": "success",
+                    "data": {
+                        "saved": path,
+                        "instances": registry.all_instances().count(),
+                        "stations": registry.all_world_objects().count(),
+                    }
+                }),
+                Err(e) => json!({
+                    "status": "error",
+                    "message": format!("Failed to save {}: {}", path, e)
+                }),
+            }
+        }
+        Command::Load { path } => {
+            match Registry::load(&path) {
+                Ok(loaded) => {
+                    *registry = loaded;
+                    json!({
+                        "status": "success",
+                        "data": {
+                            "loaded": path,
+                            "instances": registry.all_instances().count(),
+                            "stations": registry.all_world_objects().count(),
+                        }
+                    })
+                }
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
+            }
+        }
         Command::Place { instance_index } => {
             // Collect all instances into a vector for indexing
             let instances_vec: Vec<ItemInstanceId> = registry.all_instances()
@@ -583,13 +798,19 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                 id: world_object_id,
                 kind: WorldObjectKind::CraftingStation(CraftingStationId(item_id_str)),
                 tags: vec![WorldObjectTag("high_heat".to_string())], // Forge provides high heat
+                remaining_uses: None,
             };
             
             // Register the world object
             registry.register_world_object(world_object.clone());
             
-            // Remove the item instance from inventory
-            registry.remove_instance(instance_id);
+            // Take one unit of the item out of inventory
+            if let Err(e) = registry.consume_instance(instance_id, 1) {
+                return json!({
+                    "status": "error",
+                    "message": e
+                });
+            }
             
             json!({
                 "status": "success",
@@ -600,184 +821,214 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                 }
             })
         }
-        Command::Craft { recipe_id, input_indices, station_index } => {
+        Command::Disassemble { instance_index } => {
             // Collect all instances into a vector for indexing
             let instances_vec: Vec<ItemInstanceId> = registry.all_instances()
                 .map(|inst| inst.id())
                 .collect();
-            
-            // Validate indices
-            for &index in &input_indices {
-                if index >= instances_vec.len() {
-                    return json!({
-                        "status": "error",
-                        "message": format!("Invalid inventory index: {}. Inventory has {} items (indices 0-{})", 
-                            index, instances_vec.len(), 
-                            if instances_vec.is_empty() { 0 } else { instances_vec.len() - 1 })
-                    });
-                }
+
+            // Validate index
+            if instance_index >= instances_vec.len() {
+                return json!({
+                    "status": "error",
+                    "message": format!("Invalid inventory index: {}. Inventory has {} items (indices 0-{})", 
+                        instance_index, instances_vec.len(), 
+                        if instances_vec.is_empty() { 0 } else { instances_vec.len() - 1 })
+                });
             }
-            
-            // Map indices to instance IDs
-            let input_instance_ids: Vec<ItemInstanceId> = input_indices.iter()
-                .map(|&idx| instances_vec[idx])
-                .collect();
-            
-            // Collect all world objects for indexing
-            let stations_vec: Vec<WorldObjectInstanceId> = registry.all_world_objects()
-                .map(|wo| wo.id)
+
+            let instance_id = instances_vec[instance_index];
+            match registry.disassemble(instance_id) {
+                Ok(component_ids) => json!({
+                    "status": "success",
+                    "data": {
+                        "disassembled": instance_id.0,
+                        "component_ids": component_ids.iter().map(|id| id.0).collect::<Vec<_>>(),
+                    }
+                }),
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
+            }
+        }
+        Command::Lineage { instance_index } => {
+            let instances_vec: Vec<ItemInstanceId> = registry.all_instances()
+                .map(|inst| inst.id())
                 .collect();
-            
-            // Resolve station reference if provided
-            let world_object_used = if let Some(station_idx) = station_index {
-                if station_idx >= stations_vec.len() {
-                    return json!({
-                        "status": "error",
-                        "message": format!("Invalid station index: {}. There are {} stations (indices 0-{})", 
-                            station_idx, stations_vec.len(), 
-                            if stations_vec.is_empty() { 0 } else { stations_vec.len() - 1 })
-                    });
+
+            if instance_index >= instances_vec.len() {
+                return json!({
+                    "status": "error",
+                    "message": format!("Invalid inventory index: {}. Inventory has {} items (indices 0-{})", 
+                        instance_index, instances_vec.len(), 
+                        if instances_vec.is_empty() { 0 } else { instances_vec.len() - 1 })
+                });
+            }
+
+            let lineage = registry.lineage(instances_vec[instance_index]);
+            json!({
+                "status": "success",
+                "data": {
+                    "lineage": serialize_lineage(&lineage),
+                    "depth": lineage.depth(),
                 }
-                Some(stations_vec[station_idx])
-            } else {
-                None
-            };
-            
-            // Find the recipe
-            let recipe_id_obj = RecipeId(recipe_id.clone());
-            
-            // Try Simple recipe first
-            if let Some(recipe) = registry.get_simple_recipe(&recipe_id_obj) {
-                let recipe_clone = recipe.clone();
-                match registry.execute_simple_recipe(&recipe_clone, input_instance_ids.clone(), None, world_object_used) {
-                    Ok(new_instance) => {
-                        // Remove consumed instances
-                        for &id in &input_instance_ids {
-                            registry.remove_instance(id);
-                        }
-                        
-                        // Register the new instance
-                        let new_id = new_instance.id();
-                        registry.register_instance(new_instance);
-                        
-                        json!({
-                            "status": "success",
-                            "data": {
-                                "instance_id": new_id.0,
-                                "recipe": recipe_id,
-                                "type": "Simple"
-                            }
-                        })
+            })
+        }
+        Command::Sell { instance_index } => {
+            let sold = registry.all_instances().nth(instance_index).map(|inst| inst.id());
+            match registry.sell(instance_index) {
+                Ok(value) => json!({
+                    "status": "success",
+                    "data": {
+                        "sold": sold.map(|id| id.0),
+                        "value": value,
+                        "balance": registry.balance(),
                     }
-                    Err(e) => json!({
-                        "status": "error",
-                        "message": e
-                    })
-                }
+                }),
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
             }
-            // Try Component recipe
-            else if let Some(recipe) = registry.get_component_recipe(&recipe_id_obj) {
-                if input_instance_ids.len() != 1 {
-                    return json!({
-                        "status": "error",
-                        "message": format!("Component recipe requires exactly 1 input, got {}", input_instance_ids.len())
-                    });
+        }
+        Command::PlaceNode { resource_id, uses } => {
+            let resource = ResourceNodeId(resource_id);
+            // Only node kinds with a registered yield can ever be harvested
+            if registry.resource_yield(&resource).is_none() {
+                return json!({
+                    "status": "error",
+                    "message": format!("Unknown resource node '{}': no registered yield", resource.0)
+                });
+            }
+
+            let world_object_id = registry.next_world_object_id();
+            registry.register_world_object(WorldObjectInstance {
+                id: world_object_id,
+                kind: WorldObjectKind::ResourceNode(resource.clone()),
+                tags: vec![],
+                remaining_uses: uses,
+            });
+            let index = registry.all_world_objects().position(|wo| wo.id == world_object_id);
+
+            json!({
+                "status": "success",
+                "data": {
+                    "world_object_id": world_object_id.0,
+                    "index": index,
+                    "kind": format!("ResourceNode({})", resource.0),
+                    "remaining_uses": uses,
                 }
-                
-                let recipe_clone = recipe.clone();
-                match registry.execute_component_recipe(&recipe_clone, input_instance_ids[0], None, world_object_used) {
-                    Ok(new_instance) => {
-                        // Remove consumed instance
-                        registry.remove_instance(input_instance_ids[0]);
-                        
-                        // Register the new instance
-                        let new_id = new_instance.id();
-                        registry.register_instance(new_instance);
-                        
-                        json!({
-                            "status": "success",
-                            "data": {
-                                "instance_id": new_id.0,
-                                "recipe": recipe_id,
-                                "type": "Component"
-                            }
-                        })
-                    }
-                    Err(e) => json!({
-                        "status": "error",
-                        "message": e
+            })
+        }
+        Command::Harvest { node_index } => {
+            match registry.harvest(node_index) {
+                Ok(instance_id) => {
+                    let item = match registry.get_instance(instance_id) {
+                        Some(ItemInstance::Simple(si)) => si.definition.0.clone(),
+                        _ => String::new(),
+                    };
+                    let remaining_uses = registry.all_world_objects()
+                        .nth(node_index)
+                        .and_then(|wo| wo.remaining_uses);
+                    json!({
+                        "status": "success",
+                        "data": {
+                            "instance_id": instance_id.0,
+                            "item": item,
+                            "remaining_uses": remaining_uses,
+                        }
                     })
                 }
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
             }
-            // Try Composite recipe
-            else if let Some(recipe) = registry.get_composite_recipe(&recipe_id_obj) {
-                // Clone recipe and get output item info before mutable borrow
-                let recipe_clone = recipe.clone();
-                let output_item_id = recipe.output.clone();
-                
-                // Get the output item to determine slot order
-                let output_def = match registry.get_item(&output_item_id) {
-                    Some(def) => def,
-                    None => return json!({
-                        "status": "error",
-                        "message": format!("Output item {:?} not found", output_item_id)
-                    })
+        }
+        Command::Balance => {
+            json!({
+                "status": "success",
+                "data": {
+                    "balance": registry.balance(),
+                }
+            })
+        }
+        Command::Craft { recipe_id, input_indices, station_index, tool_index } => {
+            let (input_instance_ids, world_object_used, tool_used) =
+                match resolve_craft_refs(registry, &input_indices, station_index, tool_index) {
+                    Ok(refs) => refs,
+                    Err(error) => return error,
                 };
-                
-                let composite_def = match &output_def.kind {
-                    crate::ItemKind::Composite(def) => def,
-                    _ => return json!({
-                        "status": "error",
-                        "message": format!("Recipe output {:?} is not a Composite item", output_item_id)
-                    })
+
+            let recipe_id_obj = RecipeId(recipe_id.clone());
+            match registry.craft(&recipe_id_obj, input_instance_ids, tool_used, world_object_used) {
+                Ok(new_id) => json!({
+                    "status": "success",
+                    "data": {
+                        "instance_id": new_id.0,
+                        "recipe": recipe_id,
+                        "type": registry.get_recipe(&recipe_id_obj).map(|r| r.type_name()),
+                    }
+                }),
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
+            }
+        }
+        Command::Preview { recipe_id, input_indices, station_index, tool_index } => {
+            let (input_instance_ids, world_object_used, tool_used) =
+                match resolve_craft_refs(registry, &input_indices, station_index, tool_index) {
+                    Ok(refs) => refs,
+                    Err(error) => return error,
                 };
-                
-                // Match indices to slots in order
-                if input_instance_ids.len() != composite_def.slots.len() {
-                    return json!({
-                        "status": "error",
-                        "message": format!("Composite recipe requires {} components (one per slot), got {}", 
-                            composite_def.slots.len(), input_instance_ids.len())
-                    });
-                }
-                
-                let provided_components: Vec<(String, ItemInstanceId)> = composite_def.slots.iter()
-                    .zip(input_instance_ids.iter())
-                    .map(|(slot, &id)| (slot.name.clone(), id))
-                    .collect();
-                
-                match registry.execute_composite_recipe(&recipe_clone, provided_components.clone(), None, world_object_used) {
-                    Ok(new_instance) => {
-                        // Remove consumed instances
-                        for (_, id) in provided_components {
-                            registry.remove_instance(id);
-                        }
-                        
-                        // Register the new instance
-                        let new_id = new_instance.id();
-                        registry.register_instance(new_instance);
-                        
-                        json!({
-                            "status": "success",
-                            "data": {
-                                "instance_id": new_id.0,
-                                "recipe": recipe_id,
-                                "type": "Composite"
-                            }
-                        })
+
+            match registry.preview_craft(&RecipeId(recipe_id.clone()), input_instance_ids, tool_used, world_object_used) {
+                Ok(preview) => json!({
+                    "status": "success",
+                    "data": {
+                        "preview_recipe": recipe_id,
+                        "would_create": serialize_instance(&preview.output),
+                        "would_consume": preview.consumed_inputs.iter().map(|ci| json!({
+                            "instance_id": ci.instance_id.0,
+                            "quantity": ci.quantity,
+                        })).collect::<Vec<_>>(),
+                        "output_quality": preview.quality.map(|q| format!("{:?}", q)),
                     }
-                    Err(e) => json!({
-                        "status": "error",
-                        "message": e
-                    })
-                }
+                }),
+                Err(e) => json!({
+                    "status": "error",
+                    "message": e
+                }),
             }
-            else {
-                json!({
+        }
+        Command::CraftMany { recipe_id, count, input_indices, station_index, tool_index } => {
+            let (input_instance_ids, world_object_used, tool_used) =
+                match resolve_craft_refs(registry, &input_indices, station_index, tool_index) {
+                    Ok(refs) => refs,
+                    Err(error) => return error,
+                };
+
+            let recipe_id_obj = RecipeId(recipe_id.clone());
+            if registry.get_recipe(&recipe_id_obj).is_none() {
+                return json!({
                     "status": "error",
                     "message": format!("Recipe not found: {}", recipe_id)
-                })
+                });
             }
+
+            let outcome = registry.craft_batch(&recipe_id_obj, count, &input_instance_ids, tool_used, world_object_used);
+            json!({
+                "status": "success",
+                "data": {
+                    "batch_recipe": recipe_id,
+                    "requested": count,
+                    "succeeded": outcome.succeeded(),
+                    "instance_ids": outcome.crafted.iter().map(|id| id.0).collect::<Vec<_>>(),
+                    "stop_reason": outcome.stop_reason,
+                }
+            })
         }
         Command::Help => {
             json!({
@@ -792,14 +1043,26 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
                         {"command": "show item <id> (si)", "description": "Show detailed item definition"},
                         {"command": "show recipe <id> (sr)", "description": "Show recipe with requirements"},
                         {"command": "show instance <id> (sin)", "description": "Show instance details"},
-                        {"command": "new <item_id> (n)", "description": "Create raw Simple material instance"},
+                        {"command": "find-recipe <item_id> (fr)", "description": "Find recipes that produce or consume an item"},
+                        {"command": "fits <recipe_id>", "description": "List which inventory indices fit each slot of a composite recipe"},
+                        {"command": "new <item_id> [quality] (n)", "description": "Create raw Simple material instance (quality defaults to common)"},
                         {"command": "place <instance_index> (p)", "description": "Place a crafting station from inventory"},
-                        {"command": "stations", "description": "List all placed crafting stations"},
-                        {"command": "craft <recipe_id> [index1] [index2] ... [@station_index] (c)", "description": "Craft an item using a recipe and inventory indices, optionally at a station"},
+                        {"command": "place-node <resource_id> [uses] (pn)", "description": "Place a resource node to harvest (e.g. oak_tree); without uses it never runs out"},
+                        {"command": "stations [--tag <tag>]", "description": "List placed crafting stations, optionally only those with a tag (e.g. high_heat)"},
+                        {"command": "disassemble <instance_index>", "description": "Break a composite back into its components"},
+                        {"command": "lineage <instance_index> (trace)", "description": "Show every instance an item was crafted from, including consumed ones"},
+                        {"command": "sell <instance_index>", "description": "Sell an item for its material/quality value (later indices shift down by one)"},
+                        {"command": "balance", "description": "Show how much currency you have"},
+                        {"command": "harvest <station_index>", "description": "Gather one raw item from a placed resource node (limited nodes run out)"},
+                        {"command": "save <path>", "description": "Save inventory, stations, and registry to a JSON file"},
+                        {"command": "load <path>", "description": "Load a previously saved registry, replacing the current one"},
+                        {"command": "craft <recipe_id> [index1] [index2] ... [@station_index] [+tool_index] (c)", "description": "Craft an item using a recipe and inventory indices, optionally at a station and with a tool (tools lose 1 durability per use)"},
+                        {"command": "preview <recipe_id> [index1] ... [@station_index] [+tool_index]", "description": "Show what a craft would produce and consume without crafting"},
+                        {"command": "craft-many <recipe_id> <count> [index1] ... [@station_index] [+tool_index] (cm)", "description": "Craft a recipe repeatedly, stopping early when inputs run out or the tool breaks"},
                         {"command": "help (h/?)", "description": "Show this help"},
                         {"command": "exit (q)", "description": "Exit REPL"},
                     ],
-                    "note": "Shorthands: i/inv/ls (inventory), lr (list recipes), li (list items), lis (list simple items), c (craft), si (show item), sr (show recipe), sin (show instance), n (new), h/? (help), q (exit). Use 'inventory' to see numbered items. Use those numbers with 'craft' command. Use --human-readable flag for readable output format."
+                    "note": "Shorthands: i/inv/ls (inventory), lr (list recipes), li (list items), lis (list simple items), c (craft), cm (craft many), si (show item), sr (show recipe), sin (show instance), fr (find recipe), n (new), h/? (help), q (exit). Use 'inventory' to see numbered items. Use those numbers with 'craft' command. Use --human-readable flag for readable output format."
                 }
             })
         }
@@ -811,6 +1074,92 @@ pub fn execute_command(command: Command, registry: &mut Registry) -> Value {
     }
 }
 
+/// Input instance IDs, station, and tool resolved from craft command indices
+type CraftRefs = (Vec<ItemInstanceId>, Option<WorldObjectInstanceId>, Option<ItemInstanceId>);
+
+/// Input indices, station index, and tool index parsed from craft command arguments
+type CraftArgs = (Vec<usize>, Option<usize>, Option<usize>);
+
+/// Resolve craft inventory/station/tool indices to IDs.
+///
+/// On failure returns the JSON error response to send back.
+fn resolve_craft_refs(
+    registry: &Registry,
+    input_indices: &[usize],
+    station_index: Option<usize>,
+    tool_index: Option<usize>,
+) -> Result<CraftRefs, Value> {
+    // Collect all instances into a vector for indexing
+    let instances_vec: Vec<ItemInstanceId> = registry.all_instances()
+        .map(|inst| inst.id())
+        .collect();
+    
+    // Validate indices
+    for &index in input_indices.iter().chain(tool_index.iter()) {
+        if index >= instances_vec.len() {
+            return Err(json!({
+                "status": "error",
+                "message": format!("Invalid inventory index: {}. Inventory has {} items (indices 0-{})", 
+                    index, instances_vec.len(), 
+                    if instances_vec.is_empty() { 0 } else { instances_vec.len() - 1 })
+            }));
+        }
+    }
+    
+    // Map indices to instance IDs
+    let input_instance_ids: Vec<ItemInstanceId> = input_indices.iter()
+        .map(|&idx| instances_vec[idx])
+        .collect();
+    let tool_used = tool_index.map(|idx| instances_vec[idx]);
+    
+    // Collect all world objects for indexing
+    let stations_vec: Vec<WorldObjectInstanceId> = registry.all_world_objects()
+        .map(|wo| wo.id)
+        .collect();
+    
+    // Resolve station reference if provided
+    let world_object_used = if let Some(station_idx) = station_index {
+        if station_idx >= stations_vec.len() {
+            return Err(json!({
+                "status": "error",
+                "message": format!("Invalid station index: {}. There are {} stations (indices 0-{})", 
+                    station_idx, stations_vec.len(), 
+                    if stations_vec.is_empty() { 0 } else { stations_vec.len() - 1 })
+            }));
+        }
+        Some(stations_vec[station_idx])
+    } else {
+        None
+    };
+
+    Ok((input_instance_ids, world_object_used, tool_used))
+}
+
+/// Parse the trailing arguments of a craft command: input indices, `@station`, `+tool`
+fn parse_craft_args(args: &[&str]) -> Result<CraftArgs, String> {
+    let mut input_indices = Vec::new();
+    let mut station_index = None;
+    let mut tool_index = None;
+    
+    for part in args {
+        if let Some(station_str) = part.strip_prefix('@') {
+            // Station reference
+            station_index = Some(station_str.parse::<usize>()
+                .map_err(|_| format!("Invalid station index: {}", station_str))?);
+        } else if let Some(tool_str) = part.strip_prefix('+') {
+            // Tool reference (inventory index)
+            tool_index = Some(tool_str.parse::<usize>()
+                .map_err(|_| format!("Invalid tool index: {}", tool_str))?);
+        } else {
+            // Input index
+            let idx = part.parse::<usize>()
+                .map_err(|_| format!("Invalid index: {}", part))?;
+            input_indices.push(idx);
+        }
+    }
+    Ok((input_indices, station_index, tool_index))
+}
+
 /// Serialize an instance to JSON
 fn serialize_instance(instance: &ItemInstance) -> Value {
     match instance {
@@ -818,49 +1167,69 @@ fn serialize_instance(instance: &ItemInstance) -> Value {
             "id": i.id.0,
             "kind": "Simple",
             "item": i.definition.0,
-            "provenance": {
-                "recipe": i.provenance.recipe_id.0,
-                "consumed": i.provenance.consumed_inputs.iter().map(|ci| json!({
-                    "instance_id": ci.instance_id.0,
-                    "quantity": ci.quantity,
-                })).collect::<Vec<_>>(),
-            }
+            "quantity": i.quantity,
+            "quality": format!("{:?}", i.quality),
+            "provenance": serialize_provenance(&i.provenance)
         }),
         ItemInstance::Component(i) => json!({
             "id": i.id.0,
             "kind": "Component",
             "component_kind": i.component_kind.0,
             "submaterial": i.submaterial.0,
-            "provenance": {
-                "recipe": i.provenance.recipe_id.0,
-                "consumed": i.provenance.consumed_inputs.iter().map(|ci| json!({
-                    "instance_id": ci.instance_id.0,
-                    "quantity": ci.quantity,
-                })).collect::<Vec<_>>(),
-            }
+            "quality": format!("{:?}", i.quality),
+            "provenance": serialize_provenance(&i.provenance)
         }),
         ItemInstance::Composite(i) => json!({
             "id": i.id.0,
             "kind": "Composite",
             "item": i.definition.0,
             "quality": format!("{:?}", i.quality),
+            "durability": i.durability,
+            "broken": i.is_broken(),
             "components": i.components.iter().map(|(name, comp)| {
                 (name.clone(), json!({
                     "component_kind": comp.component_kind.0,
                     "submaterial": comp.submaterial.0,
                 }))
             }).collect::<serde_json::Map<_, _>>(),
-            "provenance": {
-                "recipe": i.provenance.recipe_id.0,
-                "consumed": i.provenance.consumed_inputs.iter().map(|ci| json!({
-                    "instance_id": ci.instance_id.0,
-                    "quantity": ci.quantity,
-                })).collect::<Vec<_>>(),
-            }
+            "provenance": serialize_provenance(&i.provenance)
         }),
     }
 }
 
+/// Serialize a provenance record to JSON
+fn serialize_provenance(provenance: &Provenance) -> Value {
+    json!({
+        "recipe": provenance.recipe_id.0,
+        "consumed": provenance.consumed_inputs.iter().map(|ci| json!({
+            "instance_id": ci.instance_id.0,
+            "quantity": ci.quantity,
+        })).collect::<Vec<_>>(),
+        "disassembled_from": provenance.disassembled_from.map(|id| id.0),
+    })
+}
+
+/// Serialize a lineage tree to JSON, one object per node with nested `inputs`
+fn serialize_lineage(node: &LineageNode) -> Value {
+    json!({
+        "id": node.instance_id.0,
+        "label": node.instance.as_ref().map(instance_label),
+        "recipe": node.instance.as_ref().map(|i| i.provenance().recipe_id.0.clone()),
+        "quantity": node.quantity,
+        "historical": node.historical,
+        "inputs": node.inputs.iter().map(serialize_lineage).collect::<Vec<_>>(),
+    })
+}
+
+/// Short description of an instance: the item, or the component kind and submaterial
+fn instance_label(instance: &ItemInstance) -> String {
+    match instance {
+        ItemInstance::Simple(i) => i.definition.0.clone(),
+        ItemInstance::Component(i) => format!("{} ({})", i.component_kind.0, i.submaterial.0),
+        ItemInstance::Composite(i) => i.definition.0.clone(),
+    }
+}
+
 /// Format JSON output in human-readable form
 fn format_human_readable(value: &Value) -> String {
     // Enable colors only if stdout is a TTY
@@ -959,13 +1328,18 @@ fn format_human_readable_data(data: &Value) -> String {
                     let id = obj.get("id").and_then(|v| v.as_u64()).map(|v| v.to_string()).unwrap_or_else(|| "?".to_string());
                     let kind = obj.get("kind").and_then(|v| v.as_str()).unwrap_or("?");
                     if let Some(item) = obj.get("item").and_then(|v| v.as_str()) {
-                        output.push_str(&format!("  {} {} {} {} {} {}\n", 
+                        let quantity = match obj.get("quantity").and_then(|v| v.as_u64()) {
+                            Some(qty) if qty > 1 => format!(" x{}", qty),
+                            _ => String::new(),
+                        };
+                        output.push_str(&format!("  {} {} {} {} {} {}{}\n", 
                             format!("[{}]", index).bright_magenta().bold(),
                             format!("Instance #{}", index).white(),
                             format!("(id: {})", id).cyan(),
                             format!("[{}]", kind).yellow(),
                             "->".bright_black(),
-                            item.green()));
+                            item.green(),
+                            quantity.green()));
                     } else if let Some(comp_kind) = obj.get("component_kind").and_then(|v| v.as_str()) {
                         let submaterial = obj.get("submaterial").and_then(|v| v.as_str()).unwrap_or("?");
                         output.push_str(&format!("  {} {} {} {} {} {} {}\n", 
@@ -1040,6 +1414,10 @@ fn format_human_readable_data(data: &Value) -> String {
             if let Some(item) = item_obj.get("item").and_then(|v| v.as_str()) {
                 output.push_str(&format!("Item: {}\n", item));
             }
+
+            if let Some(quantity) = item_obj.get("quantity").and_then(|v| v.as_u64()) {
+                output.push_str(&format!("Quantity: {}\n", quantity));
+            }
             
             if let Some(comp_kind) = item_obj.get("component_kind").and_then(|v| v.as_str()) {
                 output.push_str(&format!("Component Kind: {}\n", comp_kind));
@@ -1052,6 +1430,14 @@ fn format_human_readable_data(data: &Value) -> String {
             if let Some(quality) = item_obj.get("quality").and_then(|v| v.as_str()) {
                 output.push_str(&format!("Quality: {}\n", quality));
             }
+
+            if let Some(durability) = item_obj.get("durability").and_then(|v| v.as_u64()) {
+                if durability == 0 {
+                    output.push_str(&format!("Durability: {}\n", "0 (broken)".red()));
+                } else {
+                    output.push_str(&format!("Durability: {}\n", durability));
+                }
+            }
             
             if let Some(components) = item_obj.get("components").and_then(|v| v.as_object()) {
                 if !components.is_empty() {
@@ -1066,11 +1452,145 @@ fn format_human_readable_data(data: &Value) -> String {
                 }
             }
             
+            if let Some(properties) = item_obj.get("properties").and_then(|v| v.as_object()) {
+                if !properties.is_empty() {
+                    output.push_str("Properties:\n");
+                    for (name, value) in properties {
+                        let value = value.as_f64().unwrap_or(0.0);
+                        output.push_str(&format!("  - {}: {:.2}\n", name, value));
+                    }
+                }
+            }
+
             if let Some(provenance) = item_obj.get("provenance") {
                 output.push_str(&format!("Provenance: {}\n", format_provenance(provenance)));
             }
         }
         
+        // Show find-recipe results
+        if let Some(item) = item_obj.get("item").and_then(|v| v.as_str()) {
+            for (key, label) in [("produced_by", "Produced by"), ("consumed_by", "Consumed by")] {
+                if let Some(recipes) = item_obj.get(key).and_then(|v| v.as_array()) {
+                    output.push_str(&format!("{} {} ({}):\n",
+                        label.bold().cyan(), item.green(), recipes.len().to_string().cyan()));
+                    for recipe in recipes {
+                        let id = recipe.get("id").and_then(|v| v.as_str()).unwrap_or("?");
+                        let name = recipe.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+                        let rtype = recipe.get("type").and_then(|v| v.as_str()).unwrap_or("?");
+                        output.push_str(&format!("  {} {} {}\n",
+                            format!("[{}]", id).cyan().bold(),
+                            name.bold(),
+                            format!("[{}]", rtype).yellow()));
+                    }
+                }
+            }
+        }
+
+        // Show fits results
+        if let Some(recipe) = item_obj.get("fits_recipe").and_then(|v| v.as_str()) {
+            output.push_str(&format!("{} {}:\n", "Slots for".bold().cyan(), recipe.cyan().bold()));
+            for slot in item_obj.get("fits").and_then(|v| v.as_array()).into_iter().flatten() {
+                let name = slot.get("slot").and_then(|v| v.as_str()).unwrap_or("?");
+                let kind = slot.get("component_kind").and_then(|v| v.as_str()).unwrap_or("?");
+                let indices: Vec<String> = slot.get("indices")
+                    .and_then(|v| v.as_array())
+                    .map(|ids| ids.iter().filter_map(|i| i.as_u64()).map(|i| i.to_string()).collect())
+                    .unwrap_or_default();
+                let fitting = if indices.is_empty() {
+                    "none".bright_black().to_string()
+                } else {
+                    indices.join(", ").green().to_string()
+                };
+                output.push_str(&format!("  {} {}: {}\n",
+                    name.bold(),
+                    format!("[{}]", kind).yellow(),
+                    fitting));
+            }
+        }
+
+        // Show preview result
+        if let Some(recipe) = item_obj.get("preview_recipe").and_then(|v| v.as_str()) {
+            let created = item_obj.get("would_create");
+            let item = created
+                .and_then(|c| c.get("item").or_else(|| c.get("component_kind")))
+                .and_then(|v| v.as_str())
+                .unwrap_or("?");
+            let quality = item_obj.get("output_quality").and_then(|v| v.as_str())
+                .map(|q| format!(" [{}]", q))
+                .unwrap_or_default();
+            output.push_str(&format!("{} {} {} {}{}\n",
+                "Preview".green().bold(),
+                recipe.cyan().bold(),
+                "would create".bright_black(),
+                item.bold(),
+                quality.yellow()));
+            let consumed: Vec<String> = item_obj.get("would_consume")
+                .and_then(|v| v.as_array())
+                .map(|inputs| inputs.iter().map(|ci| format!("#{} x{}",
+                    ci.get("instance_id").and_then(|v| v.as_u64()).unwrap_or(0),
+                    ci.get("quantity").and_then(|v| v.as_u64()).unwrap_or(0))).collect())
+                .unwrap_or_default();
+            output.push_str(&format!("{} {}\n", "Would consume:".bright_black(), consumed.join(", ")));
+        }
+
+        // Show craft-many result
+        if let Some(recipe) = item_obj.get("batch_recipe").and_then(|v| v.as_str()) {
+            let requested = item_obj.get("requested").and_then(|v| v.as_u64()).unwrap_or(0);
+            let succeeded = item_obj.get("succeeded").and_then(|v| v.as_u64()).unwrap_or(0);
+            output.push_str(&format!("{} {} {} {}\n",
+                "Crafted".green().bold(),
+                format!("{}/{}", succeeded, requested).cyan(),
+                "using recipe:".bright_black(),
+                recipe.cyan().bold()));
+            if let Some(reason) = item_obj.get("stop_reason").and_then(|v| v.as_str()) {
+                output.push_str(&format!("{}: {}\n", "Stopped".yellow().bold(), reason));
+            }
+        }
+
+        // Show disassemble result
+        if let Some(disassembled) = item_obj.get("disassembled").and_then(|v| v.as_u64()) {
+            let component_ids: Vec<String> = item_obj.get("component_ids")
+                .and_then(|v| v.as_array())
+                .map(|ids| ids.iter().filter_map(|id| id.as_u64()).map(|id| format!("#{}", id)).collect())
+                .unwrap_or_default();
+            output.push_str(&format!("{} {} {} {}\n",
+                "Disassembled".green().bold(),
+                format!("instance #{}", disassembled).cyan(),
+                "into components:".bright_black(),
+                component_ids.join(", ").cyan().bold()));
+        }
+
+        // Show sell result and balance
+        if let Some(balance) = item_obj.get("balance").and_then(|v| v.as_u64()) {
+            if let Some(sold) = item_obj.get("sold").and_then(|v| v.as_u64()) {
+                let value = item_obj.get("value").and_then(|v| v.as_u64()).unwrap_or(0);
+                output.push_str(&format!("{} {} {} {}\n",
+                    "Sold".green().bold(),
+                    format!("instance #{}", sold).cyan(),
+                    "for".bright_black(),
+                    value.to_string().yellow().bold()));
+            }
+            output.push_str(&format!("{} {}\n", "Balance:".bright_black(), balance.to_string().yellow().bold()));
+        }
+
+        // Show lineage tree
+        if let Some(lineage) = item_obj.get("lineage") {
+            output.push_str(&format!("{}\n", "Lineage:".bold().cyan()));
+            format_lineage(lineage, 1, &mut output);
+        }
+
+        // Show save/load results
+        for (key, label) in [("saved", "Saved"), ("loaded", "Loaded")] {
+            if let Some(path) = item_obj.get(key).and_then(|v| v.as_str()) {
+                let instances = item_obj.get("instances").and_then(|v| v.as_u64()).unwrap_or(0);
+                let stations = item_obj.get("stations").and_then(|v| v.as_u64()).unwrap_or(0);
+                output.push_str(&format!("{} {} {}\n",
+                    label.green().bold(),
+                    path.cyan().bold(),
+                    format!("({} instances, {} stations)", instances, stations).bright_black()));
+            }
+        }
+
         // Show commands help
         if let Some(commands) = item_obj.get("commands").and_then(|v| v.as_array()) {
             output.push_str(&format!("{}\n", "Available Commands:".bold().cyan()));
@@ -1116,6 +1636,37 @@ fn format_human_readable_data(data: &Value) -> String {
     }
 }
 
+/// Append one indented line per lineage node, children below their parent
+fn format_lineage(node: &Value, depth: usize, output: &mut String) {
+    let id = node.get("id").and_then(|v| v.as_u64()).map(|v| v.to_string()).unwrap_or_else(|| "?".to_string());
+    let label = node.get("label").and_then(|v| v.as_str()).unwrap_or("unknown");
+    let quantity = match node.get("quantity").and_then(|v| v.as_u64()) {
+        Some(qty) => format!(" x{}", qty),
+        None => String::new(),
+    };
+    let recipe = node.get("recipe").and_then(|v| v.as_str())
+        .map(|r| format!(" [{}]", r))
+        .unwrap_or_default();
+    let historical = if node.get("historical").and_then(|v| v.as_bool()).unwrap_or(false) {
+        " (historical)".bright_black().to_string()
+    } else {
+        String::new()
+    };
+    output.push_str(&format!("{}{} {}{}{}{}\n",
+        "  ".repeat(depth),
+        format!("#{}", id).cyan(),
+        label.green(),
+        quantity.green(),
+        recipe.yellow(),
+        historical));
+
+    if let Some(inputs) = node.get("inputs").and_then(|v| v.as_array()) {
+        for input in inputs {
+            format_lineage(input, depth + 1, output);
+        }
+    }
+}
+
 fn format_kind(kind: &Value) -> String {
     if let Some(obj) = kind.as_object() {
         if let Some(typ) = obj.get("type").and_then(|v| v.as_str()) {
@@ -1202,6 +1753,9 @@ fn format_provenance(provenance: &Value) -> String {
                 }
             }
         }
+        if let Some(source) = obj.get("disassembled_from").and_then(|v| v.as_u64()) {
+            parts.push(format!("Disassembled from: #{}", source));
+        }
         if parts.is_empty() {
             "None".to_string()
         } else {
@@ -1337,6 +1891,7 @@ mod tests {
             recipe_id: "recipe_id".to_string(),
             input_indices: vec![0, 1],
             station_index: None,
+            tool_index: None,
         });
         
         // Show item shorthand
@@ -1355,6 +1910,7 @@ mod tests {
         let cmd = parse_command("n item_id").unwrap();
         assert_eq!(cmd, Command::New {
             item_id: "item_id".to_string(),
+            quality: Quality::Common,
         });
         
         // Help shorthand
@@ -1375,12 +1931,149 @@ mod tests {
         assert_eq!(cmd, Command::ShowItem("copper_ore".to_string()));
     }
 
+    #[test]
+    fn test_parse_find_recipe() {
+        let cmd = parse_command("find-recipe iron_bar").unwrap();
+        assert_eq!(cmd, Command::FindRecipe("iron_bar".to_string()));
+
+        let cmd = parse_command("fr iron_bar").unwrap();
+        assert_eq!(cmd, Command::FindRecipe("iron_bar".to_string()));
+
+        assert!(parse_command("find-recipe").is_err());
+    }
+
+    #[test]
+    fn test_parse_fits() {
+        let cmd = parse_command("fits assemble_scimitar").unwrap();
+        assert_eq!(cmd, Command::Fits("assemble_scimitar".to_string()));
+
+        assert!(parse_command("fits").is_err());
+    }
+
+    #[test]
+    fn test_parse_save_load() {
+        let cmd = parse_command("save inventory.json").unwrap();
+        assert_eq!(cmd, Command::Save { path: "inventory.json".to_string() });
+
+        let cmd = parse_command("load inventory.json").unwrap();
+        assert_eq!(cmd, Command::Load { path: "inventory.json".to_string() });
+
+        assert!(parse_command("save").is_err());
+        assert!(parse_command("load").is_err());
+    }
+
+    #[test]
+    fn test_parse_disassemble() {
+        let cmd = parse_command("disassemble 3").unwrap();
+        assert_eq!(cmd, Command::Disassemble { instance_index: 3 });
+
+        assert!(parse_command("disassemble").is_err());
+        assert!(parse_command("disassemble x").is_err());
+    }
+
+    #[test]
+    fn test_parse_lineage() {
+        let cmd = parse_command("lineage 2").unwrap();
+        assert_eq!(cmd, Command::Lineage { instance_index: 2 });
+        assert_eq!(parse_command("trace 2").unwrap(), cmd);
+
+        assert!(parse_command("lineage").is_err());
+    }
+
+    #[test]
+    fn test_parse_sell_and_balance() {
+        assert_eq!(parse_command("sell 4").unwrap(), Command::Sell { instance_index: 4 });
+        assert!(parse_command("sell").is_err());
+        assert!(parse_command("sell x").is_err());
+        assert_eq!(parse_command("balance").unwrap(), Command::Balance);
+        assert!(parse_command("lineage x").is_err());
+    }
+
+    #[test]
+    fn test_parse_stations_tag() {
+        assert_eq!(parse_command("stations").unwrap(), Command::ListStations { tag: None });
+        assert_eq!(
+            parse_command("stations --tag high_heat").unwrap(),
+            Command::ListStations { tag: Some("high_heat".to_string()) }
+        );
+        assert!(parse_command("stations --tag").is_err());
+        assert!(parse_command("stations high_heat").is_err());
+    }
+
+    #[test]
+    fn test_parse_place_node() {
+        assert_eq!(
+            parse_command("place-node oak_tree").unwrap(),
+            Command::PlaceNode { resource_id: "oak_tree".to_string(), uses: None }
+        );
+        assert_eq!(
+            parse_command("pn oak_tree 3").unwrap(),
+            Command::PlaceNode { resource_id: "oak_tree".to_string(), uses: Some(3) }
+        );
+        assert!(parse_command("place-node").is_err());
+        assert!(parse_command("place-node oak_tree -1").is_err());
+    }
+
+    #[test]
+    fn test_parse_harvest() {
+        assert_eq!(parse_command("harvest 2").unwrap(), Command::Harvest { node_index: 2 });
+        assert!(parse_command("harvest").is_err());
+        assert!(parse_command("harvest tree").is_err());
+    }
+
+    #[test]
+    fn test_parse_craft_with_station_and_tool() {
+        let cmd = parse_command("craft smelt_iron_bar 0 1 @2 +3").unwrap();
+        assert_eq!(cmd, Command::Craft {
+            recipe_id: "smelt_iron_bar".to_string(),
+            input_indices: vec![0, 1],
+            station_index: Some(2),
+            tool_index: Some(3),
+        });
+    }
+
+    #[test]
+    fn test_parse_craft_many() {
+        let cmd = parse_command("craft-many smelt_iron_bar 5 0 @0").unwrap();
+        assert_eq!(cmd, Command::CraftMany {
+            recipe_id: "smelt_iron_bar".to_string(),
+            count: 5,
+            input_indices: vec![0],
+            station_index: Some(0),
+            tool_index: None,
+        });
+
+        assert!(parse_command("cm smelt_iron_bar").is_err());
+        assert!(parse_command("cm smelt_iron_bar many").is_err());
+    }
+
+    #[test]
+    fn test_parse_preview() {
+        let cmd = parse_command("preview smelt_iron_bar 0 1 @2").unwrap();
+        assert_eq!(cmd, Command::Preview {
+            recipe_id: "smelt_iron_bar".to_string(),
+            input_indices: vec![0, 1],
+            station_index: Some(2),
+            tool_index: None,
+        });
+
+        assert!(parse_command("preview").is_err());
+    }
+
     #[test]
     fn test_parse_new() {
         let cmd = parse_command("new copper_ore").unwrap();
         assert_eq!(cmd, Command::New {
             item_id: "copper_ore".to_string(),
+            quality: Quality::Common,
+        });
+
+        let cmd = parse_command("new copper_ore crude").unwrap();
+        assert_eq!(cmd, Command::New {
+            item_id: "copper_ore".to_string(),
+            quality: Quality::Crude,
         });
+        assert!(parse_command("new copper_ore shiny").is_err());
     }
 
     #[test]
@@ -6,21 +6,27 @@
 //! - Components are crafted from submaterials (handle, binding, blade, etc.)
 //! - Composites are assembled from components (sword, pickaxe, etc.)
 
+use std::collections::HashMap;
 use crate::{
     Material, MaterialId, Submaterial, SubmaterialId, ComponentKind, ComponentKindId,
     ItemDefinition, ItemId, ItemKind, CompositeDef, CompositeSlot, CompositeCategory, ToolType,
     SimpleRecipe, ComponentRecipe, CompositeRecipe, SimpleInput, RecipeId,
-    ToolRequirement, WorldObjectRequirement, Quality, Registry,
+    ToolRequirement, WorldObjectRequirement, Quality, QualityFormula, Registry,
 };
 use crate::ids::WorldObjectTag;
 use crate::world_object::WorldObjectKind;
-use crate::ids::CraftingStationId;
+use crate::ids::{CraftingStationId, ResourceNodeId};
 
 /// Helper to create a MaterialId
 fn mat(s: &str) -> MaterialId {
     MaterialId(s.to_string())
 }
 
+/// Helper to build a material property map
+fn props(pairs: &[(&str, f32)]) -> HashMap<String, f32> {
+    pairs.iter().map(|(name, value)| (name.to_string(), *value)).collect()
+}
+
 /// Helper to create a SubmaterialId
 fn submat(s: &str) -> SubmaterialId {
     SubmaterialId(s.to_string())
@@ -36,6 +42,11 @@ fn item(s: &str) -> ItemId {
     ItemId(s.to_string())
 }
 
+/// Helper to create a ResourceNodeId
+fn resource_node(s: &str) -> ResourceNodeId {
+    ResourceNodeId(s.to_string())
+}
+
 /// Helper to create a RecipeId
 fn recipe(s: &str) -> RecipeId {
     RecipeId(s.to_string())
@@ -48,6 +59,16 @@ pub fn register_sample_content(registry: &mut Registry) {
     register_component_kinds(registry);
     register_items(registry);
     register_recipes(registry);
+    register_resource_yields(registry);
+}
+
+fn register_resource_yields(registry: &mut Registry) {
+    registry.register_resource_yield(resource_node("iron_ore_boulder"), item("iron_ore"));
+    registry.register_resource_yield(resource_node("copper_ore_boulder"), item("copper_ore"));
+    registry.register_resource_yield(resource_node("tin_ore_boulder"), item("tin_ore"));
+    registry.register_resource_yield(resource_node("oak_tree"), item("oak_wood"));
+    registry.register_resource_yield(resource_node("yew_tree"), item("yew_wood"));
+    registry.register_resource_yield(resource_node("flint_outcrop"), item("flint"));
 }
 
 fn register_materials(registry: &mut Registry) {
@@ -55,36 +76,42 @@ fn register_materials(registry: &mut Registry) {
         id: mat("leather"),
         name: "Leather".to_string(),
         description: "Flexible hide material from animals".to_string(),
+        properties: props(&[("weight", 0.5), ("hardness", 2.0), ("value", 3.0)]),
     });
 
     registry.register_material(Material {
         id: mat("wood"),
         name: "Wood".to_string(),
         description: "Sturdy timber from trees".to_string(),
+        properties: props(&[("weight", 1.0), ("hardness", 3.0), ("value", 2.0)]),
     });
 
     registry.register_material(Material {
         id: mat("metal"),
         name: "Metal".to_string(),
         description: "Hard metallic materials".to_string(),
+        properties: props(&[("weight", 3.0), ("hardness", 8.0), ("value", 8.0)]),
     });
 
     registry.register_material(Material {
         id: mat("bone"),
         name: "Bone".to_string(),
         description: "Hard skeletal material".to_string(),
+        properties: props(&[("weight", 1.5), ("hardness", 5.0), ("value", 4.0)]),
     });
 
     registry.register_material(Material {
         id: mat("fiber"),
         name: "Fiber".to_string(),
         description: "Flexible cordage and binding materials".to_string(),
+        properties: props(&[("weight", 0.2), ("hardness", 1.0), ("value", 1.0)]),
     });
 
     registry.register_material(Material {
         id: mat("stone"),
         name: "Stone".to_string(),
         description: "Hard rock materials".to_string(),
+        properties: props(&[("weight", 2.5), ("hardness", 6.0), ("value", 2.0)]),
     });
 }
 
@@ -237,6 +264,14 @@ fn register_component_kinds(registry: &mut Registry) {
         makeshift_tags: vec![],
     });
 
+    registry.register_component_kind(ComponentKind {
+        id: comp_kind("hammer_head"),
+        name: "Hammer Head".to_string(),
+        description: "Blunt striking head for smithing".to_string(),
+        accepted_materials: vec![mat("metal"), mat("stone")],
+        makeshift_tags: vec![],
+    });
+
     registry.register_component_kind(ComponentKind {
         id: comp_kind("pommel"),
         name: "Pommel".to_string(),
@@ -448,6 +483,13 @@ fn register_items(registry: &mut Registry) {
         kind: ItemKind::Component { component_kind: comp_kind("hatchet_head") },
     });
 
+    registry.register_item(ItemDefinition {
+        id: item("hammer_head"),
+        name: "Hammer Head".to_string(),
+        description: "Blunt smithing head".to_string(),
+        kind: ItemKind::Component { component_kind: comp_kind("hammer_head") },
+    });
+
     registry.register_item(ItemDefinition {
         id: item("pommel"),
         name: "Pommel".to_string(),
@@ -531,6 +573,20 @@ fn register_items(registry: &mut Registry) {
             tool_type: Some(ToolType::Hatchet),
         }),
     });
+
+    registry.register_item(ItemDefinition {
+        id: item("hammer"),
+        name: "Hammer".to_string(),
+        description: "Smithing tool for shaping metal blades and heads".to_string(),
+        kind: ItemKind::Composite(CompositeDef {
+            slots: vec![
+                CompositeSlot { name: "head".to_string(), component_kind: comp_kind("hammer_head") },
+                CompositeSlot { name: "handle".to_string(), component_kind: comp_kind("handle") },
+            ],
+            category: CompositeCategory::Tool,
+            tool_type: Some(ToolType::Hammer),
+        }),
+    });
 }
 
 fn register_recipes(registry: &mut Registry) {
@@ -630,6 +686,15 @@ fn register_recipes(registry: &mut Registry) {
         world_object: None,
     });
 
+    // No tool required: the first hammer head has to be made without a hammer
+    registry.register_component_recipe(ComponentRecipe {
+        id: recipe("craft_hammer_head"),
+        name: "Craft Hammer Head".to_string(),
+        output: comp_kind("hammer_head"),
+        tool: None,
+        world_object: None,
+    });
+
     registry.register_component_recipe(ComponentRecipe {
         id: recipe("craft_pommel"),
         name: "Craft Pommel".to_string(),
@@ -648,6 +713,7 @@ fn register_recipes(registry: &mut Registry) {
         output: item("scimitar"),
         tool: None,
         world_object: None,
+        quality_formula: QualityFormula::Average,
     });
 
     registry.register_composite_recipe(CompositeRecipe {
@@ -656,6 +722,8 @@ fn register_recipes(registry: &mut Registry) {
         output: item("sword"),
         tool: None,
         world_object: None,
+        // The blade matters most for a sword
+        quality_formula: QualityFormula::WeightedBySlot(vec![("blade".to_string(), 2)]),
     });
 
     registry.register_composite_recipe(CompositeRecipe {
@@ -664,6 +732,7 @@ fn register_recipes(registry: &mut Registry) {
         output: item("knife"),
         tool: None,
         world_object: None,
+        quality_formula: QualityFormula::Average,
     });
 
     registry.register_composite_recipe(CompositeRecipe {
@@ -672,6 +741,7 @@ fn register_recipes(registry: &mut Registry) {
         output: item("pickaxe"),
         tool: None,
         world_object: None,
+        quality_formula: QualityFormula::Average,
     });
 
     registry.register_composite_recipe(CompositeRecipe {
@@ -680,6 +750,16 @@ fn register_recipes(registry: &mut Registry) {
         output: item("hatchet"),
         tool: None,
         world_object: None,
+        quality_formula: QualityFormula::Average,
+    });
+
+    registry.register_composite_recipe(CompositeRecipe {
+        id: recipe("assemble_hammer"),
+        name: "Assemble Hammer".to_string(),
+        output: item("hammer"),
+        tool: None,
+        world_object: None,
+        quality_formula: QualityFormula::Average,
     });
 }
 
@@ -745,4 +825,12 @@ mod tests {
         assert!(handle.accepted_materials.contains(&mat("wood")));
         assert!(handle.accepted_materials.contains(&mat("bone")));
     }
+
+    #[test]
+    fn test_sample_content_validates() {
+        let mut registry = Registry::new();
+        register_sample_content(&mut registry);
+
+        assert_eq!(registry.validate(), Ok(()));
+    }
 }
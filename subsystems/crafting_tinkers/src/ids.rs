@@ -27,9 +27,9 @@ pub struct ResourceNodeId(pub String);
 pub struct CraftingStationId(pub String);
 
 /// Unique ID for a specific item instance
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
 pub struct ItemInstanceId(pub u64);
 
 /// Unique ID for a specific world object instance (resource node or crafting station)
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
 pub struct WorldObjectInstanceId(pub u64);
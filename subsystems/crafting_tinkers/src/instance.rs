@@ -14,16 +14,37 @@ use crate::provenance::Provenance;
 ///
 /// Simple items are the base tier of the crafting system and can be used as inputs
 /// to ComponentRecipes.
+///
+/// Simple instances are stackable: one instance can represent several identical
+/// units (same definition, same provenance). Components and composites are never
+/// stacked.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct SimpleInstance {
     /// Unique runtime ID for this instance
     pub id: ItemInstanceId,
     /// Reference to the item definition (what type of item this is)
     pub definition: ItemId,
+    /// Number of units in this stack (always at least 1 while registered)
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    /// Material quality, passed on to components crafted from this item
+    #[serde(default = "default_quality")]
+    pub quality: Quality,
     /// How this item was created (recipe, inputs, tools, etc.)
     pub provenance: Provenance,
 }
 
+fn default_quantity() -> u32 {
+    1
+}
+
+fn default_quality() -> Quality {
+    Quality::Common
+}
+
+/// Number of uses a freshly assembled tool composite gets before it breaks
+pub const DEFAULT_TOOL_DURABILITY: u32 = 20;
+
 /// Instance of a Component - tracks which submaterial was used.
 ///
 /// Components are crafted parts made from submaterials. They are used exclusively
@@ -47,6 +68,9 @@ pub struct ComponentInstance {
     pub component_kind: ComponentKindId,
     /// The specific submaterial used to craft this component (e.g., "deer_leather", "oak_wood")
     pub submaterial: SubmaterialId,
+    /// Quality of this component, fed into the composite's quality formula
+    #[serde(default = "default_quality")]
+    pub quality: Quality,
     /// How this component was created (recipe, input submaterial, tools, etc.)
     pub provenance: Provenance,
 }
@@ -57,8 +81,8 @@ pub struct ComponentInstance {
 /// multiple components. Each composite has slots that accept specific component kinds.
 ///
 /// # Quality Calculation
-/// Quality is currently defaulted to Common. Future implementation will calculate
-/// quality based on component submaterials and crafting conditions.
+/// Quality is computed from the qualities of the consumed components using the
+/// recipe's `QualityFormula` (Average, Min, or WeightedBySlot).
 ///
 /// # Example
 /// A scimitar composite will have:
@@ -68,7 +92,7 @@ pub struct ComponentInstance {
 ///     "handle" -> ComponentInstance(handle from oak_wood),
 ///     "binding" -> ComponentInstance(binding from deer_leather)
 ///   }
-/// - quality: Common (TODO: calculate from components)
+/// - quality: derived from the three components' qualities
 /// - provenance: tracks the recipe and all consumed component instances
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct CompositeInstance {
@@ -76,14 +100,25 @@ pub struct CompositeInstance {
     pub id: ItemInstanceId,
     /// Reference to the composite item definition (what type of composite this is)
     pub definition: ItemId,
-    /// Quality of this composite (default: Common, TODO: calculate from components)
+    /// Quality of this composite, derived from its components via the recipe's formula
     pub quality: Quality,
+    /// Remaining uses if this composite is a tool (`None` = does not wear out).
+    /// A tool at `Some(0)` is broken and can no longer be used.
+    #[serde(default)]
+    pub durability: Option<u32>,
     /// Map of slot names to the component instances used in each slot
     pub components: HashMap<String, ComponentInstance>,
     /// How this composite was created (recipe, component inputs, tools, etc.)
     pub provenance: Provenance,
 }
 
+impl CompositeInstance {
+    /// Whether this tool has run out of durability
+    pub fn is_broken(&self) -> bool {
+        self.durability == Some(0)
+    }
+}
+
 /// Unified item instance that can be any of the three instance types.
 ///
 /// The crafting system uses this enum to handle all instance types uniformly
@@ -120,6 +155,15 @@ impl ItemInstance {
         }
     }
 
+    /// Replace the unique instance ID regardless of instance type
+    pub fn set_id(&mut self, id: ItemInstanceId) {
+        match self {
+            ItemInstance::Simple(i) => i.id = id,
+            ItemInstance::Component(i) => i.id = id,
+            ItemInstance::Composite(i) => i.id = id,
+        }
+    }
+
     /// Get the provenance regardless of instance type
     pub fn provenance(&self) -> &Provenance {
         match self {
@@ -16,13 +16,13 @@ pub use ids::{
     WorldObjectInstanceId, WorldObjectTag, MaterialId, SubmaterialId, ComponentKindId,
 };
 pub use materials::{Material, Submaterial, ComponentKind};
-pub use instance::{ComponentInstance, ItemInstance, SimpleInstance, CompositeInstance};
+pub use instance::{ComponentInstance, ItemInstance, SimpleInstance, CompositeInstance, DEFAULT_TOOL_DURABILITY};
 pub use item_def::{ItemDefinition, ItemKind, CompositeDef, CompositeSlot, CompositeCategory, ToolType};
 pub use provenance::{ConsumedInput, Provenance};
-pub use quality::Quality;
+pub use quality::{Quality, QualityFormula};
 pub use recipe::{
     SimpleRecipe, ComponentRecipe, CompositeRecipe, SimpleInput,
-    ToolRequirement, WorldObjectRequirement,
+    ToolRequirement, WorldObjectRequirement, RecipeRef,
 };
-pub use registry::Registry;
+pub use registry::{Registry, BatchOutcome, CraftPreview, IdStrategy, LineageNode};
 pub use world_object::{WorldObjectKind, WorldObjectInstance};
@@ -4,11 +4,24 @@ fn main() {
     // Parse command-line arguments
     let args: Vec<String> = std::env::args().collect();
     let human_readable = args.iter().any(|arg| arg == "--human-readable" || arg == "-h");
+    let validate = args.iter().any(|arg| arg == "--validate");
     
     // Initialize registry with sample content
     let mut registry = Registry::new();
     crafting::content::register_sample_content(&mut registry);
     
+    // Optionally check that all content references resolve before starting
+    if validate {
+        if let Err(problems) = registry.validate() {
+            eprintln!("Content validation failed:");
+            for problem in problems {
+                eprintln!("  - {}", problem);
+            }
+            std::process::exit(1);
+        }
+        eprintln!("Content validation passed");
+    }
+    
     // Print welcome message if running interactively
     if atty::is(atty::Stream::Stdin) {
         eprintln!("Crafting System CLI");
@@ -1,11 +1,16 @@
+use std::collections::HashMap;
 use crate::ids::{MaterialId, SubmaterialId, ComponentKindId};
 
 /// Broad material category (e.g., leather, wood, metal)
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Material {
     pub id: MaterialId,
     pub name: String,
     pub description: String,
+    /// Numeric properties per component made of this material (e.g., "weight", "hardness").
+    /// See `Registry::composite_properties` for how they combine.
+    #[serde(default)]
+    pub properties: HashMap<String, f32>,
 }
 
 /// Specific variant of a material - these correspond to actual Simple items
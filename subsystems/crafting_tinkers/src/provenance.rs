@@ -14,6 +14,10 @@ pub struct Provenance {
     
     /// World object instance used (if any) - the kind is determined by the recipe
     pub world_object_used: Option<WorldObjectInstanceId>,
+
+    /// Composite instance this item was recovered from (if it was disassembled)
+    #[serde(default)]
+    pub disassembled_from: Option<ItemInstanceId>,
     
     /// When this was crafted (Unix timestamp)
     pub crafted_at: i64,
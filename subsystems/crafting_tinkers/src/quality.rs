@@ -9,3 +9,137 @@ pub enum Quality {
     Epic = 5,
     Legendary = 6,
 }
+
+impl Quality {
+    /// All tiers from lowest to highest
+    pub const ALL: [Quality; 7] = [
+        Quality::Makeshift,
+        Quality::Crude,
+        Quality::Common,
+        Quality::Uncommon,
+        Quality::Rare,
+        Quality::Epic,
+        Quality::Legendary,
+    ];
+
+    /// Integer rank of this tier (Makeshift = 0 ... Legendary = 6)
+    pub fn ordinal(self) -> u8 {
+        self as u8
+    }
+
+    /// Factor applied to an item's material value when it is sold (Makeshift = 1 ... Legendary = 7)
+    pub fn value_multiplier(self) -> u32 {
+        self.ordinal() as u32 + 1
+    }
+
+    /// Tier for an integer rank, or `None` if out of range
+    pub fn from_ordinal(ordinal: u8) -> Option<Quality> {
+        Quality::ALL.get(ordinal as usize).copied()
+    }
+}
+
+/// How a composite's quality is derived from the qualities of its components
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum QualityFormula {
+    /// Rounded mean of all component qualities
+    #[default]
+    Average,
+    /// The weakest component determines the result
+    Min,
+    /// Rounded weighted mean; slots not listed have weight 1
+    WeightedBySlot(Vec<(String, u32)>),
+}
+
+impl QualityFormula {
+    /// Compute the output quality from `(slot_name, component_quality)` pairs.
+    /// With no components the result is Common.
+    pub fn apply(&self, slots: &[(String, Quality)]) -> Quality {
+        if slots.is_empty() {
+            return Quality::Common;
+        }
+
+        match self {
+            QualityFormula::Average => {
+                let weighted: Vec<(u32, Quality)> = slots.iter().map(|(_, q)| (1, *q)).collect();
+                weighted_mean(&weighted)
+            }
+            QualityFormula::Min => slots.iter().map(|(_, q)| *q).min().unwrap(),
+            QualityFormula::WeightedBySlot(weights) => {
+                let weighted: Vec<(u32, Quality)> = slots.iter()
+                    .map(|(name, q)| {
+                        let weight = weights.iter()
+                            .find(|(slot, _)| slot == name)
+                            .map(|(_, w)| *w)
+                            .unwrap_or(1);
+                        (weight, *q)
+                    })
+                    .collect();
+                weighted_mean(&weighted)
+            }
+        }
+    }
+}
+
+/// Weighted mean of qualities, rounded half up to the nearest tier
+fn weighted_mean(weighted: &[(u32, Quality)]) -> Quality {
+    let total_weight: u32 = weighted.iter().map(|(w, _)| w).sum();
+    if total_weight == 0 {
+        return Quality::Common;
+    }
+    let total: u32 = weighted.iter().map(|(w, q)| w * q.ordinal() as u32).sum();
+    let rounded = (2 * total + total_weight) / (2 * total_weight);
+    Quality::from_ordinal(rounded as u8).unwrap_or(Quality::Legendary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slots(qualities: &[Quality]) -> Vec<(String, Quality)> {
+        qualities.iter().enumerate().map(|(i, q)| (format!("slot{}", i), *q)).collect()
+    }
+
+    #[test]
+    fn test_ordinal_round_trip() {
+        for quality in Quality::ALL {
+            assert_eq!(Quality::from_ordinal(quality.ordinal()), Some(quality));
+        }
+        assert_eq!(Quality::Legendary.ordinal(), 6);
+        assert_eq!(Quality::from_ordinal(7), None);
+    }
+
+    #[test]
+    fn test_average_of_high_inputs_is_high() {
+        let high = slots(&[Quality::Epic, Quality::Epic, Quality::Legendary]);
+        assert_eq!(QualityFormula::Average.apply(&high), Quality::Epic);
+    }
+
+    #[test]
+    fn test_average_rounds_to_nearest() {
+        let mixed = slots(&[Quality::Common, Quality::Uncommon]);
+        assert_eq!(QualityFormula::Average.apply(&mixed), Quality::Uncommon);
+    }
+
+    #[test]
+    fn test_min_dragged_down_by_one_low_input() {
+        let one_low = slots(&[Quality::Legendary, Quality::Crude, Quality::Legendary]);
+        assert_eq!(QualityFormula::Min.apply(&one_low), Quality::Crude);
+        assert_eq!(QualityFormula::Average.apply(&one_low), Quality::Rare);
+    }
+
+    #[test]
+    fn test_weighted_by_slot() {
+        let parts = vec![
+            ("blade".to_string(), Quality::Legendary),
+            ("handle".to_string(), Quality::Makeshift),
+        ];
+        let formula = QualityFormula::WeightedBySlot(vec![("blade".to_string(), 3)]);
+        // (6 * 3 + 0 * 1) / 4 = 4.5 -> Epic
+        assert_eq!(formula.apply(&parts), Quality::Epic);
+    }
+
+    #[test]
+    fn test_no_components_defaults_to_common() {
+        assert_eq!(QualityFormula::Min.apply(&[]), Quality::Common);
+    }
+}
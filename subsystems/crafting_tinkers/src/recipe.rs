@@ -1,6 +1,6 @@
 use crate::ids::{ItemId, RecipeId, ComponentKindId, WorldObjectTag};
 use crate::item_def::ToolType;
-use crate::quality::Quality;
+use crate::quality::{Quality, QualityFormula};
 use crate::world_object::WorldObjectKind;
 
 /// Recipe to create a Simple item (mining, harvesting, smelting, etc.)
@@ -41,6 +41,9 @@ pub struct CompositeRecipe {
     pub output: ItemId,
     pub tool: Option<ToolRequirement>,
     pub world_object: Option<WorldObjectRequirement>,
+    /// How the output quality is derived from the consumed components' qualities
+    #[serde(default)]
+    pub quality_formula: QualityFormula,
     // Inputs are implicit: whatever ComponentKinds the composite's slots require
 }
 
@@ -55,9 +58,65 @@ pub struct WorldObjectRequirement {
     pub required_tags: Vec<WorldObjectTag>,
 }
 
+impl WorldObjectRequirement {
+    /// Human-readable summary for error messages, e.g. "forge station providing [high_heat]"
+    pub fn describe(&self) -> String {
+        let mut description = match &self.kind {
+            Some(WorldObjectKind::CraftingStation(id)) => format!("{} station", id.0),
+            Some(WorldObjectKind::ResourceNode(id)) => format!("{} resource node", id.0),
+            None => "world object".to_string(),
+        };
+        if !self.required_tags.is_empty() {
+            let tags: Vec<&str> = self.required_tags.iter().map(|t| t.0.as_str()).collect();
+            description.push_str(&format!(" providing [{}]", tags.join(", ")));
+        }
+        description
+    }
+}
+
 /// Requirement for a tool in a recipe
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ToolRequirement {
     pub tool_type: ToolType,
     pub min_quality: Quality,
 }
+
+/// Borrowed view over any of the three recipe kinds.
+///
+/// Returned by registry queries that search across simple, component, and
+/// composite recipes at once (e.g. `Registry::recipes_producing`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecipeRef<'a> {
+    Simple(&'a SimpleRecipe),
+    Component(&'a ComponentRecipe),
+    Composite(&'a CompositeRecipe),
+}
+
+impl<'a> RecipeRef<'a> {
+    /// The recipe's ID
+    pub fn id(&self) -> &'a RecipeId {
+        match self {
+            RecipeRef::Simple(r) => &r.id,
+            RecipeRef::Component(r) => &r.id,
+            RecipeRef::Composite(r) => &r.id,
+        }
+    }
+
+    /// The recipe's display name
+    pub fn name(&self) -> &'a str {
+        match self {
+            RecipeRef::Simple(r) => &r.name,
+            RecipeRef::Component(r) => &r.name,
+            RecipeRef::Composite(r) => &r.name,
+        }
+    }
+
+    /// The recipe type as shown in CLI output ("Simple", "Component", "Composite")
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            RecipeRef::Simple(_) => "Simple",
+            RecipeRef::Component(_) => "Component",
+            RecipeRef::Composite(_) => "Composite",
+        }
+    }
+}
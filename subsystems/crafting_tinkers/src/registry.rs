@@ -1,12 +1,87 @@
-use std::collections::HashMap;
-use crate::ids::{ItemId, ItemInstanceId, RecipeId, MaterialId, SubmaterialId, ComponentKindId, WorldObjectInstanceId};
-use crate::instance::{ItemInstance, SimpleInstance, ComponentInstance, CompositeInstance};
-use crate::item_def::{ItemDefinition, ItemKind};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use crate::ids::{ItemId, ItemInstanceId, RecipeId, MaterialId, SubmaterialId, ComponentKindId, ResourceNodeId, WorldObjectInstanceId, WorldObjectTag};
+use crate::instance::{ItemInstance, SimpleInstance, ComponentInstance, CompositeInstance, DEFAULT_TOOL_DURABILITY};
+use crate::item_def::{ItemDefinition, ItemKind, CompositeSlot, CompositeCategory};
 use crate::materials::{Material, Submaterial, ComponentKind};
-use crate::recipe::{SimpleRecipe, ComponentRecipe, CompositeRecipe, WorldObjectRequirement};
+use crate::recipe::{SimpleRecipe, ComponentRecipe, CompositeRecipe, WorldObjectRequirement, ToolRequirement, RecipeRef};
 use crate::provenance::{Provenance, ConsumedInput};
 use crate::quality::Quality;
-use crate::world_object::WorldObjectInstance;
+use crate::world_object::{WorldObjectInstance, WorldObjectKind};
+
+/// Material properties that add up across a composite's components (see
+/// `Registry::composite_properties`). Every other property is averaged.
+const SUMMED_PROPERTIES: &[&str] = &["weight", "value"];
+
+/// Per-unit sale value of a material without a "value" property, and of simple
+/// items that aren't made of a submaterial
+const DEFAULT_MATERIAL_VALUE: f32 = 1.0;
+
+/// Outcome of `Registry::craft_batch`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchOutcome {
+    /// Instances created by the successful crafts, in order
+    pub crafted: Vec<ItemInstanceId>,
+    /// Why the batch stopped before reaching the requested count (None if it completed)
+    pub stop_reason: Option<String>,
+}
+
+impl BatchOutcome {
+    /// Number of crafts that succeeded
+    pub fn succeeded(&self) -> usize {
+        self.crafted.len()
+    }
+}
+
+/// What `Registry::preview_craft` found a craft would do
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CraftPreview {
+    /// The instance the craft would register (including the ID it would get)
+    pub output: ItemInstance,
+    /// Quality of the output (None for Simple items, which have no quality)
+    pub quality: Option<Quality>,
+    /// Instances and quantities the craft would consume
+    pub consumed_inputs: Vec<ConsumedInput>,
+}
+
+/// How `Registry::register_new_instance` picks IDs for new instances
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum IdStrategy {
+    /// Keep the ID handed out by `next_instance_id` (0, 1, 2, ...)
+    #[default]
+    Sequential,
+    /// Derive the ID from a hash of the instance's definition, quality, and
+    /// provenance (ignoring `crafted_at`), so the same craft sequence produces the
+    /// same IDs in every session.
+    ///
+    /// Genuinely identical instances (e.g. two raw ores) hash the same. On a
+    /// collision with any live or historical instance, the hash is salted with a
+    /// counter (1, 2, ...) until it is free, so the Nth identical instance always
+    /// gets the same ID. Hashed IDs use the low 63 bits.
+    ContentHash,
+}
+
+/// One instance in a `Registry::lineage` tree
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineageNode {
+    pub instance_id: ItemInstanceId,
+    /// Live instance or stored snapshot (None if the registry never recorded it,
+    /// e.g. it was consumed before lineage tracking existed)
+    pub instance: Option<ItemInstance>,
+    /// Units the parent craft consumed (None for the root)
+    pub quantity: Option<u32>,
+    /// The instance is no longer in the registry (consumed, disassembled, or removed)
+    pub historical: bool,
+    /// Nodes for the instances this one was crafted from, in provenance order
+    pub inputs: Vec<LineageNode>,
+}
+
+impl LineageNode {
+    /// Number of levels in the tree (1 for an instance with no recorded inputs)
+    pub fn depth(&self) -> usize {
+        1 + self.inputs.iter().map(LineageNode::depth).max().unwrap_or(0)
+    }
+}
 
 /// Central registry for all game content and runtime instances.
 ///
@@ -15,6 +90,7 @@ use crate::world_object::WorldObjectInstance;
 /// - Item definitions (simple, component, composite)
 /// - Recipes (simple, component, composite)
 /// - Runtime item instances with full provenance tracking
+/// - Snapshots of consumed/removed instances, so lineage survives consumption
 ///
 /// # Three-Tier Crafting System
 /// The registry enforces a strict three-tier crafting flow:
@@ -30,6 +106,11 @@ use crate::world_object::WorldObjectInstance;
 /// # Recipe Execution
 /// The registry provides validation and execution methods for all three recipe types,
 /// ensuring material compatibility and slot matching.
+///
+/// # Persistence
+/// The whole registry (content, instances, placed world objects, and ID counters)
+/// can be written to and read from JSON with `save` / `load`.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct Registry {
     // Base content
     materials: HashMap<MaterialId, Material>,
@@ -43,12 +124,25 @@ pub struct Registry {
     composite_recipes: HashMap<RecipeId, CompositeRecipe>,
     
     // Runtime instances
-    instances: HashMap<ItemInstanceId, ItemInstance>,
+    /// Ordered by ID so inventory indices (positions in `all_instances`) are stable
+    instances: BTreeMap<ItemInstanceId, ItemInstance>,
     next_instance_id: u64,
+    /// Last state of every instance that left `instances` (see `lineage`)
+    #[serde(default)]
+    history: HashMap<ItemInstanceId, ItemInstance>,
+    #[serde(default)]
+    id_strategy: IdStrategy,
+    /// Currency earned by selling instances
+    #[serde(default)]
+    balance: u64,
     
     // World object instances
-    world_objects: HashMap<WorldObjectInstanceId, WorldObjectInstance>,
+    /// Ordered by ID so station indices (positions in `all_world_objects`) are stable
+    world_objects: BTreeMap<WorldObjectInstanceId, WorldObjectInstance>,
     next_world_object_id: u64,
+    /// Simple item each kind of resource node yields when harvested
+    #[serde(default)]
+    resource_yields: HashMap<ResourceNodeId, ItemId>,
 }
 
 impl Registry {
@@ -62,13 +156,47 @@ impl Registry {
             simple_recipes: HashMap::new(),
             component_recipes: HashMap::new(),
             composite_recipes: HashMap::new(),
-            instances: HashMap::new(),
+            instances: BTreeMap::new(),
             next_instance_id: 0,
-            world_objects: HashMap::new(),
+            history: HashMap::new(),
+            id_strategy: IdStrategy::Sequential,
+            balance: 0,
+            world_objects: BTreeMap::new(),
             next_world_object_id: 0,
+            resource_yields: HashMap::new(),
         }
     }
 
+    /// Save the registry to a JSON file.
+    ///
+    /// Persists all content plus runtime state: item instances, placed world
+    /// objects, and the next-ID counters.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Load a registry previously written with `save`.
+    ///
+    /// The ID counters are bumped past the highest loaded instance and world
+    /// object IDs, so newly created instances never collide with loaded ones
+    /// even if the file was edited by hand.
+    pub fn load(path: &str) -> Result<Registry, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let mut registry: Registry = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse {}: {}", path, e))?;
+
+        if let Some(max_id) = registry.instances.keys().chain(registry.history.keys()).map(|id| id.0).max() {
+            registry.next_instance_id = registry.next_instance_id.max(max_id + 1);
+        }
+        if let Some(max_id) = registry.world_objects.keys().map(|id| id.0).max() {
+            registry.next_world_object_id = registry.next_world_object_id.max(max_id + 1);
+        }
+
+        Ok(registry)
+    }
+
     /// Register a material (broad category like "leather", "wood", "metal")
     pub fn register_material(&mut self, material: Material) {
         self.materials.insert(material.id.clone(), material);
@@ -109,9 +237,251 @@ impl Registry {
         self.instances.insert(instance.id(), instance);
     }
 
+    /// Consume `quantity` units of an instance.
+    ///
+    /// Simple instances are decremented and only removed once their stack hits
+    /// zero. Components and composites are unstackable, so they can only be
+    /// consumed whole (quantity 1).
+    pub fn consume_instance(&mut self, id: ItemInstanceId, quantity: u32) -> Result<(), String> {
+        let instance = self.instances.get_mut(&id)
+            .ok_or_else(|| format!("Instance {:?} not found", id))?;
+
+        match instance {
+            ItemInstance::Simple(si) => {
+                if quantity > si.quantity {
+                    return Err(format!(
+                        "Cannot consume {} of instance {:?}: stack only has {}",
+                        quantity, id, si.quantity
+                    ));
+                }
+                si.quantity -= quantity;
+                if si.quantity == 0 {
+                    self.archive_instance(id);
+                }
+            }
+            _ => {
+                if quantity != 1 {
+                    return Err(format!("Instance {:?} is not stackable", id));
+                }
+                self.archive_instance(id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Consume every input recorded in a provenance (see `consume_instance`)
+    pub fn consume_inputs(&mut self, provenance: &Provenance) -> Result<(), String> {
+        for input in &provenance.consumed_inputs {
+            self.consume_instance(input.instance_id, input.quantity)?;
+        }
+        Ok(())
+    }
+
+    /// Merge simple instances of the same definition and identical provenance
+    /// into single stacks.
+    ///
+    /// The lowest instance ID in each group survives and receives the combined
+    /// quantity. Components and composites are never merged. Returns the number
+    /// of instances that were folded into another stack.
+    pub fn merge_stacks(&mut self) -> usize {
+        let mut simple_ids: Vec<ItemInstanceId> = self.instances.values()
+            .filter(|inst| matches!(inst, ItemInstance::Simple(_)))
+            .map(|inst| inst.id())
+            .collect();
+        simple_ids.sort_by_key(|id| id.0);
+
+        let mut merged = 0;
+        for (i, &base_id) in simple_ids.iter().enumerate() {
+            let base = match self.instances.get(&base_id) {
+                Some(ItemInstance::Simple(si)) => si.clone(),
+                _ => continue, // already merged into an earlier stack
+            };
+
+            let mut total = base.quantity;
+            for other_id in &simple_ids[i + 1..] {
+                if let Some(ItemInstance::Simple(other)) = self.instances.get(other_id) {
+                    if other.definition == base.definition && other.quality == base.quality && other.provenance == base.provenance {
+                        total += other.quantity;
+                        self.archive_instance(*other_id);
+                        merged += 1;
+                    }
+                }
+            }
+
+            if let Some(ItemInstance::Simple(si)) = self.instances.get_mut(&base_id) {
+                si.quantity = total;
+            }
+        }
+        merged
+    }
+
+    /// Register a newly created instance, assigning its ID under the registry's
+    /// `IdStrategy`. Returns the ID it was registered with.
+    ///
+    /// Under `Sequential` the instance keeps the ID it was built with (from
+    /// `next_instance_id`); under `ContentHash` that ID is replaced.
+    pub fn register_new_instance(&mut self, mut instance: ItemInstance) -> ItemInstanceId {
+        if self.id_strategy == IdStrategy::ContentHash {
+            let id = self.content_hash_id(&instance);
+            instance.set_id(id);
+        }
+        let id = instance.id();
+        self.register_instance(instance);
+        id
+    }
+
+    /// Content-derived ID for `instance`, salted past any ID already in use
+    fn content_hash_id(&self, instance: &ItemInstance) -> ItemInstanceId {
+        let mut provenance = instance.provenance().clone();
+        provenance.crafted_at = 0;
+        let content = match instance {
+            ItemInstance::Simple(i) => serde_json::to_string(&("simple", &i.definition, i.quality, &provenance)),
+            ItemInstance::Component(i) => serde_json::to_string(&(
+                "component", &i.component_kind, &i.submaterial, i.quality, &provenance,
+            )),
+            ItemInstance::Composite(i) => serde_json::to_string(&("composite", &i.definition, i.quality, &provenance)),
+        }
+        .expect("instance content serializes");
+
+        (0u64..)
+            .map(|salt| ItemInstanceId(fnv1a(format!("{}#{}", content, salt).as_bytes()) >> 1))
+            .find(|id| !self.instances.contains_key(id) && !self.history.contains_key(id))
+            .expect("some salt is free")
+    }
+
+    /// Set how new instance IDs are chosen (see `IdStrategy`)
+    pub fn set_id_strategy(&mut self, strategy: IdStrategy) {
+        self.id_strategy = strategy;
+    }
+
+    /// How new instance IDs are chosen
+    pub fn id_strategy(&self) -> IdStrategy {
+        self.id_strategy
+    }
+
     /// Remove an item instance from the registry
     pub fn remove_instance(&mut self, id: ItemInstanceId) -> Option<ItemInstance> {
-        self.instances.remove(&id)
+        self.archive_instance(id)
+    }
+
+    /// Discard the instance at an inventory index (its position in `all_instances`).
+    ///
+    /// Unlike crafting consumption, the instance is dropped outright: no snapshot is
+    /// kept for `lineage`. Instances are ordered by ID, so the rest keep their relative
+    /// order and every index after the destroyed one shifts down by one.
+    pub fn destroy(&mut self, index: usize) -> Result<(), String> {
+        let id = self.instance_at(index)?;
+        self.instances.remove(&id);
+        Ok(())
+    }
+
+    /// Destroy the instance at an inventory index and add its `instance_value` to the
+    /// balance. Returns the value gained. Indices shift as with `destroy`.
+    pub fn sell(&mut self, index: usize) -> Result<u64, String> {
+        let id = self.instance_at(index)?;
+        let value = self.instance_value(id)?;
+        self.destroy(index)?;
+        self.balance = self.balance.saturating_add(value);
+        Ok(value)
+    }
+
+    /// Currency earned from `sell`
+    pub fn balance(&self) -> u64 {
+        self.balance
+    }
+
+    /// Sale value of an instance, derived from its materials and quality.
+    ///
+    /// A material is worth its "value" property per unit (`DEFAULT_MATERIAL_VALUE` if
+    /// it has none). Simple stacks are worth their material times quantity times
+    /// `Quality::value_multiplier`; components their material times the multiplier; composites the sum of their
+    /// components' materials times the composite's multiplier. Results are rounded,
+    /// and every instance is worth at least 1.
+    pub fn instance_value(&self, id: ItemInstanceId) -> Result<u64, String> {
+        let value = match self.get_instance(id) {
+            Some(ItemInstance::Simple(si)) => {
+                let unit = match self.get_item(&si.definition).map(|def| &def.kind) {
+                    Some(ItemKind::Simple { submaterial: Some(submaterial) }) => self.material_value(submaterial)?,
+                    _ => DEFAULT_MATERIAL_VALUE,
+                };
+                unit * si.quantity as f32 * si.quality.value_multiplier() as f32
+            }
+            Some(ItemInstance::Component(ci)) => {
+                self.material_value(&ci.submaterial)? * ci.quality.value_multiplier() as f32
+            }
+            Some(ItemInstance::Composite(ci)) => {
+                let mut materials = 0.0;
+                for component in ci.components.values() {
+                    materials += self.material_value(&component.submaterial)?;
+                }
+                materials * ci.quality.value_multiplier() as f32
+            }
+            None => return Err(format!("Instance {:?} not found", id)),
+        };
+        Ok((value.round() as u64).max(1))
+    }
+
+    /// Per-unit value of a submaterial's parent material
+    fn material_value(&self, submaterial: &SubmaterialId) -> Result<f32, String> {
+        let submaterial = self.get_submaterial(submaterial)
+            .ok_or_else(|| format!("Submaterial {:?} not found", submaterial))?;
+        let material = self.get_material(&submaterial.material)
+            .ok_or_else(|| format!("Material {:?} not found", submaterial.material))?;
+        Ok(material.properties.get("value").copied().unwrap_or(DEFAULT_MATERIAL_VALUE))
+    }
+
+    /// ID of the instance at an inventory index (its position in `all_instances`)
+    fn instance_at(&self, index: usize) -> Result<ItemInstanceId, String> {
+        self.all_instances()
+            .nth(index)
+            .map(|instance| instance.id())
+            .ok_or_else(|| format!(
+                "Invalid inventory index: {}. Inventory has {} items",
+                index, self.instances.len()
+            ))
+    }
+
+    /// Remove an instance, keeping a snapshot in the history for `lineage`
+    fn archive_instance(&mut self, id: ItemInstanceId) -> Option<ItemInstance> {
+        let instance = self.instances.remove(&id)?;
+        self.history.insert(id, instance.clone());
+        Some(instance)
+    }
+
+    /// Resolve the full ancestry of an instance into a tree.
+    ///
+    /// Each node's inputs are the instances its provenance consumed. Consumed
+    /// ancestors are no longer registered, so they are read from the snapshots
+    /// taken when they left the registry and marked `historical`. Tools and world
+    /// objects are not part of the lineage since they are not consumed.
+    pub fn lineage(&self, instance: ItemInstanceId) -> LineageNode {
+        self.lineage_node(instance, None, &mut Vec::new())
+    }
+
+    fn lineage_node(
+        &self,
+        id: ItemInstanceId,
+        quantity: Option<u32>,
+        path: &mut Vec<ItemInstanceId>,
+    ) -> LineageNode {
+        let (instance, historical) = match self.instances.get(&id) {
+            Some(live) => (Some(live.clone()), false),
+            None => (self.history.get(&id).cloned(), true),
+        };
+
+        // Guard against cycles in hand-edited saves
+        let mut inputs = Vec::new();
+        if let Some(instance) = &instance {
+            if !path.contains(&id) {
+                path.push(id);
+                inputs = instance.provenance().consumed_inputs.iter()
+                    .map(|input| self.lineage_node(input.instance_id, Some(input.quantity), path))
+                    .collect();
+                path.pop();
+            }
+        }
+
+        LineageNode { instance_id: id, instance, quantity, historical, inputs }
     }
 
     /// Get a material by ID
@@ -149,6 +519,13 @@ impl Registry {
         self.composite_recipes.get(id)
     }
 
+    /// Get a recipe of any kind by ID
+    pub fn get_recipe(&self, id: &RecipeId) -> Option<RecipeRef<'_>> {
+        self.get_simple_recipe(id).map(RecipeRef::Simple)
+            .or_else(|| self.get_component_recipe(id).map(RecipeRef::Component))
+            .or_else(|| self.get_composite_recipe(id).map(RecipeRef::Composite))
+    }
+
     /// Get an item instance by ID
     pub fn get_instance(&self, id: ItemInstanceId) -> Option<&ItemInstance> {
         self.instances.get(&id)
@@ -196,7 +573,7 @@ impl Registry {
         self.composite_recipes.values()
     }
 
-    /// Iterate over all item instances
+    /// Iterate over all item instances in ID order; inventory indices are positions here
     pub fn all_instances(&self) -> impl Iterator<Item = &ItemInstance> {
         self.instances.values()
     }
@@ -218,11 +595,113 @@ impl Registry {
         self.world_objects.get(&id)
     }
 
-    /// Iterate over all world object instances
+    /// Iterate over all world object instances in ID order; station indices are positions here
     pub fn all_world_objects(&self) -> impl Iterator<Item = &WorldObjectInstance> {
         self.world_objects.values()
     }
 
+    /// Set the simple item harvested from resource nodes of kind `node`
+    pub fn register_resource_yield(&mut self, node: ResourceNodeId, item: ItemId) {
+        self.resource_yields.insert(node, item);
+    }
+
+    /// Item harvested from resource nodes of kind `node`, if registered
+    pub fn resource_yield(&self, node: &ResourceNodeId) -> Option<&ItemId> {
+        self.resource_yields.get(node)
+    }
+
+    /// Harvest one unit from the resource node at a station index (its position in
+    /// `all_world_objects`), registering a new simple instance of the node's yield.
+    ///
+    /// The instance's provenance records the node in `world_object_used`. Nodes with
+    /// `remaining_uses` lose one use per harvest and fail once they reach zero; the
+    /// exhausted node stays placed so station indices don't shift.
+    pub fn harvest(&mut self, node_index: usize) -> Result<ItemInstanceId, String> {
+        let node = self.all_world_objects()
+            .nth(node_index)
+            .ok_or_else(|| format!(
+                "Invalid station index: {}. There are {} placed world objects",
+                node_index, self.world_objects.len()
+            ))?;
+        let WorldObjectKind::ResourceNode(resource) = &node.kind else {
+            return Err(format!("World object {:?} is not a resource node", node.kind));
+        };
+        if node.remaining_uses == Some(0) {
+            return Err(format!("Resource node {:?} is exhausted", resource.0));
+        }
+        let item = self.resource_yield(resource)
+            .ok_or_else(|| format!("Resource node {:?} has no registered yield", resource.0))?
+            .clone();
+        if !matches!(self.get_item(&item).map(|def| &def.kind), Some(ItemKind::Simple { .. })) {
+            return Err(format!("Resource node {:?} yields {:?}, which is not a Simple item", resource.0, item.0));
+        }
+        let node_id = node.id;
+
+        let instance_id = self.next_instance_id();
+        let instance = ItemInstance::Simple(SimpleInstance {
+            id: instance_id,
+            definition: item,
+            quantity: 1,
+            quality: Quality::Common,
+            provenance: Provenance {
+                recipe_id: RecipeId("harvest".to_string()),
+                consumed_inputs: vec![],
+                tool_used: None,
+                world_object_used: Some(node_id),
+                disassembled_from: None,
+                crafted_at: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs() as i64,
+            },
+        });
+        let instance_id = self.register_new_instance(instance);
+
+        if let Some(uses) = self.world_objects.get_mut(&node_id).and_then(|wo| wo.remaining_uses.as_mut()) {
+            *uses -= 1;
+        }
+        Ok(instance_id)
+    }
+
+    /// All world object instances providing `tag`, sorted by ID
+    pub fn world_objects_with_tag(&self, tag: &WorldObjectTag) -> Vec<&WorldObjectInstance> {
+        let mut objects: Vec<&WorldObjectInstance> = self.world_objects.values()
+            .filter(|wo| wo.has_tag(tag))
+            .collect();
+        objects.sort_by_key(|wo| wo.id.0);
+        objects
+    }
+
+    /// All composite item definitions in `category`, sorted by ID
+    pub fn items_in_category(&self, category: &CompositeCategory) -> Vec<&ItemDefinition> {
+        let mut items: Vec<&ItemDefinition> = self.item_definitions.values()
+            .filter(|item| matches!(&item.kind, ItemKind::Composite(def) if &def.category == category))
+            .collect();
+        items.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+        items
+    }
+
+    /// Validate the world object (station) used for a recipe against its requirement
+    ///
+    /// Recipes without a requirement accept any station or none. A recipe with a
+    /// requirement fails unless a placed world object meeting it is provided.
+    pub fn validate_world_object(
+        &self,
+        world_object_used: Option<WorldObjectInstanceId>,
+        requirement: Option<&WorldObjectRequirement>,
+    ) -> Result<(), String> {
+        let Some(requirement) = requirement else {
+            return Ok(());
+        };
+        match world_object_used {
+            Some(wo_id) => self.validate_world_object_requirement(wo_id, requirement),
+            None => Err(format!(
+                "Recipe requires a {}, but no station was provided",
+                requirement.describe()
+            )),
+        }
+    }
+
     /// Validate that a world object instance meets the requirements
     pub fn validate_world_object_requirement(
         &self,
@@ -236,8 +715,8 @@ impl Registry {
         if let Some(ref required_kind) = requirement.kind {
             if &world_object.kind != required_kind {
                 return Err(format!(
-                    "World object kind mismatch: required {:?}, got {:?}",
-                    required_kind, world_object.kind
+                    "World object kind mismatch: required {:?}, got {:?} (recipe requires a {})",
+                    required_kind, world_object.kind, requirement.describe()
                 ));
             }
         }
@@ -246,8 +725,408 @@ impl Registry {
         for required_tag in &requirement.required_tags {
             if !world_object.tags.contains(required_tag) {
                 return Err(format!(
-                    "World object missing required tag: {:?}",
-                    required_tag
+                    "World object {:?} is missing required tag {:?} (recipe requires a {})",
+                    world_object.kind, required_tag.0, requirement.describe()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Disassemble a composite back into its components.
+    ///
+    /// The composite instance is removed and a fresh ComponentInstance is registered
+    /// for each slot, carrying over the slot's component kind and submaterial. The
+    /// recovered components' provenance records the composite in `disassembled_from`.
+    /// Returns the new component instance IDs in slot order.
+    pub fn disassemble(&mut self, instance: ItemInstanceId) -> Result<Vec<ItemInstanceId>, String> {
+        let composite = match self.get_instance(instance) {
+            Some(ItemInstance::Composite(ci)) => ci.clone(),
+            Some(_) => return Err(format!("Instance {:?} is not a Composite and cannot be disassembled", instance)),
+            None => return Err(format!("Instance {:?} not found", instance)),
+        };
+
+        // Recover components in the definition's slot order when available
+        let mut slot_names: Vec<String> = match self.get_item(&composite.definition).map(|def| &def.kind) {
+            Some(ItemKind::Composite(def)) => def.slots.iter()
+                .map(|slot| slot.name.clone())
+                .filter(|name| composite.components.contains_key(name))
+                .collect(),
+            _ => Vec::new(),
+        };
+        if slot_names.len() != composite.components.len() {
+            slot_names = composite.components.keys().cloned().collect();
+            slot_names.sort();
+        }
+
+        self.archive_instance(instance);
+
+        let crafted_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let mut recovered = Vec::new();
+        for slot_name in slot_names {
+            let component = &composite.components[&slot_name];
+            let id = self.next_instance_id();
+            let id = self.register_new_instance(ItemInstance::Component(ComponentInstance {
+                id,
+                component_kind: component.component_kind.clone(),
+                submaterial: component.submaterial.clone(),
+                quality: component.quality,
+                provenance: Provenance {
+                    recipe_id: RecipeId("disassemble".to_string()),
+                    consumed_inputs: vec![ConsumedInput {
+                        instance_id: instance,
+                        quantity: 1,
+                    }],
+                    tool_used: None,
+                    world_object_used: None,
+                    disassembled_from: Some(instance),
+                    crafted_at,
+                },
+            }));
+            recovered.push(id);
+        }
+
+        Ok(recovered)
+    }
+
+    /// Check that every reference in the registered content resolves.
+    ///
+    /// Covers submaterial parents, component kind materials, item definitions
+    /// (submaterials, component kinds, composite slots), and every recipe's inputs,
+    /// outputs, and tool requirements (some registered composite must provide the
+    /// tool type). Returns all problems found, sorted, rather than stopping at the first.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        for submaterial in self.all_submaterials() {
+            if self.get_material(&submaterial.material).is_none() {
+                problems.push(format!(
+                    "Submaterial {:?} references unknown material {:?}",
+                    submaterial.id.0, submaterial.material.0
+                ));
+            }
+        }
+
+        for kind in self.all_component_kinds() {
+            for material in &kind.accepted_materials {
+                if self.get_material(material).is_none() {
+                    problems.push(format!(
+                        "Component kind {:?} accepts unknown material {:?}",
+                        kind.id.0, material.0
+                    ));
+                }
+            }
+        }
+
+        for item in self.all_items() {
+            match &item.kind {
+                ItemKind::Simple { submaterial: Some(submat_id) } => {
+                    if self.get_submaterial(submat_id).is_none() {
+                        problems.push(format!(
+                            "Item {:?} references unknown submaterial {:?}",
+                            item.id.0, submat_id.0
+                        ));
+                    }
+                }
+                ItemKind::Simple { submaterial: None } => {}
+                ItemKind::Component { component_kind } => {
+                    if self.get_component_kind(component_kind).is_none() {
+                        problems.push(format!(
+                            "Item {:?} references unknown component kind {:?}",
+                            item.id.0, component_kind.0
+                        ));
+                    }
+                }
+                ItemKind::Composite(def) => {
+                    for slot in &def.slots {
+                        if self.get_component_kind(&slot.component_kind).is_none() {
+                            problems.push(format!(
+                                "Composite {:?} slot {:?} references unknown component kind {:?}",
+                                item.id.0, slot.name, slot.component_kind.0
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (node, item) in &self.resource_yields {
+            if self.get_item(item).is_none() {
+                problems.push(format!(
+                    "Resource node {:?} yields unknown item {:?}",
+                    node.0, item.0
+                ));
+            }
+        }
+
+        for recipe in self.all_simple_recipes() {
+            if self.get_item(&recipe.output).is_none() {
+                problems.push(format!(
+                    "Recipe {:?} outputs unknown item {:?}",
+                    recipe.id.0, recipe.output.0
+                ));
+            }
+            for input in &recipe.inputs {
+                if self.get_item(&input.item_id).is_none() {
+                    problems.push(format!(
+                        "Recipe {:?} consumes unknown item {:?}",
+                        recipe.id.0, input.item_id.0
+                    ));
+                }
+            }
+            self.validate_tool_requirement(&recipe.id, recipe.tool.as_ref(), &mut problems);
+        }
+
+        for recipe in self.all_component_recipes() {
+            if self.get_component_kind(&recipe.output).is_none() {
+                problems.push(format!(
+                    "Recipe {:?} outputs unknown component kind {:?}",
+                    recipe.id.0, recipe.output.0
+                ));
+            }
+            self.validate_tool_requirement(&recipe.id, recipe.tool.as_ref(), &mut problems);
+        }
+
+        for recipe in self.all_composite_recipes() {
+            match self.get_item(&recipe.output).map(|def| &def.kind) {
+                Some(ItemKind::Composite(_)) => {}
+                Some(_) => problems.push(format!(
+                    "Recipe {:?} outputs {:?}, which is not a Composite item",
+                    recipe.id.0, recipe.output.0
+                )),
+                None => problems.push(format!(
+                    "Recipe {:?} outputs unknown item {:?}",
+                    recipe.id.0, recipe.output.0
+                )),
+            }
+            self.validate_tool_requirement(&recipe.id, recipe.tool.as_ref(), &mut problems);
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            problems.sort();
+            Err(problems)
+        }
+    }
+
+    /// Record a problem if no registered composite provides the required tool type
+    fn validate_tool_requirement(
+        &self,
+        recipe_id: &RecipeId,
+        requirement: Option<&ToolRequirement>,
+        problems: &mut Vec<String>,
+    ) {
+        let Some(requirement) = requirement else {
+            return;
+        };
+        let provided = self.all_items().any(|item| match &item.kind {
+            ItemKind::Composite(def) => def.tool_type.as_ref() == Some(&requirement.tool_type),
+            _ => false,
+        });
+        if !provided {
+            problems.push(format!(
+                "Recipe {:?} requires tool {:?}, but no registered item provides it",
+                recipe_id.0, requirement.tool_type
+            ));
+        }
+    }
+
+    // Recipe search
+
+    /// Find every recipe whose output is the given item.
+    ///
+    /// Simple and composite recipes match on their output item directly. Component
+    /// recipes output a component kind, so they match when `item` is a Component
+    /// item definition of that kind. Results are sorted by recipe ID.
+    pub fn recipes_producing(&self, item: &ItemId) -> Vec<RecipeRef<'_>> {
+        let component_kind = match self.get_item(item).map(|def| &def.kind) {
+            Some(ItemKind::Component { component_kind }) => Some(component_kind),
+            _ => None,
+        };
+
+        let mut recipes: Vec<RecipeRef<'_>> = Vec::new();
+        recipes.extend(self.all_simple_recipes()
+            .filter(|r| &r.output == item)
+            .map(RecipeRef::Simple));
+        recipes.extend(self.all_component_recipes()
+            .filter(|r| component_kind == Some(&r.output))
+            .map(RecipeRef::Component));
+        recipes.extend(self.all_composite_recipes()
+            .filter(|r| &r.output == item)
+            .map(RecipeRef::Composite));
+
+        recipes.sort_by(|a, b| a.id().0.cmp(&b.id().0));
+        recipes
+    }
+
+    /// Find every recipe that can consume the given item as an input.
+    ///
+    /// - Simple recipes match when the item is listed in their inputs
+    /// - Component recipes match when the item is a submaterial item whose parent
+    ///   material is accepted by the recipe's component kind
+    /// - Composite recipes match when the item is a Component item whose kind fills
+    ///   one of the output composite's slots
+    ///
+    /// Results are sorted by recipe ID.
+    pub fn recipes_consuming(&self, item: &ItemId) -> Vec<RecipeRef<'_>> {
+        let item_kind = self.get_item(item).map(|def| &def.kind);
+
+        // Parent material of the item, if it is a submaterial item
+        let material = match item_kind {
+            Some(ItemKind::Simple { submaterial: Some(submat_id) }) => {
+                self.get_submaterial(submat_id).map(|s| &s.material)
+            }
+            _ => None,
+        };
+
+        // Component kind of the item, if it is a component item
+        let component_kind = match item_kind {
+            Some(ItemKind::Component { component_kind }) => Some(component_kind),
+            _ => None,
+        };
+
+        let mut recipes: Vec<RecipeRef<'_>> = Vec::new();
+        recipes.extend(self.all_simple_recipes()
+            .filter(|r| r.inputs.iter().any(|input| &input.item_id == item))
+            .map(RecipeRef::Simple));
+
+        if let Some(material) = material {
+            recipes.extend(self.all_component_recipes()
+                .filter(|r| self.get_component_kind(&r.output)
+                    .is_some_and(|kind| kind.accepted_materials.contains(material)))
+                .map(RecipeRef::Component));
+        }
+
+        if let Some(component_kind) = component_kind {
+            recipes.extend(self.all_composite_recipes()
+                .filter(|r| match self.get_item(&r.output).map(|def| &def.kind) {
+                    Some(ItemKind::Composite(def)) => {
+                        def.slots.iter().any(|slot| &slot.component_kind == component_kind)
+                    }
+                    _ => false,
+                })
+                .map(RecipeRef::Composite));
+        }
+
+        recipes.sort_by(|a, b| a.id().0.cmp(&b.id().0));
+        recipes
+    }
+
+    /// Find component instances that can fill a composite slot.
+    ///
+    /// An instance fits when its component kind is the slot's kind and its
+    /// submaterial's parent material is accepted by that kind. Results are sorted
+    /// by instance ID.
+    pub fn compatible_instances(&self, slot: &CompositeSlot) -> Vec<ItemInstanceId> {
+        let Some(kind) = self.get_component_kind(&slot.component_kind) else {
+            return Vec::new();
+        };
+
+        let mut ids: Vec<ItemInstanceId> = self.all_instances()
+            .filter_map(|inst| match inst {
+                ItemInstance::Component(ci) => Some(ci),
+                _ => None,
+            })
+            .filter(|ci| ci.component_kind == slot.component_kind)
+            .filter(|ci| self.get_submaterial(&ci.submaterial)
+                .is_some_and(|s| kind.accepted_materials.contains(&s.material)))
+            .map(|ci| ci.id)
+            .collect();
+        ids.sort_by_key(|id| id.0);
+        ids
+    }
+
+    /// Derive numeric properties of a composite instance from its components' materials.
+    ///
+    /// Each component contributes the properties of its submaterial's parent material:
+    /// - Properties in `SUMMED_PROPERTIES` (e.g. "weight") are summed over all components
+    /// - Every other property (e.g. "hardness") is the mean over the components whose
+    ///   material defines it
+    ///
+    /// Fails if the instance is not a composite or a component's submaterial or
+    /// material is not registered.
+    pub fn composite_properties(&self, instance: ItemInstanceId) -> Result<HashMap<String, f32>, String> {
+        let composite = match self.get_instance(instance) {
+            Some(ItemInstance::Composite(c)) => c,
+            Some(_) => return Err(format!("Instance {:?} is not a composite", instance)),
+            None => return Err(format!("Instance {:?} not found", instance)),
+        };
+
+        // Running (total, count) for each property
+        let mut totals: HashMap<String, (f32, u32)> = HashMap::new();
+        for component in composite.components.values() {
+            let submaterial = self.get_submaterial(&component.submaterial)
+                .ok_or_else(|| format!("Submaterial {:?} not found", component.submaterial))?;
+            let material = self.get_material(&submaterial.material)
+                .ok_or_else(|| format!("Material {:?} not found", submaterial.material))?;
+            for (name, value) in &material.properties {
+                let entry = totals.entry(name.clone()).or_insert((0.0, 0));
+                entry.0 += value;
+                entry.1 += 1;
+            }
+        }
+
+        Ok(totals.into_iter()
+            .map(|(name, (total, count))| {
+                let value = if SUMMED_PROPERTIES.contains(&name.as_str()) {
+                    total
+                } else {
+                    total / count as f32
+                };
+                (name, value)
+            })
+            .collect())
+    }
+
+    /// Validate a tool against a recipe's tool requirement
+    ///
+    /// Tools are optional (hand-crafting is allowed), but a tool that is provided must
+    /// be a tool composite of the required type and quality, and must not be broken.
+    pub fn validate_tool(
+        &self,
+        tool_used: Option<ItemInstanceId>,
+        requirement: Option<&ToolRequirement>,
+    ) -> Result<(), String> {
+        let Some(tool_id) = tool_used else {
+            return Ok(());
+        };
+
+        let tool = match self.get_instance(tool_id) {
+            Some(ItemInstance::Composite(ci)) => ci,
+            Some(_) => return Err(format!("Instance {:?} is not a tool", tool_id)),
+            None => return Err(format!("Tool instance {:?} not found", tool_id)),
+        };
+
+        let tool_type = match self.get_item(&tool.definition).map(|def| &def.kind) {
+            Some(ItemKind::Composite(def)) => def.tool_type.as_ref(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("Item {:?} is not a tool", tool.definition))?;
+
+        if tool.is_broken() {
+            return Err(format!(
+                "Tool {:?} ({:?}) is broken and cannot be used",
+                tool_id, tool.definition
+            ));
+        }
+
+        if let Some(requirement) = requirement {
+            if tool_type != &requirement.tool_type {
+                return Err(format!(
+                    "Tool type mismatch: required {:?}, got {:?}",
+                    requirement.tool_type, tool_type
+                ));
+            }
+            if tool.quality < requirement.min_quality {
+                return Err(format!(
+                    "Tool quality too low: required at least {:?}, got {:?}",
+                    requirement.min_quality, tool.quality
                 ));
             }
         }
@@ -255,10 +1134,200 @@ impl Registry {
         Ok(())
     }
 
+    /// Use up one point of durability on a tool (no-op for tools that don't wear out)
+    fn wear_tool(&mut self, tool_used: Option<ItemInstanceId>) {
+        if let Some(tool_id) = tool_used {
+            if let Some(ItemInstance::Composite(ci)) = self.instances.get_mut(&tool_id) {
+                if let Some(durability) = ci.durability.as_mut() {
+                    *durability = durability.saturating_sub(1);
+                }
+            }
+        }
+    }
+
+    /// Craft any recipe by ID: validate, consume inputs, and register the output.
+    ///
+    /// Inputs are interpreted per recipe type:
+    /// - Simple: any Simple instances (stacks) covering the recipe's inputs
+    /// - Component: exactly one submaterial instance
+    /// - Composite: one component per slot, in the composite's slot order
+    ///
+    /// The craft is transactional: if anything fails, every touched instance (inputs
+    /// and tool) and the instance ID counter are restored to their prior state.
+    pub fn craft(
+        &mut self,
+        recipe_id: &RecipeId,
+        inputs: Vec<ItemInstanceId>,
+        tool_used: Option<ItemInstanceId>,
+        world_object_used: Option<WorldObjectInstanceId>,
+    ) -> Result<ItemInstanceId, String> {
+        // Snapshot everything this craft may touch so a failure can be rolled back
+        let snapshot: Vec<ItemInstance> = inputs.iter()
+            .chain(tool_used.iter())
+            .filter_map(|id| self.get_instance(*id))
+            .cloned()
+            .collect();
+        let next_instance_id = self.next_instance_id;
+
+        let result = self.craft_unchecked(recipe_id, inputs, tool_used, world_object_used);
+
+        if result.is_err() {
+            for instance in snapshot {
+                self.history.remove(&instance.id());
+                self.instances.insert(instance.id(), instance);
+            }
+            self.next_instance_id = next_instance_id;
+        }
+        result
+    }
+
+    /// Body of `craft` without the rollback
+    fn craft_unchecked(
+        &mut self,
+        recipe_id: &RecipeId,
+        inputs: Vec<ItemInstanceId>,
+        tool_used: Option<ItemInstanceId>,
+        world_object_used: Option<WorldObjectInstanceId>,
+    ) -> Result<ItemInstanceId, String> {
+        let new_instance = match self.get_recipe(recipe_id) {
+            Some(RecipeRef::Simple(recipe)) => {
+                let recipe = recipe.clone();
+                self.execute_simple_recipe(&recipe, inputs, tool_used, world_object_used)?
+            }
+            Some(RecipeRef::Component(recipe)) => {
+                let recipe = recipe.clone();
+                if inputs.len() != 1 {
+                    return Err(format!("Component recipe requires exactly 1 input, got {}", inputs.len()));
+                }
+                self.execute_component_recipe(&recipe, inputs[0], tool_used, world_object_used)?
+            }
+            Some(RecipeRef::Composite(recipe)) => {
+                let recipe = recipe.clone();
+
+                // Match inputs to slots in order
+                let slots = match self.get_item(&recipe.output).map(|def| &def.kind) {
+                    Some(ItemKind::Composite(def)) => def.slots.clone(),
+                    Some(_) => return Err(format!("Recipe output {:?} is not a Composite item", recipe.output)),
+                    None => return Err(format!("Output item {:?} not found", recipe.output)),
+                };
+                if inputs.len() != slots.len() {
+                    return Err(format!(
+                        "Composite recipe requires {} components (one per slot), got {}",
+                        slots.len(), inputs.len()
+                    ));
+                }
+                let provided_components: Vec<(String, ItemInstanceId)> = slots.iter()
+                    .zip(inputs.iter())
+                    .map(|(slot, &id)| (slot.name.clone(), id))
+                    .collect();
+
+                self.execute_composite_recipe(&recipe, provided_components, tool_used, world_object_used)?
+            }
+            None => return Err(format!("Recipe not found: {}", recipe_id.0)),
+        };
+
+        self.consume_inputs(new_instance.provenance())?;
+        Ok(self.register_new_instance(new_instance))
+    }
+
+    /// Check what `craft` would do without changing the registry.
+    ///
+    /// Runs the real craft on a scratch copy of the registry, so it passes or fails
+    /// with exactly the same validation (tool, slots, materials, world objects,
+    /// quantities) and derives the same output quality.
+    pub fn preview_craft(
+        &self,
+        recipe_id: &RecipeId,
+        inputs: Vec<ItemInstanceId>,
+        tool_used: Option<ItemInstanceId>,
+        world_object_used: Option<WorldObjectInstanceId>,
+    ) -> Result<CraftPreview, String> {
+        let mut scratch = self.clone();
+        let new_id = scratch.craft(recipe_id, inputs, tool_used, world_object_used)?;
+        let output = scratch.instances.remove(&new_id)
+            .ok_or_else(|| format!("Crafted instance {:?} not found", new_id))?;
+
+        let quality = match &output {
+            ItemInstance::Simple(_) => None,
+            ItemInstance::Component(ci) => Some(ci.quality),
+            ItemInstance::Composite(ci) => Some(ci.quality),
+        };
+        let consumed_inputs = output.provenance().consumed_inputs.clone();
+        Ok(CraftPreview { output, quality, consumed_inputs })
+    }
+
+    /// Craft the same recipe up to `count` times.
+    ///
+    /// Each craft draws from the given inputs; inputs that have been fully consumed
+    /// are dropped before the next attempt, so a stack (or a list of several
+    /// instances) feeds as many crafts as it can cover. Simple recipes see every
+    /// remaining input, component recipes the first one, and composite recipes the
+    /// first slot-count components (so list them one craft after another, each in
+    /// slot order). The batch stops at the first failure - e.g. inputs running out
+    /// or the tool breaking - and reports why. Every craft is transactional (see
+    /// `craft`), so a failed attempt leaves the registry exactly as the previous
+    /// successful craft left it.
+    pub fn craft_batch(
+        &mut self,
+        recipe_id: &RecipeId,
+        count: u32,
+        inputs: &[ItemInstanceId],
+        tool_used: Option<ItemInstanceId>,
+        world_object_used: Option<WorldObjectInstanceId>,
+    ) -> BatchOutcome {
+        let mut outcome = BatchOutcome {
+            crafted: Vec::new(),
+            stop_reason: None,
+        };
+        let per_craft = self.inputs_per_craft(recipe_id);
+
+        for _ in 0..count {
+            let mut remaining: Vec<ItemInstanceId> = inputs.iter()
+                .copied()
+                .filter(|id| self.instances.contains_key(id))
+                .collect();
+            if let Some(needed) = per_craft {
+                if remaining.len() < needed {
+                    outcome.stop_reason = Some(format!(
+                        "Out of inputs: each craft needs {}, {} left",
+                        needed, remaining.len()
+                    ));
+                    break;
+                }
+                remaining.truncate(needed);
+            }
+
+            match self.craft(recipe_id, remaining, tool_used, world_object_used) {
+                Ok(id) => outcome.crafted.push(id),
+                Err(e) => {
+                    outcome.stop_reason = Some(e);
+                    break;
+                }
+            }
+        }
+        outcome
+    }
+
+    /// How many input instances one craft of a component or composite recipe takes
+    /// (`None` for simple recipes, which take any number)
+    fn inputs_per_craft(&self, recipe_id: &RecipeId) -> Option<usize> {
+        match self.get_recipe(recipe_id)? {
+            RecipeRef::Simple(_) => None,
+            RecipeRef::Component(_) => Some(1),
+            RecipeRef::Composite(recipe) => match &self.get_item(&recipe.output)?.kind {
+                ItemKind::Composite(def) => Some(def.slots.len()),
+                _ => None,
+            },
+        }
+    }
+
     // Crafting validation and execution methods
 
     /// Execute a SimpleRecipe to create a Simple item
     ///
+    /// The output takes the lowest quality among the consumed inputs (Common if the
+    /// recipe consumes nothing).
+    ///
     /// Validates:
     /// - All required inputs are provided with correct quantities
     /// - Input items exist in the registry
@@ -271,58 +1340,74 @@ impl Registry {
         world_object_used: Option<WorldObjectInstanceId>,
     ) -> Result<ItemInstance, String> {
         // Validate world object requirement if specified
-        if let Some(ref requirement) = recipe.world_object {
-            if let Some(wo_id) = world_object_used {
-                self.validate_world_object_requirement(wo_id, requirement)?;
-            } else {
-                return Err(format!("Recipe requires a world object but none was provided"));
-            }
-        }
+        self.validate_world_object(world_object_used, recipe.world_object.as_ref())?;
 
-        // Validate all required inputs are provided
-        for required_input in &recipe.inputs {
-            let mut found_quantity = 0u32;
+        // Validate the tool (if one was used)
+        self.validate_tool(tool_used, recipe.tool.as_ref())?;
 
-            for &instance_id in &provided_inputs {
-                let instance = self.get_instance(instance_id)
-                    .ok_or_else(|| format!("Input instance {:?} not found", instance_id))?;
+        // Gather the available stack size of each provided instance (duplicates counted once)
+        let mut available: Vec<(ItemInstanceId, ItemId, u32, Quality)> = Vec::new();
+        for &instance_id in &provided_inputs {
+            if available.iter().any(|(id, _, _, _)| *id == instance_id) {
+                continue;
+            }
+
+            let instance = self.get_instance(instance_id)
+                .ok_or_else(|| format!("Input instance {:?} not found", instance_id))?;
 
-                // Get the item definition for this instance
-                let item_def = match instance {
-                    ItemInstance::Simple(si) => self.get_item(&si.definition)
+            // Get the item definition for this instance
+            let (item_def, quantity, quality) = match instance {
+                ItemInstance::Simple(si) => (
+                    self.get_item(&si.definition)
                         .ok_or_else(|| format!("Item definition {:?} not found", si.definition))?,
-                    _ => return Err(format!("SimpleRecipe can only accept Simple item instances as input")),
-                };
+                    si.quantity,
+                    si.quality,
+                ),
+                _ => return Err(format!("SimpleRecipe can only accept Simple item instances as input")),
+            };
+
+            available.push((instance_id, item_def.id.clone(), quantity, quality));
+        }
+
+        // Validate all required inputs are provided, taking units from stacks in order
+        let mut consumed_inputs: Vec<ConsumedInput> = Vec::new();
+        let mut output_quality: Option<Quality> = None;
+        for required_input in &recipe.inputs {
+            let mut remaining = required_input.quantity;
 
-                // Check if this instance matches the required input
-                if item_def.id == required_input.item_id {
-                    found_quantity += 1; // Assuming quantity 1 per instance for now
+            for (instance_id, item_id, quantity, quality) in available.iter_mut() {
+                if remaining == 0 {
+                    break;
                 }
+                if *item_id != required_input.item_id || *quantity == 0 {
+                    continue;
+                }
+
+                let taken = remaining.min(*quantity);
+                *quantity -= taken;
+                remaining -= taken;
+                output_quality = Some(output_quality.map_or(*quality, |q| q.min(*quality)));
+                consumed_inputs.push(ConsumedInput {
+                    instance_id: *instance_id,
+                    quantity: taken,
+                });
             }
 
-            if found_quantity < required_input.quantity {
+            if remaining > 0 {
                 return Err(format!(
                     "Insufficient quantity of {:?}: need {}, have {}",
-                    required_input.item_id, required_input.quantity, found_quantity
+                    required_input.item_id, required_input.quantity, required_input.quantity - remaining
                 ));
             }
         }
 
-        // Create consumed inputs list
-        let consumed_inputs: Vec<ConsumedInput> = provided_inputs
-            .iter()
-            .map(|&id| ConsumedInput {
-                instance_id: id,
-                quantity: 1, // Assuming 1 per instance
-            })
-            .collect();
-
         // Create provenance
         let provenance = Provenance {
             recipe_id: recipe.id.clone(),
             consumed_inputs,
             tool_used,
             world_object_used,
+            disassembled_from: None,
             crafted_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -334,14 +1419,19 @@ impl Registry {
         let simple_instance = SimpleInstance {
             id: instance_id,
             definition: recipe.output.clone(),
+            quantity: recipe.output_quantity,
+            quality: output_quality.unwrap_or(Quality::Common),
             provenance,
         };
 
+        self.wear_tool(tool_used);
         Ok(ItemInstance::Simple(simple_instance))
     }
 
     /// Execute a ComponentRecipe to create a Component from a submaterial
     ///
+    /// The component inherits the quality of the consumed material.
+    ///
     /// Validates:
     /// - Input is exactly one item instance
     /// - Input is a Simple item with a submaterial
@@ -355,13 +1445,10 @@ impl Registry {
         world_object_used: Option<WorldObjectInstanceId>,
     ) -> Result<ItemInstance, String> {
         // Validate world object requirement if specified
-        if let Some(ref requirement) = recipe.world_object {
-            if let Some(wo_id) = world_object_used {
-                self.validate_world_object_requirement(wo_id, requirement)?;
-            } else {
-                return Err(format!("Recipe requires a world object but none was provided"));
-            }
-        }
+        self.validate_world_object(world_object_used, recipe.world_object.as_ref())?;
+
+        // Validate the tool (if one was used)
+        self.validate_tool(tool_used, recipe.tool.as_ref())?;
 
         // Get the input instance
         let input_instance = self.get_instance(input_instance_id)
@@ -405,6 +1492,7 @@ impl Registry {
         // Clone necessary data before mutable borrow
         let output_component_kind = recipe.output.clone();
         let submaterial_id_cloned = submaterial_id.clone();
+        let quality = simple_instance.quality;
 
         // Create provenance
         let provenance = Provenance {
@@ -415,6 +1503,7 @@ impl Registry {
             }],
             tool_used,
             world_object_used,
+            disassembled_from: None,
             crafted_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -427,9 +1516,11 @@ impl Registry {
             id: instance_id,
             component_kind: output_component_kind,
             submaterial: submaterial_id_cloned,
+            quality,
             provenance,
         };
 
+        self.wear_tool(tool_used);
         Ok(ItemInstance::Component(component_instance))
     }
 
@@ -448,13 +1539,10 @@ impl Registry {
         world_object_used: Option<WorldObjectInstanceId>,
     ) -> Result<ItemInstance, String> {
         // Validate world object requirement if specified
-        if let Some(ref requirement) = recipe.world_object {
-            if let Some(wo_id) = world_object_used {
-                self.validate_world_object_requirement(wo_id, requirement)?;
-            } else {
-                return Err(format!("Recipe requires a world object but none was provided"));
-            }
-        }
+        self.validate_world_object(world_object_used, recipe.world_object.as_ref())?;
+
+        // Validate the tool (if one was used)
+        self.validate_tool(tool_used, recipe.tool.as_ref())?;
 
         // Get the output item definition
         let output_def = self.get_item(&recipe.output)
@@ -521,6 +1609,15 @@ impl Registry {
             }
         }
 
+        // Tools start with full durability; other composites don't wear out
+        let durability = composite_def.tool_type.as_ref().map(|_| DEFAULT_TOOL_DURABILITY);
+
+        // Derive output quality from the components, in slot order
+        let component_qualities: Vec<(String, Quality)> = composite_def.slots.iter()
+            .map(|slot| (slot.name.clone(), filled_slots[&slot.name].quality))
+            .collect();
+        let quality = recipe.quality_formula.apply(&component_qualities);
+
         // Create provenance
         let provenance = Provenance {
             recipe_id: recipe.id.clone(),
@@ -532,6 +1629,7 @@ impl Registry {
                 .collect(),
             tool_used,
             world_object_used,
+            disassembled_from: None,
             crafted_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -543,15 +1641,25 @@ impl Registry {
         let composite_instance = CompositeInstance {
             id: instance_id,
             definition: recipe.output.clone(),
-            quality: Quality::Common, // TODO: Implement quality calculation
+            quality,
+            durability,
             components: filled_slots,
             provenance,
         };
 
+        self.wear_tool(tool_used);
         Ok(ItemInstance::Composite(composite_instance))
     }
 }
 
+/// 64-bit FNV-1a, used for content-hashed IDs because it is stable across
+/// Rust versions (unlike `DefaultHasher`)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
 impl Default for Registry {
     fn default() -> Self {
         Self::new()
@@ -8,9 +8,19 @@ pub enum WorldObjectKind {
 }
 
 /// A world object instance placed in the world
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct WorldObjectInstance {
     pub id: WorldObjectInstanceId,
     pub kind: WorldObjectKind,
     pub tags: Vec<WorldObjectTag>,
+    /// Harvests left on a resource node (None = unlimited; unused by stations)
+    #[serde(default)]
+    pub remaining_uses: Option<u32>,
+}
+
+impl WorldObjectInstance {
+    /// Check if this world object provides `tag`
+    pub fn has_tag(&self, tag: &WorldObjectTag) -> bool {
+        self.tags.contains(tag)
+    }
 }
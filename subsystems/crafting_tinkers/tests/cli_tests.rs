@@ -89,7 +89,7 @@ fn test_component_kind_registration() {
 
     // Count all component kinds
     let component_kind_count = registry.all_component_kinds().count();
-    assert_eq!(component_kind_count, 8);
+    assert_eq!(component_kind_count, 9);
 }
 
 // ============================================================================
@@ -293,8 +293,8 @@ fn test_all_three_recipe_types_registered() {
     let composite_count = registry.all_composite_recipes().count();
 
     assert_eq!(simple_count, 2, "Should have 2 simple recipes");
-    assert_eq!(component_count, 8, "Should have 8 component recipes");
-    assert_eq!(composite_count, 5, "Should have 5 composite recipes");
+    assert_eq!(component_count, 9, "Should have 9 component recipes");
+    assert_eq!(composite_count, 6, "Should have 6 composite recipes");
 }
 
 // ============================================================================
@@ -337,7 +337,7 @@ fn test_list_recipes_command() {
     assert!(is_success(&response));
 
     let data = get_data(&response);
-    assert_eq!(data["count"], 15); // 2 simple + 8 component + 5 composite
+    assert_eq!(data["count"], 17); // 2 simple + 9 component + 6 composite
     assert!(data["recipes"].is_array());
 
     // Verify recipes have type labels
@@ -717,7 +717,7 @@ fn test_parse_show_instance() {
 #[test]
 fn test_parse_new() {
     let cmd = cli::parse_command("new deer_leather").unwrap();
-    assert!(matches!(cmd, crafting::cli::Command::New { item_id } if item_id == "deer_leather"));
+    assert!(matches!(cmd, crafting::cli::Command::New { item_id, .. } if item_id == "deer_leather"));
 }
 
 #[test]
@@ -756,6 +756,1583 @@ fn test_parse_invalid_commands() {
     assert!(result.is_err());
 }
 
+// ============================================================================
+// RECIPE SEARCH TESTS
+// ============================================================================
+
+fn recipe_ids(recipes: Vec<crafting::RecipeRef<'_>>) -> Vec<String> {
+    recipes.iter().map(|r| r.id().0.clone()).collect()
+}
+
+#[test]
+fn test_recipes_producing_simple_item() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let iron_bar = crafting::ItemId("iron_bar".to_string());
+    assert_eq!(recipe_ids(registry.recipes_producing(&iron_bar)), vec!["smelt_iron_bar"]);
+
+    // Raw ores have no recipe producing them
+    let copper_ore = crafting::ItemId("copper_ore".to_string());
+    assert!(registry.recipes_producing(&copper_ore).is_empty());
+}
+
+#[test]
+fn test_recipes_producing_component_and_composite() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let handle = crafting::ItemId("handle".to_string());
+    let producing = registry.recipes_producing(&handle);
+    assert_eq!(recipe_ids(producing.clone()), vec!["craft_handle"]);
+    assert_eq!(producing[0].type_name(), "Component");
+
+    let scimitar = crafting::ItemId("scimitar".to_string());
+    let producing = registry.recipes_producing(&scimitar);
+    assert_eq!(recipe_ids(producing.clone()), vec!["assemble_scimitar"]);
+    assert_eq!(producing[0].type_name(), "Composite");
+}
+
+#[test]
+fn test_recipes_consuming_simple_inputs() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let copper_ore = crafting::ItemId("copper_ore".to_string());
+    assert_eq!(recipe_ids(registry.recipes_consuming(&copper_ore)), vec!["smelt_bronze_bar"]);
+}
+
+#[test]
+fn test_recipes_consuming_submaterial_item() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    // iron_bar is a metal submaterial item: every component kind accepting metal can use it
+    let iron_bar = crafting::ItemId("iron_bar".to_string());
+    let consuming = recipe_ids(registry.recipes_consuming(&iron_bar));
+    assert_eq!(consuming, vec![
+        "craft_hammer_head",
+        "craft_hatchet_head",
+        "craft_knife_blade",
+        "craft_pickaxe_head",
+        "craft_pommel",
+        "craft_scimitar_blade",
+        "craft_sword_blade",
+    ]);
+    assert!(!consuming.contains(&"craft_handle".to_string()));
+}
+
+#[test]
+fn test_recipes_consuming_component_item() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let scimitar_blade = crafting::ItemId("scimitar_blade".to_string());
+    assert_eq!(recipe_ids(registry.recipes_consuming(&scimitar_blade)), vec!["assemble_scimitar"]);
+
+    // Final composites are not consumed by anything
+    let scimitar = crafting::ItemId("scimitar".to_string());
+    assert!(registry.recipes_consuming(&scimitar).is_empty());
+}
+
+#[test]
+fn test_find_recipe_command() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let response = exec_command("find-recipe handle", &mut registry);
+    assert!(is_success(&response));
+
+    let data = get_data(&response);
+    assert_eq!(data["item"], "handle");
+    let produced_by = data["produced_by"].as_array().unwrap();
+    assert_eq!(produced_by.len(), 1);
+    assert_eq!(produced_by[0]["id"], "craft_handle");
+    assert_eq!(produced_by[0]["type"], "Component");
+    let consumed_by = data["consumed_by"].as_array().unwrap();
+    assert!(consumed_by.iter().any(|r| r["id"] == "assemble_scimitar"));
+    assert!(consumed_by.iter().all(|r| r["type"] == "Composite"));
+}
+
+#[test]
+fn test_find_recipe_unknown_item() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let response = exec_command("find-recipe unobtainium", &mut registry);
+    assert_eq!(response["status"], "error");
+}
+
+// ============================================================================
+// SAVE / LOAD TESTS
+// ============================================================================
+
+/// Unique temp file path per test so parallel tests don't clobber each other
+fn temp_save_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("crafting_{}_{}.json", name, std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+#[test]
+fn test_save_load_preserves_instances_and_fresh_ids() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    for item in ["iron_ore", "iron_ore", "oak_wood", "forge"] {
+        assert!(is_success(&exec_command(&format!("new {}", item), &mut registry)));
+    }
+    let saved_ids: Vec<u64> = registry.all_instances().map(|i| i.id().0).collect();
+
+    let path = temp_save_path("save_load");
+    registry.save(&path).unwrap();
+    let mut loaded = Registry::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(loaded.all_instances().count(), 4);
+    assert_eq!(loaded.all_items().count(), registry.all_items().count());
+    for &id in &saved_ids {
+        assert!(loaded.get_instance(crafting::ItemInstanceId(id)).is_some());
+    }
+
+    // A new instance after loading must not reuse any saved ID
+    let response = exec_command("new copper_ore", &mut loaded);
+    assert!(is_success(&response));
+    let new_id = get_data(&response)["instance_id"].as_u64().unwrap();
+    assert!(!saved_ids.contains(&new_id));
+    assert_eq!(loaded.all_instances().count(), 5);
+}
+
+#[test]
+fn test_save_load_preserves_world_objects() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    exec_command("new forge", &mut registry);
+    assert!(is_success(&exec_command("place 0", &mut registry)));
+
+    let path = temp_save_path("world_objects");
+    let response = exec_command(&format!("save {}", path), &mut registry);
+    assert!(is_success(&response));
+
+    let mut fresh = Registry::new();
+    let response = exec_command(&format!("load {}", path), &mut fresh);
+    std::fs::remove_file(&path).ok();
+    assert!(is_success(&response));
+    assert_eq!(get_data(&response)["stations"], 1);
+
+    let old_station = registry.all_world_objects().next().unwrap().id;
+    assert!(fresh.get_world_object(old_station).is_some());
+    assert_ne!(fresh.next_world_object_id(), old_station);
+}
+
+#[test]
+fn test_load_missing_file_is_error() {
+    let path = temp_save_path("does_not_exist");
+    assert!(Registry::load(&path).is_err());
+
+    let mut registry = Registry::new();
+    let response = exec_command(&format!("load {}", path), &mut registry);
+    assert_eq!(response["status"], "error");
+}
+
+// ============================================================================
+// ITEM STACK TESTS
+// ============================================================================
+
+/// Find the inventory index of the first instance of `item` in `list instances`
+fn inventory_index(registry: &mut Registry, item: &str) -> usize {
+    let response = exec_command("list instances", registry);
+    let instances = get_data(&response)["instances"].as_array().unwrap().clone();
+    instances.iter()
+        .find(|i| i["item"] == item)
+        .map(|i| i["index"].as_u64().unwrap() as usize)
+        .unwrap_or_else(|| panic!("{} not in inventory", item))
+}
+
+fn raw_provenance() -> crafting::Provenance {
+    crafting::Provenance {
+        recipe_id: crafting::RecipeId("raw_material".to_string()),
+        consumed_inputs: vec![],
+        tool_used: None,
+        world_object_used: None,
+        disassembled_from: None,
+        crafted_at: 0,
+    }
+}
+
+#[test]
+fn test_merge_stacks_combines_identical_simple_instances() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    for _ in 0..5 {
+        exec_command("new iron_ore", &mut registry);
+    }
+    exec_command("new oak_wood", &mut registry);
+    assert_eq!(registry.all_instances().count(), 6);
+
+    assert_eq!(registry.merge_stacks(), 4);
+    assert_eq!(registry.all_instances().count(), 2);
+
+    // The surviving stack keeps the lowest ID and the combined quantity
+    match registry.get_instance(crafting::ItemInstanceId(0)) {
+        Some(crafting::ItemInstance::Simple(si)) => {
+            assert_eq!(si.definition.0, "iron_ore");
+            assert_eq!(si.quantity, 5);
+        }
+        other => panic!("Expected iron_ore stack, got {:?}", other),
+    }
+
+    // Merging again is a no-op
+    assert_eq!(registry.merge_stacks(), 0);
+}
+
+#[test]
+fn test_merge_stacks_requires_identical_provenance() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    for crafted_at in [0, 1] {
+        let id = registry.next_instance_id();
+        registry.register_instance(crafting::ItemInstance::Simple(crafting::SimpleInstance {
+            id,
+            definition: crafting::ItemId("iron_ore".to_string()),
+            quantity: 1,
+            quality: crafting::Quality::Common,
+            provenance: crafting::Provenance { crafted_at, ..raw_provenance() },
+        }));
+    }
+
+    assert_eq!(registry.merge_stacks(), 0);
+    assert_eq!(registry.all_instances().count(), 2);
+}
+
+#[test]
+fn test_recipe_consumes_partial_stack() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    for _ in 0..5 {
+        exec_command("new iron_ore", &mut registry);
+    }
+    registry.merge_stacks();
+
+    exec_command("new forge", &mut registry);
+    let forge = inventory_index(&mut registry, "forge");
+    assert!(is_success(&exec_command(&format!("place {}", forge), &mut registry)));
+
+    // smelt_iron_bar needs 2 iron_ore: a single stack of 5 covers it
+    let ore = inventory_index(&mut registry, "iron_ore");
+    let response = exec_command(&format!("craft smelt_iron_bar {} @0", ore), &mut registry);
+    assert!(is_success(&response), "{:?}", response);
+
+    match registry.get_instance(crafting::ItemInstanceId(0)) {
+        Some(crafting::ItemInstance::Simple(si)) => assert_eq!(si.quantity, 3),
+        other => panic!("Expected partially consumed stack, got {:?}", other),
+    }
+
+    // Consuming the rest of a stack removes the instance
+    registry.consume_instance(crafting::ItemInstanceId(0), 3).unwrap();
+    assert!(registry.get_instance(crafting::ItemInstanceId(0)).is_none());
+}
+
+#[test]
+fn test_recipe_rejects_insufficient_stack() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    exec_command("new forge", &mut registry);
+    assert!(is_success(&exec_command("place 0", &mut registry)));
+    exec_command("new iron_ore", &mut registry);
+
+    // Listing the same single-unit stack twice does not count as two units
+    let response = exec_command("craft smelt_iron_bar 0 0 @0", &mut registry);
+    assert_eq!(response["status"], "error");
+    assert!(response["message"].as_str().unwrap().contains("need 2, have 1"));
+    assert_eq!(registry.all_instances().count(), 1);
+}
+
+#[test]
+fn test_components_and_composites_never_merge() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    for _ in 0..2 {
+        let id = registry.next_instance_id();
+        registry.register_instance(crafting::ItemInstance::Component(crafting::ComponentInstance {
+            id,
+            component_kind: crafting::ComponentKindId("handle".to_string()),
+            submaterial: crafting::SubmaterialId("oak_wood".to_string()),
+            quality: crafting::Quality::Common,
+            provenance: raw_provenance(),
+        }));
+    }
+    for _ in 0..2 {
+        let id = registry.next_instance_id();
+        registry.register_instance(crafting::ItemInstance::Composite(crafting::CompositeInstance {
+            id,
+            definition: crafting::ItemId("knife".to_string()),
+            quality: crafting::Quality::Common,
+            durability: None,
+            components: std::collections::HashMap::new(),
+            provenance: raw_provenance(),
+        }));
+    }
+
+    assert_eq!(registry.merge_stacks(), 0);
+    assert_eq!(registry.all_instances().count(), 4);
+
+    // Unstackable instances can only be consumed whole
+    assert!(registry.consume_instance(crafting::ItemInstanceId(0), 2).is_err());
+}
+
+// ============================================================================
+// DISASSEMBLY TESTS
+// ============================================================================
+
+/// Register a raw simple instance (a stack of `quantity` units) and return its ID
+fn new_simple(registry: &mut Registry, item: &str, quantity: u32) -> crafting::ItemInstanceId {
+    let id = registry.next_instance_id();
+    registry.register_instance(crafting::ItemInstance::Simple(crafting::SimpleInstance {
+        id,
+        definition: crafting::ItemId(item.to_string()),
+        quantity,
+        quality: crafting::Quality::Common,
+        provenance: raw_provenance(),
+    }));
+    id
+}
+
+/// Craft a component from a raw submaterial item, consuming the input
+fn craft_component(registry: &mut Registry, recipe: &str, item: &str) -> crafting::ItemInstanceId {
+    let input = new_simple(registry, item, 1);
+    let recipe = registry.get_component_recipe(&crafting::RecipeId(recipe.to_string())).unwrap().clone();
+    let component = registry.execute_component_recipe(&recipe, input, None, None).unwrap();
+    registry.consume_inputs(component.provenance()).unwrap();
+    let id = component.id();
+    registry.register_instance(component);
+    id
+}
+
+/// Craft a knife (blade, handle, binding) from scratch and return its instance ID
+fn craft_knife(registry: &mut Registry) -> crafting::ItemInstanceId {
+    let blade = craft_component(registry, "craft_knife_blade", "iron_bar");
+    let handle = craft_component(registry, "craft_handle", "oak_wood");
+    let binding = craft_component(registry, "craft_binding", "deer_leather");
+
+    let recipe = registry.get_composite_recipe(&crafting::RecipeId("assemble_knife".to_string())).unwrap().clone();
+    let slots = vec![
+        ("blade".to_string(), blade),
+        ("handle".to_string(), handle),
+        ("binding".to_string(), binding),
+    ];
+    let knife = registry.execute_composite_recipe(&recipe, slots, None, None).unwrap();
+    registry.consume_inputs(knife.provenance()).unwrap();
+    let id = knife.id();
+    registry.register_instance(knife);
+    id
+}
+
+#[test]
+fn test_disassemble_composite_recovers_all_slots() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    assert_eq!(registry.all_instances().count(), 1);
+
+    let recovered = registry.disassemble(knife).unwrap();
+    assert_eq!(recovered.len(), 3); // knife has blade, handle, binding slots
+    assert!(registry.get_instance(knife).is_none());
+    assert_eq!(registry.all_instances().count(), 3);
+
+    // Components come back in slot order with their original submaterials
+    let expected = [("knife_blade", "iron_metal"), ("handle", "oak_wood"), ("binding", "deer_leather")];
+    for (id, (kind, submaterial)) in recovered.iter().zip(expected) {
+        match registry.get_instance(*id) {
+            Some(crafting::ItemInstance::Component(ci)) => {
+                assert_eq!(ci.component_kind.0, kind);
+                assert_eq!(ci.submaterial.0, submaterial);
+                assert_eq!(ci.provenance.disassembled_from, Some(knife));
+            }
+            other => panic!("Expected recovered component, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn test_disassemble_non_composite_is_error() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let ore = new_simple(&mut registry, "iron_ore", 1);
+    assert!(registry.disassemble(ore).is_err());
+    assert!(registry.get_instance(ore).is_some());
+
+    let handle = craft_component(&mut registry, "craft_handle", "oak_wood");
+    assert!(registry.disassemble(handle).is_err());
+}
+
+#[test]
+fn test_disassemble_command() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    let response = exec_command("disassemble 0", &mut registry);
+    assert!(is_success(&response));
+
+    let data = get_data(&response);
+    assert_eq!(data["disassembled"], knife.0);
+    assert_eq!(data["component_ids"].as_array().unwrap().len(), 3);
+
+    let response = exec_command(&format!("show instance {}", data["component_ids"][0]), &mut registry);
+    assert_eq!(get_data(&response)["provenance"]["disassembled_from"], knife.0);
+}
+
+// ============================================================================
+// TOOL DURABILITY TESTS
+// ============================================================================
+
+/// Register a component recipe for handles that requires a knife
+fn register_knife_recipe(registry: &mut Registry) -> crafting::ComponentRecipe {
+    let recipe = crafting::ComponentRecipe {
+        id: crafting::RecipeId("carve_handle".to_string()),
+        name: "Carve Handle".to_string(),
+        output: crafting::ComponentKindId("handle".to_string()),
+        tool: Some(crafting::ToolRequirement {
+            tool_type: crafting::ToolType::Knife,
+            min_quality: crafting::Quality::Crude,
+        }),
+        world_object: None,
+    };
+    registry.register_component_recipe(recipe.clone());
+    recipe
+}
+
+/// Overwrite a tool's remaining durability
+fn set_durability(registry: &mut Registry, tool: crafting::ItemInstanceId, durability: u32) {
+    let mut instance = registry.get_instance(tool).unwrap().clone();
+    if let crafting::ItemInstance::Composite(ci) = &mut instance {
+        ci.durability = Some(durability);
+    }
+    registry.register_instance(instance);
+}
+
+fn tool_durability(registry: &Registry, tool: crafting::ItemInstanceId) -> Option<u32> {
+    match registry.get_instance(tool) {
+        Some(crafting::ItemInstance::Composite(ci)) => ci.durability,
+        other => panic!("Expected tool composite, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_assembled_tool_has_durability() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    assert_eq!(tool_durability(&registry, knife), Some(crafting::DEFAULT_TOOL_DURABILITY));
+
+    let response = exec_command(&format!("show instance {}", knife.0), &mut registry);
+    assert_eq!(get_data(&response)["durability"], crafting::DEFAULT_TOOL_DURABILITY);
+    assert_eq!(get_data(&response)["broken"], false);
+}
+
+#[test]
+fn test_tool_last_use_breaks_it() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let recipe = register_knife_recipe(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    set_durability(&mut registry, knife, 1);
+
+    let wood = new_simple(&mut registry, "oak_wood", 1);
+    let handle = registry.execute_component_recipe(&recipe, wood, Some(knife), None);
+    assert!(handle.is_ok());
+    assert_eq!(tool_durability(&registry, knife), Some(0));
+
+    let response = exec_command(&format!("show instance {}", knife.0), &mut registry);
+    assert_eq!(get_data(&response)["broken"], true);
+}
+
+#[test]
+fn test_broken_tool_fails_cleanly() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let recipe = register_knife_recipe(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    set_durability(&mut registry, knife, 0);
+
+    let wood = new_simple(&mut registry, "oak_wood", 1);
+    let err = registry.execute_component_recipe(&recipe, wood, Some(knife), None).unwrap_err();
+    assert!(err.contains("broken"), "unexpected error: {}", err);
+
+    // Nothing was crafted or consumed, and durability didn't underflow
+    assert!(registry.get_instance(wood).is_some());
+    assert_eq!(tool_durability(&registry, knife), Some(0));
+}
+
+#[test]
+fn test_broken_tool_via_craft_command() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    register_knife_recipe(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    set_durability(&mut registry, knife, 0);
+    new_simple(&mut registry, "oak_wood", 1);
+
+    let knife_index = inventory_index(&mut registry, "knife");
+    let wood_index = inventory_index(&mut registry, "oak_wood");
+    let response = exec_command(&format!("craft carve_handle {} +{}", wood_index, knife_index), &mut registry);
+    assert_eq!(response["status"], "error");
+    assert!(response["message"].as_str().unwrap().contains("broken"));
+    assert_eq!(registry.all_instances().count(), 2);
+}
+
+#[test]
+fn test_tool_type_must_match_requirement() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    // craft_handle needs no tool, but craft_knife_blade needs a hammer
+    let knife = craft_knife(&mut registry);
+    let recipe = registry.get_component_recipe(&crafting::RecipeId("craft_knife_blade".to_string())).unwrap().clone();
+    let iron = new_simple(&mut registry, "iron_bar", 1);
+    let err = registry.execute_component_recipe(&recipe, iron, Some(knife), None).unwrap_err();
+    assert!(err.contains("Tool type mismatch"), "unexpected error: {}", err);
+    assert_eq!(tool_durability(&registry, knife), Some(crafting::DEFAULT_TOOL_DURABILITY));
+}
+
+// ============================================================================
+// QUALITY FORMULA TESTS
+// ============================================================================
+
+/// Register a component instance of the given kind and quality
+fn new_component(
+    registry: &mut Registry,
+    kind: &str,
+    submaterial: &str,
+    quality: crafting::Quality,
+) -> crafting::ItemInstanceId {
+    let id = registry.next_instance_id();
+    registry.register_instance(crafting::ItemInstance::Component(crafting::ComponentInstance {
+        id,
+        component_kind: crafting::ComponentKindId(kind.to_string()),
+        submaterial: crafting::SubmaterialId(submaterial.to_string()),
+        quality,
+        provenance: raw_provenance(),
+    }));
+    id
+}
+
+/// Assemble a knife from components of the given qualities using `formula`
+fn assemble_knife_with(
+    registry: &mut Registry,
+    qualities: [crafting::Quality; 3],
+    formula: crafting::QualityFormula,
+) -> crafting::Quality {
+    let blade = new_component(registry, "knife_blade", "iron_metal", qualities[0]);
+    let handle = new_component(registry, "handle", "oak_wood", qualities[1]);
+    let binding = new_component(registry, "binding", "deer_leather", qualities[2]);
+
+    let mut recipe = registry.get_composite_recipe(&crafting::RecipeId("assemble_knife".to_string())).unwrap().clone();
+    recipe.quality_formula = formula;
+    let slots = vec![
+        ("blade".to_string(), blade),
+        ("handle".to_string(), handle),
+        ("binding".to_string(), binding),
+    ];
+    match registry.execute_composite_recipe(&recipe, slots, None, None).unwrap() {
+        crafting::ItemInstance::Composite(ci) => ci.quality,
+        other => panic!("Expected composite, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_average_formula_with_high_inputs() {
+    use crafting::Quality::*;
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let quality = assemble_knife_with(&mut registry, [Rare, Rare, Rare], crafting::QualityFormula::Average);
+    assert_eq!(quality, Rare);
+
+    let quality = assemble_knife_with(&mut registry, [Epic, Legendary, Epic], crafting::QualityFormula::Average);
+    assert_eq!(quality, Epic);
+}
+
+#[test]
+fn test_min_formula_dragged_down_by_low_input() {
+    use crafting::Quality::*;
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let quality = assemble_knife_with(&mut registry, [Legendary, Makeshift, Legendary], crafting::QualityFormula::Min);
+    assert_eq!(quality, Makeshift);
+}
+
+#[test]
+fn test_weighted_formula_favors_weighted_slot() {
+    use crafting::Quality::*;
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    // blade weight 4: (6 * 4 + 2 + 2) / 6 = 4.67 -> Epic
+    let formula = crafting::QualityFormula::WeightedBySlot(vec![("blade".to_string(), 4)]);
+    let quality = assemble_knife_with(&mut registry, [Legendary, Common, Common], formula);
+    assert_eq!(quality, Epic);
+}
+
+#[test]
+fn test_crafted_components_default_to_common_quality() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    match registry.get_instance(knife) {
+        Some(crafting::ItemInstance::Composite(ci)) => assert_eq!(ci.quality, crafting::Quality::Common),
+        other => panic!("Expected knife, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_material_quality_flows_through_crafting() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let recipe_id = crafting::RecipeId("assemble_knife".to_string());
+    let mut recipe = registry.get_composite_recipe(&recipe_id).unwrap().clone();
+    recipe.quality_formula = crafting::QualityFormula::Min;
+    registry.register_composite_recipe(recipe);
+
+    assert!(is_success(&exec_command("new iron_bar crude", &mut registry)));
+    assert!(is_success(&exec_command("new oak_wood rare", &mut registry)));
+    assert!(is_success(&exec_command("new deer_leather rare", &mut registry)));
+
+    for (recipe, item) in [("craft_knife_blade", "iron_bar"), ("craft_handle", "oak_wood"), ("craft_binding", "deer_leather")] {
+        let index = inventory_index(&mut registry, item);
+        assert!(is_success(&exec_command(&format!("craft {} {}", recipe, index), &mut registry)));
+    }
+    let response = exec_command("list instances", &mut registry);
+    let instances = get_data(&response)["instances"].as_array().unwrap().clone();
+    let component = |kind: &str| instances.iter().find(|i| i["component_kind"] == kind).unwrap();
+    assert_eq!(component("knife_blade")["quality"], "Crude");
+    assert_eq!(component("handle")["quality"], "Rare");
+
+    let slots: Vec<String> = ["knife_blade", "handle", "binding"].iter()
+        .map(|kind| component(kind)["index"].to_string())
+        .collect();
+    let response = exec_command(&format!("craft assemble_knife {}", slots.join(" ")), &mut registry);
+    assert!(is_success(&response), "craft failed: {}", response);
+    let knife = crafting::ItemInstanceId(get_data(&response)["instance_id"].as_u64().unwrap());
+    match registry.get_instance(knife) {
+        Some(crafting::ItemInstance::Composite(ci)) => assert_eq!(ci.quality, crafting::Quality::Crude),
+        other => panic!("Expected knife, got {:?}", other),
+    }
+}
+
+// ============================================================================
+// BATCH CRAFTING TESTS
+// ============================================================================
+
+/// A crafting station kind, for `place_world_object`
+fn station_kind(id: &str) -> crafting::WorldObjectKind {
+    crafting::WorldObjectKind::CraftingStation(crafting::CraftingStationId(id.to_string()))
+}
+
+/// A resource node kind, for `place_world_object`
+fn node_kind(id: &str) -> crafting::WorldObjectKind {
+    crafting::WorldObjectKind::ResourceNode(crafting::ResourceNodeId(id.to_string()))
+}
+
+/// Place a world object of `kind` with the given tags and harvests left (None = unlimited),
+/// returning its ID
+fn place_world_object(
+    registry: &mut Registry,
+    kind: crafting::WorldObjectKind,
+    tags: &[&str],
+    remaining_uses: Option<u32>,
+) -> crafting::WorldObjectInstanceId {
+    let id = registry.next_world_object_id();
+    registry.register_world_object(crafting::WorldObjectInstance {
+        id,
+        kind,
+        tags: tags.iter().map(|t| crafting::WorldObjectTag(t.to_string())).collect(),
+        remaining_uses,
+    });
+    id
+}
+
+#[test]
+fn test_craft_batch_stops_when_inputs_run_out() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+
+    // 7 ore covers 3 smelts (2 each) with 1 left over
+    let ore = new_simple(&mut registry, "iron_ore", 7);
+    let recipe = crafting::RecipeId("smelt_iron_bar".to_string());
+    let outcome = registry.craft_batch(&recipe, 5, &[ore], None, Some(forge));
+
+    assert_eq!(outcome.succeeded(), 3);
+    let reason = outcome.stop_reason.unwrap();
+    assert!(reason.contains("Insufficient quantity"), "unexpected reason: {}", reason);
+
+    // The failed 4th craft consumed nothing
+    match registry.get_instance(ore) {
+        Some(crafting::ItemInstance::Simple(si)) => assert_eq!(si.quantity, 1),
+        other => panic!("Expected leftover ore, got {:?}", other),
+    }
+    let bars = registry.all_instances()
+        .filter(|i| matches!(i, crafting::ItemInstance::Simple(si) if si.definition.0 == "iron_bar"))
+        .count();
+    assert_eq!(bars, 3);
+}
+
+#[test]
+fn test_craft_batch_uses_separate_instances_then_stops() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+
+    let ores: Vec<_> = (0..6).map(|_| new_simple(&mut registry, "iron_ore", 1)).collect();
+    let recipe = crafting::RecipeId("smelt_iron_bar".to_string());
+    let outcome = registry.craft_batch(&recipe, 5, &ores, None, Some(forge));
+
+    assert_eq!(outcome.succeeded(), 3);
+    assert!(outcome.stop_reason.is_some());
+    assert!(ores.iter().all(|id| registry.get_instance(*id).is_none()));
+}
+
+#[test]
+fn test_craft_batch_stops_when_tool_breaks() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    register_knife_recipe(&mut registry);
+
+    let knife = craft_knife(&mut registry);
+    set_durability(&mut registry, knife, 2);
+    let wood = new_simple(&mut registry, "oak_wood", 5);
+
+    let recipe = crafting::RecipeId("carve_handle".to_string());
+    let outcome = registry.craft_batch(&recipe, 5, &[wood], Some(knife), None);
+
+    assert_eq!(outcome.succeeded(), 2);
+    assert!(outcome.stop_reason.unwrap().contains("broken"));
+    match registry.get_instance(wood) {
+        Some(crafting::ItemInstance::Simple(si)) => assert_eq!(si.quantity, 3),
+        other => panic!("Expected leftover wood, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_craft_batch_component_recipe_takes_one_input_per_craft() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let woods: Vec<_> = (0..5).map(|_| new_simple(&mut registry, "oak_wood", 1)).collect();
+    let recipe = crafting::RecipeId("craft_handle".to_string());
+    let outcome = registry.craft_batch(&recipe, 3, &woods, None, None);
+
+    assert_eq!(outcome.succeeded(), 3);
+    assert!(outcome.stop_reason.is_none());
+    assert!(woods[..3].iter().all(|id| registry.get_instance(*id).is_none()));
+    assert!(woods[3..].iter().all(|id| registry.get_instance(*id).is_some()));
+}
+
+#[test]
+fn test_craft_batch_composite_recipe_takes_one_component_per_slot() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    // Two knives' worth of components, listed one knife after another in slot order
+    let mut components = Vec::new();
+    for _ in 0..2 {
+        components.push(craft_component(&mut registry, "craft_knife_blade", "iron_bar"));
+        components.push(craft_component(&mut registry, "craft_handle", "oak_wood"));
+        components.push(craft_component(&mut registry, "craft_binding", "deer_leather"));
+    }
+    let recipe = crafting::RecipeId("assemble_knife".to_string());
+    let outcome = registry.craft_batch(&recipe, 3, &components, None, None);
+
+    assert_eq!(outcome.succeeded(), 2);
+    assert!(outcome.stop_reason.unwrap().contains("Out of inputs"));
+    assert!(components.iter().all(|id| registry.get_instance(*id).is_none()));
+}
+
+#[test]
+fn test_failed_craft_rolls_back() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    // The same handle in two slots passes slot validation but can't be consumed twice
+    let blade = craft_component(&mut registry, "craft_knife_blade", "iron_bar");
+    let handle = craft_component(&mut registry, "craft_handle", "oak_wood");
+    let before: Vec<_> = {
+        let mut ids: Vec<_> = registry.all_instances().map(|i| i.id().0).collect();
+        ids.sort();
+        ids
+    };
+
+    let recipe = crafting::RecipeId("assemble_knife".to_string());
+    assert!(registry.craft(&recipe, vec![blade, handle, handle], None, None).is_err());
+
+    let mut after: Vec<_> = registry.all_instances().map(|i| i.id().0).collect();
+    after.sort();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_craft_many_command() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    new_simple(&mut registry, "iron_ore", 6);
+
+    let ore = inventory_index(&mut registry, "iron_ore");
+    let response = exec_command(&format!("craft-many smelt_iron_bar 5 {} @0", ore), &mut registry);
+    assert!(is_success(&response));
+
+    let data = get_data(&response);
+    assert_eq!(data["requested"], 5);
+    assert_eq!(data["succeeded"], 3);
+    assert_eq!(data["instance_ids"].as_array().unwrap().len(), 3);
+    assert!(data["stop_reason"].is_string());
+
+    let response = exec_command("craft-many no_such_recipe 2", &mut registry);
+    assert_eq!(response["status"], "error");
+}
+
+// ============================================================================
+// SLOT COMPATIBILITY TESTS
+// ============================================================================
+
+fn scimitar_slot(registry: &Registry, name: &str) -> crafting::CompositeSlot {
+    match &registry.get_item(&crafting::ItemId("scimitar".to_string())).unwrap().kind {
+        crafting::ItemKind::Composite(def) => def.slots.iter().find(|s| s.name == name).unwrap().clone(),
+        _ => panic!("Scimitar should be composite"),
+    }
+}
+
+#[test]
+fn test_compatible_instances_filters_kind_and_material() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let oak_handle = new_component(&mut registry, "handle", "oak_wood", crafting::Quality::Common);
+    // Right kind, but leather isn't an accepted handle material
+    new_component(&mut registry, "handle", "deer_leather", crafting::Quality::Common);
+    let binding = new_component(&mut registry, "binding", "deer_leather", crafting::Quality::Common);
+    new_simple(&mut registry, "oak_wood", 1);
+
+    assert_eq!(registry.compatible_instances(&scimitar_slot(&registry, "handle")), vec![oak_handle]);
+    assert_eq!(registry.compatible_instances(&scimitar_slot(&registry, "binding")), vec![binding]);
+    assert!(registry.compatible_instances(&scimitar_slot(&registry, "blade")).is_empty());
+}
+
+#[test]
+fn test_fits_command_lists_inventory_indices_per_slot() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let oak_handle = new_component(&mut registry, "handle", "oak_wood", crafting::Quality::Common);
+    new_component(&mut registry, "handle", "deer_leather", crafting::Quality::Common);
+    let binding = new_component(&mut registry, "binding", "deer_leather", crafting::Quality::Common);
+    new_simple(&mut registry, "oak_wood", 1);
+
+    let index_of = |registry: &Registry, id: crafting::ItemInstanceId| {
+        registry.all_instances().position(|inst| inst.id() == id).unwrap() as u64
+    };
+
+    let response = exec_command("fits assemble_scimitar", &mut registry);
+    assert!(is_success(&response));
+    let fits = get_data(&response)["fits"].as_array().unwrap();
+    assert_eq!(fits.len(), 3);
+
+    let indices = |slot: &str| -> Vec<u64> {
+        let entry = fits.iter().find(|f| f["slot"] == slot).unwrap();
+        entry["indices"].as_array().unwrap().iter().map(|i| i.as_u64().unwrap()).collect()
+    };
+    assert_eq!(indices("handle"), vec![index_of(&registry, oak_handle)]);
+    assert_eq!(indices("binding"), vec![index_of(&registry, binding)]);
+    assert!(indices("blade").is_empty());
+
+    let response = exec_command("fits smelt_iron_bar", &mut registry);
+    assert!(!is_success(&response));
+}
+
+// ============================================================================
+// COMPOSITE PROPERTY TESTS
+// ============================================================================
+
+/// Register a scimitar whose handle is made of `handle_submaterial`
+fn new_scimitar(registry: &mut Registry, handle_submaterial: &str) -> crafting::ItemInstanceId {
+    let component = |kind: &str, submaterial: &str| crafting::ComponentInstance {
+        id: crafting::ItemInstanceId(0),
+        component_kind: crafting::ComponentKindId(kind.to_string()),
+        submaterial: crafting::SubmaterialId(submaterial.to_string()),
+        quality: crafting::Quality::Common,
+        provenance: raw_provenance(),
+    };
+    let components = [
+        ("blade", component("scimitar_blade", "iron_metal")),
+        ("handle", component("handle", handle_submaterial)),
+        ("binding", component("binding", "deer_leather")),
+    ];
+
+    let id = registry.next_instance_id();
+    registry.register_instance(crafting::ItemInstance::Composite(crafting::CompositeInstance {
+        id,
+        definition: crafting::ItemId("scimitar".to_string()),
+        quality: crafting::Quality::Common,
+        durability: None,
+        components: components.into_iter().map(|(slot, c)| (slot.to_string(), c)).collect(),
+        provenance: raw_provenance(),
+    }));
+    id
+}
+
+#[test]
+fn test_composite_properties_sum_weight_and_average_hardness() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let scimitar = new_scimitar(&mut registry, "oak_wood");
+    let props = registry.composite_properties(scimitar).unwrap();
+    // metal 3.0 + wood 1.0 + leather 0.5
+    assert!((props["weight"] - 4.5).abs() < 1e-6);
+    // (8 + 3 + 2) / 3
+    assert!((props["hardness"] - 13.0 / 3.0).abs() < 1e-6);
+
+    let handle = new_simple(&mut registry, "oak_wood", 1);
+    assert!(registry.composite_properties(handle).is_err());
+}
+
+#[test]
+fn test_heavier_handle_increases_composite_weight() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let wooden = new_scimitar(&mut registry, "oak_wood");
+    let bone = new_scimitar(&mut registry, "deer_bone");
+
+    let wooden_weight = registry.composite_properties(wooden).unwrap()["weight"];
+    let bone_weight = registry.composite_properties(bone).unwrap()["weight"];
+    assert!(bone_weight > wooden_weight, "{} should exceed {}", bone_weight, wooden_weight);
+
+    let response = exec_command(&format!("show instance {}", bone.0), &mut registry);
+    assert!(is_success(&response));
+    assert_eq!(get_data(&response)["properties"]["weight"].as_f64().unwrap() as f32, bone_weight);
+}
+
+// ============================================================================
+// CRAFT PREVIEW TESTS
+// ============================================================================
+
+fn registry_json(registry: &Registry) -> Value {
+    serde_json::to_value(registry).unwrap()
+}
+
+#[test]
+fn test_preview_craft_matches_real_craft_without_mutating() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    let ore = new_simple(&mut registry, "iron_ore", 3);
+    let recipe = crafting::RecipeId("smelt_iron_bar".to_string());
+
+    let before = registry_json(&registry);
+    let preview = registry.preview_craft(&recipe, vec![ore], None, Some(forge)).unwrap();
+    assert_eq!(registry_json(&registry), before);
+
+    assert_eq!(preview.quality, None);
+    assert_eq!(preview.consumed_inputs, vec![crafting::ConsumedInput { instance_id: ore, quantity: 2 }]);
+
+    let crafted = registry.craft(&recipe, vec![ore], None, Some(forge)).unwrap();
+    assert_eq!(crafted, preview.output.id());
+    match (registry.get_instance(crafted).unwrap(), &preview.output) {
+        (crafting::ItemInstance::Simple(real), crafting::ItemInstance::Simple(previewed)) => {
+            assert_eq!(real.definition, previewed.definition);
+            assert_eq!(real.quantity, previewed.quantity);
+        }
+        other => panic!("Expected simple instances, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_preview_craft_fails_like_real_craft() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let ore = new_simple(&mut registry, "iron_ore", 3);
+    let recipe = crafting::RecipeId("smelt_iron_bar".to_string());
+
+    // No forge provided
+    let before = registry_json(&registry);
+    let preview_err = registry.preview_craft(&recipe, vec![ore], None, None).unwrap_err();
+    assert_eq!(registry_json(&registry), before);
+
+    let craft_err = registry.craft(&recipe, vec![ore], None, None).unwrap_err();
+    assert_eq!(preview_err, craft_err);
+}
+
+#[test]
+fn test_preview_command_reports_composite_quality() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    new_component(&mut registry, "scimitar_blade", "iron_metal", crafting::Quality::Rare);
+    new_component(&mut registry, "handle", "oak_wood", crafting::Quality::Rare);
+    new_component(&mut registry, "binding", "deer_leather", crafting::Quality::Rare);
+
+    let slot_order = ["scimitar_blade", "handle", "binding"];
+    let indices: Vec<String> = slot_order.iter()
+        .map(|kind| registry.all_instances()
+            .position(|i| matches!(i, crafting::ItemInstance::Component(c) if c.component_kind.0 == *kind))
+            .unwrap()
+            .to_string())
+        .collect();
+
+    let before = registry_json(&registry);
+    let response = exec_command(&format!("preview assemble_scimitar {}", indices.join(" ")), &mut registry);
+    assert!(is_success(&response), "preview failed: {}", response);
+    assert_eq!(registry_json(&registry), before);
+
+    let data = get_data(&response);
+    assert_eq!(data["would_create"]["item"], "scimitar");
+    assert_eq!(data["output_quality"], "Rare");
+    assert_eq!(data["would_consume"].as_array().unwrap().len(), 3);
+
+    // Leaving out the binding fails the same way crafting does
+    let short = format!("assemble_scimitar {} {}", indices[0], indices[1]);
+    let preview = exec_command(&format!("preview {}", short), &mut registry);
+    let craft = exec_command(&format!("craft {}", short), &mut registry);
+    assert_eq!(preview["status"], "error");
+    assert_eq!(preview["message"], craft["message"]);
+}
+
+// ============================================================================
+// LINEAGE TESTS
+// ============================================================================
+
+#[test]
+fn test_lineage_resolves_consumed_ancestors() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    let ore = new_simple(&mut registry, "iron_ore", 2);
+
+    // ore -> iron bar -> hammer head; each craft consumes its whole input
+    let bar = registry.craft(&crafting::RecipeId("smelt_iron_bar".to_string()), vec![ore], None, Some(forge)).unwrap();
+    let head = registry.craft(&crafting::RecipeId("craft_hammer_head".to_string()), vec![bar], None, None).unwrap();
+    assert!(registry.get_instance(ore).is_none());
+    assert!(registry.get_instance(bar).is_none());
+
+    let lineage = registry.lineage(head);
+    assert_eq!(lineage.depth(), 3);
+    assert!(!lineage.historical);
+    assert_eq!(lineage.quantity, None);
+
+    let bar_node = &lineage.inputs[0];
+    assert_eq!(bar_node.instance_id, bar);
+    assert!(bar_node.historical);
+    assert_eq!(bar_node.quantity, Some(1));
+
+    let ore_node = &bar_node.inputs[0];
+    assert_eq!(ore_node.instance_id, ore);
+    assert!(ore_node.historical);
+    assert_eq!(ore_node.quantity, Some(2));
+    assert!(ore_node.inputs.is_empty());
+    match &ore_node.instance {
+        Some(crafting::ItemInstance::Simple(si)) => assert_eq!(si.definition.0, "iron_ore"),
+        other => panic!("Expected an iron_ore snapshot, got {:?}", other),
+    }
+
+    // The snapshots survive a save/load round trip and show up in the command output
+    let path = std::env::temp_dir().join(format!("crafting_lineage_{}.json", std::process::id()));
+    registry.save(path.to_str().unwrap()).unwrap();
+    let mut loaded = Registry::load(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.lineage(head), lineage);
+
+    let index = loaded.all_instances().position(|i| i.id() == head).unwrap();
+    let response = exec_command(&format!("lineage {}", index), &mut loaded);
+    assert!(is_success(&response), "lineage failed: {}", response);
+    let data = get_data(&response);
+    assert_eq!(data["depth"], 3);
+    assert_eq!(data["lineage"]["inputs"][0]["label"], "iron_bar");
+    assert_eq!(data["lineage"]["inputs"][0]["inputs"][0]["historical"], true);
+}
+
+#[test]
+fn test_failed_craft_leaves_no_history() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let ore = new_simple(&mut registry, "iron_ore", 2);
+
+    // No forge: the craft fails and rolls back
+    let recipe = crafting::RecipeId("smelt_iron_bar".to_string());
+    let before = registry_json(&registry);
+    assert!(registry.craft(&recipe, vec![ore], None, None).is_err());
+    assert_eq!(registry_json(&registry), before);
+    assert!(!registry.lineage(ore).historical);
+}
+
+// ============================================================================
+// CONTENT-HASH ID TESTS
+// ============================================================================
+
+/// Run a fixed craft sequence (forge, two ores, bar, hammer head) from scratch
+/// and return every instance ID it produced, in order
+fn hashed_craft_sequence() -> Vec<crafting::ItemInstanceId> {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    registry.set_id_strategy(crafting::IdStrategy::ContentHash);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+
+    let new_ore = |registry: &mut Registry| {
+        let response = exec_command("new iron_ore", registry);
+        crafting::ItemInstanceId(get_data(&response)["instance_id"].as_u64().unwrap())
+    };
+    let ore1 = new_ore(&mut registry);
+    let ore2 = new_ore(&mut registry);
+    let bar = registry.craft(&crafting::RecipeId("smelt_iron_bar".to_string()), vec![ore1, ore2], None, Some(forge)).unwrap();
+    let head = registry.craft(&crafting::RecipeId("craft_hammer_head".to_string()), vec![bar], None, None).unwrap();
+
+    assert_eq!(registry.lineage(head).depth(), 3);
+    vec![ore1, ore2, bar, head]
+}
+
+#[test]
+fn test_content_hash_ids_match_across_registries() {
+    let first = hashed_craft_sequence();
+    let second = hashed_craft_sequence();
+    assert_eq!(first, second);
+
+    // The two identical ores are told apart by the collision salt
+    assert_ne!(first[0], first[1]);
+    // Hashed, not sequential
+    assert!(first.iter().any(|id| id.0 > 1000));
+}
+
+#[test]
+fn test_content_hash_ids_differ_by_quality() {
+    // The same raw item in fresh registries, differing only in quality
+    let hashed_id = |quality: crafting::Quality| {
+        let mut registry = Registry::new();
+        crafting::content::register_sample_content(&mut registry);
+        registry.set_id_strategy(crafting::IdStrategy::ContentHash);
+        let id = registry.next_instance_id();
+        registry.register_new_instance(crafting::ItemInstance::Simple(crafting::SimpleInstance {
+            id,
+            definition: crafting::ItemId("iron_ore".to_string()),
+            quantity: 1,
+            quality,
+            provenance: raw_provenance(),
+        }))
+    };
+    assert_eq!(hashed_id(crafting::Quality::Common), hashed_id(crafting::Quality::Common));
+    assert_ne!(hashed_id(crafting::Quality::Common), hashed_id(crafting::Quality::Rare));
+}
+
+#[test]
+fn test_sequential_ids_are_the_default() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    assert_eq!(registry.id_strategy(), crafting::IdStrategy::Sequential);
+
+    let first = get_data(&exec_command("new iron_ore", &mut registry))["instance_id"].as_u64().unwrap();
+    let second = get_data(&exec_command("new iron_ore", &mut registry))["instance_id"].as_u64().unwrap();
+    assert_eq!((first, second), (0, 1));
+}
+
+// ============================================================================
+// SELLING TESTS
+// ============================================================================
+
+/// Inventory index (as used by `sell`) of an instance
+fn index_of(registry: &Registry, id: crafting::ItemInstanceId) -> usize {
+    registry.all_instances().position(|inst| inst.id() == id).unwrap()
+}
+
+#[test]
+fn test_sell_adds_value_to_balance() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let wood = new_simple(&mut registry, "oak_wood", 1);
+    let handle = new_component(&mut registry, "handle", "oak_wood", crafting::Quality::Rare);
+    let scimitar = new_scimitar(&mut registry, "oak_wood");
+    // wood 2 x Common 3
+    assert_eq!(registry.instance_value(wood), Ok(6));
+    // wood 2 x Rare 5
+    assert_eq!(registry.instance_value(handle), Ok(10));
+    // (metal 8 + wood 2 + leather 3) x Common 3
+    assert_eq!(registry.instance_value(scimitar), Ok(39));
+
+    let before: Vec<_> = registry.all_instances().map(|inst| inst.id()).collect();
+    let index = index_of(&registry, handle);
+    let response = exec_command(&format!("sell {}", index), &mut registry);
+    assert!(is_success(&response));
+    assert_eq!(get_data(&response)["sold"], handle.0);
+    assert_eq!(get_data(&response)["value"], 10);
+    assert_eq!(get_data(&response)["balance"], 10);
+
+    // Remaining instances keep their order; later indices shift down by one
+    let after: Vec<_> = registry.all_instances().map(|inst| inst.id()).collect();
+    let expected: Vec<_> = before.iter().copied().filter(|&id| id != handle).collect();
+    assert_eq!(after, expected);
+    assert!(registry.get_instance(handle).is_none());
+
+    registry.sell(index_of(&registry, scimitar)).unwrap();
+    assert_eq!(registry.balance(), 49);
+    let response = exec_command("balance", &mut registry);
+    assert_eq!(get_data(&response)["balance"], 49);
+
+    // Destroying is not selling
+    registry.destroy(index_of(&registry, wood)).unwrap();
+    assert_eq!(registry.balance(), 49);
+    assert_eq!(registry.all_instances().count(), 0);
+}
+
+#[test]
+fn test_simple_stack_value_scales_with_quality() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let stack_value = |registry: &mut Registry, quality: &str| {
+        let response = exec_command(&format!("new oak_wood {}", quality), registry);
+        let id = crafting::ItemInstanceId(get_data(&response)["instance_id"].as_u64().unwrap());
+        registry.instance_value(id).unwrap()
+    };
+    // wood 2 x Crude 2, and wood 2 x Rare 5
+    assert_eq!(stack_value(&mut registry, "crude"), 4);
+    assert_eq!(stack_value(&mut registry, "rare"), 10);
+}
+
+#[test]
+fn test_inventory_order_is_stable_across_save_load() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let ids: Vec<_> = ["oak_wood", "iron_ore", "flint", "iron_bar", "oak_wood"].iter()
+        .map(|item| new_simple(&mut registry, item, 1))
+        .collect();
+    assert_eq!(registry.all_instances().map(|inst| inst.id()).collect::<Vec<_>>(), ids);
+
+    let path = temp_save_path("inventory_order");
+    registry.save(&path).unwrap();
+    let mut loaded = Registry::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.all_instances().map(|inst| inst.id()).collect::<Vec<_>>(), ids);
+
+    // Selling index 1 removes exactly the second instance, and index 1 is then the third
+    loaded.sell(1).unwrap();
+    assert!(loaded.get_instance(ids[1]).is_none());
+    assert_eq!(loaded.all_instances().nth(1).map(|inst| inst.id()), Some(ids[2]));
+}
+
+#[test]
+fn test_sell_out_of_range_index_errors() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    new_simple(&mut registry, "oak_wood", 1);
+    new_simple(&mut registry, "iron_ore", 1);
+
+    let response = exec_command("sell 2", &mut registry);
+    assert_eq!(response["status"], "error");
+    assert!(response["message"].as_str().unwrap().contains("Invalid inventory index: 2"));
+    assert!(registry.destroy(7).is_err());
+    assert_eq!(registry.balance(), 0);
+    assert_eq!(registry.all_instances().count(), 2);
+}
+
+// ============================================================================
+// HARVEST TESTS
+// ============================================================================
+
+/// Station index (as used by `@index`) of a placed world object
+fn station_index(registry: &Registry, id: crafting::WorldObjectInstanceId) -> usize {
+    registry.all_world_objects().position(|wo| wo.id == id).unwrap()
+}
+
+#[test]
+fn test_harvest_produces_node_yield() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let node = place_world_object(&mut registry, node_kind("iron_ore_boulder"), &[], None);
+    let index = station_index(&registry, node);
+
+    let first = registry.harvest(index).unwrap();
+    let second = registry.harvest(index).unwrap();
+    assert_ne!(first, second);
+    match registry.get_instance(first) {
+        Some(crafting::ItemInstance::Simple(si)) => {
+            assert_eq!(si.definition.0, "iron_ore");
+            assert_eq!(si.quantity, 1);
+            assert_eq!(si.provenance.world_object_used, Some(node));
+        }
+        other => panic!("expected a simple instance, got {:?}", other),
+    }
+
+    let response = exec_command(&format!("harvest {}", index), &mut registry);
+    assert!(is_success(&response), "harvest failed: {}", response);
+    assert_eq!(get_data(&response)["item"], "iron_ore");
+    assert_eq!(registry.all_instances().count(), 3);
+}
+
+#[test]
+fn test_limited_node_is_exhausted() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let node = place_world_object(&mut registry, node_kind("oak_tree"), &[], Some(2));
+    let index = station_index(&registry, node);
+
+    let response = exec_command(&format!("harvest {}", index), &mut registry);
+    assert_eq!(get_data(&response)["remaining_uses"], 1);
+    let response = exec_command(&format!("harvest {}", index), &mut registry);
+    assert_eq!(get_data(&response)["remaining_uses"], 0);
+
+    let response = exec_command(&format!("harvest {}", index), &mut registry);
+    assert_eq!(response["status"], "error");
+    assert!(response["message"].as_str().unwrap().contains("exhausted"));
+    assert_eq!(registry.all_instances().count(), 2);
+    // The exhausted node stays placed
+    assert_eq!(registry.get_world_object(node).unwrap().remaining_uses, Some(0));
+}
+
+#[test]
+fn test_place_node_and_harvest_commands() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let response = exec_command("place-node oak_tree 2", &mut registry);
+    assert!(is_success(&response), "place-node failed: {}", response);
+    let data = get_data(&response);
+    assert_eq!(data["kind"], "ResourceNode(oak_tree)");
+    assert_eq!(data["remaining_uses"], 2);
+    let index = data["index"].as_u64().unwrap();
+
+    for remaining in [1, 0] {
+        let response = exec_command(&format!("harvest {}", index), &mut registry);
+        assert!(is_success(&response), "harvest failed: {}", response);
+        assert_eq!(get_data(&response)["item"], "oak_wood");
+        assert_eq!(get_data(&response)["remaining_uses"], remaining);
+    }
+
+    let response = exec_command(&format!("harvest {}", index), &mut registry);
+    assert_eq!(response["status"], "error");
+    assert!(response["message"].as_str().unwrap().contains("exhausted"));
+    assert_eq!(registry.all_instances().count(), 2);
+
+    // Node kinds without a yield can't be placed
+    let response = exec_command("place-node mithril_vein", &mut registry);
+    assert_eq!(response["status"], "error");
+    assert_eq!(registry.all_world_objects().count(), 1);
+}
+
+#[test]
+fn test_harvest_rejects_stations_and_unknown_nodes() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    let mystery = place_world_object(&mut registry, node_kind("mithril_vein"), &[], None);
+
+    let message = registry.harvest(station_index(&registry, forge)).unwrap_err();
+    assert!(message.contains("not a resource node"), "unexpected error: {}", message);
+    let message = registry.harvest(station_index(&registry, mystery)).unwrap_err();
+    assert!(message.contains("no registered yield"), "unexpected error: {}", message);
+    assert!(registry.harvest(5).is_err());
+    assert_eq!(registry.all_instances().count(), 0);
+}
+
+// ============================================================================
+// TAG AND CATEGORY QUERY TESTS
+// ============================================================================
+
+#[test]
+fn test_world_objects_with_tag() {
+    let mut registry = Registry::new();
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    let well = place_world_object(&mut registry, station_kind("well"), &["water_source"], None);
+
+    let ids = |tag: &str| -> Vec<crafting::WorldObjectInstanceId> {
+        registry.world_objects_with_tag(&crafting::WorldObjectTag(tag.to_string()))
+            .iter()
+            .map(|wo| wo.id)
+            .collect()
+    };
+    assert_eq!(ids("high_heat"), vec![forge]);
+    assert_eq!(ids("water_source"), vec![well]);
+    assert!(ids("magical").is_empty());
+}
+
+#[test]
+fn test_stations_command_filters_by_tag() {
+    let mut registry = Registry::new();
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    let well = place_world_object(&mut registry, station_kind("well"), &["water_source"], None);
+
+    let response = exec_command("stations", &mut registry);
+    assert_eq!(get_data(&response)["count"], 2);
+
+    for (tag, id, station) in [("high_heat", forge, "forge"), ("water_source", well, "well")] {
+        let response = exec_command(&format!("stations --tag {}", tag), &mut registry);
+        assert!(is_success(&response));
+        let stations = get_data(&response)["stations"].as_array().unwrap();
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0]["id"], id.0);
+        assert_eq!(stations[0]["kind"], format!("CraftingStation({})", station));
+        // Index still refers to the full station list, as used by `@station` in craft
+        assert_eq!(stations[0]["index"], station_index(&registry, id));
+    }
+
+    let response = exec_command("stations --tag magical", &mut registry);
+    assert_eq!(get_data(&response)["count"], 0);
+}
+
+#[test]
+fn test_items_in_category() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    let weapons = registry.items_in_category(&crafting::CompositeCategory::Weapon);
+    assert!(!weapons.is_empty());
+    assert!(weapons.iter().all(|item| matches!(
+        &item.kind,
+        crafting::ItemKind::Composite(def) if def.category == crafting::CompositeCategory::Weapon
+    )));
+    let tools = registry.items_in_category(&crafting::CompositeCategory::Tool);
+    assert!(!tools.is_empty());
+    assert!(tools.iter().all(|tool| weapons.iter().all(|weapon| weapon.id != tool.id)));
+}
+
+// ============================================================================
+// STATION REQUIREMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_high_heat_recipe_without_station_fails() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    new_simple(&mut registry, "iron_ore", 2);
+    let ore = inventory_index(&mut registry, "iron_ore");
+
+    let response = exec_command(&format!("craft smelt_iron_bar {}", ore), &mut registry);
+    assert_eq!(response["status"], "error");
+    let message = response["message"].as_str().unwrap();
+    assert!(message.contains("forge station providing [high_heat]"), "unexpected error: {}", message);
+    assert!(message.contains("no station was provided"), "unexpected error: {}", message);
+}
+
+#[test]
+fn test_high_heat_recipe_with_forge_succeeds() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let forge = place_world_object(&mut registry, station_kind("forge"), &["high_heat"], None);
+    new_simple(&mut registry, "iron_ore", 2);
+    let ore = inventory_index(&mut registry, "iron_ore");
+    let station = station_index(&registry, forge);
+
+    let response = exec_command(&format!("craft smelt_iron_bar {} @{}", ore, station), &mut registry);
+    assert!(is_success(&response), "craft failed: {}", response);
+}
+
+#[test]
+fn test_high_heat_recipe_with_non_forge_station_fails() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+    let workbench = place_world_object(&mut registry, station_kind("workbench"), &[], None);
+    new_simple(&mut registry, "iron_ore", 2);
+    let ore = inventory_index(&mut registry, "iron_ore");
+    let station = station_index(&registry, workbench);
+
+    let response = exec_command(&format!("craft smelt_iron_bar {} @{}", ore, station), &mut registry);
+    assert_eq!(response["status"], "error");
+    let message = response["message"].as_str().unwrap();
+    assert!(message.contains("forge station providing [high_heat]"), "unexpected error: {}", message);
+
+    // Ore is untouched by the failed craft
+    assert_eq!(registry.all_instances().count(), 1);
+}
+
+// ============================================================================
+// CONTENT VALIDATION TESTS
+// ============================================================================
+
+#[test]
+fn test_validate_reports_dangling_references() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    registry.register_simple_recipe(crafting::SimpleRecipe {
+        id: crafting::RecipeId("smelt_mithril".to_string()),
+        name: "Smelt Mithril".to_string(),
+        output: crafting::ItemId("mithril_bar".to_string()),
+        output_quantity: 1,
+        inputs: vec![crafting::SimpleInput {
+            item_id: crafting::ItemId("mithril_ore".to_string()),
+            quantity: 2,
+        }],
+        tool: Some(crafting::ToolRequirement {
+            tool_type: crafting::ToolType::Custom("tongs".to_string()),
+            min_quality: crafting::Quality::Common,
+        }),
+        world_object: None,
+    });
+    registry.register_component_recipe(crafting::ComponentRecipe {
+        id: crafting::RecipeId("craft_gem_socket".to_string()),
+        name: "Craft Gem Socket".to_string(),
+        output: crafting::ComponentKindId("gem_socket".to_string()),
+        tool: None,
+        world_object: None,
+    });
+    registry.register_composite_recipe(crafting::CompositeRecipe {
+        id: crafting::RecipeId("assemble_ore".to_string()),
+        name: "Assemble Ore".to_string(),
+        output: crafting::ItemId("iron_ore".to_string()),
+        tool: None,
+        world_object: None,
+        quality_formula: crafting::QualityFormula::Average,
+    });
+
+    let problems = registry.validate().unwrap_err();
+    assert_eq!(problems.len(), 5, "{:#?}", problems);
+    let mentions = |needle: &str| problems.iter().any(|p| p.contains(needle));
+    assert!(mentions("unknown item \"mithril_bar\""));
+    assert!(mentions("unknown item \"mithril_ore\""));
+    assert!(mentions("tool Custom(\"tongs\")"));
+    assert!(mentions("unknown component kind \"gem_socket\""));
+    assert!(mentions("not a Composite item"));
+}
+
+#[test]
+fn test_validate_reports_broken_composite_slot() {
+    let mut registry = Registry::new();
+    crafting::content::register_sample_content(&mut registry);
+
+    registry.register_item(crafting::ItemDefinition {
+        id: crafting::ItemId("flail".to_string()),
+        name: "Flail".to_string(),
+        description: "Spiked ball on a chain".to_string(),
+        kind: crafting::ItemKind::Composite(crafting::CompositeDef {
+            slots: vec![crafting::CompositeSlot {
+                name: "chain".to_string(),
+                component_kind: crafting::ComponentKindId("chain".to_string()),
+            }],
+            category: crafting::CompositeCategory::Weapon,
+            tool_type: None,
+        }),
+    });
+
+    let problems = registry.validate().unwrap_err();
+    assert_eq!(problems, vec![
+        "Composite \"flail\" slot \"chain\" references unknown component kind \"chain\"".to_string(),
+    ]);
+}
+
 // ============================================================================
 // HELP COMMAND TEST
 // ============================================================================
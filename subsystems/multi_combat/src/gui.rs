@@ -1,4 +1,4 @@
-use combat::{Combatant, CombatState, CombatResult};
+use combat::{Combatant, CombatantClass, CombatState, CombatResult};
 use macroquad::prelude::*;
 
 /// Window configuration
@@ -13,109 +13,21 @@ fn window_conf() -> Conf {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
-enum PredefinedCombatant {
-    Tank,
-    GlassCannon,
-    Balanced,
-    Bruiser,
-    Assassin,
-    Defender,
-}
-
-impl PredefinedCombatant {
-    fn to_combatant(&self) -> Combatant {
-        match self {
-            PredefinedCombatant::Tank => Combatant::TANK,
-            PredefinedCombatant::GlassCannon => Combatant::GLASS_CANNON,
-            PredefinedCombatant::Balanced => Combatant::BALANCED,
-            PredefinedCombatant::Bruiser => Combatant::BRUISER,
-            PredefinedCombatant::Assassin => Combatant::ASSASSIN,
-            PredefinedCombatant::Defender => Combatant::DEFENDER,
-        }
-    }
-
-    fn name(&self) -> &'static str {
-        match self {
-            PredefinedCombatant::Tank => "Tank",
-            PredefinedCombatant::GlassCannon => "Glass Cannon",
-            PredefinedCombatant::Balanced => "Balanced",
-            PredefinedCombatant::Bruiser => "Bruiser",
-            PredefinedCombatant::Assassin => "Assassin",
-            PredefinedCombatant::Defender => "Defender",
-        }
-    }
-
-    fn color(&self) -> Color {
-        match self {
-            PredefinedCombatant::Tank => Color::new(0.3, 0.5, 0.8, 1.0),        // Steel blue - defensive, sturdy
-            PredefinedCombatant::GlassCannon => Color::new(0.95, 0.4, 0.2, 1.0), // Bright orange-red - aggressive, dangerous
-            PredefinedCombatant::Balanced => Color::new(0.4, 0.7, 0.4, 1.0),    // Forest green - nature, balanced
-            PredefinedCombatant::Bruiser => Color::new(0.85, 0.5, 0.15, 1.0),   // Deep orange - strong, powerful
-            PredefinedCombatant::Assassin => Color::new(0.5, 0.15, 0.6, 1.0),    // Dark purple - stealthy, deadly
-            PredefinedCombatant::Defender => Color::new(0.2, 0.6, 0.85, 1.0),   // Ice blue - protective, defensive
-        }
-    }
-}
-
-/// Detect a combatant's class based on their stats
-fn detect_combatant_class(combatant: &Combatant) -> PredefinedCombatant {
-    // Match exact predefined stats first
-    if combatant.health == 20 && combatant.attack == 2 {
-        return PredefinedCombatant::Tank;
-    }
-    if combatant.health == 5 && combatant.attack == 8 {
-        return PredefinedCombatant::GlassCannon;
-    }
-    if combatant.health == 10 && combatant.attack == 5 {
-        return PredefinedCombatant::Balanced;
+/// GUI color for each combatant class
+fn class_color(class: CombatantClass) -> Color {
+    match class {
+        CombatantClass::Tank => Color::new(0.3, 0.5, 0.8, 1.0),        // Steel blue - defensive, sturdy
+        CombatantClass::GlassCannon => Color::new(0.95, 0.4, 0.2, 1.0), // Bright orange-red - aggressive, dangerous
+        CombatantClass::Balanced => Color::new(0.4, 0.7, 0.4, 1.0),    // Forest green - nature, balanced
+        CombatantClass::Bruiser => Color::new(0.85, 0.5, 0.15, 1.0),   // Deep orange - strong, powerful
+        CombatantClass::Assassin => Color::new(0.5, 0.15, 0.6, 1.0),    // Dark purple - stealthy, deadly
+        CombatantClass::Defender => Color::new(0.2, 0.6, 0.85, 1.0),   // Ice blue - protective, defensive
     }
-    if combatant.health == 15 && combatant.attack == 6 {
-        return PredefinedCombatant::Bruiser;
-    }
-    if combatant.health == 3 && combatant.attack == 10 {
-        return PredefinedCombatant::Assassin;
-    }
-    if combatant.health == 25 && combatant.attack == 1 {
-        return PredefinedCombatant::Defender;
-    }
-    
-    // Classify by stat patterns
-    let health = combatant.health;
-    let attack = combatant.attack;
-    
-    // Defender: Very high health, very low attack
-    if health >= 20 && attack <= 2 {
-        return PredefinedCombatant::Defender;
-    }
-    
-    // Tank: High health, low attack
-    if health >= 15 && attack <= 3 {
-        return PredefinedCombatant::Tank;
-    }
-    
-    // Assassin: Very low health, very high attack
-    if health <= 4 && attack >= 9 {
-        return PredefinedCombatant::Assassin;
-    }
-    
-    // Glass Cannon: Low health, high attack
-    if health <= 6 && attack >= 7 {
-        return PredefinedCombatant::GlassCannon;
-    }
-    
-    // Bruiser: High health, medium-high attack
-    if health >= 12 && attack >= 5 {
-        return PredefinedCombatant::Bruiser;
-    }
-    
-    // Default to Balanced for everything else
-    PredefinedCombatant::Balanced
 }
 
 /// Get color for a combatant based on their class
 fn get_combatant_color(combatant: &Combatant) -> Color {
-    detect_combatant_class(combatant).color()
+    class_color(combatant.classify())
 }
 
 struct CombatGUI {
@@ -154,7 +66,7 @@ enum InputField {
 
 #[derive(Clone)]
 struct TeamMemberUI {
-    preset: PredefinedCombatant,
+    preset: CombatantClass,
     custom: bool,
     health: String,
     attack: String,
@@ -164,7 +76,7 @@ struct TeamMemberUI {
 impl TeamMemberUI {
     fn new() -> Self {
         Self {
-            preset: PredefinedCombatant::Balanced,
+            preset: CombatantClass::Balanced,
             custom: false,
             health: "10".to_string(),
             attack: "5".to_string(),
@@ -188,7 +100,7 @@ impl TeamMemberUI {
             // Detect class from stats for custom combatants
             get_combatant_color(&self.to_combatant())
         } else {
-            self.preset.color()
+            class_color(self.preset)
         }
     }
 }
@@ -482,17 +394,17 @@ impl CombatGUI {
         is_clicked
     }
 
-    fn draw_preset_button(&self, x: f32, y: f32, width: f32, height: f32, preset: PredefinedCombatant, is_selected: bool) -> bool {
+    fn draw_preset_button(&self, x: f32, y: f32, width: f32, height: f32, preset: CombatantClass, is_selected: bool) -> bool {
         let (mouse_x, mouse_y) = mouse_position();
         let is_hovered = mouse_x >= x && mouse_x <= x + width && mouse_y >= y && mouse_y <= y + height;
         let is_clicked = is_hovered && is_mouse_button_pressed(MouseButton::Left);
 
         let bg_color = if is_selected {
-            preset.color()
+            class_color(preset)
         } else if is_hovered {
-            Color::new(preset.color().r * 0.7, preset.color().g * 0.7, preset.color().b * 0.7, 1.0)
+            Color::new(class_color(preset).r * 0.7, class_color(preset).g * 0.7, class_color(preset).b * 0.7, 1.0)
         } else {
-            Color::new(preset.color().r * 0.5, preset.color().g * 0.5, preset.color().b * 0.5, 1.0)
+            Color::new(class_color(preset).r * 0.5, class_color(preset).g * 0.5, class_color(preset).b * 0.5, 1.0)
         };
 
         self.draw_rounded_rect(x, y, width, height, 6.0, bg_color);
@@ -710,12 +622,12 @@ impl CombatGUI {
         draw_text("New Member Preset:", left_panel_x, preset_y, 14.0, Color::new(0.4, 0.4, 0.4, 1.0));
         
         let preset_buttons = [
-            PredefinedCombatant::Tank,
-            PredefinedCombatant::GlassCannon,
-            PredefinedCombatant::Balanced,
-            PredefinedCombatant::Bruiser,
-            PredefinedCombatant::Assassin,
-            PredefinedCombatant::Defender,
+            CombatantClass::Tank,
+            CombatantClass::GlassCannon,
+            CombatantClass::Balanced,
+            CombatantClass::Bruiser,
+            CombatantClass::Assassin,
+            CombatantClass::Defender,
         ];
         
         let button_spacing = 6.0;
@@ -870,7 +782,7 @@ impl CombatGUI {
                 } else {
                     base_color
                 };
-                (*c, max_hp, color)
+                (c.clone(), max_hp, color)
             }).collect();
             let s2: Vec<(Combatant, i32, Color)> = state.side2.iter().enumerate().map(|(i, c)| {
                 let (max_hp, original_attack) = if let Some((_, _, s2_before, _, _, _)) = self.combat_history.first() {
@@ -896,7 +808,7 @@ impl CombatGUI {
                 } else {
                     base_color
                 };
-                (*c, max_hp, color)
+                (c.clone(), max_hp, color)
             }).collect();
             (s1, s2)
         } else {
@@ -920,7 +832,7 @@ impl CombatGUI {
                 } else {
                     base_color
                 };
-                (c, c.health, color)
+                (c.clone(), c.health, color)
             }).collect();
             let s2: Vec<(Combatant, i32, Color)> = self.side2_team.iter().enumerate().map(|(i, m)| {
                 let c = m.to_combatant();
@@ -941,7 +853,7 @@ impl CombatGUI {
                 } else {
                     base_color
                 };
-                (c, c.health, color)
+                (c.clone(), c.health, color)
             }).collect();
             (s1, s2)
         };
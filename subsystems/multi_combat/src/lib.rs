@@ -1,43 +1,167 @@
+pub use combat_engine::{simulate, simulate_bounded, CombatEngine, CombatOutcome, Side, DEFAULT_MAX_ROUNDS};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Multi-combatant combat system with simultaneous attack resolution
 /// Similar to Super Auto Pets combat mechanics
 /// Supports multiple combatants on each side, with front-to-back attacking
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Combatant {
     pub health: i32,
     pub attack: i32,
     pub leadership: i32,  // Max followers this leader can have (team size = leader + leadership)
+    pub attack_kind: AttackKind,
+    /// Spawned in this combatant's position when it is defeated
+    pub summon: Option<Box<Combatant>>,
+    /// Shown in result messages when leading a side; empty falls back to "Side 1"/"Side 2"
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: String,
+}
+
+/// How a combatant's attack spreads across the enemy formation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AttackKind {
+    /// Full damage to the target only
+    #[default]
+    Single,
+    /// Full damage to the target plus `splash` damage to the combatant directly behind it
+    Cleave { splash: i32 },
 }
 
 /// Predefined combatants with different stat combinations
 impl Combatant {
     /// Tank: High health, low attack - survives long but deals little damage
-    pub const TANK: Combatant = Combatant { health: 20, attack: 2, leadership: 4 };
+    pub const TANK: Combatant = Combatant { health: 20, attack: 2, leadership: 4, attack_kind: AttackKind::Single, summon: None, name: String::new() };
     
     /// Glass Cannon: Low health, high attack - deals massive damage but fragile
-    pub const GLASS_CANNON: Combatant = Combatant { health: 5, attack: 8, leadership: 3 };
+    pub const GLASS_CANNON: Combatant = Combatant { health: 5, attack: 8, leadership: 3, attack_kind: AttackKind::Single, summon: None, name: String::new() };
     
     /// Balanced Fighter: Medium health and attack - well-rounded combatant
-    pub const BALANCED: Combatant = Combatant { health: 10, attack: 5, leadership: 3 };
+    pub const BALANCED: Combatant = Combatant { health: 10, attack: 5, leadership: 3, attack_kind: AttackKind::Single, summon: None, name: String::new() };
     
     /// Bruiser: High health, medium attack - durable and hits hard
-    pub const BRUISER: Combatant = Combatant { health: 15, attack: 6, leadership: 4 };
+    pub const BRUISER: Combatant = Combatant { health: 15, attack: 6, leadership: 4, attack_kind: AttackKind::Single, summon: None, name: String::new() };
     
     /// Assassin: Very low health, very high attack - extreme glass cannon
-    pub const ASSASSIN: Combatant = Combatant { health: 3, attack: 10, leadership: 2 };
+    pub const ASSASSIN: Combatant = Combatant { health: 3, attack: 10, leadership: 2, attack_kind: AttackKind::Single, summon: None, name: String::new() };
     
     /// Defender: Very high health, very low attack - ultimate tank
-    pub const DEFENDER: Combatant = Combatant { health: 25, attack: 1, leadership: 5 };
+    pub const DEFENDER: Combatant = Combatant { health: 25, attack: 1, leadership: 5, attack_kind: AttackKind::Single, summon: None, name: String::new() };
+}
+
+/// Archetype of a combatant, matching the predefined stat lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatantClass {
+    Tank,
+    GlassCannon,
+    Balanced,
+    Bruiser,
+    Assassin,
+    Defender,
+}
+
+impl CombatantClass {
+    /// Every class, in the order the predefined combatants are declared
+    pub const ALL: [CombatantClass; 6] = [
+        CombatantClass::Tank,
+        CombatantClass::GlassCannon,
+        CombatantClass::Balanced,
+        CombatantClass::Bruiser,
+        CombatantClass::Assassin,
+        CombatantClass::Defender,
+    ];
+
+    /// The predefined combatant for this class
+    pub fn to_combatant(self) -> Combatant {
+        match self {
+            CombatantClass::Tank => Combatant::TANK,
+            CombatantClass::GlassCannon => Combatant::GLASS_CANNON,
+            CombatantClass::Balanced => Combatant::BALANCED,
+            CombatantClass::Bruiser => Combatant::BRUISER,
+            CombatantClass::Assassin => Combatant::ASSASSIN,
+            CombatantClass::Defender => Combatant::DEFENDER,
+        }
+    }
+
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            CombatantClass::Tank => "Tank",
+            CombatantClass::GlassCannon => "Glass Cannon",
+            CombatantClass::Balanced => "Balanced",
+            CombatantClass::Bruiser => "Bruiser",
+            CombatantClass::Assassin => "Assassin",
+            CombatantClass::Defender => "Defender",
+        }
+    }
+}
+
+/// Aggregate snapshot of both sides, from `CombatState::summary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombatSummary {
+    pub side1_alive: usize,
+    pub side2_alive: usize,
+    /// Sum of health over side 1's living combatants
+    pub side1_total_hp: i32,
+    /// Sum of health over side 2's living combatants
+    pub side2_total_hp: i32,
+    pub rounds: u32,
 }
 
+/// Who won and what they have left, from `CombatState::result_detail`
+///
+/// Useful for lopsided fights such as `CombatState::boss_fight`, where "side 1 wins"
+/// says nothing about how close the boss came to falling.
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultDetail {
+    pub result: CombatResult,
+    /// Winning side (None while ongoing or on a draw)
+    pub winner: Option<Side>,
+    /// Living combatants on the winning side, front first (empty without a winner)
+    pub survivors: Vec<Combatant>,
+    pub rounds: u32,
+}
+
+/// Leadership above this value grants the leader's followers bonus attack
+pub const AURA_LEADERSHIP_THRESHOLD: i32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CombatState {
     pub side1: Vec<Combatant>,
     pub side2: Vec<Combatant>,
     pub round: u32,
+    pub targeting: TargetingMode,
+    /// Snapshots taken before each round, for `undo_round` (None unless `with_history`)
+    #[cfg_attr(feature = "serde", serde(skip))]
+    history: Option<Vec<RoundSnapshot>>,
+}
+
+/// Both sides and the round counter as they were before a round executed
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RoundSnapshot {
+    side1: Vec<Combatant>,
+    side2: Vec<Combatant>,
+    round: u32,
+}
+
+/// How combatants choose which enemy to attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TargetingMode {
+    /// Every combatant attacks the front-most enemy (index 0)
+    #[default]
+    FrontOnly,
+    /// The front combatant attacks the front-most enemy, while combatants at
+    /// index >= 1 (archers) attack the rear-most enemy
+    Ranged,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CombatResult {
     /// Combat continues, both sides still have living combatants
     Ongoing,
@@ -52,13 +176,145 @@ pub enum CombatResult {
 impl Combatant {
     /// Create a new combatant with specified health, attack, and leadership stats
     pub fn new(health: i32, attack: i32, leadership: i32) -> Self {
-        Self { health, attack, leadership }
+        Self { health, attack, leadership, attack_kind: AttackKind::Single, summon: None, name: String::new() }
+    }
+
+    /// Start building a combatant by named stat; unset stats default to 0,
+    /// a single-target attack, and no summon
+    pub fn builder() -> CombatantBuilder {
+        CombatantBuilder::default()
+    }
+
+    /// Set the combatant spawned in this one's position when it is defeated
+    pub fn with_summon(mut self, summon: Combatant) -> Self {
+        self.summon = Some(Box::new(summon));
+        self
+    }
+
+    /// Set how this combatant's attack spreads across the enemy formation
+    pub fn with_attack_kind(mut self, attack_kind: AttackKind) -> Self {
+        self.attack_kind = attack_kind;
+        self
+    }
+
+    /// Set the name used in result messages
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// The combatant's name, or `fallback` if it has none
+    pub fn display_name<'a>(&'a self, fallback: &'a str) -> &'a str {
+        if self.name.is_empty() {
+            fallback
+        } else {
+            &self.name
+        }
     }
 
     /// Check if this combatant is defeated (health <= 0)
     pub fn is_defeated(&self) -> bool {
         self.health <= 0
     }
+
+    /// Classify by health and attack (leadership is ignored)
+    ///
+    /// Exact predefined stat lines map to their own class. Anything else is checked
+    /// in order against these thresholds, falling back to Balanced:
+    /// - Defender: health >= 20, attack <= 2
+    /// - Tank: health >= 15, attack <= 3
+    /// - Assassin: health <= 4, attack >= 9
+    /// - Glass Cannon: health <= 6, attack >= 7
+    /// - Bruiser: health >= 12, attack >= 5
+    pub fn classify(&self) -> CombatantClass {
+        let health = self.health;
+        let attack = self.attack;
+
+        // Match exact predefined stats first
+        if let Some(class) = CombatantClass::ALL.into_iter().find(|class| {
+            let preset = class.to_combatant();
+            preset.health == health && preset.attack == attack
+        }) {
+            return class;
+        }
+
+        // Classify by stat patterns
+        if health >= 20 && attack <= 2 {
+            CombatantClass::Defender
+        } else if health >= 15 && attack <= 3 {
+            CombatantClass::Tank
+        } else if health <= 4 && attack >= 9 {
+            CombatantClass::Assassin
+        } else if health <= 6 && attack >= 7 {
+            CombatantClass::GlassCannon
+        } else if health >= 12 && attack >= 5 {
+            CombatantClass::Bruiser
+        } else {
+            CombatantClass::Balanced
+        }
+    }
+}
+
+/// Builder returned by `Combatant::builder`
+#[derive(Debug, Clone, Default)]
+pub struct CombatantBuilder {
+    health: i32,
+    attack: i32,
+    leadership: i32,
+    attack_kind: AttackKind,
+    summon: Option<Combatant>,
+    name: String,
+}
+
+impl CombatantBuilder {
+    pub fn health(mut self, health: i32) -> Self {
+        self.health = health;
+        self
+    }
+
+    pub fn attack(mut self, attack: i32) -> Self {
+        self.attack = attack;
+        self
+    }
+
+    /// Max followers; 0 means the combatant can only fight alone as leader
+    pub fn leadership(mut self, leadership: i32) -> Self {
+        self.leadership = leadership;
+        self
+    }
+
+    pub fn attack_kind(mut self, attack_kind: AttackKind) -> Self {
+        self.attack_kind = attack_kind;
+        self
+    }
+
+    pub fn summon(mut self, summon: Combatant) -> Self {
+        self.summon = Some(summon);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Finish the combatant, rejecting negative health or leadership
+    pub fn build(self) -> Result<Combatant, String> {
+        if self.health < 0 {
+            return Err(format!("Health cannot be negative (got {})", self.health));
+        }
+        if self.leadership < 0 {
+            return Err(format!("Leadership cannot be negative (got {})", self.leadership));
+        }
+        Ok(Combatant {
+            health: self.health,
+            attack: self.attack,
+            leadership: self.leadership,
+            attack_kind: self.attack_kind,
+            summon: self.summon.map(Box::new),
+            name: self.name,
+        })
+    }
 }
 
 impl CombatState {
@@ -91,9 +347,96 @@ impl CombatState {
             side1,
             side2,
             round: 0,
+            targeting: TargetingMode::default(),
+            history: None,
+        })
+    }
+
+    /// Create a new combat state, requiring both teams to pass `validate_team_strict`
+    pub fn new_strict(side1: Vec<Combatant>, side2: Vec<Combatant>) -> Result<Self, String> {
+        Self::validate_team_strict(&side1).map_err(|e| format!("Side 1: {}", e))?;
+        Self::validate_team_strict(&side2).map_err(|e| format!("Side 2: {}", e))?;
+        Self::new(side1, side2)
+    }
+
+    /// Create a lone `boss` (side 1) against a `swarm` (side 2)
+    /// The boss's leadership isn't checked, since it has no followers to lead; the swarm is
+    /// validated as usual, so its front combatant must be able to lead the rest
+    pub fn boss_fight(boss: Combatant, swarm: Vec<Combatant>) -> Result<Self, String> {
+        if swarm.is_empty() {
+            return Err("Swarm cannot be empty".to_string());
+        }
+        Self::validate_team_size(&swarm).map_err(|e| format!("Swarm: {}", e))?;
+
+        Ok(Self {
+            side1: vec![boss],
+            side2: swarm,
+            round: 0,
+            targeting: TargetingMode::default(),
+            history: None,
         })
     }
 
+    /// Create a new combat state, optionally applying each leader's aura to its followers
+    /// See `apply_leader_aura` for the formula
+    pub fn new_with_aura(side1: Vec<Combatant>, side2: Vec<Combatant>, aura: bool) -> Result<Self, String> {
+        let mut state = Self::new(side1, side2)?;
+        if aura {
+            state.apply_leader_aura();
+        }
+        Ok(state)
+    }
+
+    /// Buff every follower (index >= 1) on each side with the leader's aura:
+    /// `+1 attack` per point of the leader's leadership above `AURA_LEADERSHIP_THRESHOLD`
+    /// The leader does not buff itself. Intended to be called once, before the first round
+    pub fn apply_leader_aura(&mut self) {
+        for side in [&mut self.side1, &mut self.side2] {
+            let bonus = match side.first() {
+                Some(leader) => (leader.leadership - AURA_LEADERSHIP_THRESHOLD).max(0),
+                None => continue,
+            };
+            for follower in side.iter_mut().skip(1) {
+                follower.attack += bonus;
+            }
+        }
+    }
+
+    /// Set how combatants choose which enemy to attack
+    pub fn with_targeting(mut self, targeting: TargetingMode) -> Self {
+        self.targeting = targeting;
+        self
+    }
+
+    /// Snapshot the state before every round so `undo_round` can step back
+    /// Off by default, since batch simulations don't need the extra clones
+    pub fn with_history(mut self) -> Self {
+        self.history = Some(Vec::new());
+        self
+    }
+
+    /// Restore the state from before the most recent round, including any removed combatants
+    /// Returns false if history is disabled or no rounds have been executed since it was enabled
+    pub fn undo_round(&mut self) -> bool {
+        match self.history.as_mut().and_then(|history| history.pop()) {
+            Some(snapshot) => {
+                self.side1 = snapshot.side1;
+                self.side2 = snapshot.side2;
+                self.round = snapshot.round;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Index of the enemy targeted by the attacker at `attacker_index`
+    fn target_index(&self, attacker_index: usize, enemy_count: usize) -> usize {
+        match self.targeting {
+            TargetingMode::Ranged if attacker_index >= 1 => enemy_count - 1,
+            _ => 0,
+        }
+    }
+
     /// Get the front-most combatant for a side (index 0)
     pub fn get_front_combatant(&self, side: usize) -> Option<&Combatant> {
         match side {
@@ -116,30 +459,73 @@ impl CombatState {
         Ok(())
     }
 
+    /// Add the damage from one attacker into the enemy side's damage vector
+    /// Cleave splash past the last enemy is ignored
+    fn add_attack_damage(&self, damage: &mut [i32], attacker_index: usize, attacker: &Combatant) {
+        let target = self.target_index(attacker_index, damage.len());
+        damage[target] += attacker.attack;
+        if let AttackKind::Cleave { splash } = attacker.attack_kind {
+            if let Some(behind) = damage.get_mut(target + 1) {
+                *behind += splash;
+            }
+        }
+    }
+
+    /// Validate a team against every formation it can shift into
+    ///
+    /// When the front combatant dies, everyone behind it shifts forward and the new
+    /// front combatant becomes the leader of the remaining team. Assuming combatants
+    /// fall front to back, the combatant at index `i` will lead the `len - i` that remain,
+    /// so each one needs `leadership + 1 >= len - i`. Summons are not considered
+    pub fn validate_team_strict(team: &[Combatant]) -> Result<(), String> {
+        Self::validate_team_size(team)?;
+        for (i, combatant) in team.iter().enumerate() {
+            let remaining = team.len() - i;
+            let max_size = (combatant.leadership + 1).max(0) as usize;
+            if remaining > max_size {
+                return Err(format!(
+                    "Combatant at position {} would lead {} combatants but has leadership capacity of {}",
+                    i + 1,
+                    remaining,
+                    max_size
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Execute one round of combat where all combatants attack simultaneously
-    /// Each combatant attacks the front-most enemy (index 0 of opposing side)
+    /// Each combatant attacks the front-most enemy (index 0 of opposing side),
+    /// or the rear-most enemy for back-row combatants in `TargetingMode::Ranged`
     /// Returns the combat result after this round
     pub fn execute_round(&mut self) -> CombatResult {
+        if let Some(history) = self.history.as_mut() {
+            history.push(RoundSnapshot {
+                side1: self.side1.clone(),
+                side2: self.side2.clone(),
+                round: self.round,
+            });
+        }
         self.round += 1;
 
         // Collect all damage to apply simultaneously
         let mut side1_damage = vec![0; self.side1.len()];
         let mut side2_damage = vec![0; self.side2.len()];
 
-        // Side 1 attacks front-most enemy (side2[0])
+        // Side 1 attacks side 2 (front-most enemy unless ranged)
         if !self.side2.is_empty() {
-            for combatant in self.side1.iter() {
+            for (i, combatant) in self.side1.iter().enumerate() {
                 if !combatant.is_defeated() {
-                    side2_damage[0] += combatant.attack;
+                    self.add_attack_damage(&mut side2_damage, i, combatant);
                 }
             }
         }
 
-        // Side 2 attacks front-most enemy (side1[0])
+        // Side 2 attacks side 1 (front-most enemy unless ranged)
         if !self.side1.is_empty() {
-            for combatant in self.side2.iter() {
+            for (i, combatant) in self.side2.iter().enumerate() {
                 if !combatant.is_defeated() {
-                    side1_damage[0] += combatant.attack;
+                    self.add_attack_damage(&mut side1_damage, i, combatant);
                 }
             }
         }
@@ -156,14 +542,50 @@ impl CombatState {
             }
         }
 
-        // Remove defeated combatants and shift remaining forward
-        self.side1.retain(|c| !c.is_defeated());
-        self.side2.retain(|c| !c.is_defeated());
+        // Remove defeated combatants (spawning their summons) and shift remaining forward
+        self.remove_defeated();
 
         // Determine result
         self.get_result()
     }
 
+    /// Execute one round and record every combatant's health before and after it
+    pub fn execute_round_logged(&mut self) -> RoundRecord {
+        let health = |side: &[Combatant]| side.iter().map(|c| c.health).collect::<Vec<_>>();
+        let side1_before = health(&self.side1);
+        let side2_before = health(&self.side2);
+        let result = self.execute_round();
+        RoundRecord {
+            round: self.round,
+            side1_before,
+            side2_before,
+            side1_after: health(&self.side1),
+            side2_after: health(&self.side2),
+            result,
+        }
+    }
+
+    /// Remove all defeated combatants and shift remaining forward
+    /// A defeated combatant with a summon is replaced in place by that summon,
+    /// which first acts in the following round. Leadership is not re-checked
+    pub fn remove_defeated(&mut self) {
+        Self::remove_defeated_from(&mut self.side1);
+        Self::remove_defeated_from(&mut self.side2);
+    }
+
+    fn remove_defeated_from(side: &mut Vec<Combatant>) {
+        let combatants = std::mem::take(side);
+        for combatant in combatants {
+            if !combatant.is_defeated() {
+                side.push(combatant);
+            } else if let Some(summon) = combatant.summon {
+                if !summon.is_defeated() {
+                    side.push(*summon);
+                }
+            }
+        }
+    }
+
     /// Get the current combat result without executing a round
     pub fn get_result(&self) -> CombatResult {
         let side1_alive = self.side1.iter().any(|c| !c.is_defeated());
@@ -177,6 +599,49 @@ impl CombatState {
         }
     }
 
+    /// Summarize living combatants and remaining health on each side
+    pub fn summary(&self) -> CombatSummary {
+        let alive = |side: &[Combatant]| side.iter().filter(|c| !c.is_defeated()).count();
+        let total_hp = |side: &[Combatant]| side.iter().filter(|c| !c.is_defeated()).map(|c| c.health).sum();
+        CombatSummary {
+            side1_alive: alive(&self.side1),
+            side2_alive: alive(&self.side2),
+            side1_total_hp: total_hp(&self.side1),
+            side2_total_hp: total_hp(&self.side2),
+            rounds: self.round,
+        }
+    }
+
+    /// Report the result along with the winning side's survivors and the rounds fought
+    pub fn result_detail(&self) -> ResultDetail {
+        let result = self.get_result();
+        let winner = result.winner();
+        let survivors = match winner {
+            Some(Side::First) => &self.side1[..],
+            Some(Side::Second) => &self.side2[..],
+            None => &[],
+        };
+        ResultDetail {
+            result,
+            winner,
+            survivors: survivors.iter().filter(|c| !c.is_defeated()).cloned().collect(),
+            rounds: self.round,
+        }
+    }
+
+    /// Margin of victory from side 1's perspective: side 1's remaining total HP if it won,
+    /// the negated remaining total HP of side 2 if side 1 lost, and 0 on a draw
+    /// While combat is ongoing, this is the difference in remaining total HP
+    pub fn margin_of_victory(&self) -> i32 {
+        let summary = self.summary();
+        match self.get_result() {
+            CombatResult::Side1Wins => summary.side1_total_hp,
+            CombatResult::Side2Wins => -summary.side2_total_hp,
+            CombatResult::Draw => 0,
+            CombatResult::Ongoing => summary.side1_total_hp - summary.side2_total_hp,
+        }
+    }
+
     /// Simulate combat to completion, executing rounds until someone wins or draw
     /// Returns the final state and result
     pub fn simulate_combat(mut self) -> (Self, CombatResult) {
@@ -190,6 +655,175 @@ impl CombatState {
     }
 }
 
+impl CombatResult {
+    /// Describe the result using each side's leader, e.g. "Knight defeats Goblin King"
+    ///
+    /// Pass the teams as they started, since a defeated leader is gone from the final state.
+    /// Unnamed or missing leaders are called "Side 1"/"Side 2". If neither leader has a
+    /// name this is the plain form ("Side 1 Wins", "Draw", ...).
+    pub fn describe(&self, side1: &[Combatant], side2: &[Combatant]) -> String {
+        let leader_name = |side: &[Combatant], fallback: &'static str| {
+            side.first().map_or(fallback.to_string(), |leader| leader.display_name(fallback).to_string())
+        };
+        let named = side1.first().is_some_and(|c| !c.name.is_empty()) || side2.first().is_some_and(|c| !c.name.is_empty());
+        let name1 = leader_name(side1, "Side 1");
+        let name2 = leader_name(side2, "Side 2");
+        match (self, named) {
+            (CombatResult::Side1Wins, false) => "Side 1 Wins".to_string(),
+            (CombatResult::Side2Wins, false) => "Side 2 Wins".to_string(),
+            (CombatResult::Draw, false) => "Draw".to_string(),
+            (CombatResult::Side1Wins, true) => format!("{} defeats {}", name1, name2),
+            (CombatResult::Side2Wins, true) => format!("{} defeats {}", name2, name1),
+            (CombatResult::Draw, true) => format!("Draw between {} and {}", name1, name2),
+            (CombatResult::Ongoing, _) => "Ongoing".to_string(),
+        }
+    }
+}
+
+impl CombatOutcome for CombatResult {
+    fn is_ongoing(&self) -> bool {
+        *self == CombatResult::Ongoing
+    }
+
+    fn winner(&self) -> Option<Side> {
+        match self {
+            CombatResult::Side1Wins => Some(Side::First),
+            CombatResult::Side2Wins => Some(Side::Second),
+            _ => None,
+        }
+    }
+}
+
+/// Generic engine view of a team fight
+impl CombatEngine for CombatState {
+    type Result = CombatResult;
+
+    fn execute_round(&mut self) -> CombatResult {
+        CombatState::execute_round(self)
+    }
+
+    fn get_result(&self) -> CombatResult {
+        CombatState::get_result(self)
+    }
+
+    fn round(&self) -> u32 {
+        self.round
+    }
+}
+
+/// One round of a team fight: each side's health by position before and after it
+///
+/// The `after` lists only hold combatants still standing (including freshly spawned summons),
+/// so a shorter `after` list means the combatants past its end were defeated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RoundRecord {
+    /// Round number, starting at 1
+    pub round: u32,
+    pub side1_before: Vec<i32>,
+    pub side2_before: Vec<i32>,
+    pub side1_after: Vec<i32>,
+    pub side2_after: Vec<i32>,
+    /// Result after this round
+    pub result: CombatResult,
+}
+
+/// A saved fight: the starting state and round cap, plus the rounds and result it produced
+///
+/// Combat has no randomness, so the initial state (teams, targeting and round) and the cap
+/// fully determine the fight. `rounds` and `result` are kept only to check a replay against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FightLog {
+    pub initial: CombatState,
+    pub max_rounds: u32,
+    pub rounds: Vec<RoundRecord>,
+    /// `Ongoing` if the round cap was reached with both sides standing
+    pub result: CombatResult,
+}
+
+impl FightLog {
+    /// Run `initial` to completion with `DEFAULT_MAX_ROUNDS` and record the fight
+    pub fn record(initial: CombatState) -> Self {
+        Self::record_bounded(initial, DEFAULT_MAX_ROUNDS)
+    }
+
+    /// Run `initial` to completion with a custom round cap and record the fight
+    pub fn record_bounded(initial: CombatState, max_rounds: u32) -> Self {
+        let mut log = Self { initial, max_rounds, rounds: Vec::new(), result: CombatResult::Ongoing };
+        let mut replay = log.replay();
+        let rounds: Vec<RoundRecord> = replay.by_ref().map(|step| step.record).collect();
+        let result = replay.result();
+        log.rounds = rounds;
+        log.result = result;
+        log
+    }
+
+    /// Step through the fight again, recomputing every round from `initial`
+    pub fn replay(&self) -> Replay<'_> {
+        Replay { log: self, state: self.initial.clone(), finished: false }
+    }
+
+    /// Serialize the fight log to JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("FightLog is always serializable")
+    }
+
+    /// Deserialize a fight log previously produced by `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse fight log: {}", e))
+    }
+}
+
+/// One recomputed round of a `Replay`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayStep {
+    /// State after the round
+    pub state: CombatState,
+    /// The round as recomputed from the initial state
+    pub record: RoundRecord,
+    /// The same round as stored in the log (None if the log has fewer rounds)
+    pub stored: Option<RoundRecord>,
+}
+
+impl ReplayStep {
+    /// True if the recomputed round matches the stored one
+    pub fn matches_log(&self) -> bool {
+        self.stored.as_ref() == Some(&self.record)
+    }
+}
+
+/// Iterator over the rounds of a `FightLog`, recomputed one at a time (see `FightLog::replay`)
+pub struct Replay<'a> {
+    log: &'a FightLog,
+    state: CombatState,
+    finished: bool,
+}
+
+impl Replay<'_> {
+    /// Result of the replayed fight so far (still `Ongoing` if the round cap was reached)
+    pub fn result(&self) -> CombatResult {
+        self.state.get_result()
+    }
+}
+
+impl Iterator for Replay<'_> {
+    type Item = ReplayStep;
+
+    fn next(&mut self) -> Option<ReplayStep> {
+        if self.finished || self.state.round >= self.log.max_rounds {
+            return None;
+        }
+        let played = self.state.round - self.log.initial.round;
+        let stored = self.log.rounds.get(played as usize).cloned();
+        let record = self.state.execute_round_logged();
+        self.finished = record.result != CombatResult::Ongoing;
+        Some(ReplayStep { state: self.state.clone(), record, stored })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +837,31 @@ mod tests {
         assert!(!c.is_defeated());
     }
 
+    #[test]
+    fn test_combatant_builder() {
+        let c = Combatant::builder().leadership(3).attack(5).health(10).build().unwrap();
+        assert_eq!(c, Combatant::new(10, 5, 3));
+        
+        // Unset stats default to 0, single-target, no summon
+        let c = Combatant::builder().health(4).build().unwrap();
+        assert_eq!(c.attack, 0);
+        assert_eq!(c.leadership, 0);
+        assert_eq!(c.attack_kind, AttackKind::Single);
+        assert!(c.summon.is_none());
+        
+        let c = Combatant::builder()
+            .health(6)
+            .attack_kind(AttackKind::Cleave { splash: 2 })
+            .summon(Combatant::new(1, 1, 0))
+            .build()
+            .unwrap();
+        assert_eq!(c.attack_kind, AttackKind::Cleave { splash: 2 });
+        assert_eq!(c.summon.as_deref(), Some(&Combatant::new(1, 1, 0)));
+        
+        assert!(Combatant::builder().health(-1).build().is_err());
+        assert!(Combatant::builder().health(1).leadership(-1).build().is_err());
+    }
+
     #[test]
     fn test_combatant_defeated() {
         let mut c = Combatant::new(10, 5, 3);
@@ -403,4 +1062,365 @@ mod tests {
         assert_eq!(state.get_front_combatant(2).unwrap().health, 8);
         assert!(state.get_front_combatant(3).is_none());
     }
+
+    #[test]
+    fn test_ranged_back_row_snipes_rear() {
+        // Side 1: tank in front, archer behind
+        // Side 2: tank in front protecting a fragile unit behind it
+        let side1 = vec![Combatant::new(30, 1, 2), Combatant::new(5, 6, 0)];
+        let side2 = vec![Combatant::new(30, 1, 2), Combatant::new(4, 8, 0)];
+
+        // Front-only: everyone hits the enemy tank, the fragile unit survives
+        let mut state = CombatState::new(side1.clone(), side2.clone()).unwrap();
+        state.execute_round();
+        assert_eq!(state.side2.len(), 2);
+        assert_eq!(state.side2[0].health, 23); // 30 - (1 + 6)
+
+        // Ranged: the archer shoots past the tank and kills the fragile unit
+        let mut state = CombatState::new(side1, side2)
+            .unwrap()
+            .with_targeting(TargetingMode::Ranged);
+        let result = state.execute_round();
+        assert_eq!(result, CombatResult::Ongoing);
+        assert_eq!(state.side2.len(), 1);
+        assert_eq!(state.side2[0].health, 29); // only the tank's 1 attack
+        // Side 2's back unit fired at side 1's archer in the same simultaneous round
+        assert_eq!(state.side1.len(), 1);
+        assert_eq!(state.side1[0].health, 29);
+    }
+
+    #[test]
+    fn test_cleave_kills_stacked_followers() {
+        let cleaver = Combatant::new(20, 4, 0).with_attack_kind(AttackKind::Cleave { splash: 3 });
+        let side2 = vec![Combatant::new(4, 1, 2), Combatant::new(3, 1, 1), Combatant::new(3, 1, 0)];
+        let mut state = CombatState::new(vec![cleaver], side2).unwrap();
+
+        // Front takes 4 (dies), index 1 takes 3 splash (dies), index 2 is untouched
+        let result = state.execute_round();
+        assert_eq!(result, CombatResult::Ongoing);
+        assert_eq!(state.side2.len(), 1);
+        assert_eq!(state.side2[0].health, 3);
+        assert_eq!(state.side1[0].health, 17); // all three enemies hit the cleaver
+    }
+
+    #[test]
+    fn test_cleave_splash_stacks_and_ignores_missing_target() {
+        let cleaver = Combatant::new(20, 2, 1).with_attack_kind(AttackKind::Cleave { splash: 2 });
+        let side2 = vec![Combatant::new(10, 0, 1), Combatant::new(10, 0, 0)];
+        let mut state = CombatState::new(vec![cleaver.clone(), cleaver.clone()], side2).unwrap();
+
+        // Two cleavers: front takes 2 + 2, the unit behind takes 2 + 2 splash
+        state.execute_round();
+        assert_eq!(state.side2[0].health, 6);
+        assert_eq!(state.side2[1].health, 6);
+
+        // A lone enemy: splash has nowhere to go
+        let mut state = CombatState::new(vec![cleaver], vec![Combatant::new(10, 0, 0)]).unwrap();
+        state.execute_round();
+        assert_eq!(state.side2[0].health, 8);
+    }
+
+    #[test]
+    fn test_summon_on_death_continues_fight() {
+        let leader = Combatant::new(3, 2, 1).with_summon(Combatant::new(6, 1, 0));
+        let follower = Combatant::new(10, 1, 0);
+        let mut state = CombatState::new(vec![leader, follower], vec![Combatant::new(20, 5, 0)]).unwrap();
+
+        // Round 1: leader dies and its summon takes the front position
+        let result = state.execute_round();
+        assert_eq!(result, CombatResult::Ongoing);
+        assert_eq!(state.side1.len(), 2);
+        assert_eq!(state.side1[0], Combatant::new(6, 1, 0));
+        assert_eq!(state.side1[1].health, 10);
+        assert_eq!(state.side2[0].health, 17); // 2 + 1, the summon did not act yet
+
+        // Round 2: the summon now attacks alongside the follower
+        state.execute_round();
+        assert_eq!(state.side1[0].health, 1);
+        assert_eq!(state.side2[0].health, 15);
+    }
+
+    #[test]
+    fn test_simultaneous_deaths_each_summon() {
+        let token = Combatant::new(1, 1, 0);
+        let side1 = vec![Combatant::new(2, 3, 1).with_summon(token.clone())];
+        let side2 = vec![Combatant::new(3, 2, 1).with_summon(token.clone())];
+        let mut state = CombatState::new(side1, side2).unwrap();
+
+        // Both die at once; each is replaced by its token instead of a draw
+        let result = state.execute_round();
+        assert_eq!(result, CombatResult::Ongoing);
+        assert_eq!(state.side1, vec![token.clone()]);
+        assert_eq!(state.side2, vec![token]);
+
+        // The tokens trade hits and this time nobody is left
+        assert_eq!(state.execute_round(), CombatResult::Draw);
+    }
+
+    #[test]
+    fn test_leader_aura_buffs_followers() {
+        // Leadership 4 is 2 above the threshold: +2 attack per follower
+        let side1 = vec![Combatant::new(10, 5, 4), Combatant::new(8, 3, 0), Combatant::new(6, 1, 0)];
+        // Leadership 2 is at the threshold: no bonus
+        let side2 = vec![Combatant::new(10, 5, 2), Combatant::new(8, 3, 0)];
+
+        let state = CombatState::new_with_aura(side1.clone(), side2.clone(), true).unwrap();
+        assert_eq!(state.side1[0].attack, 5); // leader does not buff itself
+        assert_eq!(state.side1[1].attack, 5);
+        assert_eq!(state.side1[2].attack, 3);
+        assert_eq!(state.side2[1].attack, 3);
+
+        // Aura off leaves raw stats
+        let state = CombatState::new_with_aura(side1.clone(), side2, false).unwrap();
+        assert_eq!(state.side1, side1);
+    }
+
+    #[test]
+    fn test_summary_decisive_win() {
+        let side1 = vec![Combatant::new(10, 5, 1), Combatant::new(8, 3, 0)];
+        let side2 = vec![Combatant::new(12, 4, 0)];
+        let (final_state, result) = CombatState::new(side1, side2).unwrap().simulate_combat();
+
+        // Round 1: side1[0] 10 -> 6, side2 12 -> 4; Round 2: side1[0] 6 -> 2, side2 4 -> -4
+        assert_eq!(result, CombatResult::Side1Wins);
+        assert_eq!(
+            final_state.summary(),
+            CombatSummary { side1_alive: 2, side2_alive: 0, side1_total_hp: 10, side2_total_hp: 0, rounds: 2 }
+        );
+        assert_eq!(final_state.margin_of_victory(), 10);
+
+        let (flipped, _) = CombatState::new(vec![Combatant::new(12, 4, 0)], vec![Combatant::new(10, 5, 1), Combatant::new(8, 3, 0)])
+            .unwrap()
+            .simulate_combat();
+        assert_eq!(flipped.margin_of_victory(), -10);
+    }
+
+    #[test]
+    fn test_summary_draw() {
+        let (final_state, result) = CombatState::new(vec![Combatant::new(5, 5, 0)], vec![Combatant::new(5, 5, 0)])
+            .unwrap()
+            .simulate_combat();
+
+        assert_eq!(result, CombatResult::Draw);
+        let summary = final_state.summary();
+        assert_eq!(summary.side1_alive, 0);
+        assert_eq!(summary.side2_alive, 0);
+        assert_eq!(summary.rounds, 1);
+        assert_eq!(final_state.margin_of_victory(), 0);
+    }
+
+    #[test]
+    fn test_strict_validation_after_leader_dies() {
+        // Leadership 2 supports the whole team, but once the leader dies the
+        // leadership-0 follower would be leading two combatants
+        let team = vec![Combatant::new(10, 5, 2), Combatant::new(8, 3, 0), Combatant::new(6, 2, 0)];
+        assert!(CombatState::validate_team_size(&team).is_ok());
+        assert!(CombatState::validate_team_strict(&team).is_err());
+
+        let opponent = vec![Combatant::new(10, 5, 0)];
+        assert!(CombatState::new(team.clone(), opponent.clone()).is_ok());
+        let err = CombatState::new_strict(team, opponent.clone()).unwrap_err();
+        assert!(err.starts_with("Side 1"));
+
+        // Each successive front unit can lead whoever is left behind it
+        let team = vec![Combatant::new(10, 5, 2), Combatant::new(8, 3, 1), Combatant::new(6, 2, 0)];
+        assert!(CombatState::validate_team_strict(&team).is_ok());
+        assert!(CombatState::new_strict(team, opponent).is_ok());
+    }
+
+    #[test]
+    fn test_predefined_combatants_classify_to_own_class() {
+        for class in CombatantClass::ALL {
+            assert_eq!(class.to_combatant().classify(), class, "{}", class.name());
+        }
+    }
+
+    #[test]
+    fn test_classify_borderline_stats() {
+        // Tank's exact stats win over the Defender thresholds they also satisfy
+        assert_eq!(Combatant::new(20, 2, 0).classify(), CombatantClass::Tank);
+        assert_eq!(Combatant::new(21, 2, 0).classify(), CombatantClass::Defender);
+        assert_eq!(Combatant::new(15, 3, 0).classify(), CombatantClass::Tank);
+        assert_eq!(Combatant::new(15, 4, 0).classify(), CombatantClass::Balanced);
+        assert_eq!(Combatant::new(4, 9, 0).classify(), CombatantClass::Assassin);
+        assert_eq!(Combatant::new(5, 9, 0).classify(), CombatantClass::GlassCannon);
+        assert_eq!(Combatant::new(7, 7, 0).classify(), CombatantClass::Balanced);
+        assert_eq!(Combatant::new(12, 5, 0).classify(), CombatantClass::Bruiser);
+        assert_eq!(Combatant::new(11, 5, 0).classify(), CombatantClass::Balanced);
+    }
+
+    #[test]
+    fn test_undo_round_restores_prior_state() {
+        let side1 = vec![Combatant::new(5, 6, 1), Combatant::new(4, 2, 0)];
+        let side2 = vec![Combatant::new(6, 3, 1).with_summon(Combatant::new(2, 1, 0)), Combatant::new(8, 2, 0)];
+        let mut state = CombatState::new(side1, side2).unwrap().with_history();
+        let start = state.clone();
+
+        state.execute_round();
+        let after_one = state.clone();
+        state.execute_round();
+        assert_ne!(state, after_one);
+
+        assert!(state.undo_round());
+        assert_eq!(state, after_one);
+        assert!(state.undo_round());
+        assert_eq!(state, start);
+        assert!(!state.undo_round());
+        assert_eq!(state, start);
+    }
+
+    #[test]
+    fn test_undo_round_without_history() {
+        let mut state = CombatState::new(vec![Combatant::BALANCED], vec![Combatant::TANK]).unwrap();
+        state.execute_round();
+        let after = state.clone();
+        assert!(!state.undo_round());
+        assert_eq!(state, after);
+    }
+
+    #[test]
+    fn test_generic_simulate_matches_simulate_combat() {
+        let side1 = vec![Combatant::new(10, 4, 1), Combatant::new(6, 3, 0)];
+        let side2 = vec![Combatant::new(9, 5, 0)];
+        let state = CombatState::new(side1, side2).unwrap();
+        let (generic, result) = simulate(state.clone());
+        let (concrete, expected) = state.simulate_combat();
+        assert_eq!(result, expected);
+        assert_eq!(generic, concrete);
+        assert_eq!(result.winner(), Some(Side::First));
+        assert_eq!(CombatResult::Side2Wins.winner(), Some(Side::Second));
+        assert!(CombatResult::Ongoing.is_ongoing());
+    }
+
+    /// A swarm of `size` 5/2 combatants led by one that can lead them all
+    fn swarm(size: usize) -> Vec<Combatant> {
+        let mut swarm = vec![Combatant::new(5, 2, size as i32 - 1)];
+        swarm.extend(std::iter::repeat_n(Combatant::new(5, 2, 0), size - 1));
+        swarm
+    }
+
+    #[test]
+    fn test_boss_survives_small_swarm() {
+        let boss = Combatant::new(30, 10, 0);
+        let (state, result) = CombatState::boss_fight(boss, swarm(3)).unwrap().simulate_combat();
+        assert_eq!(result, CombatResult::Side1Wins);
+
+        // Swarm hits for 6, 4, then 2 as it shrinks
+        let detail = state.result_detail();
+        assert_eq!(detail.winner, Some(Side::First));
+        assert_eq!(detail.rounds, 3);
+        assert_eq!(detail.survivors.len(), 1);
+        assert_eq!(detail.survivors[0].health, 18);
+    }
+
+    #[test]
+    fn test_boss_loses_to_big_swarm() {
+        let boss = Combatant::new(30, 10, 0);
+        let (state, result) = CombatState::boss_fight(boss, swarm(10)).unwrap().simulate_combat();
+        assert_eq!(result, CombatResult::Side2Wins);
+
+        // 20 + 18 damage fells the boss in round 2, after it has killed two of the swarm
+        let detail = state.result_detail();
+        assert_eq!(detail.winner, Some(Side::Second));
+        assert_eq!(detail.rounds, 2);
+        assert_eq!(detail.survivors.len(), 8);
+        assert!(detail.survivors.iter().all(|c| c.health == 5));
+    }
+
+    #[test]
+    fn test_boss_fight_validation() {
+        // A boss with negative leadership can't lead even itself under `new`, but fights alone here
+        let boss = Combatant::new(30, 10, -1);
+        assert!(CombatState::new(vec![boss.clone()], swarm(2)).is_err());
+        assert!(CombatState::boss_fight(boss.clone(), swarm(2)).is_ok());
+
+        // The swarm still needs a leader for its size
+        let leaderless = vec![Combatant::new(5, 2, 0); 3];
+        assert!(CombatState::boss_fight(boss.clone(), leaderless).is_err());
+        assert!(CombatState::boss_fight(boss, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_boss_fight_empty_swarm() {
+        let err = CombatState::boss_fight(Combatant::new(30, 10, 0), Vec::new()).unwrap_err();
+        assert_eq!(err, "Swarm cannot be empty");
+    }
+
+    #[test]
+    fn test_result_detail_without_winner() {
+        let state = CombatState::new(vec![Combatant::BALANCED], vec![Combatant::BALANCED]).unwrap();
+        let detail = state.result_detail();
+        assert_eq!(detail.result, CombatResult::Ongoing);
+        assert_eq!(detail.winner, None);
+        assert!(detail.survivors.is_empty());
+        assert_eq!(detail.rounds, 0);
+    }
+
+    #[test]
+    fn test_replay_reproduces_stored_fight() {
+        let summoner = Combatant::builder().health(6).attack(3).leadership(2).summon(Combatant::new(4, 2, 0)).build().unwrap();
+        let initial = CombatState::new(vec![summoner, Combatant::new(8, 3, 0)], vec![Combatant::BRUISER, Combatant::new(5, 4, 0)])
+            .unwrap()
+            .with_targeting(TargetingMode::Ranged);
+        let log = FightLog::record(initial.clone());
+        let (final_state, result) = initial.simulate_combat();
+
+        let mut replay = log.replay();
+        let steps: Vec<ReplayStep> = replay.by_ref().collect();
+        assert_eq!(steps.len(), log.rounds.len());
+        assert!(steps.iter().all(ReplayStep::matches_log));
+        assert_eq!(steps.last().unwrap().state, final_state);
+        assert_eq!(replay.result(), result);
+        assert_eq!(replay.result(), log.result);
+    }
+
+    #[test]
+    fn test_replay_flags_tampered_rounds() {
+        let mut log = FightLog::record(CombatState::new(vec![Combatant::new(10, 5, 0)], vec![Combatant::new(8, 3, 0)]).unwrap());
+        log.rounds[0].side2_after[0] += 1;
+
+        let steps: Vec<ReplayStep> = log.replay().collect();
+        assert!(!steps[0].matches_log());
+        assert!(steps[1..].iter().all(ReplayStep::matches_log));
+        // The recomputed round ignores the stored value
+        assert_eq!(steps[0].record.side2_after, vec![3]);
+    }
+
+    #[test]
+    fn test_replay_stops_at_round_cap() {
+        let log = FightLog::record_bounded(CombatState::new(vec![Combatant::new(5, 0, 0)], vec![Combatant::new(5, 0, 0)]).unwrap(), 5);
+        assert_eq!(log.rounds.len(), 5);
+        assert_eq!(log.result, CombatResult::Ongoing);
+
+        let mut replay = log.replay();
+        assert_eq!(replay.by_ref().count(), 5);
+        assert_eq!(replay.result(), CombatResult::Ongoing);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_fight_log_json_round_trip() {
+        let log = FightLog::record(CombatState::new(vec![Combatant::BALANCED, Combatant::TANK], vec![Combatant::ASSASSIN]).unwrap());
+        assert_eq!(FightLog::from_json(&log.to_json()).unwrap(), log);
+        assert!(FightLog::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn test_named_fight_result() {
+        let side1 = vec![Combatant::new(10, 5, 1).with_name("Knight"), Combatant::new(6, 2, 0)];
+        let side2 = vec![Combatant::builder().health(8).attack(3).leadership(1).name("Goblin King").build().unwrap()];
+        let (_, result) = CombatState::new(side1.clone(), side2.clone()).unwrap().simulate_combat();
+        assert_eq!(result, CombatResult::Side1Wins);
+        assert_eq!(result.describe(&side1, &side2), "Knight defeats Goblin King");
+        assert_eq!(CombatResult::Side2Wins.describe(&side1, &side2), "Goblin King defeats Knight");
+
+        // An unnamed leader falls back to its side
+        let unnamed = vec![Combatant::new(8, 3, 0)];
+        assert_eq!(result.describe(&side1, &unnamed), "Knight defeats Side 2");
+        assert_eq!(CombatResult::Draw.describe(&unnamed, &side2), "Draw between Side 1 and Goblin King");
+
+        // Without names, the plain form is unchanged
+        assert_eq!(result.describe(&unnamed, &unnamed), "Side 1 Wins");
+        assert_eq!(CombatResult::Draw.describe(&unnamed, &[]), "Draw");
+    }
 }
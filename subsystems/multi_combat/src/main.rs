@@ -1,9 +1,21 @@
-use combat::{Combatant, CombatState, CombatResult};
+use combat::{Combatant, CombatState, CombatResult, FightLog, DEFAULT_MAX_ROUNDS};
+use serde_json::{json, Value};
 use std::io::{self, Write};
 
+// One-shot exit codes, so scripts can tell the outcome without parsing output
+const EXIT_DRAW: i32 = 0;
+const EXIT_SIDE1_WINS: i32 = 1;
+const EXIT_SIDE2_WINS: i32 = 2;
+/// A single round that left both sides standing
+const EXIT_NO_WINNER: i32 = 3;
+/// Bad arguments or unknown command
+const EXIT_USAGE: i32 = 64;
+
 struct CombatSession {
     side1: Vec<Combatant>,
     side2: Vec<Combatant>,
+    /// Combat being stepped with `round`, discarded whenever the teams change
+    in_progress: Option<CombatState>,
 }
 
 impl CombatSession {
@@ -11,10 +23,12 @@ impl CombatSession {
         Self {
             side1: Vec::new(),
             side2: Vec::new(),
+            in_progress: None,
         }
     }
 
     fn add_side1(&mut self, health: i32, attack: i32, leadership: i32) -> Result<(), String> {
+        self.in_progress = None;
         let combatant = Combatant::new(health, attack, leadership);
         
         // If this is the first combatant, it becomes the leader
@@ -37,6 +51,7 @@ impl CombatSession {
     }
 
     fn add_side2(&mut self, health: i32, attack: i32, leadership: i32) -> Result<(), String> {
+        self.in_progress = None;
         let combatant = Combatant::new(health, attack, leadership);
         
         // If this is the first combatant, it becomes the leader
@@ -59,16 +74,19 @@ impl CombatSession {
     }
 
     fn clear_side1(&mut self) {
+        self.in_progress = None;
         self.side1.clear();
         println!("Side 1 cleared");
     }
 
     fn clear_side2(&mut self) {
+        self.in_progress = None;
         self.side2.clear();
         println!("Side 2 cleared");
     }
 
     fn remove_side1(&mut self) -> Result<(), String> {
+        self.in_progress = None;
         if self.side1.is_empty() {
             return Err("Side 1 is already empty".to_string());
         }
@@ -78,6 +96,7 @@ impl CombatSession {
     }
 
     fn remove_side2(&mut self) -> Result<(), String> {
+        self.in_progress = None;
         if self.side2.is_empty() {
             return Err("Side 2 is already empty".to_string());
         }
@@ -122,13 +141,65 @@ impl CombatSession {
         let state = CombatState::new(self.side1.clone(), self.side2.clone())
             .map_err(|e| format!("Invalid team configuration: {}", e))?;
         
-        run_full_combat(state);
+        run_full_combat(state, false);
+        Ok(())
+    }
+
+    /// Execute one round of the in-progress combat, starting one from the teams if needed
+    fn step(&mut self) -> Result<(), String> {
+        let state = match self.in_progress.take() {
+            Some(state) => state,
+            None => {
+                if self.side1.is_empty() || self.side2.is_empty() {
+                    return Err("Both sides need combatants before stepping a round.".to_string());
+                }
+                CombatState::new(self.side1.clone(), self.side2.clone())
+                    .map_err(|e| format!("Invalid team configuration: {}", e))?
+                    .with_history()
+            }
+        };
+        let state = self.in_progress.insert(state);
+        if state.get_result() != CombatResult::Ongoing {
+            return Err(format!("Combat is over ({}). Use 'undo' to step back or 'reset' to start again.", state.get_result().describe(&self.side1, &self.side2)));
+        }
+        run_single_round(state, false);
+        Ok(())
+    }
+
+    /// Step the in-progress combat back one round
+    fn undo(&mut self) -> Result<(), String> {
+        let state = self.in_progress.as_mut().ok_or_else(|| "No combat in progress. Use 'round' first.".to_string())?;
+        if !state.undo_round() {
+            return Err("No rounds to undo.".to_string());
+        }
+        println!("Undid round {} (now at round {})", state.round + 1, state.round);
+        for (label, side) in [("Side 1", &state.side1), ("Side 2", &state.side2)] {
+            print!("  {}: ", label);
+            for (i, c) in side.iter().enumerate() {
+                let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
+                print!("[{}: HP={}, ATK={}, LDR={}] ", role, c.health, c.attack, c.leadership);
+            }
+            println!();
+        }
         Ok(())
     }
+
+    fn reset(&mut self) {
+        self.in_progress = None;
+        println!("In-progress combat discarded");
+    }
 }
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+    // --json prints the outcome as JSON on stdout and moves the human report to stderr
+    let json = args.iter().any(|a| a == "--json");
+    args.retain(|a| a != "--json");
+    // --save <file> writes the fight as a log that `replay` can step through
+    let save_path = take_option(&mut args, "--save");
+    // --name1/--name2 <name> label the side leaders in result messages
+    let name1 = take_option(&mut args, "--name1").unwrap_or_default();
+    let name2 = take_option(&mut args, "--name2").unwrap_or_default();
     
     // If arguments provided, run in one-shot mode (backward compatibility)
     if args.len() > 1 {
@@ -144,13 +215,13 @@ fn main() {
                     eprintln!("Format: combat <health1> <attack1> <leadership1> [...] -- <health1> <attack1> <leadership1> [...]");
                     eprintln!("Example: combat 10 5 3 -- 8 3 3");
                     eprintln!("Example: combat 10 5 3 8 3 2 -- 12 4 3");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let sep_pos = separator_pos.unwrap();
                 if sep_pos < 2 || sep_pos >= args.len() - 1 {
                     eprintln!("Error: Invalid argument format");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let side1_args = &args[2..sep_pos];
@@ -158,26 +229,26 @@ fn main() {
                 
                 if side1_args.len() % 3 != 0 {
                     eprintln!("Error: Side 1 arguments must be in groups of 3 (health attack leadership)");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 if side2_args.len() % 3 != 0 {
                     eprintln!("Error: Side 2 arguments must be in groups of 3 (health attack leadership)");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let mut side1 = Vec::new();
                 for chunk in side1_args.chunks(3) {
                     let health: i32 = chunk[0].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid health: {}", chunk[0]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let attack: i32 = chunk[1].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid attack: {}", chunk[1]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let leadership: i32 = chunk[2].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid leadership: {}", chunk[2]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     side1.push(Combatant::new(health, attack, leadership));
                 }
@@ -186,24 +257,39 @@ fn main() {
                 for chunk in side2_args.chunks(3) {
                     let health: i32 = chunk[0].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid health: {}", chunk[0]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let attack: i32 = chunk[1].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid attack: {}", chunk[1]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let leadership: i32 = chunk[2].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid leadership: {}", chunk[2]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     side2.push(Combatant::new(health, attack, leadership));
                 }
+                if let Some(leader) = side1.first_mut() {
+                    leader.name = name1;
+                }
+                if let Some(leader) = side2.first_mut() {
+                    leader.name = name2;
+                }
                 
                 match CombatState::new(side1, side2) {
-                    Ok(state) => run_full_combat(state),
+                    Ok(state) => {
+                        let log = run_full_combat(state, json);
+                        if let Some(path) = &save_path {
+                            if let Err(e) = save_fight_log(&log, path) {
+                                eprintln!("Error: {}", e);
+                                std::process::exit(EXIT_USAGE);
+                            }
+                        }
+                        std::process::exit(exit_code(log.result));
+                    }
                     Err(e) => {
                         eprintln!("Error: {}", e);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     }
                 }
             }
@@ -214,13 +300,13 @@ fn main() {
                     eprintln!("Error: combat-round requires '--' separator between sides");
                     eprintln!("Format: combat-round <health1> <attack1> <leadership1> [...] -- <health1> <attack1> <leadership1> [...]");
                     eprintln!("Example: combat-round 10 5 3 -- 8 3 3");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let sep_pos = separator_pos.unwrap();
                 if sep_pos < 2 || sep_pos >= args.len() - 1 {
                     eprintln!("Error: Invalid argument format");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let side1_args = &args[2..sep_pos];
@@ -228,26 +314,26 @@ fn main() {
                 
                 if side1_args.len() % 3 != 0 {
                     eprintln!("Error: Side 1 arguments must be in groups of 3 (health attack leadership)");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 if side2_args.len() % 3 != 0 {
                     eprintln!("Error: Side 2 arguments must be in groups of 3 (health attack leadership)");
-                    std::process::exit(1);
+                    std::process::exit(EXIT_USAGE);
                 }
                 
                 let mut side1 = Vec::new();
                 for chunk in side1_args.chunks(3) {
                     let health: i32 = chunk[0].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid health: {}", chunk[0]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let attack: i32 = chunk[1].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid attack: {}", chunk[1]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let leadership: i32 = chunk[2].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid leadership: {}", chunk[2]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     side1.push(Combatant::new(health, attack, leadership));
                 }
@@ -256,24 +342,44 @@ fn main() {
                 for chunk in side2_args.chunks(3) {
                     let health: i32 = chunk[0].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid health: {}", chunk[0]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let attack: i32 = chunk[1].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid attack: {}", chunk[1]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     let leadership: i32 = chunk[2].parse().unwrap_or_else(|_| {
                         eprintln!("Error: Invalid leadership: {}", chunk[2]);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     });
                     side2.push(Combatant::new(health, attack, leadership));
                 }
+                if let Some(leader) = side1.first_mut() {
+                    leader.name = name1;
+                }
+                if let Some(leader) = side2.first_mut() {
+                    leader.name = name2;
+                }
                 
                 match CombatState::new(side1, side2) {
-                    Ok(mut state) => run_single_round(&mut state),
+                    Ok(mut state) => std::process::exit(exit_code(run_single_round(&mut state, json))),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(EXIT_USAGE);
+                    }
+                }
+            }
+            "replay" => {
+                if args.len() < 3 {
+                    eprintln!("Error: replay requires a file: replay <file>");
+                    eprintln!("Example: replay fight.json");
+                    std::process::exit(EXIT_USAGE);
+                }
+                match run_replay(&args[2]) {
+                    Ok(result) => std::process::exit(exit_code(result)),
                     Err(e) => {
                         eprintln!("Error: {}", e);
-                        std::process::exit(1);
+                        std::process::exit(EXIT_USAGE);
                     }
                 }
             }
@@ -283,7 +389,7 @@ fn main() {
             _ => {
                 eprintln!("Error: Unknown command: {}", args[1]);
                 print_usage();
-                std::process::exit(1);
+                std::process::exit(EXIT_USAGE);
             }
         }
     } else {
@@ -399,6 +505,19 @@ fn run_interactive() {
                             }
                         }
                     }
+                    "round" | "step" | "r" => {
+                        if let Err(e) = session.step() {
+                            eprintln!("Error: {}", e);
+                        }
+                    }
+                    "undo" | "u" => {
+                        if let Err(e) = session.undo() {
+                            eprintln!("Error: {}", e);
+                        }
+                    }
+                    "reset" => {
+                        session.reset();
+                    }
                     _ => {
                         eprintln!("Unknown command: {}. Type 'help' for available commands.", parts[0]);
                     }
@@ -430,6 +549,20 @@ fn print_usage() {
     println!("    Execute one round of combat");
     println!("    Example: combat-round 10 5 3 -- 8 3 3");
     println!("    Alias: cr");
+    println!();
+    println!("  replay <file>");
+    println!("    Step through a fight saved with --save, pressing Enter for each round");
+    println!("    Rounds are recomputed and checked against the stored log");
+    println!("    Example: replay fight.json");
+    println!();
+    println!("Options:");
+    println!("  --json         Print the result and final teams as JSON on stdout (human output goes to stderr)");
+    println!("  --save <file>  With 'combat', also write the fight to <file> for 'replay'");
+    println!("  --name1 <name> / --name2 <name>");
+    println!("                 Name the side leaders in result messages, e.g. \"Knight defeats Goblin King\"");
+    println!();
+    println!("Exit Codes (one-shot commands):");
+    println!("  0 draw, 1 side 1 wins, 2 side 2 wins, 3 no winner yet (combat-round), 64 usage error");
 }
 
 fn print_interactive_help() {
@@ -442,6 +575,9 @@ fn print_interactive_help() {
     println!("  remove-side2                             Remove last combatant from side 2 (alias: remove2)");
     println!("  show                                     Display current team states (alias: status, s)");
     println!("  fight                                     Run combat with saved teams (aliases: go, rip, f)");
+    println!("  round                                    Execute one round of the in-progress combat (aliases: step, r)");
+    println!("  undo                                     Undo the last round of the in-progress combat (alias: u)");
+    println!("  reset                                    Discard the in-progress combat");
     println!("  help                                      Show this help message (alias: h)");
     println!("  quit                                      Exit the program (aliases: exit, q)");
     println!();
@@ -451,9 +587,14 @@ fn print_interactive_help() {
     println!("  combat> add-side2 12 4 3");
     println!("  combat> show");
     println!("  combat> fight");
+    println!("  combat> round");
+    println!("  combat> undo");
 }
 
-fn run_full_combat(mut state: CombatState) {
+/// Run and report a fight, returning it as a log so `--save` doesn't have to fight it again
+fn run_full_combat(mut state: CombatState, json: bool) -> FightLog {
+    let initial = state.clone();
+    let mut rounds = Vec::new();
     let mut history = Vec::new();
     
     loop {
@@ -461,7 +602,9 @@ fn run_full_combat(mut state: CombatState) {
         let side1_before: Vec<(i32, i32)> = state.side1.iter().map(|c| (c.health, c.attack)).collect();
         let side2_before: Vec<(i32, i32)> = state.side2.iter().map(|c| (c.health, c.attack)).collect();
         
-        let result = state.execute_round();
+        let record = state.execute_round_logged();
+        let result = record.result;
+        rounds.push(record);
         
         let side1_after: Vec<(i32, i32)> = state.side1.iter().map(|c| (c.health, c.attack)).collect();
         let side2_after: Vec<(i32, i32)> = state.side2.iter().map(|c| (c.health, c.attack)).collect();
@@ -469,88 +612,213 @@ fn run_full_combat(mut state: CombatState) {
         history.push((round_before + 1, side1_before, side2_before, side1_after, side2_after, result));
         
         match result {
-            CombatResult::Ongoing => continue,
+            CombatResult::Ongoing if state.round < DEFAULT_MAX_ROUNDS => continue,
             _ => {
-                println!("Combat Result: {}", format_result(result));
-                println!("Total Rounds: {}", state.round);
+                let mut report = String::new();
+                report += &format!("Combat Result: {}\n", result.describe(&initial.side1, &initial.side2));
+                report += &format!("Total Rounds: {}\n", state.round);
                 
-                println!("\nFinal State:");
-                println!("  Side 1: {} combatant(s)", state.side1.len());
+                report += "\nFinal State:\n";
+                report += &format!("  Side 1: {} combatant(s)\n", state.side1.len());
                 for (i, c) in state.side1.iter().enumerate() {
                     let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-                    println!("    {}: HP={}, ATK={}, LDR={}", role, c.health, c.attack, c.leadership);
+                    report += &format!("    {}: HP={}, ATK={}, LDR={}\n", role, c.health, c.attack, c.leadership);
                 }
-                println!("  Side 2: {} combatant(s)", state.side2.len());
+                report += &format!("  Side 2: {} combatant(s)\n", state.side2.len());
                 for (i, c) in state.side2.iter().enumerate() {
                     let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-                    println!("    {}: HP={}, ATK={}, LDR={}", role, c.health, c.attack, c.leadership);
+                    report += &format!("    {}: HP={}, ATK={}, LDR={}\n", role, c.health, c.attack, c.leadership);
                 }
                 
                 if !history.is_empty() {
-                    println!("\nRound History:");
+                    report += "\nRound History:\n";
                     for (round, s1_before, s2_before, s1_after, s2_after, _) in history {
-                        println!("  Round {}:", round);
-                        print!("    Side 1: ");
+                        report += &format!("  Round {}:\n", round);
+                        report += "    Side 1: ";
                         for (i, (h_before, a_before)) in s1_before.iter().enumerate() {
                             if i < s1_after.len() {
-                                print!("[{} -> {} (ATK={})] ", h_before, s1_after[i].0, a_before);
+                                report += &format!("[{} -> {} (ATK={})] ", h_before, s1_after[i].0, a_before);
                             } else {
-                                print!("[{} (defeated)] ", h_before);
+                                report += &format!("[{} (defeated)] ", h_before);
                             }
                         }
-                        println!();
-                        print!("    Side 2: ");
+                        report += "\n";
+                        report += "    Side 2: ";
                         for (i, (h_before, a_before)) in s2_before.iter().enumerate() {
                             if i < s2_after.len() {
-                                print!("[{} -> {} (ATK={})] ", h_before, s2_after[i].0, a_before);
+                                report += &format!("[{} -> {} (ATK={})] ", h_before, s2_after[i].0, a_before);
                             } else {
-                                print!("[{} (defeated)] ", h_before);
+                                report += &format!("[{} (defeated)] ", h_before);
                             }
                         }
-                        println!();
+                        report += "\n";
                     }
                 }
-                break;
+                emit(&report, json, || result_json(&state, result));
+                return FightLog { initial, max_rounds: DEFAULT_MAX_ROUNDS, rounds, result };
             }
         }
     }
 }
 
-fn run_single_round(state: &mut CombatState) {
+fn run_single_round(state: &mut CombatState, json: bool) -> CombatResult {
+    let (side1_start, side2_start) = (state.side1.clone(), state.side2.clone());
     let side1_before: Vec<(i32, i32)> = state.side1.iter().map(|c| (c.health, c.attack)).collect();
     let side2_before: Vec<(i32, i32)> = state.side2.iter().map(|c| (c.health, c.attack)).collect();
     
     let result = state.execute_round();
     
-    println!("Round: {}", state.round);
-    println!("Side 1:");
+    let mut report = String::new();
+    report += &format!("Round: {}\n", state.round);
+    report += "Side 1:\n";
     for (i, (h_before, a_before)) in side1_before.iter().enumerate() {
+        let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
         if i < state.side1.len() {
-            let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-            println!("  {}: {} -> {} (ATK={})", role, h_before, state.side1[i].health, a_before);
+            report += &format!("  {}: {} -> {} (ATK={})\n", role, h_before, state.side1[i].health, a_before);
         } else {
-            let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-            println!("  {}: {} -> defeated (ATK={})", role, h_before, a_before);
+            report += &format!("  {}: {} -> defeated (ATK={})\n", role, h_before, a_before);
         }
     }
-    println!("Side 2:");
+    report += "Side 2:\n";
     for (i, (h_before, a_before)) in side2_before.iter().enumerate() {
+        let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
         if i < state.side2.len() {
-            let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-            println!("  {}: {} -> {} (ATK={})", role, h_before, state.side2[i].health, a_before);
+            report += &format!("  {}: {} -> {} (ATK={})\n", role, h_before, state.side2[i].health, a_before);
         } else {
+            report += &format!("  {}: {} -> defeated (ATK={})\n", role, h_before, a_before);
+        }
+    }
+    report += &format!("Result: {}\n", result.describe(&side1_start, &side2_start));
+    
+    emit(&report, json, || result_json(state, result));
+    result
+}
+
+fn take_option(args: &mut Vec<String>, flag: &str) -> Option<String> {
+    let index = args.iter().position(|a| a == flag)?;
+    args.remove(index);
+    if index < args.len() {
+        Some(args.remove(index))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+fn save_fight_log(log: &FightLog, path: &str) -> Result<(), String> {
+    std::fs::write(path, log.to_json()).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+#[cfg(not(feature = "serde"))]
+fn save_fight_log(_log: &FightLog, _path: &str) -> Result<(), String> {
+    Err("--save needs the serde feature (build without --no-default-features)".to_string())
+}
+
+/// Step through a saved fight, recomputing each round and warning where it disagrees with the log
+#[cfg(feature = "serde")]
+fn run_replay(path: &str) -> Result<CombatResult, String> {
+    let json = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let log = FightLog::from_json(&json)?;
+    let format_result = |result: CombatResult| result.describe(&log.initial.side1, &log.initial.side2);
+
+    println!("Replaying {}: {} stored rounds, stored result: {}", path, log.rounds.len(), format_result(log.result));
+    for (name, side) in [("Side 1", &log.initial.side1), ("Side 2", &log.initial.side2)] {
+        println!("{}: {} combatant(s)", name, side.len());
+        for (i, c) in side.iter().enumerate() {
             let role = if i == 0 { "Leader" } else { &format!("Follower {}", i) };
-            println!("  {}: {} -> defeated (ATK={})", role, h_before, a_before);
+            println!("  {}: HP={}, ATK={}, LDR={}", role, c.health, c.attack, c.leadership);
         }
     }
+    println!("Press Enter for each round, or 'q' to play the rest without stopping");
+
+    // Stop waiting once the user quits or stdin runs out
+    let mut stepping = true;
+    let mut replayed = 0;
+    let mut replay = log.replay();
+    for step in replay.by_ref() {
+        if stepping {
+            let mut input = String::new();
+            stepping = matches!(io::stdin().read_line(&mut input), Ok(n) if n > 0) && input.trim() != "q";
+        }
+
+        let record = &step.record;
+        println!("Round {}:", record.round);
+        println!("  Side 1: {}", format_health_change(&record.side1_before, &record.side1_after));
+        println!("  Side 2: {}", format_health_change(&record.side2_before, &record.side2_after));
+        if !step.matches_log() {
+            let stored = match &step.stored {
+                Some(s) => format!("stored side 1 {}, side 2 {}",
+                    format_health_change(&s.side1_before, &s.side1_after),
+                    format_health_change(&s.side2_before, &s.side2_after)),
+                None => "missing from the stored log".to_string(),
+            };
+            eprintln!("Warning: round {} disagrees with the stored log ({})", record.round, stored);
+        }
+        replayed += 1;
+    }
+
+    let result = replay.result();
+    if replayed < log.rounds.len() {
+        eprintln!("Warning: stored log has {} rounds but the fight ended after {}", log.rounds.len(), replayed);
+    }
+    if result != log.result {
+        eprintln!("Warning: replayed result ({}) disagrees with the stored result ({})",
+            format_result(result), format_result(log.result));
+    }
     println!("Result: {}", format_result(result));
+    Ok(result)
+}
+
+#[cfg(not(feature = "serde"))]
+fn run_replay(_path: &str) -> Result<CombatResult, String> {
+    Err("replay needs the serde feature (build without --no-default-features)".to_string())
+}
+
+/// Each position's health as `[before -> after]`, or `[before (defeated)]` past the survivors
+#[cfg(feature = "serde")]
+fn format_health_change(before: &[i32], after: &[i32]) -> String {
+    before.iter().enumerate()
+        .map(|(i, h)| match after.get(i) {
+            Some(a) => format!("[{} -> {}]", h, a),
+            None => format!("[{} (defeated)]", h),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Print the human report to stdout, or in json mode print it to stderr and the JSON to stdout
+fn emit(report: &str, json: bool, to_json: impl FnOnce() -> Value) {
+    if json {
+        eprint!("{}", report);
+        println!("{}", to_json());
+    } else {
+        print!("{}", report);
+    }
+}
+
+/// Result and surviving combatants, shaped like the crafting CLI's `{"status", "data"}` responses
+fn result_json(state: &CombatState, result: CombatResult) -> Value {
+    let team = |side: &[Combatant]| -> Vec<Value> {
+        side.iter()
+            .map(|c| json!({ "name": c.name, "health": c.health, "attack": c.attack, "leadership": c.leadership }))
+            .collect()
+    };
+    json!({
+        "status": "success",
+        "data": {
+            "result": format!("{:?}", result),
+            "rounds": state.round,
+            "side1": team(&state.side1),
+            "side2": team(&state.side2),
+        }
+    })
 }
 
-fn format_result(result: CombatResult) -> &'static str {
+fn exit_code(result: CombatResult) -> i32 {
     match result {
-        CombatResult::Side1Wins => "Side 1 Wins",
-        CombatResult::Side2Wins => "Side 2 Wins",
-        CombatResult::Draw => "Draw",
-        CombatResult::Ongoing => "Ongoing",
+        CombatResult::Draw => EXIT_DRAW,
+        CombatResult::Side1Wins => EXIT_SIDE1_WINS,
+        CombatResult::Side2Wins => EXIT_SIDE2_WINS,
+        CombatResult::Ongoing => EXIT_NO_WINNER,
     }
 }
@@ -0,0 +1,103 @@
+use serde_json::Value;
+use std::process::{Command, Output};
+
+/// Run the combat binary with `args` and return its output
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_combat"))
+        .args(args)
+        .output()
+        .expect("Failed to run combat binary")
+}
+
+fn parse_stdout(output: &Output) -> Value {
+    serde_json::from_slice(&output.stdout).expect("stdout should be a single JSON value")
+}
+
+#[test]
+fn test_exit_code_reports_winner() {
+    assert_eq!(run(&["combat", "10", "5", "3", "--", "8", "3", "3"]).status.code(), Some(1));
+    assert_eq!(run(&["combat", "8", "3", "3", "--", "10", "5", "3"]).status.code(), Some(2));
+    assert_eq!(run(&["combat", "5", "5", "3", "--", "5", "5", "3"]).status.code(), Some(0));
+}
+
+#[test]
+fn test_usage_error_exit_code() {
+    assert_eq!(run(&["combat", "10", "5", "3"]).status.code(), Some(64));
+    assert_eq!(run(&["not-a-command"]).status.code(), Some(64));
+}
+
+#[test]
+fn test_json_output() {
+    let output = run(&["--json", "combat", "10", "5", "3", "--", "8", "3", "3"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let response = parse_stdout(&output);
+    assert_eq!(response["status"], "success");
+    let data = &response["data"];
+    assert_eq!(data["result"], "Side1Wins");
+    assert_eq!(data["rounds"], 2);
+    assert_eq!(data["side1"][0]["health"], 4);
+    assert_eq!(data["side2"].as_array().unwrap().len(), 0);
+
+    // Human report moves to stderr
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Combat Result: Side 1 Wins"));
+}
+
+#[test]
+fn test_json_single_round() {
+    let output = run(&["combat-round", "10", "5", "3", "--", "8", "3", "3", "--json"]);
+    assert_eq!(output.status.code(), Some(3));
+
+    let data = parse_stdout(&output)["data"].clone();
+    assert_eq!(data["result"], "Ongoing");
+    assert_eq!(data["rounds"], 1);
+    assert_eq!(data["side1"][0]["health"], 7);
+    assert_eq!(data["side2"][0]["health"], 3);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_replay_saved_fight() {
+    let path = std::env::temp_dir().join(format!("multi_combat_replay_{}.json", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = run(&["combat", "10", "5", "3", "--", "8", "3", "3", "--save", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+
+    // Stdin is closed, so the replay runs straight through
+    let output = run(&["replay", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Round 1:\n  Side 1: [10 -> 7]\n  Side 2: [8 -> 3]"));
+    assert!(stdout.contains("Round 2:\n  Side 1: [7 -> 4]\n  Side 2: [3 (defeated)]"));
+    assert!(stdout.contains("Result: Side 1 Wins"));
+    assert!(output.stderr.is_empty());
+
+    // Edit a stored round and the stored result: the replay still recomputes the real fight but warns
+    let saved = std::fs::read_to_string(&path).unwrap();
+    let (head, tail) = saved.rsplit_once("\"result\": \"Side1Wins\"").unwrap();
+    let tampered = format!("{}\"result\": \"Draw\"{}", head, tail).replacen("\"round\": 1,", "\"round\": 5,", 1);
+    std::fs::write(&path, tampered).unwrap();
+    let output = run(&["replay", path_str]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Warning: round 1 disagrees with the stored log"));
+    assert!(stderr.contains("Warning: replayed result (Side 1 Wins) disagrees with the stored result (Draw)"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_named_leaders() {
+    let output = run(&["combat", "10", "5", "3", "6", "2", "0", "--", "8", "3", "3", "--name1", "Knight", "--name2", "Goblin King"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Combat Result: Knight defeats Goblin King"));
+
+    // Only one leader named: the other falls back to its side
+    let output = run(&["combat-round", "10", "5", "3", "--", "8", "3", "3", "--name2", "Goblin King"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Result: Ongoing"));
+    let output = run(&["combat", "8", "3", "3", "--", "10", "5", "3", "--name2", "Goblin King"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Combat Result: Goblin King defeats Side 1"));
+}
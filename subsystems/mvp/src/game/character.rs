@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use super::crafting::ItemInstanceId;
 
 /// Character inventory - simple list of items (no stacking)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Inventory {
     /// Items in the inventory (no stacking, infinite capacity for now)
     pub items: Vec<ItemInstanceId>,
@@ -41,9 +42,22 @@ impl Inventory {
     }
 }
 
+/// XP awarded per point of a defeated enemy's max health
+pub const XP_PER_ENEMY_HEALTH: u32 = 2;
+
+/// Max health gained on each level-up
+pub const MAX_HEALTH_PER_LEVEL: i32 = 3;
+
+/// Attack gained on each level-up
+pub const ATTACK_PER_LEVEL: i32 = 1;
+
+fn default_level() -> u32 {
+    1
+}
+
 /// Character struct representing the player character
 /// Contains position and stats (health, attack, defense, accuracy, evasion)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Character {
     /// Current land coordinates
     pub land_position: (i32, i32),
@@ -65,6 +79,12 @@ pub struct Character {
     pub inventory: Inventory,
     /// Currently equipped item (tool slot)
     pub equipped: Option<ItemInstanceId>,
+    /// Character level, starting at 1
+    #[serde(default = "default_level")]
+    pub level: u32,
+    /// Experience towards the next level (resets to the remainder on level-up)
+    #[serde(default)]
+    pub xp: u32,
 }
 
 impl Character {
@@ -84,6 +104,8 @@ impl Character {
             evasion: 0,     // Base evasion: 0
             inventory,
             equipped: None,
+            level: 1,
+            xp: 0,
         }
     }
 
@@ -137,6 +159,40 @@ impl Character {
         self.evasion
     }
 
+    /// Get current level
+    pub fn get_level(&self) -> u32 {
+        self.level
+    }
+
+    /// Get experience towards the next level
+    pub fn get_xp(&self) -> u32 {
+        self.xp
+    }
+
+    /// XP needed to go from `level` to `level + 1`: 20 at level 1, 40 at level 2, ...
+    pub fn xp_to_next_level(level: u32) -> u32 {
+        20 * level
+    }
+
+    /// Add experience, levelling up as many times as it covers.
+    /// Each level-up adds `MAX_HEALTH_PER_LEVEL` max health and `ATTACK_PER_LEVEL` attack
+    /// and heals to full. Returns the number of levels gained.
+    pub fn gain_xp(&mut self, amount: u32) -> u32 {
+        self.xp += amount;
+        let mut levels_gained = 0;
+        while self.xp >= Self::xp_to_next_level(self.level) {
+            self.xp -= Self::xp_to_next_level(self.level);
+            self.level += 1;
+            self.max_health += MAX_HEALTH_PER_LEVEL;
+            self.attack += ATTACK_PER_LEVEL;
+            levels_gained += 1;
+        }
+        if levels_gained > 0 {
+            self.health = self.max_health;
+        }
+        levels_gained
+    }
+
     /// Take damage (reduce health)
     /// Health cannot go below 0
     pub fn take_damage(&mut self, damage: i32) {
@@ -242,4 +298,29 @@ mod tests {
         char.take_damage(5);
         assert!(char.is_defeated()); // Still defeated
     }
+
+    #[test]
+    fn test_xp_below_threshold_does_not_level() {
+        let mut char = Character::new();
+        char.take_damage(4);
+
+        assert_eq!(char.gain_xp(Character::xp_to_next_level(1) - 1), 0);
+        assert_eq!(char.level, 1);
+        assert_eq!(char.xp, 19);
+        assert_eq!((char.max_health, char.attack, char.health), (10, 5, 6));
+    }
+
+    #[test]
+    fn test_xp_crossing_thresholds_levels_up() {
+        let mut char = Character::new();
+        char.take_damage(4);
+
+        // 20 for level 2 plus 40 for level 3, with 5 left over
+        assert_eq!(char.gain_xp(65), 2);
+        assert_eq!(char.level, 3);
+        assert_eq!(char.xp, 5);
+        assert_eq!(char.max_health, 10 + 2 * MAX_HEALTH_PER_LEVEL);
+        assert_eq!(char.attack, 5 + 2 * ATTACK_PER_LEVEL);
+        assert_eq!(char.health, char.max_health);
+    }
 }
@@ -2,8 +2,9 @@
 /// Copied from ../combat/ subsystem
 
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Combatant {
     pub health: i32,
     pub attack: i32,
@@ -33,13 +34,36 @@ impl Combatant {
     pub const DEFENDER: Combatant = Combatant { health: 25, attack: 1, defense: 3, accuracy: 10, evasion: 0 };
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CombatState {
     pub player: Combatant,
     pub enemy: Combatant,
     pub round: u32,
 }
 
+/// A fight against an enemy team, resolved round by round by the `multi_combat` crate.
+///
+/// The player is a one-unit side. Every living enemy attacks the player each round,
+/// while the player hits only the front enemy, so the back line keeps dealing damage
+/// until the front falls. Defense reduces damage as in 1v1 fights (minimum 1), but
+/// accuracy and evasion are ignored: team attacks always land.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamCombatState {
+    pub player: Combatant,
+    /// The whole team in formation order (front first); defeated members stay at 0 health
+    pub enemies: Vec<Combatant>,
+    pub round: u32,
+}
+
+/// The fight in progress: one enemy or an enemy team.
+/// Untagged, so saves from before team fights still load as `Single`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActiveCombat {
+    Single(CombatState),
+    Team(TeamCombatState),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CombatResult {
     /// Combat continues, both combatants still alive
@@ -136,14 +160,109 @@ impl CombatState {
     }
 }
 
+impl TeamCombatState {
+    /// Create a team fight; `enemies` is the formation, front first
+    pub fn new(player: Combatant, enemies: Vec<Combatant>) -> Self {
+        Self {
+            player,
+            enemies,
+            round: 0,
+        }
+    }
+
+    /// The front-most living enemy, which the player is attacking
+    pub fn front_enemy(&self) -> Option<&Combatant> {
+        self.enemies.iter().find(|enemy| !enemy.is_defeated())
+    }
+
+    /// Execute one round through `multi_combat` and copy the resulting health back.
+    /// Returns the combat result after this round
+    pub fn execute_round(&mut self) -> CombatResult {
+        let living: Vec<usize> = (0..self.enemies.len())
+            .filter(|&i| !self.enemies[i].is_defeated())
+            .collect();
+        if living.is_empty() || self.player.is_defeated() {
+            return self.get_result();
+        }
+        self.round += 1;
+
+        // Defense is folded into attack, since multi_combat has no defense stat
+        let front_defense = self.enemies[living[0]].defense;
+        let player_side = vec![multi_combat::Combatant::new(
+            self.player.health,
+            self.player.calculate_damage(front_defense),
+            0,
+        )];
+        let enemy_side = living
+            .iter()
+            .map(|&i| {
+                let enemy = &self.enemies[i];
+                // The front enemy leads the rest of the team
+                multi_combat::Combatant::new(
+                    enemy.health,
+                    enemy.calculate_damage(self.player.defense),
+                    living.len() as i32 - 1,
+                )
+            })
+            .collect();
+        let mut fight = multi_combat::CombatState::new(player_side, enemy_side)
+            .expect("team leadership always covers the living team");
+        fight.execute_round();
+
+        self.player.health = fight.side1.first().map_or(0, |player| player.health.max(0));
+        // multi_combat drops defeated combatants. With front-only targeting and single
+        // attacks only the front enemy takes damage, so any missing survivor is the front one
+        let fallen = living.len() - fight.side2.len();
+        for (slot, &i) in living.iter().enumerate() {
+            self.enemies[i].health = match slot.checked_sub(fallen) {
+                Some(survivor) => fight.side2[survivor].health.max(0),
+                None => 0,
+            };
+        }
+
+        self.get_result()
+    }
+
+    /// Get the current combat result without executing a round
+    pub fn get_result(&self) -> CombatResult {
+        let player_defeated = self.player.is_defeated();
+        let team_defeated = self.enemies.iter().all(|enemy| enemy.is_defeated());
+
+        match (player_defeated, team_defeated) {
+            (false, false) => CombatResult::Ongoing,
+            (true, false) => CombatResult::EnemyWins,
+            (false, true) => CombatResult::PlayerWins,
+            (true, true) => CombatResult::Draw,
+        }
+    }
+}
+
+impl ActiveCombat {
+    /// Rounds fought so far
+    pub fn round(&self) -> u32 {
+        match self {
+            ActiveCombat::Single(combat) => combat.round,
+            ActiveCombat::Team(combat) => combat.round,
+        }
+    }
+
+    /// The player's side of the fight
+    pub fn player(&self) -> &Combatant {
+        match self {
+            ActiveCombat::Single(combat) => &combat.player,
+            ActiveCombat::Team(combat) => &combat.player,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_single_round() {
-        let player = Combatant::new(10, 5);
-        let enemy = Combatant::new(8, 3);
+        let player = Combatant::new(10, 5, 0, 10, 0);
+        let enemy = Combatant::new(8, 3, 0, 10, 0);
         let mut state = CombatState::new(player, enemy);
         
         let result = state.execute_round();
@@ -156,8 +275,8 @@ mod tests {
 
     #[test]
     fn test_player_wins() {
-        let player = Combatant::new(10, 5);
-        let enemy = Combatant::new(3, 2);
+        let player = Combatant::new(10, 5, 0, 10, 0);
+        let enemy = Combatant::new(3, 2, 0, 10, 0);
         let mut state = CombatState::new(player, enemy);
         
         // Round 1: player takes 2 damage (10 -> 8), enemy takes 5 damage (3 -> -2, defeated)
@@ -170,8 +289,8 @@ mod tests {
 
     #[test]
     fn test_enemy_wins() {
-        let player = Combatant::new(3, 2);
-        let enemy = Combatant::new(10, 5);
+        let player = Combatant::new(3, 2, 0, 10, 0);
+        let enemy = Combatant::new(10, 5, 0, 10, 0);
         let mut state = CombatState::new(player, enemy);
         
         // Round 1: player takes 5 damage (3 -> -2, defeated), enemy takes 2 damage (10 -> 8)
@@ -184,8 +303,8 @@ mod tests {
 
     #[test]
     fn test_draw() {
-        let player = Combatant::new(5, 5);
-        let enemy = Combatant::new(5, 5);
+        let player = Combatant::new(5, 5, 0, 10, 0);
+        let enemy = Combatant::new(5, 5, 0, 10, 0);
         let mut state = CombatState::new(player, enemy);
         
         // Round 1: Both take 5 damage (5 -> 0), both defeated simultaneously
@@ -198,8 +317,8 @@ mod tests {
 
     #[test]
     fn test_restore_health() {
-        let player = Combatant::new(10, 5);
-        let enemy = Combatant::new(8, 3);
+        let player = Combatant::new(10, 5, 0, 10, 0);
+        let enemy = Combatant::new(8, 3, 0, 10, 0);
         let mut state = CombatState::new(player, enemy);
         
         // Execute a round to damage both
@@ -212,4 +331,32 @@ mod tests {
         assert_eq!(state.player.health, 10);
         assert_eq!(state.enemy.health, 8);
     }
+
+    #[test]
+    fn test_team_back_line_attacks_and_front_falls_first() {
+        let player = Combatant::new(20, 6, 1, 10, 0);
+        let enemies = vec![
+            Combatant::new(6, 3, 0, 10, 0),
+            Combatant::new(4, 2, 0, 10, 0),
+            Combatant::new(9, 1, 0, 10, 0),
+        ];
+        let mut state = TeamCombatState::new(player, enemies);
+        
+        // Round 1: the whole team hits the player for (3-1) + (2-1) + max(1-1, 1) = 4; the front takes 6
+        assert_eq!(state.execute_round(), CombatResult::Ongoing);
+        assert_eq!(state.player.health, 16);
+        assert_eq!(state.enemies.iter().map(|e| e.health).collect::<Vec<_>>(), vec![0, 4, 9]);
+        assert_eq!(state.front_enemy().unwrap().attack, 2);
+        
+        // Round 2: two enemies left deal 2; the new front falls
+        assert_eq!(state.execute_round(), CombatResult::Ongoing);
+        assert_eq!(state.player.health, 14);
+        assert_eq!(state.enemies.iter().map(|e| e.health).collect::<Vec<_>>(), vec![0, 0, 9]);
+        
+        // Rounds 3-4: the last enemy takes 6 + 6
+        assert_eq!(state.execute_round(), CombatResult::Ongoing);
+        assert_eq!(state.execute_round(), CombatResult::PlayerWins);
+        assert_eq!(state.round, 4);
+        assert_eq!(state.player.health, 12);
+    }
 }
@@ -1,6 +1,27 @@
 use super::game_state::{GameState, CurrentMode};
 use super::combat::CombatResult;
 
+/// Parse a move direction with an optional repeat count (e.g. "u", "ur", "up", "u3", "dl2")
+/// Returns (dx, dy, emoji, steps)
+fn parse_direction(direction: &str) -> Option<(i32, i32, &'static str, u32)> {
+    let digits_at = direction.find(|c: char| c.is_ascii_digit()).unwrap_or(direction.len());
+    let (name, count) = direction.split_at(digits_at);
+    let steps = if count.is_empty() { 1 } else { count.parse::<u32>().ok().filter(|&n| n > 0)? };
+    
+    let (dx, dy, emoji) = match name {
+        "u" | "up" => (0, -1, "⬆️"),
+        "d" | "down" => (0, 1, "⬇️"),
+        "l" | "left" => (-1, 0, "⬅️"),
+        "r" | "right" => (1, 0, "➡️"),
+        "ur" => (1, -1, "↗️"),
+        "ul" => (-1, -1, "↖️"),
+        "dr" => (1, 1, "↘️"),
+        "dl" => (-1, 1, "↙️"),
+        _ => return None,
+    };
+    Some((dx, dy, emoji, steps))
+}
+
 /// Execute a command and return (success, message)
 pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
     // Handle commands with arguments first
@@ -98,29 +119,52 @@ pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
     if command.starts_with("move ") || command.starts_with("m ") {
         let parts: Vec<&str> = command.split_whitespace().collect();
         if parts.len() < 2 {
-            return (false, "Usage: m <direction> or move <direction> (e.g., 'm u' or 'move up'). Directions: u/up, d/down, l/left, r/right".to_string());
+            return (false, "Usage: m <direction>[count] or move <direction> (e.g., 'm u', 'm ur' or 'm u3'). Directions: u/up, d/down, l/left, r/right, ur, ul, dr, dl".to_string());
         }
         
         let direction = parts[1].to_lowercase();
-        let (dx, dy, emoji) = match direction.as_str() {
-            "u" | "up" => (0, -1, "⬆️"),
-            "d" | "down" => (0, 1, "⬇️"),
-            "l" | "left" => (-1, 0, "⬅️"),
-            "r" | "right" => (1, 0, "➡️"),
-            _ => return (false, "Invalid direction. Use u/up, d/down, l/left, or r/right".to_string()),
+        let (dx, dy, emoji, steps) = match parse_direction(&direction) {
+            Some(parsed) => parsed,
+            None => return (false, "Invalid direction. Use u/up, d/down, l/left, r/right, ur, ul, dr, or dl, optionally followed by a count (e.g., 'u3')".to_string()),
         };
         
         return match state.current_mode {
             CurrentMode::Terrain => {
-                state.move_terrain(dx, dy);
+                // Step one land at a time so a multi-step move halts on an enemy's land
+                let mut stopped = None;
+                for step in 1..=steps {
+                    let before = state.current_land();
+                    state.move_terrain(dx, dy);
+                    let (x, y) = state.current_land();
+                    if (x, y) == before {
+                        break; // Clamped at the world edge
+                    }
+                    let enemy = state.world.terrain.get(&(x, y))
+                        .and_then(|land| land.opponents().into_iter().find(|enemy| !enemy.is_defeated()));
+                    if let Some(enemy) = enemy {
+                        if step < steps {
+                            stopped = Some(enemy.name.clone());
+                        }
+                        break;
+                    }
+                }
                 let (x, y) = state.current_land();
-                (true, format!("{} L[{},{}]", emoji, x, y))
+                match stopped {
+                    Some(name) => (true, format!("{} L[{},{}] (stopped: {} ahead)", emoji, x, y, name)),
+                    None => (true, format!("{} L[{},{}]", emoji, x, y)),
+                }
             }
             CurrentMode::Combat => {
                 (false, "Cannot move during combat. Use 'a' to attack or 'x' to flee.".to_string())
             }
             CurrentMode::Land => {
-                state.move_land(dx, dy);
+                for _ in 0..steps {
+                    let before = state.current_tile();
+                    state.move_land(dx, dy);
+                    if state.current_tile() == before {
+                        break; // Clamped at the land edge
+                    }
+                }
                 if let Some((x, y)) = state.current_tile() {
                     (true, format!("{} T[{},{}]", emoji, x, y))
                 } else {
@@ -254,7 +298,7 @@ pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
     match command {
         "m" => {
             // Standalone m - show usage
-            (false, "Usage: m <direction> (e.g., 'm u' for up). Directions: u/up, d/down, l/left, r/right".to_string())
+            (false, "Usage: m <direction>[count] (e.g., 'm u' for up, 'm u3' for three up). Directions: u/up, d/down, l/left, r/right, ur, ul, dr, dl".to_string())
         }
         "up" | "down" | "right" => {
             // Legacy single-letter commands - redirect to move command
@@ -301,21 +345,43 @@ pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
         }
         "attack" | "a" => {
             if state.current_mode == CurrentMode::Combat {
+                let level_before = state.character.get_level();
                 let result = state.combat_attack();
                 match result {
                     CombatResult::Ongoing => {
                         let (land_x, land_y) = state.current_land();
-                        let enemy = state.world.terrain.get(&(land_x, land_y))
-                            .and_then(|land| land.enemy.as_ref())
-                            .unwrap();
-                        (true, format!("⚔️ Attack! P:{}/{} E:{}/{}", 
+                        let opponents = state.world.terrain[&(land_x, land_y)].opponents();
+                        let living: Vec<_> = opponents.iter().filter(|enemy| !enemy.is_defeated()).collect();
+                        let enemy = living[0];
+                        let mut message = format!("⚔️ Attack! P:{}/{} E:{}/{}", 
                             state.character.get_health(),
                             state.character.get_max_health(),
                             enemy.health,
-                            enemy.max_health))
+                            enemy.max_health);
+                        if living.len() > 1 {
+                            message += &format!(" (+{} behind)", living.len() - 1);
+                        }
+                        (true, message)
                     }
                     CombatResult::PlayerWins => {
-                        (true, "⚔️ Victory!".to_string())
+                        let (land_x, land_y) = state.current_land();
+                        let opponents = state.world.terrain[&(land_x, land_y)].opponents();
+                        let names: Vec<&str> = opponents.iter().map(|enemy| enemy.name.as_str()).collect();
+                        let loot_names: Vec<String> = opponents.iter()
+                            .flat_map(|enemy| &enemy.loot)
+                            .map(|item_id| state.crafting_registry.get_item(item_id)
+                                .map(|def| def.name.clone())
+                                .unwrap_or_else(|| item_id.0.clone()))
+                            .collect();
+                        let mut message = if loot_names.is_empty() {
+                            format!("⚔️ Victory over {}!", names.join(", "))
+                        } else {
+                            format!("⚔️ Victory over {}! Loot: {}", names.join(", "), loot_names.join(", "))
+                        };
+                        if state.character.get_level() > level_before {
+                            message += &format!(" Level up! Now level {}", state.character.get_level());
+                        }
+                        (true, message)
                     }
                     CombatResult::EnemyWins | CombatResult::Draw => {
                         (true, "⚔️ Defeated!".to_string())
@@ -352,31 +418,32 @@ pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
                 let tile = &mut land.tiles[tile_y][tile_x];
                 
                 if tile.items.is_empty() {
-                    return (false, "No items here to pick up".to_string());
+                    return (false, "Nothing here to pick up. Move onto a tile with items first.".to_string());
                 }
                 
-                // Get first item (without removing yet)
-                let item_id = tile.items[0];
-                
-                // Check if item is pickupable
-                let (item_name, is_pickupable) = state.crafting_registry.get_instance(item_id)
-                    .and_then(|instance| {
-                        match instance {
-                            crate::game::crafting::ItemInstance::Simple(s) => {
-                                state.crafting_registry.get_item(&s.definition)
-                                    .map(|def| (def.name.clone(), def.pickupable))
+                // Look up (name, pickupable) for each item on the tile
+                let item_info: Vec<(String, bool)> = tile.items.iter().map(|&item_id| {
+                    state.crafting_registry.get_instance(item_id)
+                        .and_then(|instance| {
+                            match instance {
+                                crate::game::crafting::ItemInstance::Simple(s) => {
+                                    state.crafting_registry.get_item(&s.definition)
+                                        .map(|def| (def.name.clone(), def.pickupable))
+                                }
+                                _ => None
                             }
-                            _ => None
-                        }
-                    })
-                    .unwrap_or_else(|| ("Unknown Item".to_string(), false));
+                        })
+                        .unwrap_or_else(|| ("Unknown Item".to_string(), false))
+                }).collect();
                 
-                if !is_pickupable {
-                    return (false, format!("{} cannot be picked up. You may need to use a tool to harvest it.", item_name));
-                }
+                // Take the first pickupable item, skipping resource nodes like trees and boulders
+                let Some(index) = item_info.iter().position(|(_, is_pickupable)| *is_pickupable) else {
+                    return (false, format!("{} cannot be picked up. You may need to use a tool to harvest it.", item_info[0].0));
+                };
+                let item_name = item_info[index].0.clone();
                 
                 // Remove from tile and add to inventory
-                tile.items.remove(0);
+                let item_id = tile.items.remove(index);
                 state.character.inventory.add_item(item_id);
                 
                 (true, format!("📦 Picked up {}", item_name))
@@ -384,6 +451,30 @@ pub fn execute_command(state: &mut GameState, command: &str) -> (bool, String) {
                 (false, "Land not found".to_string())
             }
         }
+        "talk" => {
+            // Can only talk in land view
+            if state.current_mode != CurrentMode::Land {
+                return (false, "Can only talk to people in land view".to_string());
+            }
+            
+            let (land_x, land_y) = state.current_land();
+            let (tile_x, tile_y) = match state.current_tile() {
+                Some(pos) => pos,
+                None => return (false, "Not in land view".to_string()),
+            };
+            
+            let Some(land) = state.world.terrain.get_mut(&(land_x, land_y)) else {
+                return (false, "Land not found".to_string());
+            };
+            let Some(npc) = land.tiles[tile_y][tile_x].npc.as_mut() else {
+                return (false, "There's no one here to talk to.".to_string());
+            };
+            let name = npc.name.clone();
+            match npc.talk() {
+                Some(line) => (true, format!("💬 {}: \"{}\"", name, line)),
+                None => (false, format!("{} has nothing to say.", name)),
+            }
+        }
         "drop" | "d" => {
             // Can only drop in land view
             if state.current_mode != CurrentMode::Land {
@@ -714,9 +805,11 @@ Combat Commands:
                 CurrentMode::Land => {
                     r#"
 Commands:
-  M <dir>, MOVE   - Move (e.g., 'm u' or 'move up'). Directions: u/d/l/r
+  M <dir>, MOVE   - Move (e.g., 'm u' or 'move up'). Directions: u/d/l/r/ur/ul/dr/dl
+                    Add a count to repeat (e.g., 'm u3')
   X, EXIT         - Exit land view
   PICKUP, P, GET  - Pick up item from current tile
+  TALK            - Talk to the person on the current tile
   D, DROP         - Drop first item from inventory
   U, USE          - Use equipped tool on world object or substrate
   L, PLACE <idx>  - Place item as world object (e.g., 'l 0' to place forge)
@@ -734,7 +827,8 @@ Commands:
                 _ => {
                     r#"
 Commands:
-  M <dir>, MOVE   - Move (e.g., 'm u' or 'move up'). Directions: u/d/l/r
+  M <dir>, MOVE   - Move (e.g., 'm u' or 'move up'). Directions: u/d/l/r/ur/ul/dr/dl
+                    Add a count to repeat (e.g., 'm u3')
   X, ENTER        - Enter land view (may trigger combat if enemy present)
   E, EQUIP <idx>  - Equip item from inventory (e.g., 'e 0')
   UNEQUIP         - Unequip current item
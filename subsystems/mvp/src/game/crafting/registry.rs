@@ -30,6 +30,7 @@ use super::world_object::WorldObjectInstance;
 /// # Recipe Execution
 /// The registry provides validation and execution methods for all three recipe types,
 /// ensuring material compatibility and slot matching.
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct CraftingRegistry {
     // Base content
     materials: HashMap<MaterialId, Material>,
@@ -8,7 +8,7 @@ pub enum WorldObjectKind {
 }
 
 /// A world object instance placed in the world
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct WorldObjectInstance {
     pub id: WorldObjectInstanceId,
     pub kind: WorldObjectKind,
@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+use std::fs;
+use serde::{Deserialize, Serialize};
 use super::world::types::{World, Substrate, Biome, EnemyType};
-use super::character::Character;
-use super::combat::CombatResult;
+use super::character::{Character, XP_PER_ENEMY_HEALTH};
+use super::combat::{ActiveCombat, CombatResult, CombatState, Combatant, TeamCombatState};
 use super::crafting::{CraftingRegistry, ItemInstanceId, WorldObjectInstanceId, ItemId};
 
 /// Information about a tile
@@ -8,11 +11,14 @@ use super::crafting::{CraftingRegistry, ItemInstanceId, WorldObjectInstanceId, I
 pub struct TileInfo {
     pub substrate: Substrate,
     pub items: Vec<ItemInstanceId>,
+    pub world_object: Option<WorldObjectInstanceId>,
     pub biome: Biome,
+    /// Name of the NPC on the tile, if any
+    pub npc: Option<String>,
 }
 
 /// View mode enum for tracking which view is active
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CurrentMode {
     /// Terrain view: Shows biome overview (one tile per land)
     Terrain,
@@ -23,14 +29,20 @@ pub enum CurrentMode {
 }
 
 /// Game state that tracks the current world and player position
+#[derive(Serialize, Deserialize)]
 pub struct GameState {
     pub world: World,
     pub current_mode: CurrentMode,
     pub character: Character,
     /// Combat round counter (0 when not in combat, increments during combat)
     pub combat_round: u32,
+    /// Combatant snapshot of the fight in progress (None outside combat view)
+    pub combat_state: Option<ActiveCombat>,
     /// Crafting registry containing all items, recipes, and instances
     pub crafting_registry: CraftingRegistry,
+    /// Lands the character has viewed or entered (fog of war)
+    #[serde(default)]
+    pub explored: HashSet<(i32, i32)>,
 }
 
 impl GameState {
@@ -45,7 +57,80 @@ impl GameState {
             current_mode: CurrentMode::Terrain,
             character,
             combat_round: 0,
+            combat_state: None,
             crafting_registry,
+            explored: HashSet::from([(0, 0)]),
+        }
+    }
+
+    /// Check whether the character has seen the land at the given coordinates
+    pub fn is_explored(&self, x: i32, y: i32) -> bool {
+        self.explored.contains(&(x, y))
+    }
+
+    /// Save the full game state (world, character, view mode, combat, items) as JSON
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize game state: {}", e))?;
+        fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    /// Load a game state previously written by `save`
+    pub fn load(path: &str) -> Result<GameState, String> {
+        let json = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let mut state: GameState = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse save file: {}", e))?;
+        state.restore_combat()?;
+        Ok(state)
+    }
+
+    /// Re-link a loaded combat snapshot to the enemy stored in the world.
+    /// The enemy's max health only lives on the world `Enemy`, so the snapshot's
+    /// health is checked against it and copied back to keep both in agreement.
+    fn restore_combat(&mut self) -> Result<(), String> {
+        let (land_x, land_y) = self.current_land();
+        match (self.current_mode, &self.combat_state) {
+            (CurrentMode::Combat, Some(ActiveCombat::Single(combat))) => {
+                let enemy = self.world.terrain.get_mut(&(land_x, land_y))
+                    .and_then(|land| land.enemy.as_mut())
+                    .ok_or_else(|| format!("Save is in combat but L[{},{}] has no enemy", land_x, land_y))?;
+                if combat.enemy.health > enemy.max_health {
+                    return Err(format!(
+                        "Saved enemy health {} exceeds its max health {}",
+                        combat.enemy.health, enemy.max_health
+                    ));
+                }
+                enemy.health = combat.enemy.health;
+                self.character.health = combat.player.health;
+                self.combat_round = combat.round;
+                Ok(())
+            }
+            (CurrentMode::Combat, Some(ActiveCombat::Team(combat))) => {
+                let team = self.world.terrain.get_mut(&(land_x, land_y))
+                    .and_then(|land| land.enemy_team.as_mut())
+                    .ok_or_else(|| format!("Save is in team combat but L[{},{}] has no enemy team", land_x, land_y))?;
+                if team.len() != combat.enemies.len() {
+                    return Err(format!(
+                        "Saved team has {} enemies but L[{},{}] has {}",
+                        combat.enemies.len(), land_x, land_y, team.len()
+                    ));
+                }
+                for (enemy, saved) in team.iter_mut().zip(&combat.enemies) {
+                    if saved.health > enemy.max_health {
+                        return Err(format!(
+                            "Saved enemy health {} exceeds its max health {}",
+                            saved.health, enemy.max_health
+                        ));
+                    }
+                    enemy.health = saved.health;
+                }
+                self.character.health = combat.player.health;
+                self.combat_round = combat.round;
+                Ok(())
+            }
+            (CurrentMode::Combat, None) => Err("Save is in combat but has no combat state".to_string()),
+            (_, Some(_)) => Err("Save has a combat state outside of combat view".to_string()),
+            (_, None) => Ok(()),
         }
     }
 
@@ -107,7 +192,9 @@ impl GameState {
         Some(TileInfo {
             substrate: tile.substrate.clone(),
             items: tile.items.clone(),
+            world_object: tile.world_object,
             biome: biome.clone(),
+            npc: tile.npc.as_ref().map(|npc| npc.name.clone()),
         })
     }
 
@@ -125,6 +212,7 @@ impl GameState {
         // Update character position (source of truth)
         self.character.set_land_position(new_x, new_y);
         self.character.set_tile_position(None);
+        self.explored.insert((new_x, new_y));
     }
 
     /// Move within the current land (land view)
@@ -150,10 +238,16 @@ impl GameState {
         }
 
         let (land_x, land_y) = self.character.get_land_position();
+        self.explored.insert((land_x, land_y));
         
         // Check if land has an enemy
         if let Some(land) = self.world.terrain.get(&(land_x, land_y)) {
-            if let Some(enemy) = &land.enemy {
+            if land.has_living_team() {
+                self.start_team_combat(land_x, land_y);
+            } else if land.enemy_team.is_some() {
+                // Team already defeated, proceed to land view
+                self.enter_land_view_internal(land_x, land_y);
+            } else if let Some(enemy) = &land.enemy {
                 // Check if enemy is already defeated
                 if enemy.is_defeated() {
                     // Enemy defeated, proceed to land view
@@ -190,10 +284,54 @@ impl GameState {
         }
         
         // Enter combat mode and reset round counter
+        let (player, enemy) = self.build_combatants(&enemy);
+        self.combat_state = Some(ActiveCombat::Single(CombatState::new(player, enemy)));
+        self.current_mode = CurrentMode::Combat;
+        self.combat_round = 0;
+    }
+
+    /// Start a team fight against the land's enemy team, restored to full health
+    fn start_team_combat(&mut self, land_x: i32, land_y: i32) {
+        let Some(team) = self.world.terrain.get_mut(&(land_x, land_y))
+            .and_then(|land| land.enemy_team.as_mut()) else {
+            return;
+        };
+        for enemy in team.iter_mut() {
+            enemy.restore_health();
+        }
+        let team = team.clone();
+        
+        self.combat_state = Some(ActiveCombat::Team(self.build_team_combat(&team)));
         self.current_mode = CurrentMode::Combat;
         self.combat_round = 0;
     }
 
+    /// Create a team fight for the player (with bonuses) against `team`
+    fn build_team_combat(&self, team: &[super::world::types::Enemy]) -> TeamCombatState {
+        let enemies = team.iter().map(Self::enemy_combatant).collect();
+        TeamCombatState::new(self.player_combatant(), enemies)
+    }
+
+    /// Create combat system combatants for the player (with bonuses) and an enemy
+    fn build_combatants(&self, enemy: &super::world::types::Enemy) -> (Combatant, Combatant) {
+        (self.player_combatant(), Self::enemy_combatant(enemy))
+    }
+
+    /// The player as a combatant, with equipment bonuses
+    fn player_combatant(&self) -> Combatant {
+        Combatant::new(
+            self.character.health,
+            self.get_total_attack(),
+            self.get_total_defense(),
+            self.get_total_accuracy(),
+            self.get_total_evasion(),
+        )
+    }
+
+    fn enemy_combatant(enemy: &super::world::types::Enemy) -> Combatant {
+        Combatant::new(enemy.health, enemy.attack, enemy.defense, enemy.accuracy, enemy.evasion)
+    }
+
     /// Execute a combat round (attack)
     /// Returns the combat result
     pub fn combat_attack(&mut self) -> CombatResult {
@@ -204,79 +342,18 @@ impl GameState {
         // Increment round counter
         self.combat_round += 1;
         
-        let (land_x, land_y) = self.character.get_land_position();
-        
-        // Get player stats (with bonuses)
-        let player_attack = self.get_total_attack();
-        let player_defense = self.get_total_defense();
-        let player_accuracy = self.get_total_accuracy();
-        let player_evasion = self.get_total_evasion();
-        
-        // Get enemy (must exist if we're in combat)
-        let enemy = self.world.terrain.get_mut(&(land_x, land_y))
-            .and_then(|land| land.enemy.as_mut())
-            .expect("Enemy must exist in combat mode");
-        
-        // Store enemy stats
-        let enemy_attack = enemy.attack;
-        let enemy_defense = enemy.defense;
-        let enemy_accuracy = enemy.accuracy;
-        let enemy_evasion = enemy.evasion;
-        
-        // Create combatants for the combat system
-        let player_combatant = super::combat::Combatant::new(
-            self.character.health,
-            player_attack,
-            player_defense,
-            player_accuracy,
-            player_evasion,
-        );
-        
-        let enemy_combatant = super::combat::Combatant::new(
-            enemy.health,
-            enemy_attack,
-            enemy_defense,
-            enemy_accuracy,
-            enemy_evasion,
-        );
-        
-        // Execute one round using the combat system
-        let mut combat_state = super::combat::CombatState::new(player_combatant, enemy_combatant);
-        let result = combat_state.execute_round();
-        
-        // Update actual health values from combat result
-        self.character.health = combat_state.player.health;
-        enemy.health = combat_state.enemy.health;
+        let result = match self.combat_state {
+            Some(ActiveCombat::Team(_)) => self.team_combat_round(),
+            _ => self.single_combat_round(),
+        };
         
         // Handle combat conclusion
         match result {
-            CombatResult::PlayerWins => {
-                // Get enemy type before modifying anything
-                let enemy_type = enemy.enemy_type;
-                
-                // Combat won - reset round counter and enter land view
-                self.combat_round = 0;
-                self.enter_land_view_internal(land_x, land_y);
-                
-                // Drop carcass on center tile (4,4)
-                let carcass_item_id = Self::get_carcass_for_enemy(enemy_type);
-                let carcass_instance = self.crafting_registry.create_simple_item(&carcass_item_id);
-                
-                // Place on center tile where player spawns
-                if let Some(land) = self.world.terrain.get_mut(&(land_x, land_y)) {
-                    land.tiles[4][4].items.push(carcass_instance);
-                }
-            }
+            CombatResult::PlayerWins => self.win_combat(),
             CombatResult::EnemyWins | CombatResult::Draw => {
                 // Player defeated - restore both to their starting states
-                let (land_x, land_y) = self.character.get_land_position();
-                
                 // Restore enemy health in world (so they're full health next time)
-                if let Some(land) = self.world.terrain.get_mut(&(land_x, land_y)) {
-                    if let Some(ref mut enemy) = land.enemy {
-                        enemy.restore_health();
-                    }
-                }
+                self.restore_enemies();
                 
                 // Restore character to half health
                 let half_health = self.character.get_max_health() / 2;
@@ -294,20 +371,121 @@ impl GameState {
         result
     }
 
-    /// Flee from combat (restore enemy health and return to terrain view)
-    /// Character health persists (not restored)
-    pub fn combat_flee(&mut self) {
-        // Restore enemy health in world (so they're full health next time)
+    /// Execute one round against the land's single enemy
+    fn single_combat_round(&mut self) -> CombatResult {
+        let (land_x, land_y) = self.character.get_land_position();
+        
+        // Get enemy (must exist if we're in combat)
+        let world_enemy = self.world.terrain.get(&(land_x, land_y))
+            .and_then(|land| land.enemy.as_ref())
+            .expect("Enemy must exist in combat mode");
+        
+        // Create combatants for the combat system from current stats
+        let (player_combatant, enemy_combatant) = self.build_combatants(world_enemy);
+        
+        // Execute one round using the combat system
+        let mut combat_state = CombatState::new(player_combatant, enemy_combatant);
+        combat_state.round = self.combat_state.as_ref().map_or(0, |c| c.round());
+        let result = combat_state.execute_round();
+        
+        // Update actual health values from combat result
+        self.character.health = combat_state.player.health;
+        let enemy = self.world.terrain.get_mut(&(land_x, land_y))
+            .and_then(|land| land.enemy.as_mut())
+            .expect("Enemy must exist in combat mode");
+        enemy.health = combat_state.enemy.health;
+        self.combat_state = match result {
+            CombatResult::Ongoing => Some(ActiveCombat::Single(combat_state)),
+            _ => None,
+        };
+        result
+    }
+
+    /// Execute one round against the land's enemy team (via `multi_combat`)
+    fn team_combat_round(&mut self) -> CombatResult {
+        let (land_x, land_y) = self.character.get_land_position();
+        let team = self.world.terrain.get(&(land_x, land_y))
+            .and_then(|land| land.enemy_team.as_ref())
+            .expect("Enemy team must exist in team combat");
+        
+        // Rebuild from current stats so equipment changes mid-fight apply
+        let mut combat_state = self.build_team_combat(team);
+        combat_state.round = self.combat_state.as_ref().map_or(0, |c| c.round());
+        let result = combat_state.execute_round();
+        
+        self.character.health = combat_state.player.health;
+        let team = self.world.terrain.get_mut(&(land_x, land_y))
+            .and_then(|land| land.enemy_team.as_mut())
+            .expect("Enemy team must exist in team combat");
+        for (enemy, combatant) in team.iter_mut().zip(&combat_state.enemies) {
+            enemy.health = combatant.health;
+        }
+        self.combat_state = match result {
+            CombatResult::Ongoing => Some(ActiveCombat::Team(combat_state)),
+            _ => None,
+        };
+        result
+    }
+
+    /// Award XP and loot for the defeated enemy (or whole team), drop carcasses on
+    /// the center tile, and enter land view
+    fn win_combat(&mut self) {
+        let (land_x, land_y) = self.character.get_land_position();
+        let Some(land) = self.world.terrain.get(&(land_x, land_y)) else {
+            return;
+        };
+        // A land with a team was won through the team fight, otherwise through its single enemy
+        let defeated: Vec<super::world::types::Enemy> = match &land.enemy_team {
+            Some(team) => team.clone(),
+            None => land.enemy.iter().cloned().collect(),
+        };
+        
+        // Combat won - reset round counter and enter land view
+        self.combat_round = 0;
+        self.enter_land_view_internal(land_x, land_y);
+        
+        for enemy in &defeated {
+            let xp = enemy.max_health.max(0) as u32 * XP_PER_ENEMY_HEALTH;
+            self.character.gain_xp(xp);
+            
+            // Drop carcass on center tile (4,4), where the player spawns
+            let carcass_item_id = Self::get_carcass_for_enemy(enemy.enemy_type);
+            let carcass_instance = self.crafting_registry.create_simple_item(&carcass_item_id);
+            if let Some(land) = self.world.terrain.get_mut(&(land_x, land_y)) {
+                land.tiles[4][4].items.push(carcass_instance);
+            }
+            
+            // Loot goes straight into the inventory
+            for item_id in &enemy.loot {
+                let loot_instance = self.crafting_registry.create_simple_item(item_id);
+                self.character.inventory.add_item(loot_instance);
+            }
+        }
+    }
+
+    /// Restore the current land's enemy and team to full health
+    fn restore_enemies(&mut self) {
         let (land_x, land_y) = self.character.get_land_position();
         if let Some(land) = self.world.terrain.get_mut(&(land_x, land_y)) {
             if let Some(ref mut enemy) = land.enemy {
                 enemy.restore_health();
             }
+            for enemy in land.enemy_team.iter_mut().flatten() {
+                enemy.restore_health();
+            }
         }
+    }
+
+    /// Flee from combat (restore enemy health and return to terrain view)
+    /// Character health persists (not restored)
+    pub fn combat_flee(&mut self) {
+        // Restore enemy health in world (so they're full health next time)
+        self.restore_enemies();
         
         // Exit combat and return to terrain view
         // Character health is NOT restored - it persists
         self.combat_round = 0;
+        self.combat_state = None;
         self.current_mode = CurrentMode::Terrain;
     }
 
@@ -625,4 +803,217 @@ mod tests {
         assert!(!state.land_exists(5, 5));
         assert!(!state.land_exists(-1, -1));
     }
+
+    #[test]
+    fn test_save_and_load_mid_combat() {
+        let mut state = create_test_state();
+        
+        // (1,0) has a rabbit; fight one round so both sides are damaged
+        state.move_terrain(1, 0);
+        state.enter_land();
+        assert_eq!(state.current_mode, CurrentMode::Combat);
+        state.character.health = 50;
+        state.character.max_health = 50;
+        state.combat_attack();
+        assert_eq!(state.current_mode, CurrentMode::Combat);
+        assert!(state.combat_state.is_some());
+        
+        let path = std::env::temp_dir().join(format!("mvp_save_test_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        state.save(path).unwrap();
+        let loaded = GameState::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        
+        assert_eq!(loaded.world, state.world);
+        assert_eq!(loaded.character, state.character);
+        assert_eq!(loaded.current_mode, CurrentMode::Combat);
+        assert_eq!(loaded.combat_round, state.combat_round);
+        assert_eq!(loaded.combat_state, state.combat_state);
+        assert_eq!(loaded.explored, state.explored);
+        
+        // Enemy max health survives so fleeing still restores it fully
+        let enemy = loaded.world.terrain[&(1, 0)].enemy.as_ref().unwrap();
+        assert_eq!(enemy.max_health, 7);
+        match loaded.combat_state.as_ref().unwrap() {
+            ActiveCombat::Single(combat) => assert_eq!(enemy.health, combat.enemy.health),
+            other => panic!("Expected a single-enemy fight, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_rejects_combat_without_enemy() {
+        let mut state = create_test_state();
+        state.current_mode = CurrentMode::Combat;
+        state.combat_state = Some(ActiveCombat::Single(CombatState::new(Combatant::TANK, Combatant::TANK)));
+        
+        let path = std::env::temp_dir().join(format!("mvp_bad_save_test_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        state.save(path).unwrap();
+        let result = GameState::load(path);
+        std::fs::remove_file(path).unwrap();
+        
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_victory_grants_loot() {
+        let mut state = create_test_state();
+        
+        // Give the rabbit at (1,0) some loot and make the fight a guaranteed one-round win
+        let loot = vec![ItemId("flint".to_string()), ItemId("stick".to_string())];
+        let enemy = state.world.terrain.get_mut(&(1, 0)).unwrap().enemy.as_mut().unwrap();
+        enemy.loot = loot.clone();
+        enemy.evasion = 0;
+        enemy.accuracy = 0;
+        state.character.attack = 100;
+        
+        state.move_terrain(1, 0);
+        state.enter_land();
+        let inventory_before = state.character.inventory.len();
+        
+        assert_eq!(state.combat_attack(), CombatResult::PlayerWins);
+        assert_eq!(state.character.inventory.len(), inventory_before + loot.len());
+        
+        let granted: Vec<ItemId> = state.character.inventory.items.iter()
+            .map(|&id| match state.crafting_registry.get_instance(id) {
+                Some(crate::game::crafting::ItemInstance::Simple(s)) => s.definition.clone(),
+                other => panic!("Unexpected loot instance: {:?}", other),
+            })
+            .collect();
+        assert_eq!(granted, loot);
+    }
+
+    #[test]
+    fn test_victories_award_xp_and_level_up() {
+        let mut state = create_test_state();
+        
+        // Two easy fights at (1,0) and (2,0): 6 max health is 12 XP each, so only the second crosses 20
+        let mut enemy = state.world.terrain[&(1, 0)].enemy.clone().unwrap();
+        enemy.health = 6;
+        enemy.max_health = 6;
+        enemy.evasion = 0;
+        enemy.accuracy = 0;
+        for coords in [(1, 0), (2, 0)] {
+            state.world.terrain.get_mut(&coords).unwrap().enemy = Some(enemy.clone());
+        }
+        state.character.attack = 100;
+        
+        state.move_terrain(1, 0);
+        state.enter_land();
+        assert_eq!(state.combat_attack(), CombatResult::PlayerWins);
+        assert_eq!((state.character.level, state.character.xp), (1, 12));
+        assert_eq!(state.character.max_health, 10);
+        
+        state.exit_land();
+        state.move_terrain(1, 0);
+        state.enter_land();
+        assert_eq!(state.combat_attack(), CombatResult::PlayerWins);
+        assert_eq!((state.character.level, state.character.xp), (2, 4));
+        assert_eq!(state.character.max_health, 13);
+        assert_eq!(state.character.attack, 101);
+        assert_eq!(state.character.health, 13);
+    }
+
+    /// Put a three-enemy team (front first) on (2,2), which has no single enemy
+    fn add_test_team(state: &mut GameState) {
+        use crate::game::world::types::Enemy;
+        
+        let team = vec![
+            Enemy::new(EnemyType::Wolf, 8, 3).with_loot(vec![ItemId("flint".to_string())]),
+            Enemy::new(EnemyType::Fox, 5, 2),
+            Enemy::new(EnemyType::Snake, 4, 4),
+        ];
+        state.world.terrain.get_mut(&(2, 2)).unwrap().enemy_team = Some(team);
+    }
+
+    #[test]
+    fn test_team_fight_resolves_to_win() {
+        let mut state = create_test_state();
+        add_test_team(&mut state);
+        state.character.max_health = 100;
+        state.character.health = 100;
+        state.character.attack = 10;
+        
+        state.move_terrain(2, 2);
+        state.enter_land();
+        assert_eq!(state.current_mode, CurrentMode::Combat);
+        assert!(matches!(state.combat_state, Some(ActiveCombat::Team(_))));
+        
+        // Each round fells the front enemy, so the team falls in three rounds
+        assert_eq!(state.combat_attack(), CombatResult::Ongoing);
+        assert_eq!(state.combat_attack(), CombatResult::Ongoing);
+        assert_eq!(state.combat_attack(), CombatResult::PlayerWins);
+        
+        assert_eq!(state.current_mode, CurrentMode::Land);
+        assert!(state.combat_state.is_none());
+        assert!(!state.world.terrain[&(2, 2)].has_living_team());
+        // One carcass per team member, loot from every member, XP for the whole team
+        assert_eq!(state.world.terrain[&(2, 2)].tiles[4][4].items.len(), 3);
+        assert_eq!(state.character.inventory.len(), 1);
+        assert_eq!(state.character.level, 2);
+        
+        // The defeated team no longer blocks the land
+        state.exit_land();
+        state.enter_land();
+        assert_eq!(state.current_mode, CurrentMode::Land);
+    }
+
+    #[test]
+    fn test_team_fight_resolves_to_loss() {
+        let mut state = create_test_state();
+        add_test_team(&mut state);
+        state.character.health = 10;
+        state.character.attack = 1;
+        
+        state.move_terrain(2, 2);
+        state.enter_land();
+        let mut result = CombatResult::Ongoing;
+        for _ in 0..10 {
+            result = state.combat_attack();
+            if result != CombatResult::Ongoing {
+                break;
+            }
+        }
+        
+        assert_eq!(result, CombatResult::EnemyWins);
+        assert_eq!(state.current_mode, CurrentMode::Terrain);
+        assert_eq!(state.character.health, state.character.max_health / 2);
+        // The whole team is back at full health for the next attempt
+        let team = state.world.terrain[&(2, 2)].enemy_team.as_ref().unwrap();
+        assert!(team.iter().all(|enemy| enemy.health == enemy.max_health));
+    }
+
+    #[test]
+    fn test_save_and_load_mid_team_fight() {
+        let mut state = create_test_state();
+        add_test_team(&mut state);
+        state.character.health = 50;
+        state.character.max_health = 50;
+        
+        state.move_terrain(2, 2);
+        state.enter_land();
+        assert_eq!(state.combat_attack(), CombatResult::Ongoing);
+        
+        let path = std::env::temp_dir().join(format!("mvp_team_save_test_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        state.save(path).unwrap();
+        let loaded = GameState::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        
+        assert_eq!(loaded.combat_state, state.combat_state);
+        assert_eq!(loaded.world, state.world);
+    }
+
+    #[test]
+    fn test_moving_marks_lands_explored() {
+        let mut state = create_test_state();
+        
+        state.move_terrain(1, 0);
+        state.move_terrain(1, 0);
+        
+        let expected: HashSet<(i32, i32)> = [(0, 0), (1, 0), (2, 0)].into_iter().collect();
+        assert_eq!(state.explored, expected);
+        assert!(state.is_explored(1, 0));
+        assert!(!state.is_explored(0, 1));
+    }
 }
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
-use serde::{Deserialize, Serialize};
-use crate::game::crafting::{ItemInstanceId, WorldObjectInstanceId};
+use std::fmt;
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::game::crafting::{ItemId, ItemInstanceId, WorldObjectInstanceId};
 
 /// Enemy type variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -19,12 +21,16 @@ pub enum EnemyType {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Enemy {
     pub enemy_type: EnemyType,
+    /// Display name (defaults to the enemy type's name)
+    pub name: String,
     pub health: i32,
     pub attack: i32,
     pub defense: i32,   // Reduces incoming physical damage
     pub accuracy: i32,  // Hit chance = accuracy * 10%
     pub evasion: i32,   // Reduces attacker's accuracy
     pub max_health: i32, // Store max health for restoration when fleeing
+    /// Items granted to the player on victory (in addition to the carcass)
+    pub loot: Vec<ItemId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -43,6 +49,19 @@ pub struct Tile {
     pub substrate: Substrate,
     pub items: Vec<ItemInstanceId>,
     pub world_object: Option<WorldObjectInstanceId>,
+    /// Character standing on this tile who can be talked to
+    #[serde(default)]
+    pub npc: Option<Npc>,
+}
+
+/// A non-hostile character with a few lines of dialog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Npc {
+    pub name: String,
+    pub lines: Vec<String>,
+    /// Index of the line said on the next `talk`
+    #[serde(default)]
+    pub next_line: usize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -69,15 +88,72 @@ pub struct Land {
     pub bottom_right: Biome, // corner (1 tile)
     /// Optional enemy that blocks this land (must be defeated to enter)
     pub enemy: Option<Enemy>,
+    /// Optional enemy team (front first) fought as one multi-enemy battle.
+    /// Takes precedence over `enemy` while any member is alive
+    #[serde(default)]
+    pub enemy_team: Option<Vec<Enemy>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct World {
     pub name: String,
+    #[serde(serialize_with = "serialize_terrain", deserialize_with = "deserialize_terrain")]
     pub terrain: HashMap<(i32, i32), Land>,
     pub seed: u64,
 }
 
+/// JSON object keys must be strings, so terrain is stored keyed by "x,y"
+fn serialize_terrain<S>(terrain: &HashMap<(i32, i32), Land>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::SerializeMap;
+    let mut map = serializer.serialize_map(Some(terrain.len()))?;
+    for (k, v) in terrain {
+        let key = format!("{},{}", k.0, k.1);
+        map.serialize_entry(&key, v)?;
+    }
+    map.end()
+}
+
+fn deserialize_terrain<'de, D>(deserializer: D) -> Result<HashMap<(i32, i32), Land>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct TerrainVisitor;
+
+    impl<'de> Visitor<'de> for TerrainVisitor {
+        type Value = HashMap<(i32, i32), Land>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map with \"x,y\" string keys")
+        }
+
+        fn visit_map<M>(self, mut access: M) -> Result<Self::Value, M::Error>
+        where
+            M: serde::de::MapAccess<'de>,
+        {
+            let mut map = HashMap::new();
+            while let Some((key, value)) = access.next_entry::<String, Land>()? {
+                let coords = key
+                    .split_once(',')
+                    .and_then(|(x, y)| Some((x.parse::<i32>().ok()?, y.parse::<i32>().ok()?)));
+                match coords {
+                    Some(coords) => {
+                        map.insert(coords, value);
+                    }
+                    None => {
+                        return Err(serde::de::Error::custom(format!("invalid land key: {}", key)));
+                    }
+                }
+            }
+            Ok(map)
+        }
+    }
+
+    deserializer.deserialize_map(TerrainVisitor)
+}
+
 impl Biome {
     pub fn to_char(&self) -> &str {
         match self {
@@ -160,15 +236,29 @@ impl Enemy {
         
         Self {
             enemy_type,
+            name: enemy_type.display_name().to_string(),
             health,
             attack,
             defense,
             accuracy,
             evasion,
             max_health: health,
+            loot: Vec::new(),
         }
     }
 
+    /// Set the items this enemy drops into the player's inventory when defeated
+    pub fn with_loot(mut self, loot: Vec<ItemId>) -> Self {
+        self.loot = loot;
+        self
+    }
+
+    /// Give this enemy a custom display name
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
     /// Check if enemy is defeated
     pub fn is_defeated(&self) -> bool {
         self.health <= 0
@@ -179,3 +269,43 @@ impl Enemy {
         self.health = self.max_health;
     }
 }
+
+impl Npc {
+    /// Create an NPC that starts from its first line
+    pub fn new(name: &str, lines: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            lines: lines.iter().map(|line| line.to_string()).collect(),
+            next_line: 0,
+        }
+    }
+
+    /// Say the next line, wrapping back to the first after the last
+    /// Returns None if the NPC has nothing to say
+    pub fn talk(&mut self) -> Option<&str> {
+        if self.lines.is_empty() {
+            return None;
+        }
+        let index = self.next_line % self.lines.len();
+        self.next_line = (index + 1) % self.lines.len();
+        Some(&self.lines[index])
+    }
+}
+
+impl Land {
+    /// The enemies fought on entering, front first: the team if there is one,
+    /// otherwise the single enemy
+    pub fn opponents(&self) -> Vec<&Enemy> {
+        match &self.enemy_team {
+            Some(team) => team.iter().collect(),
+            None => self.enemy.iter().collect(),
+        }
+    }
+
+    /// True if the land has an enemy team with at least one living member
+    pub fn has_living_team(&self) -> bool {
+        self.enemy_team
+            .as_ref()
+            .is_some_and(|team| team.iter().any(|enemy| !enemy.is_defeated()))
+    }
+}
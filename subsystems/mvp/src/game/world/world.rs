@@ -1,4 +1,4 @@
-use super::types::{Biome, Land, Substrate, Tile, World, Enemy, EnemyType};
+use super::types::{Biome, Land, Substrate, Tile, World, Enemy, EnemyType, Npc};
 use crate::game::crafting::{CraftingRegistry, ItemInstanceId, ItemId, RecipeId, Provenance};
 use std::collections::HashMap;
 
@@ -46,7 +46,16 @@ pub fn create_hardcoded_world(crafting_registry: &mut CraftingRegistry) -> World
             let bottom_right_biome = if y < 4 && x < 4 { biome_grid[y + 1][x + 1].clone() } else { center_biome.clone() };
 
             // Generate tiles based on biome
-            let tiles = generate_tiles_for_biome(&center_biome, x as i32, y as i32, crafting_registry);
+            let mut tiles = generate_tiles_for_biome(&center_biome, x as i32, y as i32, crafting_registry);
+
+            // A guide waits where the player first enters the starting land
+            if (x, y) == (0, 0) {
+                tiles[4][4].npc = Some(Npc::new("Old Wren", &[
+                    "Welcome, traveler. The lands grow wilder to the south and east.",
+                    "Knap some flint into a blade before you face anything bigger than a rabbit.",
+                    "They say an ancient dragon sleeps in the far mountains.",
+                ]));
+            }
 
             // Determine enemy based on mixed biome/difficulty approach
             // Distance from start (0,0) scales difficulty
@@ -54,7 +63,7 @@ pub fn create_hardcoded_world(crafting_registry: &mut CraftingRegistry) -> World
             let enemy = match (x, y) {
                 (0, 0) => None, // Start position - no enemy
                 
-                (4, 4) => Some(Enemy::new(EnemyType::Dragon, 22, 9)), // Boss
+                (4, 4) => Some(Enemy::new(EnemyType::Dragon, 22, 9).with_name("Ancient Dragon").with_loot(vec![item_id("iron_bar"), item_id("bronze_bar")])), // Boss
                 
                 // Distance 1 - Weak enemies (Rabbit)
                 (1, 0) => Some(Enemy::new(EnemyType::Rabbit, 7, 2)), // Plains
@@ -69,19 +78,19 @@ pub fn create_hardcoded_world(crafting_registry: &mut CraftingRegistry) -> World
                 (3, 0) => Some(Enemy::new(EnemyType::Wolf, 12, 5)), // Forest
                 (2, 1) => Some(Enemy::new(EnemyType::Fox, 10, 4)), // Forest
                 (1, 2) => Some(Enemy::new(EnemyType::Wolf, 13, 5)), // Forest
-                (0, 3) => Some(Enemy::new(EnemyType::Spider, 11, 4)), // Forest
+                (0, 3) => Some(Enemy::new(EnemyType::Spider, 11, 4).with_loot(vec![item_id("plant_fiber")])), // Forest
                 
                 // Distance 4-5 - Medium enemies
-                (4, 0) => Some(Enemy::new(EnemyType::Spider, 12, 5)), // Forest
+                (4, 0) => Some(Enemy::new(EnemyType::Spider, 12, 5).with_loot(vec![item_id("plant_fiber")])), // Forest
                 (3, 1) => None, // Lake area - fewer enemies
                 (2, 2) => None, // Lake center - no enemy
                 (1, 3) => Some(Enemy::new(EnemyType::Wolf, 14, 5)), // Forest
                 (0, 4) => Some(Enemy::new(EnemyType::Spider, 13, 5)), // Forest
                 
                 // Distance 5-6 - Strong enemies (Snake, Lion)
-                (4, 1) => Some(Enemy::new(EnemyType::Snake, 14, 6)), // Mountain
+                (4, 1) => Some(Enemy::new(EnemyType::Snake, 14, 6).with_loot(vec![item_id("flint")])), // Mountain
                 (3, 2) => None, // Give player room to explore
-                (2, 3) => Some(Enemy::new(EnemyType::Snake, 13, 5)), // Plains
+                (2, 3) => Some(Enemy::new(EnemyType::Snake, 13, 5).with_loot(vec![item_id("flint")])), // Plains
                 (1, 4) => Some(Enemy::new(EnemyType::Wolf, 15, 6)), // Plains
                 
                 // Distance 6-7 - Strong enemies (Lion)
@@ -109,6 +118,7 @@ pub fn create_hardcoded_world(crafting_registry: &mut CraftingRegistry) -> World
                 bottom_left: bottom_left_biome,
                 bottom_right: bottom_right_biome,
                 enemy,
+                enemy_team: None,
             };
 
             terrain.insert((x as i32, y as i32), land);
@@ -122,6 +132,11 @@ pub fn create_hardcoded_world(crafting_registry: &mut CraftingRegistry) -> World
     }
 }
 
+/// Helper to create an ItemId
+fn item_id(s: &str) -> ItemId {
+    ItemId(s.to_string())
+}
+
 /// Create a simple item instance (for world objects like rock, tree, stick)
 fn create_simple_item_instance(crafting_registry: &mut CraftingRegistry, item_id: &str) -> ItemInstanceId {
     let instance_id = crafting_registry.next_instance_id();
@@ -149,6 +164,7 @@ fn generate_tiles_for_biome(biome: &Biome, land_x: i32, land_y: i32, crafting_re
             substrate: Substrate::Grass,
             items: vec![],
             world_object: None,
+            npc: None,
         })
     });
 
@@ -415,8 +431,8 @@ mod tests {
         assert!(start_land.is_some());
         
         if let Some(land) = start_land {
-            // Start should be Plains biome
-            assert_eq!(land.center, Biome::Plains);
+            // Start should be Meadow biome
+            assert_eq!(land.center, Biome::Meadow);
         }
     }
 
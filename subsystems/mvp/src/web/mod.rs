@@ -15,16 +15,21 @@ pub mod state_builder;
 // Re-export public types for convenient access
 pub use types::*;
 
-use crate::game::game_state::CurrentMode;
+use crate::game::game_state::{CurrentMode, GameState};
 use crate::game::commands::execute_command;
 use state_builder::{build_terrain_state, build_land_state, build_combat_state, build_serializable_character};
 
+/// The only file the web save/load endpoints touch
+pub const DEFAULT_SAVE_PATH: &str = "mvp_save.json";
+
 /// Create the web server router
 pub fn create_router(game_state: SharedGameState) -> Router {
     Router::new()
         .route("/", get(index))
         .route("/api/state", get(get_state))
         .route("/api/command", post(handle_command))
+        .route("/api/save", post(save_game))
+        .route("/api/load", post(load_game))
         .nest_service("/static", ServeDir::new("static"))
         .nest_service("/assets", ServeDir::new("../../assets"))
         .with_state(game_state)
@@ -79,6 +84,51 @@ async fn handle_command(
     Ok(Json(response))
 }
 
+/// Save the game to disk
+async fn save_game(
+    State(game_state): State<SharedGameState>,
+    req: Option<Json<SaveRequest>>,
+) -> Result<Json<SaveResponse>, StatusCode> {
+    if let Some(rejection) = reject_client_path(req) {
+        return Ok(Json(rejection));
+    }
+    let state = game_state.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    
+    let response = match state.save(DEFAULT_SAVE_PATH) {
+        Ok(()) => SaveResponse { success: true, message: format!("💾 Saved to {}", DEFAULT_SAVE_PATH) },
+        Err(e) => SaveResponse { success: false, message: e },
+    };
+    Ok(Json(response))
+}
+
+/// Replace the running game with one loaded from disk
+async fn load_game(
+    State(game_state): State<SharedGameState>,
+    req: Option<Json<SaveRequest>>,
+) -> Result<Json<SaveResponse>, StatusCode> {
+    if let Some(rejection) = reject_client_path(req) {
+        return Ok(Json(rejection));
+    }
+    
+    let response = match GameState::load(DEFAULT_SAVE_PATH) {
+        Ok(loaded) => {
+            let mut state = game_state.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            *state = loaded;
+            SaveResponse { success: true, message: format!("📂 Loaded {}", DEFAULT_SAVE_PATH) }
+        }
+        Err(e) => SaveResponse { success: false, message: e },
+    };
+    Ok(Json(response))
+}
+
+/// Clients can't choose the save file, so a request naming one fails instead of
+/// silently writing somewhere else
+fn reject_client_path(req: Option<Json<SaveRequest>>) -> Option<SaveResponse> {
+    req.and_then(|Json(req)| req.path).map(|path| SaveResponse {
+        success: false,
+        message: format!("Can't use '{}': saves always go to {}", path, DEFAULT_SAVE_PATH),
+    })
+}
 
 #[cfg(test)]
 mod tests {
@@ -99,7 +149,7 @@ mod tests {
         let mut state = create_test_state();
         
         state.move_terrain(2, 2);
-        let (success, message) = execute_command(&mut state, "u");
+        let (success, message) = execute_command(&mut state, "m u");
         
         assert!(success);
         assert!(message.contains("L["));
@@ -110,7 +160,7 @@ mod tests {
     fn test_command_move_down_terrain() {
         let mut state = create_test_state();
         
-        let (success, message) = execute_command(&mut state, "d");
+        let (success, message) = execute_command(&mut state, "m d");
         
         assert!(success);
         assert!(message.contains("L["));
@@ -122,7 +172,7 @@ mod tests {
         let mut state = create_test_state();
         
         state.move_terrain(2, 2);
-        let (success, message) = execute_command(&mut state, "l");
+        let (success, message) = execute_command(&mut state, "m l");
         
         assert!(success);
         assert!(message.contains("L["));
@@ -133,7 +183,7 @@ mod tests {
     fn test_command_move_right_terrain() {
         let mut state = create_test_state();
         
-        let (success, message) = execute_command(&mut state, "r");
+        let (success, message) = execute_command(&mut state, "m r");
         
         assert!(success);
         assert!(message.contains("L["));
@@ -148,7 +198,7 @@ mod tests {
         let initial_tile = state.current_tile().unwrap();
         state.move_land(0, 2); // Move down first
         
-        let (success, message) = execute_command(&mut state, "u");
+        let (success, message) = execute_command(&mut state, "m u");
         
         assert!(success);
         assert!(message.contains("T["));
@@ -163,7 +213,7 @@ mod tests {
         state.enter_land();
         let initial_tile = state.current_tile().unwrap();
         
-        let (success, message) = execute_command(&mut state, "d");
+        let (success, message) = execute_command(&mut state, "m d");
         
         assert!(success);
         assert!(message.contains("T["));
@@ -231,7 +281,7 @@ mod tests {
         
         assert!(success);
         assert!(message.contains("Commands"));
-        assert!(message.contains("Arrow Keys"));
+        assert!(message.contains("MOVE"));
     }
 
     #[test]
@@ -254,4 +304,169 @@ mod tests {
         assert!(message.contains("Empty command"));
     }
 
+    /// Find a tile in the current land whose items satisfy `pred`
+    fn find_tile(state: &GameState, pred: impl Fn(&[crate::game::crafting::ItemInstanceId]) -> bool) -> Option<(usize, usize)> {
+        let land = &state.world.terrain[&state.current_land()];
+        (0..8).flat_map(|y| (0..8).map(move |x| (x, y)))
+            .find(|&(x, y)| pred(&land.tiles[y][x].items))
+    }
+
+    fn is_pickupable(state: &GameState, id: crate::game::crafting::ItemInstanceId) -> bool {
+        match state.crafting_registry.get_instance(id) {
+            Some(crate::game::crafting::ItemInstance::Simple(s)) => state.crafting_registry
+                .get_item(&s.definition)
+                .is_some_and(|def| def.pickupable),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_command_pickup() {
+        let mut state = create_test_state();
+        state.enter_land();
+        
+        let (x, y) = find_tile(&state, |items| items.iter().any(|&id| is_pickupable(&state, id)))
+            .expect("Start land should have a pickupable item");
+        state.character.set_tile_position(Some((x, y)));
+        let items_before = state.world.terrain[&(0, 0)].tiles[y][x].items.len();
+        
+        let (success, message) = execute_command(&mut state, "pickup");
+        
+        assert!(success, "{}", message);
+        assert!(message.contains("Picked up"));
+        assert_eq!(state.character.inventory.len(), 1);
+        assert_eq!(state.world.terrain[&(0, 0)].tiles[y][x].items.len(), items_before - 1);
+        
+        let picked = state.character.inventory.items[0];
+        assert!(state.crafting_registry.get_instance(picked).is_some());
+    }
+
+    #[test]
+    fn test_command_pickup_empty_tile() {
+        let mut state = create_test_state();
+        state.enter_land();
+        
+        let (x, y) = find_tile(&state, |items| items.is_empty()).expect("Start land should have an empty tile");
+        state.character.set_tile_position(Some((x, y)));
+        
+        let (success, message) = execute_command(&mut state, "pickup");
+        
+        assert!(!success);
+        assert!(message.contains("Nothing here"));
+        assert!(state.character.inventory.is_empty());
+    }
+
+    #[test]
+    fn test_land_state_exposes_current_tile_objects() {
+        let mut state = create_test_state();
+        state.enter_land();
+        
+        let (x, y) = find_tile(&state, |items| !items.is_empty()).expect("Start land should have items");
+        state.character.set_tile_position(Some((x, y)));
+        
+        let info = build_land_state(&state).current_tile_info.unwrap();
+        assert_eq!(info.objects.len(), state.world.terrain[&(0, 0)].tiles[y][x].items.len());
+    }
+
+    #[test]
+    fn test_command_move_repeat_land() {
+        let mut state = create_test_state();
+        state.enter_land();
+        assert_eq!(state.current_tile(), Some((4, 4)));
+        
+        let (success, message) = execute_command(&mut state, "m u3");
+        assert!(success);
+        assert!(message.contains("T[4,1]"));
+        assert_eq!(state.current_tile(), Some((4, 1)));
+        
+        // Only one tile left before the edge
+        let (success, _) = execute_command(&mut state, "m u3");
+        assert!(success);
+        assert_eq!(state.current_tile(), Some((4, 0)));
+    }
+
+    #[test]
+    fn test_command_move_diagonal() {
+        let mut state = create_test_state();
+        state.enter_land();
+        
+        let (success, message) = execute_command(&mut state, "m ur");
+        assert!(success);
+        assert!(message.contains("T[5,3]"));
+        assert_eq!(state.current_tile(), Some((5, 3)));
+        
+        let (success, _) = execute_command(&mut state, "m dl2");
+        assert!(success);
+        assert_eq!(state.current_tile(), Some((3, 5)));
+    }
+
+    #[test]
+    fn test_command_move_repeat_terrain_stops_at_enemy() {
+        let mut state = create_test_state();
+        
+        // (2,2) has no enemy; (2,1) has a fox, so moving up two stops there
+        state.move_terrain(2, 2);
+        let (success, message) = execute_command(&mut state, "m u2");
+        
+        assert!(success);
+        assert!(message.contains("stopped"));
+        assert_eq!(state.current_land(), (2, 1));
+        assert_eq!(state.current_mode, CurrentMode::Terrain);
+    }
+
+    #[test]
+    fn test_command_move_invalid_count() {
+        let mut state = create_test_state();
+        
+        let (success, _) = execute_command(&mut state, "m u0");
+        assert!(!success);
+        assert_eq!(state.current_land(), (0, 0));
+    }
+
+    #[test]
+    fn test_command_talk_cycles_npc_lines() {
+        let mut state = create_test_state();
+        state.enter_land();
+        let lines = state.world.terrain[&(0, 0)].tiles[4][4].npc.clone()
+            .expect("Start tile should have the guide NPC").lines;
+        assert_eq!(build_land_state(&state).current_tile_info.unwrap().npc.as_deref(), Some("Old Wren"));
+        
+        for expected in lines.iter().chain(lines.first()) {
+            let (success, message) = execute_command(&mut state, "talk");
+            assert!(success);
+            assert!(message.contains(expected.as_str()), "{} should contain {}", message, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_reject_client_path() {
+        let path = std::env::temp_dir().join(format!("mvp_web_{}.json", std::process::id()));
+        let request = || Some(Json(SaveRequest { path: Some(path.to_str().unwrap().to_string()) }));
+        let shared: SharedGameState = std::sync::Arc::new(std::sync::Mutex::new(create_test_state()));
+        
+        let Json(response) = save_game(State(shared.clone()), request()).await.unwrap();
+        assert!(!response.success);
+        assert!(response.message.contains(DEFAULT_SAVE_PATH));
+        assert!(!path.exists());
+        
+        let Json(response) = load_game(State(shared), request()).await.unwrap();
+        assert!(!response.success);
+    }
+
+    #[test]
+    fn test_command_talk_without_npc() {
+        let mut state = create_test_state();
+        state.enter_land();
+        execute_command(&mut state, "m u");
+        assert!(state.world.terrain[&(0, 0)].tiles[3][4].npc.is_none());
+        
+        let (success, message) = execute_command(&mut state, "talk");
+        assert!(!success);
+        assert!(message.contains("no one here"));
+        
+        // Talking is only possible in land view
+        execute_command(&mut state, "x");
+        let (success, _) = execute_command(&mut state, "talk");
+        assert!(!success);
+    }
 }
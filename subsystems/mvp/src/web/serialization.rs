@@ -1,4 +1,4 @@
-use crate::game::crafting::{CraftingRegistry, ItemInstanceId, ItemInstance};
+use crate::game::crafting::{CraftingRegistry, ItemInstanceId, ItemInstance, WorldObjectInstanceId, WorldObjectKind};
 use crate::game::game_state::GameState;
 
 /// Convert an item instance ID to its display name
@@ -22,6 +22,15 @@ pub fn get_item_name(registry: &CraftingRegistry, instance_id: ItemInstanceId) -
         })
 }
 
+/// Convert a world object instance ID to its display name
+pub fn get_world_object_name(registry: &CraftingRegistry, world_object_id: WorldObjectInstanceId) -> Option<String> {
+    registry.get_world_object(world_object_id)
+        .map(|world_object| match &world_object.kind {
+            WorldObjectKind::ResourceNode(id) => id.0.clone(),
+            WorldObjectKind::CraftingStation(id) => id.0.clone(),
+        })
+}
+
 /// Serialize a list of instance IDs to their display names
 pub fn serialize_item_list(registry: &CraftingRegistry, instance_ids: &[ItemInstanceId]) -> Vec<String> {
     instance_ids.iter()
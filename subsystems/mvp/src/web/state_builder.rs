@@ -1,7 +1,24 @@
+use crate::game::character::Character;
 use crate::game::game_state::GameState;
-use super::serialization::{get_item_name, serialize_inventory, serialize_equipped};
+use crate::game::world::types::Enemy;
+use super::serialization::{get_item_name, get_world_object_name, serialize_item_list, serialize_inventory, serialize_equipped};
 use super::types::*;
 
+/// Stats and status of one enemy, for tooltips and team listings
+fn enemy_info(e: &Enemy) -> TerrainEnemyInfo {
+    TerrainEnemyInfo {
+        enemy_type: e.enemy_type.display_name().to_string(),
+        name: e.name.clone(),
+        health: e.health,
+        max_health: e.max_health,
+        attack: e.attack,
+        defense: e.defense,
+        accuracy: e.accuracy,
+        evasion: e.evasion,
+        is_defeated: e.is_defeated(),
+    }
+}
+
 /// Build terrain view state (all lands with biome + enemy info, no tiles)
 pub fn build_terrain_state(state: &GameState) -> TerrainGameState {
     let mut lands = Vec::new();
@@ -11,21 +28,19 @@ pub fn build_terrain_state(state: &GameState) -> TerrainGameState {
         for x in 0..5 {
             let coords = (x, y);
             if let Some(land) = state.world.terrain.get(&coords) {
-                let enemy = land.enemy.as_ref().map(|e| TerrainEnemyInfo {
-                    enemy_type: e.enemy_type.display_name().to_string(),
-                    health: e.health,
-                    max_health: e.max_health,
-                    attack: e.attack,
-                    defense: e.defense,
-                    accuracy: e.accuracy,
-                    evasion: e.evasion,
-                    is_defeated: e.is_defeated(),
-                });
+                // For a team, show the front-most living member (or the leader once all are down)
+                let opponents = land.opponents();
+                let enemy = opponents.iter()
+                    .find(|e| !e.is_defeated())
+                    .or(opponents.first())
+                    .map(|e| enemy_info(e));
                 
                 lands.push(TerrainLandInfo {
                     coords,
                     biome: format!("{:?}", land.center),
                     enemy,
+                    team_size: opponents.len(),
+                    explored: state.is_explored(x, y),
                 });
             }
         }
@@ -59,20 +74,39 @@ pub fn build_land_state(state: &GameState) -> LandGameState {
         }).collect()
     }).collect();
     
+    let current_tile_info = state.current_tile_info().map(|info| SerializableTileInfo {
+        substrate: format!("{:?}", info.substrate),
+        biome: format!("{:?}", info.biome),
+        objects: serialize_item_list(&state.crafting_registry, &info.items),
+        world_object: info.world_object
+            .and_then(|id| get_world_object_name(&state.crafting_registry, id)),
+        npc: info.npc,
+    });
+    
     LandGameState {
         land_coords: (land_x, land_y),
         current_tile,
         tiles,
         biome: format!("{:?}", land.center),
+        current_tile_info,
     }
 }
 
 /// Build combat view state
 pub fn build_combat_state(state: &GameState) -> CombatGameState {
     let (land_x, land_y) = state.current_land();
-    let enemy = state.world.terrain.get(&(land_x, land_y))
-        .and_then(|land| land.enemy.as_ref())
+    let land = state.world.terrain.get(&(land_x, land_y))
+        .expect("Land should exist when in combat view");
+    // The player is fighting the front-most living opponent
+    let opponents = land.opponents();
+    let enemy = opponents.iter()
+        .find(|e| !e.is_defeated())
+        .or(opponents.first())
         .expect("Enemy should exist when in combat view");
+    let team = match &land.enemy_team {
+        Some(team) => team.iter().map(enemy_info).collect(),
+        None => Vec::new(),
+    };
     
     CombatGameState {
         land_coords: (land_x, land_y),
@@ -91,7 +125,9 @@ pub fn build_combat_state(state: &GameState) -> CombatGameState {
             evasion: enemy.evasion,
         },
         enemy_type: enemy.enemy_type.display_name().to_string(),
+        enemy_name: enemy.name.clone(),
         enemy_max_health: enemy.max_health,
+        team,
         round: state.combat_round,
     }
 }
@@ -101,6 +137,10 @@ pub fn build_serializable_character(state: &GameState) -> SerializableCharacter
     SerializableCharacter {
         health: state.character.get_health(),
         max_health: state.character.get_max_health(),
+        level: state.character.get_level(),
+        xp: state.character.get_xp(),
+        xp_to_next_level: Character::xp_to_next_level(state.character.get_level()),
+        base_attack: state.character.get_attack(),
         attack: state.get_total_attack(),
         defense: state.get_total_defense(),
         accuracy: state.get_total_accuracy(),
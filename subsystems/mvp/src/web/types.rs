@@ -12,10 +12,24 @@ pub struct SerializableTile {
     pub objects: Vec<String>,
 }
 
-/// Enemy info for terrain view (just status + stats for tooltips)
+/// Details of the tile the character is standing on
+#[derive(Debug, Serialize)]
+pub struct SerializableTileInfo {
+    pub substrate: String,
+    pub biome: String,
+    /// Names of the items still lying on the tile
+    pub objects: Vec<String>,
+    /// Placed world object (crafting station or resource node), if any
+    pub world_object: Option<String>,
+    /// Name of the NPC standing on the tile, if any
+    pub npc: Option<String>,
+}
+
+/// Enemy info for terrain view tooltips and combat team listings (just status + stats)
 #[derive(Debug, Serialize)]
 pub struct TerrainEnemyInfo {
     pub enemy_type: String,
+    pub name: String,
     pub health: i32,
     pub max_health: i32,
     pub attack: i32,
@@ -31,6 +45,10 @@ pub struct TerrainLandInfo {
     pub coords: (i32, i32),
     pub biome: String,
     pub enemy: Option<TerrainEnemyInfo>,
+    /// Number of enemies fought here (more than 1 for an enemy team)
+    pub team_size: usize,
+    /// Whether the character has seen this land (unexplored lands can be dimmed)
+    pub explored: bool,
 }
 
 /// Terrain view state
@@ -47,6 +65,7 @@ pub struct LandGameState {
     pub current_tile: (usize, usize),
     pub tiles: Vec<Vec<SerializableTile>>,
     pub biome: String,
+    pub current_tile_info: Option<SerializableTileInfo>,
 }
 
 /// Combat view state
@@ -56,7 +75,11 @@ pub struct CombatGameState {
     pub player: SerializableCombatant,
     pub enemy: SerializableCombatant,
     pub enemy_type: String,
+    pub enemy_name: String,
     pub enemy_max_health: i32,
+    /// Every member of an enemy team, front first (empty for a single enemy).
+    /// `enemy` is the front-most living member
+    pub team: Vec<TerrainEnemyInfo>,
     pub round: u32,
 }
 
@@ -74,6 +97,11 @@ pub enum CoreGameState {
 pub struct SerializableCharacter {
     pub health: i32,
     pub max_health: i32,
+    pub level: u32,
+    pub xp: u32,
+    pub xp_to_next_level: u32,
+    /// Attack before equipment bonuses (grows with level)
+    pub base_attack: i32,
     pub attack: i32,
     pub defense: i32,
     pub accuracy: i32,
@@ -112,3 +140,17 @@ pub struct CommandResponse {
     pub message: String,
     pub game_state: GameStateResponse,
 }
+
+/// Save/load request. The file is always `DEFAULT_SAVE_PATH`; a request that
+/// sets `path` is rejected
+#[derive(Debug, Default, Deserialize)]
+pub struct SaveRequest {
+    pub path: Option<String>,
+}
+
+/// Save/load response
+#[derive(Debug, Serialize)]
+pub struct SaveResponse {
+    pub success: bool,
+    pub message: String,
+}
@@ -887,6 +887,9 @@ function renderTerrainView(terrainState) {
             
             if (land) {
                 cell.style.backgroundColor = rgbToCss(getBiomeColor(land.biome));
+                if (!land.explored) {
+                    cell.classList.add('unexplored');
+                }
                 
                 // Add biome decorative icons
                 const biomeIcons = BIOME_ICONS[land.biome];
@@ -1015,6 +1018,15 @@ function renderCombatView(combatState) {
 
     const getHealthClass = (percent) => percent > 60 ? 'high' : (percent > 30 ? 'medium' : 'low');
 
+    // Enemy teams list every member, front first; defeated members are struck through
+    const teamRows = (combatState.team || []).length > 1
+        ? combatState.team.map(member => `
+                    <div class="combat-stat-row">
+                        <span class="combat-stat-label">${member.is_defeated ? `<s>${member.name}</s>` : member.name}</span>
+                        <span class="combat-stat-value">${member.health}/${member.max_health}</span>
+                    </div>`).join('')
+        : '';
+
     // Build the combat view structure
     combatView.innerHTML = `
         <div class="combat-header">
@@ -1078,6 +1090,7 @@ function renderCombatView(combatState) {
                         <span class="combat-stat-label">EVA</span>
                         <span class="combat-stat-value">${enemy.evasion}</span>
                     </div>
+                    ${teamRows}
                 </div>
             </div>
         </div>
@@ -1175,9 +1188,10 @@ function updateStatus() {
         const char = gameState.character;
         const healthPercent = (char.health / char.max_health) * 100;
         document.getElementById('character-health').textContent = `${char.health}/${char.max_health} (${Math.round(healthPercent)}%)`;
+        document.getElementById('character-level').textContent = `${char.level} (${char.xp}/${char.xp_to_next_level} XP)`;
         
         // Display attack with bonus breakdown if applicable
-        const baseAttack = 5; // Current MVP hardcoded base attack value
+        const baseAttack = char.base_attack; // Grows with level; the rest comes from equipment
         const totalAttack = char.attack;
         const attackBonus = totalAttack - baseAttack;
         
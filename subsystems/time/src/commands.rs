@@ -1,4 +1,4 @@
-use crate::time::{TimeError, TimeState};
+use crate::time::{TimeError, TimeState, Timestamp};
 
 #[derive(Debug, Clone)]
 pub struct CommandOutcome {
@@ -18,11 +18,33 @@ pub fn execute_command(state: &mut TimeState, command: &str) -> CommandOutcome {
     }
 
     if let Some(rest) = normalized.strip_prefix("tick ") {
-        return match rest.parse::<u32>() {
-            Ok(delta) => apply_delta(state, delta, format!("Ticked {} minute(s)", delta)),
-            Err(_) => CommandOutcome {
+        let Ok(delta) = rest.parse::<i64>() else {
+            return CommandOutcome {
                 success: false,
-                message: "Invalid tick value. Usage: tick <minutes>".to_string(),
+                message: "Invalid tick value. Usage: tick <minutes> (game minutes, not seconds)"
+                    .to_string(),
+                minutes_advanced: 0,
+            };
+        };
+        let before = state.timestamp();
+        return match state.tick(delta) {
+            Ok(now) => {
+                // Pause and speed scale the request, so report what actually elapsed
+                let advanced = now.minutes_since(before);
+                CommandOutcome {
+                    success: true,
+                    message: format!(
+                        "Ticked {} minute(s), now day {} {}",
+                        advanced,
+                        now.day,
+                        state.hhmm()
+                    ),
+                    minutes_advanced: advanced as u32,
+                }
+            }
+            Err(err) => CommandOutcome {
+                success: false,
+                message: format!("Failed to advance time: {}", err),
                 minutes_advanced: 0,
             },
         };
@@ -31,7 +53,84 @@ pub fn execute_command(state: &mut TimeState, command: &str) -> CommandOutcome {
     if normalized == "tick" {
         return CommandOutcome {
             success: false,
-            message: "Usage: tick <minutes>".to_string(),
+            message: "Usage: tick <minutes> (game minutes, not seconds)".to_string(),
+            minutes_advanced: 0,
+        };
+    }
+
+    if let Some(rest) = normalized.strip_prefix("elapsed ") {
+        let Some(since) = parse_timestamp(rest) else {
+            return CommandOutcome {
+                success: false,
+                message: "Invalid timestamp. Usage: elapsed <day> <hh:mm>".to_string(),
+                minutes_advanced: 0,
+            };
+        };
+        return CommandOutcome {
+            success: true,
+            message: format!(
+                "{} minute(s) since day {} {:02}:{:02}",
+                state.elapsed_since(since),
+                since.day,
+                since.minute / 60,
+                since.minute % 60
+            ),
+            minutes_advanced: 0,
+        };
+    }
+
+    if normalized == "elapsed" {
+        return CommandOutcome {
+            success: false,
+            message: "Usage: elapsed <day> <hh:mm>".to_string(),
+            minutes_advanced: 0,
+        };
+    }
+
+    if normalized == "pause" {
+        state.pause();
+        return CommandOutcome {
+            success: true,
+            message: "Clock paused".to_string(),
+            minutes_advanced: 0,
+        };
+    }
+
+    if normalized == "resume" {
+        state.resume();
+        return CommandOutcome {
+            success: true,
+            message: format!("Clock resumed at {}x", state.speed()),
+            minutes_advanced: 0,
+        };
+    }
+
+    if let Some(rest) = normalized.strip_prefix("speed ") {
+        let Ok(multiplier) = rest.parse::<f32>() else {
+            return CommandOutcome {
+                success: false,
+                message: "Invalid speed value. Usage: speed <multiplier>".to_string(),
+                minutes_advanced: 0,
+            };
+        };
+        return match state.set_speed(multiplier) {
+            Ok(()) => CommandOutcome {
+                success: true,
+                message: format!("Clock speed set to {}x", multiplier),
+                minutes_advanced: 0,
+            },
+            Err(err) => CommandOutcome {
+                success: false,
+                message: format!("Failed to set speed: {}", err),
+                minutes_advanced: 0,
+            },
+        };
+    }
+
+    if normalized == "speed" {
+        return CommandOutcome {
+            success: false,
+            message: "Usage: speed <multiplier>".to_string(),
             minutes_advanced: 0,
         };
     }
@@ -53,19 +152,35 @@ pub fn execute_command(state: &mut TimeState, command: &str) -> CommandOutcome {
 
 fn apply_delta(state: &mut TimeState, delta: u32, ok_message: String) -> CommandOutcome {
     match state.advance_minutes(delta) {
-        Ok(()) => CommandOutcome {
+        Ok(advanced) => CommandOutcome {
             success: true,
             message: ok_message,
-            minutes_advanced: delta,
+            minutes_advanced: advanced,
         },
         Err(TimeError::DayOverflow) => CommandOutcome {
             success: false,
             message: "Failed to advance time: day overflow".to_string(),
             minutes_advanced: 0,
         },
+        Err(err) => CommandOutcome {
+            success: false,
+            message: format!("Failed to advance time: {}", err),
+            minutes_advanced: 0,
+        },
     }
 }
 
+/// Parse `<day> <hh:mm>` into a timestamp. Hours and minutes must be in range.
+fn parse_timestamp(text: &str) -> Option<Timestamp> {
+    let (day, hhmm) = text.split_once(' ')?;
+    let (hour, minute) = hhmm.split_once(':')?;
+    let (hour, minute) = (hour.parse::<u16>().ok()?, minute.parse::<u16>().ok()?);
+    if hour >= 24 || minute >= 60 {
+        return None;
+    }
+    Some(Timestamp::new(day.parse().ok()?, hour * 60 + minute))
+}
+
 fn normalize_command(command: &str) -> String {
     command
         .split_whitespace()
@@ -87,6 +202,36 @@ mod tests {
         assert_eq!(state.timestamp().minute, 60);
     }
 
+    #[test]
+    fn ticks_accumulate_and_elapsed_reports_difference() {
+        let mut state = TimeState::default();
+
+        assert!(execute_command(&mut state, "tick 700").success);
+        let out = execute_command(&mut state, "tick 800");
+        assert!(out.success);
+        assert_eq!(out.minutes_advanced, 800);
+        assert_eq!(out.message, "Ticked 800 minute(s), now day 1 01:00");
+
+        let out = execute_command(&mut state, "elapsed 0 00:30");
+        assert!(out.success);
+        assert_eq!(out.message, "1470 minute(s) since day 0 00:30");
+        assert!(!execute_command(&mut state, "elapsed 0 25:00").success);
+        assert!(!execute_command(&mut state, "elapsed").success);
+    }
+
+    #[test]
+    fn negative_tick_is_rejected() {
+        let mut state = TimeState::default();
+        let out = execute_command(&mut state, "tick -5");
+
+        assert!(!out.success);
+        assert_eq!(
+            out.message,
+            format!("Failed to advance time: {}", TimeError::NegativeDelta)
+        );
+        assert_eq!(state.timestamp().minute, 0);
+    }
+
     #[test]
     fn parses_named_action_with_normalization() {
         let mut state = TimeState::default();
@@ -96,6 +241,41 @@ mod tests {
         assert_eq!(state.timestamp().minute, 40);
     }
 
+    #[test]
+    fn pause_resume_and_speed_commands() {
+        let mut state = TimeState::default();
+
+        assert!(execute_command(&mut state, "pause").success);
+        let out = execute_command(&mut state, "tick 30");
+        assert!(out.success);
+        assert_eq!(out.minutes_advanced, 0);
+
+        assert!(execute_command(&mut state, "Resume").success);
+        assert!(execute_command(&mut state, "speed 2").success);
+        let out = execute_command(&mut state, "tick 30");
+        assert_eq!(out.minutes_advanced, 60);
+        assert_eq!(state.timestamp().minute, 60);
+
+        let out = execute_command(&mut state, "speed -3");
+        assert!(!out.success);
+        assert_eq!(state.speed(), 2.0);
+    }
+
+    #[test]
+    fn tick_message_reports_minutes_actually_advanced() {
+        let mut state = TimeState::default();
+
+        execute_command(&mut state, "pause");
+        let out = execute_command(&mut state, "tick 30");
+        assert!(out.success);
+        assert_eq!(out.message, "Ticked 0 minute(s), now day 0 00:00");
+
+        execute_command(&mut state, "resume");
+        execute_command(&mut state, "speed 0.5");
+        let out = execute_command(&mut state, "tick 30");
+        assert_eq!(out.message, "Ticked 15 minute(s), now day 0 00:15");
+    }
+
     #[test]
     fn invalid_command_does_not_mutate_time() {
         let mut state = TimeState::default();
@@ -3,5 +3,5 @@ pub mod time;
 pub mod web;
 
 pub use commands::{execute_command, CommandOutcome};
-pub use time::{ClockAngles, Lightzone, TimeError, TimeState, Timestamp};
+pub use time::{ClockAngles, Lightzone, ScheduledEvent, TimeError, TimeState, TimeZone, Timestamp};
 pub use web::create_router;
@@ -4,7 +4,21 @@ use tokio::net::TcpListener;
 
 #[tokio::main]
 async fn main() {
-    let shared = Arc::new(Mutex::new(TimeState::default()));
+    // Optional `--load <path>` restores a saved clock at boot
+    let args: Vec<String> = std::env::args().collect();
+    let state = match args.iter().position(|arg| arg == "--load") {
+        Some(index) => {
+            let path = args
+                .get(index + 1)
+                .expect("--load requires a path to a saved time state");
+            let state = TimeState::load(path).expect("failed to load saved time state");
+            println!("Restored time state from {} ({})", path, state.hhmm());
+            state
+        }
+        None => TimeState::default(),
+    };
+
+    let shared = Arc::new(Mutex::new(state));
     let app = create_router(shared);
 
     let listener = TcpListener::bind("127.0.0.1:3001")
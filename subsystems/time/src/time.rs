@@ -1,9 +1,16 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub const MINUTES_PER_DAY: u32 = 1440;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// First minute of each lightzone, in order through the day.
+const LIGHTZONE_STARTS: [(u16, Lightzone); 3] = [
+    (240, Lightzone::Morning),
+    (720, Lightzone::Afternoon),
+    (1200, Lightzone::Night),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Lightzone {
     Morning,
@@ -11,36 +18,138 @@ pub enum Lightzone {
     Night,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Timestamp {
     pub day: usize,
     pub minute: u16,
     pub lightzone: Lightzone,
 }
 
+impl Timestamp {
+    /// Build a timestamp, carrying minutes past the end of the day into `day`.
+    pub fn new(day: usize, minute: u16) -> Self {
+        let carry = usize::from(minute) / MINUTES_PER_DAY as usize;
+        let minute = minute % MINUTES_PER_DAY as u16;
+        Self {
+            day: day.saturating_add(carry),
+            minute,
+            lightzone: lightzone_for_minute(minute),
+        }
+    }
+
+    /// Clock hand angles for this timestamp's minute of day. Pure: no `TimeState` needed.
+    pub fn clock_angles(&self) -> ClockAngles {
+        clock_angles_for_minute(self.minute)
+    }
+
+    /// Signed minutes from `earlier` to this timestamp (negative if `earlier` is later).
+    pub fn minutes_since(&self, earlier: Timestamp) -> i64 {
+        self.total_minutes() - earlier.total_minutes()
+    }
+
+    fn instant(&self) -> (usize, u16) {
+        (self.day, self.minute)
+    }
+
+    fn total_minutes(&self) -> i64 {
+        let day = i64::try_from(self.day).unwrap_or(i64::MAX / i64::from(MINUTES_PER_DAY));
+        day.saturating_mul(i64::from(MINUTES_PER_DAY)) + i64::from(self.minute)
+    }
+}
+
+/// A labelled marker that fires once the clock reaches `at`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    pub at: Timestamp,
+    pub label: String,
+}
+
+/// Angles in degrees, measured clockwise from 12 o'clock.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ClockAngles {
     pub hand_15_deg: f64,
     pub dial_45_deg_total: f64,
     pub dial_45_deg_visual: f64,
+    /// 12-hour analog hour hand, advancing 0.5 degrees per minute between hours
+    pub hour_hand_deg: f64,
+    /// Analog minute hand, 6 degrees per minute
+    pub minute_hand_deg: f64,
+    /// Analog second hand; always 0 since timestamps have minute resolution
+    pub second_hand_deg: f64,
 }
 
-#[derive(Debug, Clone)]
+/// Fixed offset from the shared reference clock, in minutes.
+///
+/// All clients share one absolute `Timestamp`; a zone only changes how that instant
+/// is displayed locally (clock hands, lightzone, and which day it is).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TimeZone {
+    pub offset_minutes: i32,
+}
+
+impl TimeZone {
+    pub const UTC: TimeZone = TimeZone { offset_minutes: 0 };
+
+    pub fn from_offset_minutes(offset_minutes: i32) -> Self {
+        Self { offset_minutes }
+    }
+
+    pub fn from_offset_hours(offset_hours: i32) -> Self {
+        Self {
+            offset_minutes: offset_hours.saturating_mul(60),
+        }
+    }
+
+    /// Convert an absolute day/minute into this zone's local day/minute.
+    /// Local days before day 0 are clamped to day 0.
+    pub fn localize(&self, day: usize, minute: u16) -> (usize, u16) {
+        let minutes_per_day = i64::from(MINUTES_PER_DAY);
+        let shifted = i64::from(minute) + i64::from(self.offset_minutes);
+        let day_shift = shifted.div_euclid(minutes_per_day);
+        let local_minute = shifted.rem_euclid(minutes_per_day) as u16;
+
+        let local_day = if day_shift < 0 {
+            day.saturating_sub(usize::try_from(-day_shift).unwrap_or(usize::MAX))
+        } else {
+            day.saturating_add(usize::try_from(day_shift).unwrap_or(usize::MAX))
+        };
+        (local_day, local_minute)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeState {
     day: usize,
     minute: u16,
     action_costs: HashMap<String, u32>,
+    paused: bool,
+    speed: f32,
+    /// Sub-minute remainder carried between scaled advances
+    pending_fraction: f64,
+    /// Pending events, kept in chronological order
+    events: Vec<ScheduledEvent>,
+    /// Labels fired since the last `take_fired`
+    fired: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TimeError {
     DayOverflow,
+    InvalidSpeed,
+    /// A tick asked to move the clock backwards
+    NegativeDelta,
+    Persistence(String),
 }
 
 impl std::fmt::Display for TimeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TimeError::DayOverflow => write!(f, "day overflow while advancing time"),
+            TimeError::InvalidSpeed => {
+                write!(f, "speed multiplier must be a non-negative number")
+            }
+            TimeError::NegativeDelta => write!(f, "cannot tick by a negative number of minutes"),
+            TimeError::Persistence(msg) => write!(f, "failed to persist time state: {}", msg),
         }
     }
 }
@@ -56,6 +165,11 @@ impl Default for TimeState {
             day: 0,
             minute: 0,
             action_costs,
+            paused: false,
+            speed: 1.0,
+            pending_fraction: 0.0,
+            events: Vec::new(),
+            fired: Vec::new(),
         }
     }
 }
@@ -67,6 +181,11 @@ impl TimeState {
             day,
             minute: normalized_minute,
             action_costs,
+            paused: false,
+            speed: 1.0,
+            pending_fraction: 0.0,
+            events: Vec::new(),
+            fired: Vec::new(),
         }
     }
 
@@ -84,15 +203,185 @@ impl TimeState {
         format!("{:02}:{:02}", hour, minute)
     }
 
+    /// The lightzone at the current instant. On a boundary minute this is the zone
+    /// being entered (04:00 is already Morning).
+    pub fn current_lightzone(&self) -> Lightzone {
+        lightzone_for_minute(self.minute)
+    }
+
+    /// The next lightzone boundary strictly after the current instant, and the zone
+    /// it starts. Standing exactly on a boundary counts as already inside that zone,
+    /// so the transition returned is the one after it.
+    pub fn next_transition(&self) -> (Timestamp, Lightzone) {
+        match LIGHTZONE_STARTS
+            .iter()
+            .find(|(start, _)| *start > self.minute)
+        {
+            Some(&(start, zone)) => (Timestamp::new(self.day, start), zone),
+            None => {
+                let (start, zone) = LIGHTZONE_STARTS[0];
+                (Timestamp::new(self.day.saturating_add(1), start), zone)
+            }
+        }
+    }
+
     pub fn action_costs(&self) -> &HashMap<String, u32> {
         &self.action_costs
     }
 
-    pub fn advance_minutes(&mut self, delta: u32) -> Result<(), TimeError> {
-        let current = u32::from(self.minute);
-        let total = current + delta;
-        let day_carry = total / MINUTES_PER_DAY;
-        let next_minute = (total % MINUTES_PER_DAY) as u16;
+    /// True while the clock is frozen, either by `pause` or a speed of 0.0.
+    pub fn is_paused(&self) -> bool {
+        self.paused || self.speed == 0.0
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Unfreeze the clock. A speed of 0.0 counts as paused, so it is reset to 1.0.
+    pub fn resume(&mut self) {
+        self.paused = false;
+        if self.speed == 0.0 {
+            self.speed = 1.0;
+        }
+    }
+
+    pub fn set_speed(&mut self, multiplier: f32) -> Result<(), TimeError> {
+        if !multiplier.is_finite() || multiplier < 0.0 {
+            return Err(TimeError::InvalidSpeed);
+        }
+        self.speed = multiplier;
+        Ok(())
+    }
+
+    /// Advance the clock by `delta` requested minutes, scaled by the current speed.
+    /// Returns the number of whole minutes actually advanced (0 while paused).
+    pub fn advance_minutes(&mut self, delta: u32) -> Result<u32, TimeError> {
+        if self.is_paused() {
+            return Ok(0);
+        }
+
+        let scaled = f64::from(delta) * f64::from(self.speed) + self.pending_fraction;
+        let whole = scaled.floor();
+        if whole > f64::from(u32::MAX) {
+            return Err(TimeError::DayOverflow);
+        }
+        let advanced = whole as u32;
+
+        self.advance_raw(advanced)?;
+        self.pending_fraction = scaled - whole;
+        let due = self.fire_due();
+        self.fired.extend(due);
+        Ok(advanced)
+    }
+
+    /// Advance by `minutes` requested game minutes like `advance_minutes` (so pause and
+    /// speed apply) and return the new timestamp. The unit is game minutes, not seconds:
+    /// one tick of 1 is one minute on the clock at normal speed. Negative values are
+    /// rejected; the clock is monotonic.
+    pub fn tick(&mut self, minutes: i64) -> Result<Timestamp, TimeError> {
+        if minutes < 0 {
+            return Err(TimeError::NegativeDelta);
+        }
+        let minutes = u32::try_from(minutes).map_err(|_| TimeError::DayOverflow)?;
+        self.advance_minutes(minutes)?;
+        Ok(self.timestamp())
+    }
+
+    /// Signed minutes elapsed from `since` to now (negative if `since` is in the future).
+    pub fn elapsed_since(&self, since: Timestamp) -> i64 {
+        self.timestamp().minutes_since(since)
+    }
+
+    /// Register `label` to fire once the clock reaches `at`. Events at the same
+    /// instant fire in the order they were scheduled.
+    pub fn schedule(&mut self, at: Timestamp, label: String) {
+        let index = self
+            .events
+            .partition_point(|event| event.at.instant() <= at.instant());
+        self.events.insert(index, ScheduledEvent { at, label });
+    }
+
+    pub fn pending_events(&self) -> &[ScheduledEvent] {
+        &self.events
+    }
+
+    /// Jump the clock to `t` (never backwards, ignoring speed and pause) and return
+    /// the labels of every event at or before the new instant, in chronological order.
+    /// The caller gets them directly, so they are not also queued for `take_fired`.
+    pub fn advance_to(&mut self, t: Timestamp) -> Vec<String> {
+        if t.instant() > self.timestamp().instant() {
+            self.day = t.day;
+            self.minute = t.minute % MINUTES_PER_DAY as u16;
+        }
+        self.fire_due()
+    }
+
+    pub fn to_json(&self) -> Result<String, TimeError> {
+        serde_json::to_string_pretty(self).map_err(|e| TimeError::Persistence(e.to_string()))
+    }
+
+    /// Restore a saved state. The clock resumes from the saved instant; the saved
+    /// minute and speed are validated and event order is re-established.
+    pub fn from_json(json: &str) -> Result<Self, TimeError> {
+        let mut state: TimeState =
+            serde_json::from_str(json).map_err(|e| TimeError::Persistence(e.to_string()))?;
+
+        if u32::from(state.minute) >= MINUTES_PER_DAY {
+            return Err(TimeError::Persistence(format!(
+                "minute {} is outside 0..{}",
+                state.minute, MINUTES_PER_DAY
+            )));
+        }
+        if !state.speed.is_finite() || state.speed < 0.0 {
+            return Err(TimeError::InvalidSpeed);
+        }
+        if !(0.0..1.0).contains(&state.pending_fraction) {
+            state.pending_fraction = 0.0;
+        }
+
+        let mut events = std::mem::take(&mut state.events);
+        events.sort_by_key(|event| event.at.instant());
+        for event in events {
+            state.schedule(Timestamp::new(event.at.day, event.at.minute), event.label);
+        }
+        Ok(state)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), TimeError> {
+        std::fs::write(path, self.to_json()?).map_err(|e| TimeError::Persistence(e.to_string()))
+    }
+
+    pub fn load(path: &str) -> Result<Self, TimeError> {
+        let json =
+            std::fs::read_to_string(path).map_err(|e| TimeError::Persistence(e.to_string()))?;
+        Self::from_json(&json)
+    }
+
+    /// Drain labels fired by any advance since the previous call.
+    pub fn take_fired(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.fired)
+    }
+
+    fn fire_due(&mut self) -> Vec<String> {
+        let now = self.timestamp().instant();
+        let split = self
+            .events
+            .partition_point(|event| event.at.instant() <= now);
+        self.events
+            .drain(..split)
+            .map(|event| event.label)
+            .collect()
+    }
+
+    fn advance_raw(&mut self, delta: u32) -> Result<(), TimeError> {
+        let total = u64::from(self.minute) + u64::from(delta);
+        let day_carry = total / u64::from(MINUTES_PER_DAY);
+        let next_minute = (total % u64::from(MINUTES_PER_DAY)) as u16;
 
         let carry = usize::try_from(day_carry).map_err(|_| TimeError::DayOverflow)?;
         self.day = self.day.checked_add(carry).ok_or(TimeError::DayOverflow)?;
@@ -101,17 +390,48 @@ impl TimeState {
     }
 
     pub fn clock_angles(&self) -> ClockAngles {
-        let minute_of_day = f64::from(self.minute);
-        let hand_15_deg = minute_of_day * 0.25;
-        let dial_45_deg_total = minute_of_day * 0.75;
-        let dial_45_deg_visual = dial_45_deg_total.rem_euclid(360.0);
+        clock_angles_for_minute(self.minute)
+    }
 
-        ClockAngles {
-            hand_15_deg,
-            dial_45_deg_total,
-            dial_45_deg_visual,
+    /// The current instant as seen from `zone`.
+    pub fn local_timestamp(&self, zone: TimeZone) -> Timestamp {
+        let (day, minute) = zone.localize(self.day, self.minute);
+        Timestamp {
+            day,
+            minute,
+            lightzone: lightzone_for_minute(minute),
         }
     }
+
+    /// Clock hands for the current instant as seen from `zone`.
+    pub fn local_clock(&self, zone: TimeZone) -> ClockAngles {
+        let (_, minute) = zone.localize(self.day, self.minute);
+        clock_angles_for_minute(minute)
+    }
+
+    /// `hhmm` for the current instant as seen from `zone`.
+    pub fn local_hhmm(&self, zone: TimeZone) -> String {
+        let (_, minute) = zone.localize(self.day, self.minute);
+        format!("{:02}:{:02}", minute / 60, minute % 60)
+    }
+}
+
+pub fn clock_angles_for_minute(minute: u16) -> ClockAngles {
+    let minute_of_day = f64::from(minute);
+    let hand_15_deg = minute_of_day * 0.25;
+    let dial_45_deg_total = minute_of_day * 0.75;
+    let dial_45_deg_visual = dial_45_deg_total.rem_euclid(360.0);
+    let hour_hand_deg = (minute_of_day * 0.5).rem_euclid(360.0);
+    let minute_hand_deg = (minute_of_day * 6.0).rem_euclid(360.0);
+
+    ClockAngles {
+        hand_15_deg,
+        dial_45_deg_total,
+        dial_45_deg_visual,
+        hour_hand_deg,
+        minute_hand_deg,
+        second_hand_deg: 0.0,
+    }
 }
 
 pub fn lightzone_for_minute(minute: u16) -> Lightzone {
@@ -128,8 +448,10 @@ mod tests {
 
     #[test]
     fn advance_minutes_basic() {
-        let mut state = TimeState::default();
-        state.minute = 200;
+        let mut state = TimeState {
+            minute: 200,
+            ..TimeState::default()
+        };
 
         state.advance_minutes(40).unwrap();
 
@@ -139,8 +461,10 @@ mod tests {
 
     #[test]
     fn day_rollover_single() {
-        let mut state = TimeState::default();
-        state.minute = 1439;
+        let mut state = TimeState {
+            minute: 1439,
+            ..TimeState::default()
+        };
 
         state.advance_minutes(1).unwrap();
 
@@ -169,10 +493,356 @@ mod tests {
     }
 
     #[test]
-    fn overflow_guard() {
+    fn analog_angles_at_three() {
+        let angles = Timestamp::new(0, 180).clock_angles();
+        assert_eq!(angles.hour_hand_deg, 90.0);
+        assert_eq!(angles.minute_hand_deg, 0.0);
+        assert_eq!(angles.second_hand_deg, 0.0);
+    }
+
+    #[test]
+    fn analog_hour_hand_advances_between_hours() {
+        let angles = Timestamp::new(2, 390).clock_angles();
+        assert_eq!(angles.hour_hand_deg, 195.0);
+        assert_eq!(angles.minute_hand_deg, 180.0);
+
+        // 18:30 reads the same as 06:30 on a 12-hour face
+        assert_eq!(Timestamp::new(0, 1110).clock_angles().hour_hand_deg, 195.0);
+    }
+
+    #[test]
+    fn analog_angles_at_midnight() {
+        let angles = Timestamp::new(5, 0).clock_angles();
+        assert_eq!(angles.hour_hand_deg, 0.0);
+        assert_eq!(angles.minute_hand_deg, 0.0);
+        assert_eq!(angles.second_hand_deg, 0.0);
+        assert_eq!(angles, TimeState::new(5, 0, HashMap::new()).clock_angles());
+    }
+
+    #[test]
+    fn next_transition_through_the_day() {
+        let at = |minute| TimeState::new(3, minute, HashMap::new());
+
+        assert_eq!(at(100).current_lightzone(), Lightzone::Night);
+        assert_eq!(
+            at(100).next_transition(),
+            (Timestamp::new(3, 240), Lightzone::Morning)
+        );
+        assert_eq!(at(500).current_lightzone(), Lightzone::Morning);
+        assert_eq!(
+            at(500).next_transition(),
+            (Timestamp::new(3, 720), Lightzone::Afternoon)
+        );
+        assert_eq!(at(1000).current_lightzone(), Lightzone::Afternoon);
+        assert_eq!(
+            at(1000).next_transition(),
+            (Timestamp::new(3, 1200), Lightzone::Night)
+        );
+        assert_eq!(at(1300).current_lightzone(), Lightzone::Night);
+        assert_eq!(
+            at(1300).next_transition(),
+            (Timestamp::new(4, 240), Lightzone::Morning)
+        );
+    }
+
+    #[test]
+    fn exact_boundary_reports_zone_being_entered() {
+        let dawn = TimeState::new(1, 240, HashMap::new());
+        assert_eq!(dawn.current_lightzone(), Lightzone::Morning);
+        assert_eq!(
+            dawn.next_transition(),
+            (Timestamp::new(1, 720), Lightzone::Afternoon)
+        );
+
+        let dusk = TimeState::new(1, 1200, HashMap::new());
+        assert_eq!(dusk.current_lightzone(), Lightzone::Night);
+        assert_eq!(
+            dusk.next_transition(),
+            (Timestamp::new(2, 240), Lightzone::Morning)
+        );
+    }
+
+    #[test]
+    fn zones_twelve_hours_apart() {
+        let state = TimeState::new(3, 360, HashMap::new());
+        let home = TimeZone::UTC;
+        let away = TimeZone::from_offset_hours(12);
+
+        let home_clock = state.local_clock(home);
+        let away_clock = state.local_clock(away);
+        assert_eq!(home_clock.hand_15_deg, 90.0);
+        assert_eq!(away_clock.hand_15_deg, 270.0);
+        assert_eq!(
+            (away_clock.hand_15_deg - home_clock.hand_15_deg).rem_euclid(360.0),
+            180.0
+        );
+
+        assert_eq!(state.local_timestamp(home).lightzone, Lightzone::Morning);
+        assert_eq!(state.local_timestamp(away).lightzone, Lightzone::Afternoon);
+        assert_eq!(state.local_hhmm(away), "18:00");
+        assert_eq!(state.local_timestamp(away).day, 3);
+    }
+
+    #[test]
+    fn utc_zone_matches_reference_clock() {
+        let state = TimeState::new(0, 905, HashMap::new());
+        assert_eq!(state.local_clock(TimeZone::UTC), state.clock_angles());
+        assert_eq!(state.local_timestamp(TimeZone::UTC), state.timestamp());
+    }
+
+    #[test]
+    fn negative_offset_crosses_into_previous_day() {
+        let state = TimeState::new(1, 60, HashMap::new());
+        let local = state.local_timestamp(TimeZone::from_offset_minutes(-120));
+
+        assert_eq!(local.day, 0);
+        assert_eq!(local.minute, 1380);
+        assert_eq!(local.lightzone, Lightzone::Night);
+    }
+
+    #[test]
+    fn positive_offset_crosses_into_next_day() {
+        let state = TimeState::new(0, 1400, HashMap::new());
+        let local = state.local_timestamp(TimeZone::from_offset_hours(2));
+
+        assert_eq!(local.day, 1);
+        assert_eq!(local.minute, 80);
+        assert_eq!(
+            state
+                .local_clock(TimeZone::from_offset_hours(2))
+                .hand_15_deg,
+            20.0
+        );
+    }
+
+    #[test]
+    fn negative_offset_before_day_zero_clamps() {
+        let state = TimeState::new(0, 30, HashMap::new());
+        let local = state.local_timestamp(TimeZone::from_offset_hours(-1));
+
+        assert_eq!(local.day, 0);
+        assert_eq!(local.minute, 1410);
+    }
+
+    #[test]
+    fn paused_clock_does_not_advance() {
         let mut state = TimeState::default();
-        state.day = usize::MAX;
-        state.minute = 1439;
+        state.pause();
+
+        assert!(state.is_paused());
+        assert_eq!(state.advance_minutes(90), Ok(0));
+        assert_eq!(state.timestamp().minute, 0);
+
+        state.resume();
+        assert_eq!(state.advance_minutes(90), Ok(90));
+        assert_eq!(state.timestamp().minute, 90);
+    }
+
+    #[test]
+    fn fast_forward_scales_advance() {
+        let mut state = TimeState::default();
+        state.set_speed(4.0).unwrap();
+
+        assert_eq!(state.advance_minutes(400), Ok(1600));
+        assert_eq!(state.timestamp().day, 1);
+        assert_eq!(state.timestamp().minute, 160);
+    }
+
+    #[test]
+    fn slow_motion_carries_fractional_minutes() {
+        let mut state = TimeState::default();
+        state.set_speed(0.5).unwrap();
+
+        assert_eq!(state.advance_minutes(1), Ok(0));
+        assert_eq!(state.advance_minutes(1), Ok(1));
+        assert_eq!(state.advance_minutes(3), Ok(1));
+        assert_eq!(state.timestamp().minute, 2);
+    }
+
+    #[test]
+    fn zero_speed_matches_pause() {
+        let mut paused = TimeState::default();
+        paused.pause();
+        let mut stopped = TimeState::default();
+        stopped.set_speed(0.0).unwrap();
+
+        assert_eq!(paused.is_paused(), stopped.is_paused());
+        assert_eq!(paused.advance_minutes(120), stopped.advance_minutes(120));
+        assert_eq!(paused.timestamp(), stopped.timestamp());
+
+        stopped.resume();
+        assert_eq!(stopped.speed(), 1.0);
+        assert_eq!(stopped.advance_minutes(10), Ok(10));
+    }
+
+    #[test]
+    fn negative_speed_rejected() {
+        let mut state = TimeState::default();
+
+        assert_eq!(state.set_speed(-1.0), Err(TimeError::InvalidSpeed));
+        assert_eq!(state.set_speed(f32::NAN), Err(TimeError::InvalidSpeed));
+        assert_eq!(state.speed(), 1.0);
+    }
+
+    #[test]
+    fn ticks_accumulate_and_report_new_clock() {
+        let mut state = TimeState::default();
+        let start = state.timestamp();
+
+        assert_eq!(state.tick(1000), Ok(Timestamp::new(0, 1000)));
+        assert_eq!(state.tick(500), Ok(Timestamp::new(1, 60)));
+        assert_eq!(state.tick(0), Ok(Timestamp::new(1, 60)));
+        assert_eq!(state.elapsed_since(start), 1500);
+        assert_eq!(state.elapsed_since(Timestamp::new(2, 0)), -1380);
+
+        state.set_speed(2.0).unwrap();
+        assert_eq!(state.tick(30), Ok(Timestamp::new(1, 120)));
+        state.pause();
+        assert_eq!(state.tick(30), Ok(Timestamp::new(1, 120)));
+        assert_eq!(state.elapsed_since(start), 1560);
+    }
+
+    #[test]
+    fn negative_tick_rejected() {
+        let mut state = TimeState::new(0, 100, HashMap::new());
+
+        assert_eq!(state.tick(-1), Err(TimeError::NegativeDelta));
+        assert_eq!(state.timestamp().minute, 100);
+    }
+
+    #[test]
+    fn large_advance_fires_multiple_events_in_order() {
+        let mut state = TimeState::default();
+        state.schedule(Timestamp::new(2, 30), "third".to_string());
+        state.schedule(Timestamp::new(0, 600), "first".to_string());
+        state.schedule(Timestamp::new(5, 0), "later".to_string());
+        state.schedule(Timestamp::new(1, 1439), "second".to_string());
+
+        let fired = state.advance_to(Timestamp::new(3, 0));
+
+        assert_eq!(fired, vec!["first", "second", "third"]);
+        assert_eq!(state.timestamp(), Timestamp::new(3, 0));
+        assert_eq!(state.pending_events().len(), 1);
+        assert_eq!(state.pending_events()[0].label, "later");
+    }
+
+    #[test]
+    fn same_instant_events_keep_schedule_order() {
+        let mut state = TimeState::default();
+        state.schedule(Timestamp::new(0, 100), "a".to_string());
+        state.schedule(Timestamp::new(0, 100), "b".to_string());
+        state.schedule(Timestamp::new(0, 50), "early".to_string());
+
+        assert_eq!(
+            state.advance_to(Timestamp::new(0, 100)),
+            vec!["early", "a", "b"]
+        );
+    }
+
+    #[test]
+    fn event_at_current_instant_fires() {
+        let mut state = TimeState::new(1, 200, HashMap::new());
+        state.schedule(Timestamp::new(1, 200), "now".to_string());
+
+        assert_eq!(state.advance_to(state.timestamp()), vec!["now"]);
+        assert!(state.pending_events().is_empty());
+    }
+
+    #[test]
+    fn advance_to_never_moves_backwards() {
+        let mut state = TimeState::new(2, 0, HashMap::new());
+
+        state.advance_to(Timestamp::new(1, 0));
+
+        assert_eq!(state.timestamp(), Timestamp::new(2, 0));
+    }
+
+    #[test]
+    fn advance_to_does_not_queue_returned_events() {
+        let mut state = TimeState::default();
+        state.schedule(Timestamp::new(0, 30), "half hour".to_string());
+        state.schedule(Timestamp::new(0, 90), "hour and a half".to_string());
+
+        assert_eq!(state.advance_to(Timestamp::new(0, 60)), vec!["half hour"]);
+        assert!(state.take_fired().is_empty());
+
+        // Events fired by a later tick are still queued as usual
+        state.advance_minutes(60).unwrap();
+        assert_eq!(state.take_fired(), vec!["hour and a half"]);
+    }
+
+    #[test]
+    fn ticking_collects_fired_events_until_taken() {
+        let mut state = TimeState::default();
+        state.schedule(Timestamp::new(0, 30), "half hour".to_string());
+        state.schedule(Timestamp::new(0, 90), "hour and a half".to_string());
+
+        state.advance_minutes(60).unwrap();
+        state.advance_minutes(60).unwrap();
+
+        assert_eq!(state.take_fired(), vec!["half hour", "hour and a half"]);
+        assert!(state.take_fired().is_empty());
+    }
+
+    #[test]
+    fn json_round_trip_preserves_clock_and_events() {
+        let mut state = TimeState::new(4, 1000, HashMap::new());
+        state.set_speed(2.0).unwrap();
+        state.schedule(Timestamp::new(4, 1100), "dusk bell".to_string());
+        state.schedule(Timestamp::new(6, 10), "market".to_string());
+
+        let restored = TimeState::from_json(&state.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.timestamp(), Timestamp::new(4, 1000));
+        assert_eq!(restored.speed(), 2.0);
+        assert_eq!(restored.pending_events(), state.pending_events());
+    }
+
+    #[test]
+    fn restored_clock_continues_from_saved_instant() {
+        let mut state = TimeState::new(9, 1430, HashMap::new());
+        state.schedule(Timestamp::new(10, 5), "midnight watch".to_string());
+
+        let mut restored = TimeState::from_json(&state.to_json().unwrap()).unwrap();
+        restored.advance_minutes(20).unwrap();
+
+        assert_eq!(restored.timestamp(), Timestamp::new(10, 10));
+        assert_eq!(restored.take_fired(), vec!["midnight watch"]);
+    }
+
+    #[test]
+    fn save_and_load_file() {
+        let path = std::env::temp_dir().join(format!("time_state_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut state = TimeState::new(2, 300, HashMap::new());
+        state.schedule(Timestamp::new(3, 0), "dawn".to_string());
+
+        state.save(path).unwrap();
+        let loaded = TimeState::load(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(loaded.timestamp(), state.timestamp());
+        assert_eq!(loaded.pending_events(), state.pending_events());
+    }
+
+    #[test]
+    fn load_rejects_out_of_range_minute() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(&TimeState::default().to_json().unwrap()).unwrap();
+        json["minute"] = serde_json::json!(1440);
+
+        let result = TimeState::from_json(&json.to_string());
+
+        assert!(matches!(result, Err(TimeError::Persistence(_))));
+    }
+
+    #[test]
+    fn overflow_guard() {
+        let mut state = TimeState {
+            day: usize::MAX,
+            minute: 1439,
+            ..TimeState::default()
+        };
 
         let result = state.advance_minutes(1);
 
@@ -1,7 +1,7 @@
 use crate::commands::{execute_command, CommandOutcome};
-use crate::time::{ClockAngles, Lightzone, TimeState, Timestamp};
+use crate::time::{ClockAngles, Lightzone, TimeState, TimeZone, Timestamp};
 use axum::{
-    extract::State,
+    extract::{Query, State},
     http::StatusCode,
     response::{Html, Json},
     routing::{get, post},
@@ -26,6 +26,17 @@ pub struct ClockAnglesResponse {
     pub hand_15_deg: f64,
     pub dial_45_deg_total: f64,
     pub dial_45_deg_visual: f64,
+    pub hour_hand_deg: f64,
+    pub minute_hand_deg: f64,
+    pub second_hand_deg: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LightzoneResponse {
+    pub lightzone: Lightzone,
+    /// First minute of the next lightzone (a boundary minute belongs to the zone it starts)
+    pub next_transition: TimestampResponse,
+    pub next_lightzone: Lightzone,
 }
 
 #[derive(Debug, Serialize)]
@@ -39,13 +50,16 @@ pub struct TimeResponse {
     pub success: bool,
     pub message: String,
     pub minutes_advanced: u32,
+    pub paused: bool,
+    pub speed: f32,
     pub timestamp: TimestampResponse,
     pub clock_angles: ClockAnglesResponse,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct TickRequest {
-    pub minutes: u32,
+    /// Signed so negative deltas reach `TimeState::tick` and are reported, not rejected as bad JSON
+    pub minutes: i64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,6 +67,51 @@ pub struct CommandRequest {
     pub command: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ElapsedQuery {
+    pub day: usize,
+    pub minute: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ElapsedResponse {
+    pub since: TimestampResponse,
+    pub now: TimestampResponse,
+    /// Negative if `since` is in the future
+    pub minutes: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DueResponse {
+    pub fired: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleRequest {
+    pub day: usize,
+    pub minute: u16,
+    pub label: String,
+}
+
+/// Body for `/api/save` and `/api/load`. The file is always `DEFAULT_SAVE_PATH`;
+/// clients can't pick one, and a body naming a `path` is rejected.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PersistRequest {}
+
+pub const DEFAULT_SAVE_PATH: &str = "time_state.json";
+
+#[derive(Debug, Deserialize)]
+pub struct SpeedRequest {
+    pub multiplier: f32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ZoneQuery {
+    /// Client's offset from the reference clock, in minutes
+    pub offset_minutes: Option<i32>,
+}
+
 pub fn create_router(state: SharedTimeState) -> Router {
     Router::new()
         .route("/", get(index))
@@ -60,6 +119,15 @@ pub fn create_router(state: SharedTimeState) -> Router {
         .route("/api/tick", post(post_tick))
         .route("/api/command", post(post_command))
         .route("/api/actions", get(get_actions))
+        .route("/api/pause", post(post_pause))
+        .route("/api/resume", post(post_resume))
+        .route("/api/speed", post(post_speed))
+        .route("/api/schedule", post(post_schedule))
+        .route("/api/due", get(get_due))
+        .route("/api/elapsed", get(get_elapsed))
+        .route("/api/lightzone", get(get_lightzone))
+        .route("/api/save", post(post_save))
+        .route("/api/load", post(post_load))
         .nest_service("/static", ServeDir::new("static"))
         .with_state(state)
 }
@@ -68,17 +136,22 @@ async fn index() -> Html<&'static str> {
     Html(include_str!("../static/index.html"))
 }
 
-async fn get_state(State(state): State<SharedTimeState>) -> Result<Json<TimeResponse>, StatusCode> {
+async fn get_state(
+    State(state): State<SharedTimeState>,
+    Query(query): Query<ZoneQuery>,
+) -> Result<Json<TimeResponse>, StatusCode> {
     let guard = state
         .lock()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    Ok(Json(build_response(
+    let zone = TimeZone::from_offset_minutes(query.offset_minutes.unwrap_or(0));
+    Ok(Json(build_zoned_response(
         &guard,
         CommandOutcome {
             success: true,
             message: "ok".to_string(),
             minutes_advanced: 0,
         },
+        zone,
     )))
 }
 
@@ -112,6 +185,160 @@ async fn post_command(
     Ok(Json(build_response(&guard, outcome)))
 }
 
+async fn post_pause(
+    State(state): State<SharedTimeState>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let outcome = execute_command(&mut guard, "pause");
+    Ok(Json(build_response(&guard, outcome)))
+}
+
+async fn post_resume(
+    State(state): State<SharedTimeState>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let outcome = execute_command(&mut guard, "resume");
+    Ok(Json(build_response(&guard, outcome)))
+}
+
+async fn post_speed(
+    State(state): State<SharedTimeState>,
+    Json(req): Json<SpeedRequest>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let outcome = match guard.set_speed(req.multiplier) {
+        Ok(()) => CommandOutcome {
+            success: true,
+            message: format!("Clock speed set to {}x", req.multiplier),
+            minutes_advanced: 0,
+        },
+        Err(err) => CommandOutcome {
+            success: false,
+            message: format!("Failed to set speed: {}", err),
+            minutes_advanced: 0,
+        },
+    };
+    Ok(Json(build_response(&guard, outcome)))
+}
+
+async fn post_schedule(
+    State(state): State<SharedTimeState>,
+    Json(req): Json<ScheduleRequest>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let at = Timestamp::new(req.day, req.minute);
+    let outcome = CommandOutcome {
+        success: true,
+        message: format!(
+            "Scheduled '{}' for day {} {:02}:{:02}",
+            req.label,
+            at.day,
+            at.minute / 60,
+            at.minute % 60
+        ),
+        minutes_advanced: 0,
+    };
+    guard.schedule(at, req.label);
+    Ok(Json(build_response(&guard, outcome)))
+}
+
+async fn get_due(State(state): State<SharedTimeState>) -> Result<Json<DueResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(DueResponse {
+        fired: guard.take_fired(),
+    }))
+}
+
+async fn get_elapsed(
+    State(state): State<SharedTimeState>,
+    Query(query): Query<ElapsedQuery>,
+) -> Result<Json<ElapsedResponse>, StatusCode> {
+    let guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let since = Timestamp::new(query.day, query.minute);
+    let since_hhmm = format!("{:02}:{:02}", since.minute / 60, since.minute % 60);
+    Ok(Json(ElapsedResponse {
+        since: timestamp_response(since, since_hhmm),
+        now: timestamp_response(guard.timestamp(), guard.hhmm()),
+        minutes: guard.elapsed_since(since),
+    }))
+}
+
+async fn get_lightzone(
+    State(state): State<SharedTimeState>,
+) -> Result<Json<LightzoneResponse>, StatusCode> {
+    let guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let (at, next_lightzone) = guard.next_transition();
+    let hhmm = format!("{:02}:{:02}", at.minute / 60, at.minute % 60);
+    Ok(Json(LightzoneResponse {
+        lightzone: guard.current_lightzone(),
+        next_transition: timestamp_response(at, hhmm),
+        next_lightzone,
+    }))
+}
+
+async fn post_save(
+    State(state): State<SharedTimeState>,
+    Json(_): Json<PersistRequest>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let path = DEFAULT_SAVE_PATH;
+    let outcome = match guard.save(path) {
+        Ok(()) => CommandOutcome {
+            success: true,
+            message: format!("Saved time state to {}", path),
+            minutes_advanced: 0,
+        },
+        Err(err) => CommandOutcome {
+            success: false,
+            message: err.to_string(),
+            minutes_advanced: 0,
+        },
+    };
+    Ok(Json(build_response(&guard, outcome)))
+}
+
+async fn post_load(
+    State(state): State<SharedTimeState>,
+    Json(_): Json<PersistRequest>,
+) -> Result<Json<TimeResponse>, StatusCode> {
+    let mut guard = state
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let path = DEFAULT_SAVE_PATH;
+    let outcome = match TimeState::load(path) {
+        Ok(loaded) => {
+            *guard = loaded;
+            CommandOutcome {
+                success: true,
+                message: format!("Loaded time state from {}", path),
+                minutes_advanced: 0,
+            }
+        }
+        Err(err) => CommandOutcome {
+            success: false,
+            message: err.to_string(),
+            minutes_advanced: 0,
+        },
+    };
+    Ok(Json(build_response(&guard, outcome)))
+}
+
 async fn get_actions(
     State(state): State<SharedTimeState>,
 ) -> Result<Json<Vec<ActionCostResponse>>, StatusCode> {
@@ -131,14 +358,24 @@ async fn get_actions(
 }
 
 fn build_response(state: &TimeState, outcome: CommandOutcome) -> TimeResponse {
-    let timestamp = state.timestamp();
-    let angles = state.clock_angles();
+    build_zoned_response(state, outcome, TimeZone::UTC)
+}
+
+fn build_zoned_response(
+    state: &TimeState,
+    outcome: CommandOutcome,
+    zone: TimeZone,
+) -> TimeResponse {
+    let timestamp = state.local_timestamp(zone);
+    let angles = state.local_clock(zone);
 
     TimeResponse {
         success: outcome.success,
         message: outcome.message,
         minutes_advanced: outcome.minutes_advanced,
-        timestamp: timestamp_response(timestamp, state.hhmm()),
+        paused: state.is_paused(),
+        speed: state.speed(),
+        timestamp: timestamp_response(timestamp, state.local_hhmm(zone)),
         clock_angles: angles_response(angles),
     }
 }
@@ -157,6 +394,9 @@ fn angles_response(angles: ClockAngles) -> ClockAnglesResponse {
         hand_15_deg: angles.hand_15_deg,
         dial_45_deg_total: angles.dial_45_deg_total,
         dial_45_deg_visual: angles.dial_45_deg_visual,
+        hour_hand_deg: angles.hour_hand_deg,
+        minute_hand_deg: angles.minute_hand_deg,
+        second_hand_deg: angles.second_hand_deg,
     }
 }
 
@@ -166,6 +406,7 @@ mod tests {
     use axum::body::Body;
     use axum::http::{Method, Request};
     use http_body_util::BodyExt;
+    use std::collections::HashMap;
     use tower::util::ServiceExt;
 
     fn test_app() -> Router {
@@ -193,6 +434,27 @@ mod tests {
         assert!(body.contains("\"minute\":0"));
     }
 
+    #[tokio::test]
+    async fn get_state_in_offset_zone() {
+        let app = test_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/state?offset_minutes=-60")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"minute\":1380"));
+        assert!(body.contains("\"hhmm\":\"23:00\""));
+    }
+
     #[tokio::test]
     async fn post_tick_advances_time() {
         let app = test_app();
@@ -216,6 +478,207 @@ mod tests {
         assert!(body.contains("\"minute\":60"));
     }
 
+    #[tokio::test]
+    async fn post_tick_rejects_negative() {
+        let app = test_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/api/tick")
+                    .header("content-type", "application/json")
+                    .body(Body::from("{\"minutes\":-10}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"success\":false"));
+        assert!(body.contains("\"minute\":0"));
+    }
+
+    #[tokio::test]
+    async fn get_elapsed_reports_minutes_since() {
+        let app = create_router(Arc::new(Mutex::new(TimeState::new(1, 90, HashMap::new()))));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/elapsed?day=0&minute=1400")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["minutes"], 130);
+        assert_eq!(body["since"]["hhmm"], "23:20");
+        assert_eq!(body["now"]["day"], 1);
+    }
+
+    #[tokio::test]
+    async fn post_pause_freezes_ticks() {
+        let app = test_app();
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/api/pause")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/api/tick")
+                    .header("content-type", "application/json")
+                    .body(Body::from("{\"minutes\":60}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"paused\":true"));
+        assert!(body.contains("\"minutes_advanced\":0"));
+        assert!(body.contains("\"minute\":0"));
+    }
+
+    #[tokio::test]
+    async fn post_speed_rejects_negative() {
+        let app = test_app();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/api/speed")
+                    .header("content-type", "application/json")
+                    .body(Body::from("{\"multiplier\":-2.0}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"success\":false"));
+        assert!(body.contains("\"speed\":1.0"));
+    }
+
+    #[tokio::test]
+    async fn get_due_returns_events_fired_since_last_poll() {
+        let mut state = TimeState::default();
+        state.schedule(Timestamp::new(0, 10), "bell".to_string());
+        let app = create_router(Arc::new(Mutex::new(state)));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri("/api/tick")
+                    .header("content-type", "application/json")
+                    .body(Body::from("{\"minutes\":15}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let due = |app: Router| async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri("/api/due")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+
+        assert_eq!(due(app.clone()).await, "{\"fired\":[\"bell\"]}");
+        assert_eq!(due(app).await, "{\"fired\":[]}");
+    }
+
+    #[tokio::test]
+    async fn post_save_then_load_restores_clock() {
+        let shared = Arc::new(Mutex::new(TimeState::new(5, 700, HashMap::new())));
+        let app = create_router(shared.clone());
+
+        let post = |uri: &'static str, body: String| {
+            Request::builder()
+                .method(Method::POST)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let response = app
+            .clone()
+            .oneshot(post("/api/save", "{}".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        *shared.lock().unwrap() = TimeState::default();
+
+        let response = app
+            .oneshot(post("/api/load", "{}".to_string()))
+            .await
+            .unwrap();
+        std::fs::remove_file(DEFAULT_SAVE_PATH).unwrap();
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(body.contains("\"success\":true"));
+        assert!(body.contains("\"day\":5"));
+        assert!(body.contains("\"minute\":700"));
+    }
+
+    #[tokio::test]
+    async fn post_save_rejects_client_path() {
+        let path = std::env::temp_dir().join(format!("time_web_{}.json", std::process::id()));
+        let app = create_router(Arc::new(Mutex::new(TimeState::default())));
+
+        for uri in ["/api/save", "/api/load"] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(Method::POST)
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(format!("{{\"path\":{:?}}}", path)))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        }
+        assert!(!path.exists());
+    }
+
     #[tokio::test]
     async fn post_command_craft_totem() {
         let app = test_app();
@@ -262,6 +725,33 @@ mod tests {
         assert!(body.contains("\"minute\":0"));
     }
 
+    #[tokio::test]
+    async fn get_lightzone_reports_next_dawn() {
+        let app = create_router(Arc::new(Mutex::new(TimeState::new(
+            0,
+            1300,
+            HashMap::new(),
+        ))));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/lightzone")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["lightzone"], "NIGHT");
+        assert_eq!(body["next_lightzone"], "MORNING");
+        assert_eq!(body["next_transition"]["day"], 1);
+        assert_eq!(body["next_transition"]["hhmm"], "04:00");
+    }
+
     #[tokio::test]
     async fn actions_include_craft_totem() {
         let app = test_app();
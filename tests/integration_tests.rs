@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use Q::types::World;
 use Q::generation::{generate_world, initialize_world};
-use Q::io::{load_world, save_world};
+use Q::io::{export_land_png, export_world_png, load_world, save_world, world_path, IoError, VOID_COLOR};
 use Q::display::{print_land, print_world};
 
 #[test]
@@ -9,11 +9,12 @@ fn test_save_and_load_world() {
     let mut world = World {
         name: "TestSaveLoad".to_string(),
         terrain: HashMap::new(),
+        seed: 999,
     };
     initialize_world(&mut world, 999);
     
     // Save the world
-    save_world(&world).expect("Failed to save world");
+    save_world(&world, world_path(&world.name).unwrap()).expect("Failed to save world");
     
     // Load it back
     let loaded_world = load_world("TestSaveLoad.json").expect("Failed to load world");
@@ -40,11 +41,77 @@ fn test_save_and_load_world() {
     std::fs::remove_file("worlds/TestSaveLoad.json").ok();
 }
 
+#[test]
+fn test_save_world_to_explicit_path_round_trip() {
+    let mut world = World {
+        name: "ExplicitPath".to_string(),
+        terrain: HashMap::new(),
+        seed: 31,
+    };
+    generate_world(&mut world, 31, 0, 0, 1, 1);
+
+    let dir = std::env::temp_dir().join(format!("q_save_{}", std::process::id()));
+    let path = dir.join("nested").join("explicit.json");
+    save_world(&world, &path).expect("Failed to save world");
+
+    // The temp file is renamed into place rather than left behind
+    assert!(path.exists());
+    assert!(!dir.join("nested").join("explicit.json.tmp").exists());
+
+    let loaded = load_world(path.to_str().unwrap()).expect("Failed to load world");
+    assert_eq!(loaded, world);
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_loaded_world_extends_like_original_seed() {
+    let mut world = World {
+        name: "Extend".to_string(),
+        terrain: HashMap::new(),
+        seed: 4242,
+    };
+    generate_world(&mut world, 4242, -1, -1, 1, 1);
+
+    let path = std::env::temp_dir().join(format!("q_extend_{}.json", std::process::id()));
+    save_world(&world, &path).expect("Failed to save world");
+    let mut loaded = load_world(path.to_str().unwrap()).expect("Failed to load world");
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded.seed, 4242);
+
+    let created = loaded.extend_generated(-3, -2, 3, 2);
+    assert_eq!(created.len(), 7 * 5 - 3 * 3);
+    assert!(!created.contains(&(0, 0)));
+
+    let mut one_shot = World {
+        name: "Extend".to_string(),
+        terrain: HashMap::new(),
+        seed: 4242,
+    };
+    generate_world(&mut one_shot, 4242, -3, -2, 3, 2);
+    assert_eq!(loaded, one_shot);
+}
+
+#[test]
+fn test_load_world_error_variants() {
+    let dir = std::env::temp_dir().join(format!("q_load_errors_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let corrupt = dir.join("corrupt.json");
+    std::fs::write(&corrupt, r#"{ "name": "Broken", "terrain": "#).unwrap();
+
+    assert!(matches!(load_world(corrupt.to_str().unwrap()), Err(IoError::Deserialize(_))));
+    assert!(matches!(load_world(dir.join("missing.json").to_str().unwrap()), Err(IoError::Read(_))));
+    assert!(matches!(world_path(""), Err(IoError::InvalidName(_))));
+    assert!(matches!(world_path(".."), Err(IoError::InvalidName(_))));
+    assert_eq!(world_path("Example").unwrap(), std::path::Path::new("worlds/Example.json"));
+    std::fs::remove_dir_all(&dir).ok();
+}
+
 #[test]
 fn test_world_display_functions() {
     let mut world = World {
         name: "DisplayTest".to_string(),
         terrain: HashMap::new(),
+        seed: 123,
     };
     initialize_world(&mut world, 123);
     
@@ -61,6 +128,7 @@ fn test_large_world_generation() {
     let mut world = World {
         name: "LargeWorld".to_string(),
         terrain: HashMap::new(),
+        seed: 42,
     };
     
     // Generate a larger area
@@ -73,6 +141,8 @@ fn test_large_world_generation() {
     let mut has_meadow = false;
     let mut has_lake = false;
     let mut has_mountain = false;
+    let mut has_plains = false;
+    let mut has_desert = false;
     
     for land in world.terrain.values() {
         match land.center {
@@ -80,13 +150,54 @@ fn test_large_world_generation() {
             Q::types::Biome::Meadow => has_meadow = true,
             Q::types::Biome::Lake => has_lake = true,
             Q::types::Biome::Mountain => has_mountain = true,
+            Q::types::Biome::Plains => has_plains = true,
+            Q::types::Biome::Desert => has_desert = true,
         }
     }
     
     // Should have multiple biomes
-    let unique_biomes = [has_forest, has_meadow, has_lake, has_mountain]
+    let unique_biomes = [has_forest, has_meadow, has_lake, has_mountain, has_plains, has_desert]
         .iter()
         .filter(|&&b| b)
         .count();
     assert!(unique_biomes > 1, "Expected multiple biomes, found {}", unique_biomes);
 }
+
+#[test]
+fn test_export_land_and_world_png() {
+    let mut world = World {
+        name: "PngTest".to_string(),
+        terrain: HashMap::new(),
+        seed: 7,
+    };
+    generate_world(&mut world, 7, 0, 0, 1, 0);
+
+    let dir = std::env::temp_dir();
+    let land_path = dir.join(format!("q_land_{}.png", std::process::id()));
+    let land_path = land_path.to_str().unwrap();
+    export_land_png(&world.terrain[&(0, 0)], land_path, 3).expect("Failed to export land");
+
+    assert!(std::fs::metadata(land_path).unwrap().len() > 0);
+    let img = image::open(land_path).unwrap().to_rgb8();
+    assert_eq!(img.dimensions(), (24, 24));
+    std::fs::remove_file(land_path).ok();
+
+    // (2, 0) was never generated and should render as void
+    let world_path = dir.join(format!("q_world_{}.png", std::process::id()));
+    let world_path = world_path.to_str().unwrap();
+    export_world_png(&world, 0, 0, 2, 0, world_path, 2).expect("Failed to export world");
+
+    let img = image::open(world_path).unwrap().to_rgb8();
+    assert_eq!(img.dimensions(), (48, 16));
+    assert_eq!(img.get_pixel(40, 8).0, VOID_COLOR);
+    assert_ne!(img.get_pixel(0, 0).0, VOID_COLOR);
+    std::fs::remove_file(world_path).ok();
+
+    assert!(matches!(export_land_png(&world.terrain[&(0, 0)], land_path, 0), Err(IoError::InvalidExport(_))));
+    assert!(matches!(export_world_png(&world, 1, 0, 0, 0, world_path, 2), Err(IoError::InvalidExport(_))));
+    let missing_dir = dir.join("q_missing_dir").join("world.png");
+    assert!(matches!(
+        export_world_png(&world, 0, 0, 0, 0, missing_dir.to_str().unwrap(), 2),
+        Err(IoError::Image(_))
+    ));
+}